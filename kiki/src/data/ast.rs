@@ -9,30 +9,72 @@ pub enum FileItem {
     Struct(Struct),
     Enum(Enum),
     Terminal(TerminalEnum),
+    Precedence(PrecedenceDeclaration),
+    Lexer(Lexer),
+    Allow(Allow),
 }
 
 #[derive(Clone, Debug)]
 pub struct Struct {
+    pub doc: Vec<DocComment>,
     pub attributes: Vec<Attribute>,
     pub name: Ident,
+    /// If this is empty, the struct is not generic.
+    pub params: Vec<Ident>,
+    pub result_type: Option<Type>,
     pub fieldset: Fieldset,
+    pub action: Option<SemanticAction>,
 }
 
 #[derive(Clone, Debug)]
 pub struct Enum {
+    pub doc: Vec<DocComment>,
     pub attributes: Vec<Attribute>,
     pub name: Ident,
+    /// If this is empty, the enum is not generic.
+    pub params: Vec<Ident>,
+    pub result_type: Option<Type>,
     pub variants: Vec<EnumVariant>,
 }
 
 #[derive(Clone, Debug)]
 pub struct TerminalEnum {
+    pub doc: Vec<DocComment>,
     pub attributes: Vec<Attribute>,
-    pub name: Ident,
+    pub name: TerminalEnumName,
+    /// `terminal (Token, Position) { ... }`.
+    /// If this is `Some`, every token comes with a span
+    /// (i.e., a `std::ops::Range<Position>`).
+    pub span_position_type: Option<Type>,
     pub variants: Vec<TerminalEnumVariant>,
 }
 
-pub use crate::data::token::Attribute;
+#[derive(Clone, Debug)]
+pub enum TerminalEnumName {
+    /// `terminal Token { ... }`.
+    /// Kiki generates the enum.
+    Local(Ident),
+    /// `terminal extern crate::lexer::Token { ... }`.
+    /// The enum is defined outside the grammar,
+    /// so Kiki does not generate it.
+    /// The path is never empty.
+    Extern(Vec<Ident>),
+}
+
+impl TerminalEnumName {
+    /// Returns the identifier that the generated code
+    /// looks up in its own scope.
+    /// For a local enum, this is the enum name.
+    /// For an extern enum, this is the first segment of the path.
+    pub fn scope_ident(&self) -> &Ident {
+        match self {
+            TerminalEnumName::Local(ident) => ident,
+            TerminalEnumName::Extern(path) => &path[0],
+        }
+    }
+}
+
+pub use crate::data::token::{Attribute, DocComment, SemanticAction};
 
 #[derive(Clone, Debug)]
 pub enum Fieldset {
@@ -53,14 +95,6 @@ impl Fieldset {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-
-    pub fn get_symbol_ident(&self, i: usize) -> &IdentOrTerminalIdent {
-        match self {
-            Fieldset::Empty => panic!("Called Fieldset::get_symbol_ident on Fieldset::Empty"),
-            Fieldset::Named(named) => &named.fields[i].symbol,
-            Fieldset::Tuple(tuple) => tuple.fields[i].symbol(),
-        }
-    }
 }
 
 #[derive(Clone, Debug)]
@@ -75,8 +109,9 @@ impl NamedFieldset {
 }
 #[derive(Clone, Debug)]
 pub struct NamedField {
+    pub doc: Vec<DocComment>,
     pub name: IdentOrUnderscore,
-    pub symbol: IdentOrTerminalIdent,
+    pub symbol: FieldSymbol,
 }
 
 impl NamedField {
@@ -101,8 +136,8 @@ impl TupleFieldset {
 
 #[derive(Clone, Debug)]
 pub enum TupleField {
-    Used(IdentOrTerminalIdent),
-    Skipped(IdentOrTerminalIdent),
+    Used(FieldSymbol),
+    Skipped(FieldSymbol),
 }
 
 impl TupleField {
@@ -115,7 +150,7 @@ impl TupleField {
 }
 
 impl TupleField {
-    pub fn symbol(&self) -> &IdentOrTerminalIdent {
+    pub fn symbol(&self) -> &FieldSymbol {
         match self {
             TupleField::Used(symbol) => symbol,
             TupleField::Skipped(symbol) => symbol,
@@ -123,16 +158,135 @@ impl TupleField {
     }
 }
 
+#[derive(Clone, Debug)]
+pub enum FieldSymbol {
+    Plain(IdentOrTerminalIdent),
+    Repetition(Repetition),
+    Optional(Optional),
+    Call(FieldSymbolCall),
+    Instantiation(Instantiation),
+}
+
+/// A symbol followed by `*` or `+`.
+#[derive(Clone, Debug)]
+pub struct Repetition {
+    pub element: IdentOrTerminalIdent,
+    pub kind: RepetitionKind,
+    /// The position of the `*` or `+`.
+    pub operator_position: ByteIndex,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RepetitionKind {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+}
+
+/// A function-like field symbol, such as `sep(Expr, $Comma, trailing)`.
+///
+/// The callee and flags are not validated by the parser,
+/// so `callee` may be an unknown function name
+/// and `flags` may contain unknown flags.
+#[derive(Clone, Debug)]
+pub struct FieldSymbolCall {
+    pub callee: Ident,
+    pub element: IdentOrTerminalIdent,
+    pub separator: IdentOrTerminalIdent,
+    pub flags: Vec<Ident>,
+}
+
+/// A generic nonterminal applied to arguments, such as `List<Expr>`.
+///
+/// The parser does not check that `callee` is generic
+/// or that the number of arguments matches the number of parameters.
+#[derive(Clone, Debug)]
+pub struct Instantiation {
+    pub callee: Ident,
+    pub args: Vec<IdentOrTerminalIdent>,
+}
+
+/// A symbol followed by `?`.
+#[derive(Clone, Debug)]
+pub struct Optional {
+    pub element: IdentOrTerminalIdent,
+    /// The position of the `?`.
+    pub operator_position: ByteIndex,
+}
+
 #[derive(Clone, Debug)]
 pub struct EnumVariant {
+    pub doc: Vec<DocComment>,
     pub name: Ident,
     pub fieldset: Fieldset,
+    pub precedence: Option<TerminalIdent>,
+    pub action: Option<SemanticAction>,
 }
 
 #[derive(Clone, Debug)]
 pub struct TerminalEnumVariant {
+    pub doc: Vec<DocComment>,
     pub name: TerminalIdent,
     pub type_: Type,
+    /// The pattern (e.g., `= Ident(name, _)`) that
+    /// maps a variant of an extern terminal enum to this terminal.
+    ///
+    /// The parser allows patterns on local terminal enums too,
+    /// so validation must reject them there.
+    pub pattern: Option<TerminalPattern>,
+    /// If this variant was declared as a literal (e.g., `"(": ()`),
+    /// or was registered for a literal used in a rule,
+    /// this is the literal's value.
+    ///
+    /// The parser always sets this to `None`.
+    /// Validation sets it when it replaces the literal's
+    /// provisional name with a generated one.
+    pub literal: Option<String>,
+}
+
+/// A pattern on a variant of an extern terminal enum,
+/// such as `Ident(name, _)` or `Plus`.
+#[derive(Clone, Debug)]
+pub struct TerminalPattern {
+    pub variant: Ident,
+    /// If this is `None`, the variant is a unit variant.
+    /// Otherwise, each field is either bound to a name or ignored.
+    pub fields: Option<Vec<IdentOrUnderscore>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PrecedenceDeclaration {
+    pub associativity: Option<Ident>,
+    pub terminals: Vec<TerminalIdent>,
+}
+
+/// `lexer { ... }`.
+#[derive(Clone, Debug)]
+pub struct Lexer {
+    /// `lexer` is a contextual keyword,
+    /// so the parser accepts any identifier here.
+    /// Validation rejects identifiers other than `lexer`.
+    pub keyword: Ident,
+    pub rules: Vec<LexerRule>,
+}
+
+/// `allow(lint_name, ...)`.
+#[derive(Clone, Debug)]
+pub struct Allow {
+    /// `allow` is a contextual keyword,
+    /// so the parser accepts any identifier here.
+    /// Validation rejects identifiers other than `allow`.
+    pub keyword: Ident,
+    pub lints: Vec<Ident>,
+}
+
+/// `$Terminal = pattern` or `_ = pattern`.
+/// The latter is a skip rule.
+#[derive(Clone, Debug)]
+pub struct LexerRule {
+    pub name: TerminalIdentOrUnderscore,
+    pub pattern: LexerPattern,
 }
 
 #[derive(Clone, Debug)]
@@ -148,4 +302,19 @@ pub struct ComplexType {
     pub args: Vec<Type>,
 }
 
-pub use crate::data::cst::{Ident, IdentOrTerminalIdent, IdentOrUnderscore, TerminalIdent, Token};
+/// A symbol used in a field.
+///
+/// A literal (e.g., `"("`) is a `Terminal` whose name is
+/// the literal's provisional name
+/// (see `DollarlessTerminalName::literal`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdentOrTerminalIdent {
+    Ident(Ident),
+    Terminal(TerminalIdent),
+}
+
+pub use crate::data::cst::{
+    Ident, IdentOrUnderscore, LexerPattern, TerminalIdent, TerminalIdentOrUnderscore, Token,
+};
+pub use crate::data::token::StringLiteral;
+use crate::data::ByteIndex;
@@ -0,0 +1,102 @@
+use super::*;
+
+/// An input that demonstrates a table conflict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counterexample {
+    pub kind: CounterexampleKind,
+    /// For a unifying counterexample,
+    /// both examples have the same sentence,
+    /// but different derivations.
+    ///
+    /// For a non-unifying counterexample,
+    /// the examples are for the first and second conflicting items,
+    /// in the same order as `TableConflictErr::items`.
+    pub examples: (Example, Example),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CounterexampleKind {
+    /// The grammar is ambiguous:
+    /// a single sentence has two derivations.
+    Unifying,
+    /// No ambiguous sentence was found.
+    /// The two sentences agree up to (and including) the conflicting lookahead,
+    /// but the parser must take a different action for each.
+    /// This usually means the grammar needs more than one token of lookahead.
+    NonUnifying,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub sentence: Vec<DollarlessTerminalName>,
+    /// The number of terminals the parser has consumed
+    /// when it reaches the conflict.
+    /// The next terminal (if any) is the conflicting lookahead.
+    pub dot: usize,
+    /// The derivation of `sentence` from a start symbol.
+    pub derivation: Derivation,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Derivation {
+    Terminal(DollarlessTerminalName),
+    Rule {
+        /// The index of the rule in `validated_file::File::get_rules`.
+        rule_index: usize,
+        children: Vec<Derivation>,
+    },
+}
+
+impl Derivation {
+    /// Returns the terminals this derivation derives.
+    pub fn get_yield(&self) -> Vec<&DollarlessTerminalName> {
+        match self {
+            Derivation::Terminal(terminal) => vec![terminal],
+            Derivation::Rule { children, .. } => {
+                children.iter().flat_map(Derivation::get_yield).collect()
+            }
+        }
+    }
+}
+
+impl TableConflictErr {
+    /// Returns the example's sentence with a `•` at the conflict
+    /// (e.g., `$Num $Plus $Num • $Plus $Num`).
+    pub fn format_sentence(&self, example: &Example) -> String {
+        let mut words: Vec<String> = example
+            .sentence
+            .iter()
+            .map(|terminal| self.format_terminal(terminal))
+            .collect();
+        words.insert(example.dot, "•".to_owned());
+        words.join(" ")
+    }
+
+    /// Returns the derivation, with each rule written as
+    /// its constructor applied to its children
+    /// (e.g., `Expr::Add(Expr::Num($Num) $Plus Expr::Num($Num))`).
+    /// Helper rules are written as the sugar they implement
+    /// (e.g., `$Num*($Num*() $Num)`).
+    pub fn format_derivation(&self, derivation: &Derivation) -> String {
+        match derivation {
+            Derivation::Terminal(terminal) => self.format_terminal(terminal),
+            Derivation::Rule {
+                rule_index,
+                children,
+            } => {
+                let rule = self.file.get_rules().nth(*rule_index).unwrap();
+                let children: Vec<String> = children
+                    .iter()
+                    .map(|child| self.format_derivation(child))
+                    .collect();
+                let constructor_name = match rule.constructor_name {
+                    validated_file::ConstructorName::Helper { helper_name, .. } => {
+                        self.format_nonterminal(helper_name)
+                    }
+                    constructor_name => constructor_name.to_string(),
+                };
+                format!("{constructor_name}({})", children.join(" "))
+            }
+        }
+    }
+}
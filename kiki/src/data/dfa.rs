@@ -0,0 +1,44 @@
+use crate::data::*;
+
+/// A deterministic finite automaton that recognizes
+/// the rules of a `lexer` section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dfa {
+    /// The start state is always state `0`.
+    pub states: Vec<DfaState>,
+    /// The `i`th entry is the terminal produced by the lexer's `i`th rule,
+    /// or `None` if the `i`th rule is a skip rule.
+    pub rule_terminals: Vec<Option<DollarlessTerminalName>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DfaState {
+    /// The transitions are sorted by character,
+    /// and no two transitions overlap.
+    /// If no transition contains a character,
+    /// the automaton gets stuck on that character.
+    pub transitions: Vec<DfaTransition>,
+    /// If the text read so far matches one or more rules,
+    /// this is the index of the earliest such rule.
+    pub accepted_rule: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DfaTransition {
+    /// The first character that triggers this transition.
+    pub start: char,
+    /// The last character (inclusive) that triggers this transition.
+    pub end: char,
+    pub target: usize,
+}
+
+impl Dfa {
+    /// Returns the state reached from `state` by reading `c`, if any.
+    pub fn transition(&self, state: usize, c: char) -> Option<usize> {
+        self.states[state]
+            .transitions
+            .iter()
+            .find(|transition| transition.start <= c && c <= transition.end)
+            .map(|transition| transition.target)
+    }
+}
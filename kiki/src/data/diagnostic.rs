@@ -0,0 +1,640 @@
+use super::*;
+
+use std::fmt::{self, Display, Formatter, Write};
+use std::path::Path;
+
+/// Displays the error's message, without any source positions.
+/// To display a rustc-style diagnostic with
+/// file paths, line numbers, and source excerpts,
+/// use `KikiErr::display`.
+impl Display for KikiErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_diagnostic().message)
+    }
+}
+
+impl std::error::Error for KikiErr {}
+
+impl KikiErr {
+    /// Returns a value that displays the error as a rustc-style diagnostic.
+    /// `src` must be the grammar source that produced the error,
+    /// and `path` is the path printed in the diagnostic.
+    pub fn display<'a>(&'a self, src: &'a str, path: &'a Path) -> KikiErrDisplay<'a> {
+        KikiErrDisplay {
+            err: self,
+            src,
+            path,
+        }
+    }
+}
+
+pub struct KikiErrDisplay<'a> {
+    err: &'a KikiErr,
+    src: &'a str,
+    path: &'a Path,
+}
+
+impl Display for KikiErrDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let diagnostic = self.err.get_diagnostic();
+        write!(
+            f,
+            "{}",
+            diagnostic.render(self.src, &self.path.display().to_string())
+        )
+    }
+}
+
+struct Diagnostic {
+    message: String,
+    /// The first label is the primary label.
+    labels: Vec<Label>,
+    notes: Vec<String>,
+}
+
+struct Label {
+    start: ByteIndex,
+    /// If this is `None`, the label spans the token at `start`.
+    end: Option<ByteIndex>,
+    text: String,
+}
+
+impl Diagnostic {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            labels: vec![],
+            notes: vec![],
+        }
+    }
+
+    fn label(mut self, position: ByteIndex, text: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            start: position,
+            end: None,
+            text: text.into(),
+        });
+        self
+    }
+
+    fn span_label(mut self, start: ByteIndex, end: ByteIndex, text: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            start,
+            end: Some(end),
+            text: text.into(),
+        });
+        self
+    }
+
+    fn note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    fn render(&self, src: &str, path: &str) -> String {
+        let gutter_width = self
+            .labels
+            .iter()
+            .map(|label| label.start.to_line_and_column(src).line.to_string().len())
+            .max()
+            .unwrap_or(0);
+        let gutter = " ".repeat(gutter_width);
+
+        let mut out = format!("error: {}\n", self.message);
+
+        if self.labels.is_empty() {
+            writeln!(out, "{gutter}--> {path}").unwrap();
+        }
+
+        for (i, label) in self.labels.iter().enumerate() {
+            let is_primary = i == 0;
+            let (start, len) = get_highlighted_range(src, label);
+            let location = ByteIndex(start).to_line_and_column(src);
+            let arrow = if is_primary { "-->" } else { ":::" };
+            let line = get_line(src, start);
+            let indent: String = line
+                .chars()
+                .take(location.column - 1)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            let underline = (if is_primary { "^" } else { "-" }).repeat(len);
+            let line_number = location.line;
+
+            writeln!(out, "{gutter}{arrow} {path}:{location}").unwrap();
+            writeln!(out, "{gutter} |").unwrap();
+            writeln!(out, "{line_number:>gutter_width$} | {line}").unwrap();
+            writeln!(out, "{gutter} | {indent}{underline} {}", label.text).unwrap();
+        }
+
+        for note in &self.notes {
+            writeln!(out, "{gutter} = note: {note}").unwrap();
+        }
+
+        out
+    }
+}
+
+/// Returns the start (in bytes) and the length (in `char`s)
+/// of the part of the label's line to underline.
+/// The range never extends past the end of the line,
+/// and it is always at least one `char` long.
+fn get_highlighted_range(src: &str, label: &Label) -> (usize, usize) {
+    let mut start = label.start.0.min(src.len());
+    while !src.is_char_boundary(start) {
+        start -= 1;
+    }
+
+    // Terminal positions usually point past the `$`.
+    if label.end.is_none() && src[..start].ends_with('$') {
+        start -= "$".len();
+    }
+
+    let rest_of_line = src[start..].split('\n').next().unwrap_or("");
+    let len = match label.end {
+        Some(end) => {
+            let end = end.0.saturating_sub(start).min(rest_of_line.len());
+            rest_of_line
+                .char_indices()
+                .take_while(|(i, _)| *i < end)
+                .count()
+        }
+        None => get_token_len(rest_of_line),
+    };
+
+    (start, len.max(1))
+}
+
+/// Returns the length (in `char`s) of the token
+/// at the start of `s`.
+/// This is a heuristic, since errors only store start positions.
+fn get_token_len(s: &str) -> usize {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut chars = s.chars();
+    match chars.next() {
+        Some('$') => 1 + chars.take_while(|c| is_ident_char(*c)).count(),
+        Some('"') => {
+            let mut len = 1;
+            let mut is_escaped = false;
+            for c in chars {
+                len += 1;
+                match c {
+                    '\\' if !is_escaped => is_escaped = true,
+                    '"' if !is_escaped => return len,
+                    _ => is_escaped = false,
+                }
+            }
+            len
+        }
+        Some('r') if s[1..].starts_with(['"', '#']) => {
+            let hashes = &s[1..s[1..].find('"').map_or(1, |i| i + 1)];
+            let closing_delimiter = format!("\"{hashes}");
+            let opening_delimiter_len = "r\"".len() + hashes.len();
+            match s[opening_delimiter_len..].find(&closing_delimiter) {
+                Some(i) => s[..opening_delimiter_len + i + closing_delimiter.len()]
+                    .chars()
+                    .count(),
+                None => s.chars().count(),
+            }
+        }
+        Some('#') if s.starts_with("#[") => {
+            let mut depth = 0;
+            for (i, c) in s.chars().enumerate() {
+                match c {
+                    '[' => depth += 1,
+                    ']' if depth == 1 => return i + 1,
+                    ']' => depth -= 1,
+                    _ => {}
+                }
+            }
+            s.chars().count()
+        }
+        Some(c) if is_ident_char(c) => 1 + chars.take_while(|c| is_ident_char(*c)).count(),
+        Some('=') if s.starts_with("=>") => "=>".len(),
+        Some('/') if s.starts_with("/*") => "/*".len(),
+        _ => 1,
+    }
+}
+
+/// Returns the line containing the byte at `index`,
+/// without the line terminator.
+fn get_line(src: &str, index: usize) -> &str {
+    let line_start = src[..index].rfind('\n').map_or(0, |i| i + "\n".len());
+    let line_end = src[index..].find('\n').map_or(src.len(), |i| index + i);
+    src[line_start..line_end].trim_end_matches('\r')
+}
+
+impl KikiErr {
+    fn get_diagnostic(&self) -> Diagnostic {
+        match self {
+            KikiErr::Lex(position, Some(c)) => {
+                Diagnostic::new(format!("unexpected character {c:?}"))
+                    .label(*position, "unexpected character")
+            }
+            KikiErr::Lex(position, None) => {
+                Diagnostic::new("unexpected end of input").label(*position, "input ends here")
+            }
+            KikiErr::Parse(start, content, _) if content.is_empty() => {
+                Diagnostic::new("unexpected end of input").label(*start, "input ends here")
+            }
+            KikiErr::Parse(start, content, end) => Diagnostic::new(format!(
+                "unexpected token `{content}`"
+            ))
+            .span_label(*start, *end, "unexpected token"),
+            KikiErr::UnterminatedSemanticAction(position) => {
+                Diagnostic::new("unterminated semantic action")
+                    .label(*position, "semantic action starts here")
+                    .note("a semantic action ends at the first `;` outside brackets and strings")
+            }
+            KikiErr::UnterminatedBlockComment(position) => {
+                Diagnostic::new("unterminated block comment")
+                    .label(*position, "block comment starts here")
+            }
+            KikiErr::UnterminatedStringLiteral(position) => {
+                Diagnostic::new("unterminated string literal")
+                    .label(*position, "string literal starts here")
+            }
+            KikiErr::NoStartSymbol => Diagnostic::new("no start symbol was declared")
+                .note("declare one with `start Name`"),
+            KikiErr::StartSymbolRedeclared(name, first, second) => {
+                Diagnostic::new(format!("start symbol `{name}` is declared more than once"))
+                    .label(*second, "redeclared here")
+                    .label(*first, "first declared here")
+            }
+            KikiErr::ParseFnNameClash(fn_name, first_name, first, second_name, second) => {
+                Diagnostic::new(format!(
+                    "start symbols `{first_name}` and `{second_name}` would both generate `{fn_name}`"
+                ))
+                .label(*second, format!("`{second_name}` generates `{fn_name}`"))
+                .label(*first, format!("`{first_name}` also generates `{fn_name}`"))
+                .note("rename one of the start symbols")
+            }
+            KikiErr::NoTerminalEnum => Diagnostic::new("no terminal enum was declared")
+                .note("declare one with `terminal Name { ... }`"),
+            KikiErr::MultipleTerminalEnums(positions) => label_all(
+                Diagnostic::new("more than one terminal enum was declared"),
+                positions,
+            ),
+            KikiErr::SymbolOrTerminalEnumNameFirstLetterNotUppercase(position) => {
+                Diagnostic::new("name must start with an uppercase letter")
+                    .label(*position, "does not start with an uppercase letter")
+            }
+            KikiErr::FieldFirstLetterNotLowercase(position) => {
+                Diagnostic::new("field name must start with a lowercase letter")
+                    .label(*position, "does not start with a lowercase letter")
+            }
+            KikiErr::NameClash(name, first, second) => {
+                Diagnostic::new(format!("the name `{name}` is defined more than once"))
+                    .label(*second, "redefined here")
+                    .label(*first, "first defined here")
+            }
+            KikiErr::NonterminalEnumVariantNameClash(name, first, second) => {
+                Diagnostic::new(format!("the variant name `{name}` is used more than once"))
+                    .label(*second, "reused here")
+                    .label(*first, "first used here")
+            }
+            KikiErr::NonterminalEnumVariantSymbolSequenceClash(symbols, first, second) => {
+                let symbols = symbols
+                    .iter()
+                    .map(format_symbol)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                Diagnostic::new(format!(
+                    "two variants have the same symbol sequence `{symbols}`"
+                ))
+                .label(*second, "second variant")
+                .label(*first, "first variant")
+            }
+            KikiErr::UndefinedNonterminal(name, position) => {
+                Diagnostic::new(format!("undefined nonterminal `{name}`"))
+                    .label(*position, "not defined")
+            }
+            KikiErr::UndefinedTerminal(name, position) => {
+                Diagnostic::new(format!("undefined terminal `${name}`"))
+                    .label(*position, "not a variant of the terminal enum")
+            }
+            KikiErr::UnknownAssociativity(name, position) => {
+                Diagnostic::new(format!("unknown associativity `{name}`"))
+                    .label(*position, "unknown associativity")
+                    .note("expected `left`, `right`, or `nonassoc`")
+            }
+            KikiErr::TerminalPrecedenceRedeclared(name, first, second) => Diagnostic::new(format!(
+                "the precedence of `{}` is declared more than once",
+                format_terminal(name)
+            ))
+            .label(*second, "redeclared here")
+            .label(*first, "first declared here"),
+            KikiErr::PrecedenceOverrideTerminalHasNoPrecedence(name, position) => {
+                Diagnostic::new(format!(
+                    "`prec` override terminal `{}` has no precedence",
+                    format_terminal(name)
+                ))
+                .label(*position, "has no precedence")
+                .note("declare its precedence in a `precedence` declaration")
+            }
+            KikiErr::UnknownFieldSymbolFunction(name, position) => {
+                Diagnostic::new(format!("unknown function `{name}`"))
+                    .label(*position, "unknown function")
+                    .note("the only built-in function is `sep`")
+            }
+            KikiErr::UnknownSeparatedListFlag(name, position) => {
+                Diagnostic::new(format!("unknown separated list flag `{name}`"))
+                    .label(*position, "unknown flag")
+            }
+            KikiErr::SeparatedListFlagRepeated(name, first, second) => {
+                Diagnostic::new(format!("separated list flag `{name}` is repeated"))
+                    .label(*second, "repeated here")
+                    .label(*first, "first used here")
+            }
+            KikiErr::MissingSemanticAction(name, position) => {
+                Diagnostic::new(format!("rule `{name}` has no semantic action"))
+                    .label(*position, "expected `=> ...;`")
+                    .note("every rule of a nonterminal with a result type needs a semantic action")
+            }
+            KikiErr::SemanticActionWithoutResultType(name, position) => Diagnostic::new(format!(
+                "rule `{name}` has a semantic action, but its nonterminal has no result type"
+            ))
+            .label(*position, "unexpected semantic action"),
+            KikiErr::OuterAttributeOnNonterminalWithResultType(name, position) => Diagnostic::new(
+                format!("`{name}` has a result type, so it cannot have outer attributes"),
+            )
+            .label(*position, "no type is generated for this nonterminal"),
+            KikiErr::GenericParamNameClash(name, first, second) => Diagnostic::new(format!(
+                "generic parameter `{name}` is declared more than once"
+            ))
+            .label(*second, "redeclared here")
+            .label(*first, "first declared here"),
+            KikiErr::GenericNonterminalUsedWithoutArgs(name, position) => Diagnostic::new(format!(
+                "generic nonterminal `{name}` is used without arguments"
+            ))
+            .label(*position, format!("expected `{name}<...>`")),
+            KikiErr::NonterminalIsNotGeneric(name, position) => {
+                Diagnostic::new(format!("nonterminal `{name}` is not generic"))
+                    .label(*position, "cannot take arguments")
+            }
+            KikiErr::GenericArgCountMismatch(name, param_count, arg_count, position) => {
+                let was_or_were = if *arg_count == 1 { "was" } else { "were" };
+                Diagnostic::new(format!(
+                    "`{name}` takes {}, but {arg_count} {was_or_were} given",
+                    pluralize_arguments(*param_count)
+                ))
+                .label(
+                    *position,
+                    format!("expected {}", pluralize_arguments(*param_count)),
+                )
+            }
+            KikiErr::InGenericInstantiation(err, name, args, position) => {
+                let args: Vec<String> = args.iter().map(format_symbol).collect();
+                err.get_diagnostic().label(
+                    *position,
+                    format!("in `{name}<{}>`, instantiated here", args.join(", ")),
+                )
+            }
+            KikiErr::OuterAttributeOnExternTerminalEnum(position) => {
+                Diagnostic::new("an extern terminal enum cannot have outer attributes")
+                    .label(*position, "Kiki does not generate this enum")
+            }
+            KikiErr::ExternTerminalMissingPattern(name, position) => Diagnostic::new(format!(
+                "extern terminal `{}` has no pattern",
+                format_terminal(name)
+            ))
+            .label(*position, "expected `= Pattern`"),
+            KikiErr::TerminalPatternOnLocalTerminalEnum(name, position) => {
+                Diagnostic::new(format!(
+                    "terminal `{}` has a pattern, but its terminal enum is not extern",
+                    format_terminal(name)
+                ))
+                .label(*position, "unexpected pattern")
+            }
+            KikiErr::TerminalPatternBindsMultipleFields(name, position) => {
+                Diagnostic::new(format!(
+                    "the pattern of terminal `{}` binds more than one field",
+                    format_terminal(name)
+                ))
+                .label(*position, "second binding")
+            }
+            KikiErr::TerminalPatternBindsNoField(name, position) => Diagnostic::new(format!(
+                "the pattern of terminal `{}` binds no field",
+                format_terminal(name)
+            ))
+            .label(*position, "expected a binding")
+            .note("only terminals of type `()` may have patterns without bindings"),
+            KikiErr::ExternTerminalVariantRepeated(name, first, second) => Diagnostic::new(
+                format!("extern variant `{name}` is matched by more than one terminal"),
+            )
+            .label(*second, "matched again here")
+            .label(*first, "first matched here"),
+            KikiErr::SpanFieldNameClash(position) => {
+                Diagnostic::new("field name `span` is reserved")
+                    .label(*position, "clashes with the generated `span` field")
+                    .note("the terminal enum is spanned, so every node has a `span` field")
+            }
+            KikiErr::MultipleLexers(positions) => label_all(
+                Diagnostic::new("more than one lexer was declared"),
+                positions,
+            ),
+            KikiErr::LexerWithExternTerminalEnum(position) => {
+                Diagnostic::new("a grammar with an extern terminal enum cannot have a lexer")
+                    .label(*position, "lexer declared here")
+            }
+            KikiErr::LexerSpanPositionTypeNotUsize(position) => {
+                Diagnostic::new("a lexer requires a span position type of `usize`")
+                    .label(*position, "lexer declared here")
+                    .note("the generated lexer produces byte offsets")
+            }
+            KikiErr::LexerTerminalRedeclared(name, first, second) => Diagnostic::new(format!(
+                "terminal `{}` has more than one lexer rule",
+                format_terminal(name)
+            ))
+            .label(*second, "redeclared here")
+            .label(*first, "first declared here"),
+            KikiErr::InvalidRegex(position, message) => {
+                Diagnostic::new(format!("invalid regex: {message}")).label(*position, message)
+            }
+            KikiErr::LexerPatternMatchesEmptyString(position) => {
+                Diagnostic::new("lexer pattern matches the empty string")
+                    .label(*position, "matches the empty string")
+            }
+            KikiErr::EmptyLiteralTerminal(position) => {
+                Diagnostic::new("literal terminals cannot be empty")
+                    .label(*position, "empty literal")
+            }
+            KikiErr::ExternLiteralTerminalNotDeclared(literal, position) => Diagnostic::new(
+                format!("literal terminal {literal} is not declared in the extern terminal enum"),
+            )
+            .label(*position, "undeclared literal")
+            .note(format!(
+                "declare it with a pattern (e.g., `{literal}: () = Variant`)"
+            )),
+            KikiErr::NonproductiveNonterminal(name, position) => {
+                Diagnostic::new(format!("nonterminal `{name}` derives no finite input"))
+                    .label(
+                        *position,
+                        format!("every rule eventually requires another `{name}`"),
+                    )
+                    .note("add a rule that does not (directly or indirectly) refer to itself")
+            }
+            KikiErr::EmptyStartLanguage(name, position) => {
+                Diagnostic::new(format!("start symbol `{name}` derives no finite input"))
+                    .label(*position, "the parser would reject every input")
+            }
+            KikiErr::UnitCycle(cycle) => {
+                let names = cycle
+                    .iter()
+                    .chain(cycle.first())
+                    .map(|(name, _)| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                let mut diagnostic =
+                    Diagnostic::new(format!("nonterminals form a cycle of unit rules: {names}"));
+                for (i, (name, position)) in cycle.iter().enumerate() {
+                    let (next, _) = &cycle[(i + 1) % cycle.len()];
+                    diagnostic = diagnostic.label(*position, format!("`{name}` -> `{next}`"));
+                }
+                diagnostic.note("the grammar is ambiguous, since the cycle can repeat forever")
+            }
+            KikiErr::UnknownLint(name, position) => {
+                Diagnostic::new(format!("unknown lint `{name}`"))
+                    .label(*position, "unknown lint")
+                    .note(format!(
+                        "expected one of {}",
+                        KikiWarning::LINT_NAMES
+                            .iter()
+                            .map(|name| format!("`{name}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+            }
+            KikiErr::TableConflict(conflict) => conflict.get_diagnostic(),
+        }
+    }
+}
+
+impl TableConflictErr {
+    fn get_diagnostic(&self) -> Diagnostic {
+        let machine::StateIndex(state_index) = self.state_index;
+        let lookahead = match self.lookahead {
+            machine::Lookahead::Terminal(_) => format!("`{}`", self.format_lookahead()),
+            machine::Lookahead::Eof => self.format_lookahead(),
+        };
+        let mut diagnostic = Diagnostic::new(format!(
+            "{} conflict on {lookahead} in parser state {state_index}",
+            self.kind
+        ));
+
+        let first_action = match self.kind {
+            ConflictKind::ShiftReduce => "shift",
+            ConflictKind::ReduceReduce => "reduce",
+        };
+        let (first_position, second_position) = self.rule_positions();
+        for (item, position, action) in [
+            (&self.items.1, second_position, "reduce"),
+            (&self.items.0, first_position, first_action),
+        ] {
+            let text = format!("{action} `{}`", self.format_item(item));
+            diagnostic = match position {
+                Some(position) => diagnostic.label(position, text),
+                None => diagnostic.note(format!("{text} (the augmented start rule)")),
+            };
+        }
+
+        diagnostic = if self.prefix.is_empty() {
+            diagnostic.note(format!(
+                "the conflict occurs at the start of the input, when the next token is {lookahead}"
+            ))
+        } else {
+            diagnostic.note(format!(
+                "the conflict occurs after the input `{}`, when the next token is {lookahead}",
+                self.format_prefix()
+            ))
+        };
+
+        if let Some(counterexample) = &self.counterexample {
+            let (first_example, second_example) = &counterexample.examples;
+            diagnostic = match counterexample.kind {
+                CounterexampleKind::Unifying => diagnostic
+                    .note(format!(
+                        "the grammar is ambiguous: `{}` has two derivations",
+                        self.format_sentence(first_example)
+                    ))
+                    .note(format!(
+                        "first derivation: `{}`",
+                        self.format_derivation(&first_example.derivation)
+                    ))
+                    .note(format!(
+                        "second derivation: `{}`",
+                        self.format_derivation(&second_example.derivation)
+                    )),
+                CounterexampleKind::NonUnifying => diagnostic
+                    .note(format!(
+                        "example of the {first_action}: `{}`, derived as `{}`",
+                        self.format_sentence(first_example),
+                        self.format_derivation(&first_example.derivation)
+                    ))
+                    .note(format!(
+                        "example of the reduce: `{}`, derived as `{}`",
+                        self.format_sentence(second_example),
+                        self.format_derivation(&second_example.derivation)
+                    )),
+            };
+        }
+
+        if let Some(lalr_merge) = &self.lalr_merge {
+            diagnostic = diagnostic.note("this conflict exists only because of LALR state merging");
+            for (prefixes, item) in [
+                (&lalr_merge.first_prefixes, &self.items.0),
+                (&lalr_merge.second_prefixes, &self.items.1),
+            ] {
+                for prefix in prefixes {
+                    diagnostic = diagnostic.note(format!(
+                        "the LR(1) state after `{}` reduces `{}` on {lookahead}",
+                        self.format_terminals(prefix),
+                        self.format_item(item)
+                    ));
+                }
+            }
+            diagnostic = diagnostic.note(
+                "LALR(1) merges these states, since they have the same items apart from lookaheads",
+            ).note(
+                "`ParserAlgorithm::MinimalLr1` only merges states when the merge causes no conflict",
+            );
+        }
+
+        match self.kind {
+            ConflictKind::ShiftReduce => diagnostic
+                .note("a `precedence` declaration or a `prec` override may resolve this conflict"),
+            ConflictKind::ReduceReduce => diagnostic,
+        }
+    }
+}
+
+fn pluralize_arguments(count: usize) -> String {
+    if count == 1 {
+        "1 argument".to_owned()
+    } else {
+        format!("{count} arguments")
+    }
+}
+
+fn label_all(mut diagnostic: Diagnostic, positions: &[ByteIndex]) -> Diagnostic {
+    for position in positions {
+        diagnostic = diagnostic.label(*position, "declared here");
+    }
+    diagnostic
+}
+
+fn format_symbol(symbol: &Symbol) -> String {
+    match symbol {
+        Symbol::Terminal(name) => format_terminal(name),
+        Symbol::Nonterminal(name) => name.clone(),
+    }
+}
+
+fn format_terminal(name: &DollarlessTerminalName) -> String {
+    if let Some(value) = name.literal_value() {
+        format!("{value:?}")
+    } else if name.is_error() {
+        name.to_string()
+    } else {
+        format!("${name}")
+    }
+}
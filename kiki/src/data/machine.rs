@@ -2,24 +2,45 @@ use crate::data::{table::Quasiterminal, *};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Machine {
-    pub start: StateIndex,
+    /// The start state of each start symbol,
+    /// in the same order as `File::starts`.
+    pub starts: Vec<StateIndex>,
     pub states: Oset<State>,
     pub transitions: Oset<Transition>,
+    /// The symbol and destination of each transition,
+    /// grouped by the state the transition leaves.
+    /// This is derived from `transitions`,
+    /// so we can find a state's transitions
+    /// without scanning every transition in the machine.
+    successors: Vec<Vec<(Symbol, StateIndex)>>,
 }
 
 impl Machine {
+    pub fn new(
+        starts: Vec<StateIndex>,
+        states: Oset<State>,
+        transitions: Oset<Transition>,
+    ) -> Self {
+        let mut successors = vec![vec![]; states.len()];
+        for transition in &transitions {
+            successors[transition.from.0].push((transition.symbol.clone(), transition.to));
+        }
+        Machine {
+            starts,
+            states,
+            transitions,
+            successors,
+        }
+    }
+
     pub fn get_shift_dest(
         &self,
         start: StateIndex,
         terminal: &DollarlessTerminalName,
     ) -> Option<StateIndex> {
-        self.transitions.iter().find_map(|t| {
-            if t.from == start && t.symbol == *terminal {
-                Some(t.to)
-            } else {
-                None
-            }
-        })
+        self.successors[start.0]
+            .iter()
+            .find_map(|(symbol, to)| if symbol == terminal { Some(*to) } else { None })
     }
 }
 
@@ -49,7 +70,9 @@ pub struct StateItem {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RuleIndex {
     Original(usize),
-    Augmented,
+    /// The augmented rule `S' -> S` for the start symbol
+    /// at the given index of `File::starts`.
+    Augmented(usize),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -59,7 +82,7 @@ pub enum Lookahead {
 }
 
 impl Lookahead {
-    pub fn as_quasiterminal(&self) -> Quasiterminal {
+    pub fn as_quasiterminal(&self) -> Quasiterminal<'_> {
         match self {
             Lookahead::Terminal(t) => Quasiterminal::Terminal(t),
             Lookahead::Eof => Quasiterminal::Eof,
@@ -76,3 +99,25 @@ pub struct Transition {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct StateIndex(pub usize);
+
+/// The states of the canonical LR(1) machine
+/// that LALR(1) merged into the states of a machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LalrMergeRecord {
+    /// The start state of each start symbol,
+    /// in the same order as `File::starts`.
+    pub lr1_starts: Vec<StateIndex>,
+    pub lr1_states: Vec<MergedState>,
+    pub lr1_transitions: Oset<Transition>,
+}
+
+/// A state of the canonical LR(1) machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergedState {
+    /// The LALR(1) state that this state was merged into.
+    pub merged_into: StateIndex,
+    /// The items whose dot is at the end of their rule.
+    /// The other items are irrelevant to reduce/reduce conflicts,
+    /// so we don't record them.
+    pub reductions: Oset<StateItem>,
+}
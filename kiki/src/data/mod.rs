@@ -1,14 +1,22 @@
 pub mod ast;
+pub mod counterexample;
 pub mod cst;
+pub mod dfa;
+pub mod diagnostic;
 pub mod index_updater;
 pub mod machine;
+pub mod options;
 pub mod oset;
+pub mod regex;
 pub mod table;
 pub mod token;
 pub mod unnormalized_machine;
 pub mod validated_file;
 
+pub use counterexample::*;
+pub use diagnostic::*;
 pub use index_updater::*;
+pub use options::*;
 pub use oset::*;
 
 #[derive(Debug)]
@@ -22,8 +30,19 @@ pub enum KikiErr {
         Option<char>,
     ),
     Parse(ByteIndex, String, ByteIndex),
+    /// The position is the position of the `=>`.
+    UnterminatedSemanticAction(ByteIndex),
+    /// The position is the position of the outermost unclosed `/*`.
+    UnterminatedBlockComment(ByteIndex),
+    /// The position is the position of the opening `"`
+    /// (or of the `r`, for a regex literal).
+    UnterminatedStringLiteral(ByteIndex),
     NoStartSymbol,
-    MultipleStartSymbols(Vec<ByteIndex>),
+    StartSymbolRedeclared(String, ByteIndex, ByteIndex),
+    /// Two start symbols would get public parse functions with the same name.
+    /// The fields are the function name,
+    /// and the name and position of each of the two start symbols.
+    ParseFnNameClash(String, String, ByteIndex, String, ByteIndex),
     NoTerminalEnum,
     MultipleTerminalEnums(Vec<ByteIndex>),
     SymbolOrTerminalEnumNameFirstLetterNotUppercase(ByteIndex),
@@ -33,22 +52,380 @@ pub enum KikiErr {
     NonterminalEnumVariantSymbolSequenceClash(Vec<Symbol>, ByteIndex, ByteIndex),
     UndefinedNonterminal(String, ByteIndex),
     UndefinedTerminal(DollarlessTerminalName, ByteIndex),
+    UnknownAssociativity(String, ByteIndex),
+    TerminalPrecedenceRedeclared(DollarlessTerminalName, ByteIndex, ByteIndex),
+    PrecedenceOverrideTerminalHasNoPrecedence(DollarlessTerminalName, ByteIndex),
+    UnknownFieldSymbolFunction(String, ByteIndex),
+    UnknownSeparatedListFlag(String, ByteIndex),
+    SeparatedListFlagRepeated(String, ByteIndex, ByteIndex),
+    /// A rule of a nonterminal with a declared result type
+    /// does not have a semantic action.
+    /// The string is the rule's constructor name (e.g., `Expr::Add`),
+    /// and the position is the position of the struct or variant name.
+    MissingSemanticAction(String, ByteIndex),
+    /// A rule of a nonterminal _without_ a declared result type
+    /// has a semantic action.
+    /// The position is the position of the `=>`.
+    SemanticActionWithoutResultType(String, ByteIndex),
+    /// A nonterminal with a declared result type has outer attributes.
+    /// Since no type is generated for such a nonterminal,
+    /// the attributes would have nothing to apply to.
+    OuterAttributeOnNonterminalWithResultType(String, ByteIndex),
+    GenericParamNameClash(String, ByteIndex, ByteIndex),
+    /// A generic nonterminal is used as a plain symbol
+    /// (e.g., `List` instead of `List<Expr>`).
+    GenericNonterminalUsedWithoutArgs(String, ByteIndex),
+    /// A non-generic nonterminal is applied to arguments.
+    NonterminalIsNotGeneric(String, ByteIndex),
+    /// The fields are the generic nonterminal's name,
+    /// the number of parameters it declares,
+    /// the number of arguments it was given,
+    /// and the position of the callee at the use site.
+    GenericArgCountMismatch(String, usize, usize, ByteIndex),
+    /// The boxed error was raised while validating
+    /// an instantiation of a generic nonterminal
+    /// (e.g., `List<Expr>`).
+    /// The boxed error points into the generic nonterminal's body.
+    /// The remaining fields are the generic nonterminal's name,
+    /// the arguments,
+    /// and the position of the callee at the use site.
+    InGenericInstantiation(Box<KikiErr>, String, Vec<Symbol>, ByteIndex),
+    /// An extern terminal enum has outer attributes.
+    /// Since Kiki does not generate the enum,
+    /// the attributes would have nothing to apply to.
+    OuterAttributeOnExternTerminalEnum(ByteIndex),
+    /// A variant of an extern terminal enum has no `= Pattern`.
+    ExternTerminalMissingPattern(DollarlessTerminalName, ByteIndex),
+    /// A variant of a local (i.e., non-extern) terminal enum has a `= Pattern`.
+    /// The position is the position of the pattern.
+    TerminalPatternOnLocalTerminalEnum(DollarlessTerminalName, ByteIndex),
+    /// A terminal pattern binds more than one field.
+    /// The position is the position of the second binding.
+    TerminalPatternBindsMultipleFields(DollarlessTerminalName, ByteIndex),
+    /// A terminal pattern binds no field,
+    /// but the terminal's type is not `()`.
+    /// The position is the position of the pattern.
+    TerminalPatternBindsNoField(DollarlessTerminalName, ByteIndex),
+    /// Two terminal patterns match the same variant of the extern enum.
+    ExternTerminalVariantRepeated(String, ByteIndex, ByteIndex),
+    /// The terminal enum is spanned,
+    /// so every generated struct and enum variant has a `span` field.
+    /// However, a named field is also called `span`.
+    SpanFieldNameClash(ByteIndex),
+    /// The positions are the positions of the `lexer` keywords.
+    MultipleLexers(Vec<ByteIndex>),
+    /// A grammar with an extern terminal enum has a `lexer` section.
+    /// The lexer cannot construct tokens of a type
+    /// that Kiki does not generate.
+    /// The position is the position of the `lexer` keyword.
+    LexerWithExternTerminalEnum(ByteIndex),
+    /// The terminal enum is spanned, and it has a `lexer` section,
+    /// but the span position type is not `usize`.
+    /// The generated lexer always produces byte offsets.
+    /// The position is the position of the `lexer` keyword.
+    LexerSpanPositionTypeNotUsize(ByteIndex),
+    /// Two lexer rules produce the same terminal.
+    LexerTerminalRedeclared(DollarlessTerminalName, ByteIndex, ByteIndex),
+    /// The position is the position of the offending part of the regex.
+    /// The string describes the problem.
+    InvalidRegex(ByteIndex, String),
+    /// The lexer would loop forever if it matched the empty string.
+    /// The position is the position of the pattern.
+    LexerPatternMatchesEmptyString(ByteIndex),
+    /// A literal terminal is empty (i.e., `""`).
+    /// The position is the position of the literal.
+    EmptyLiteralTerminal(ByteIndex),
+    /// A literal terminal is used in a rule,
+    /// but the terminal enum is extern,
+    /// and it does not declare the literal (e.g., `"(": () = LParen`).
+    /// Kiki cannot know which extern variant the literal corresponds to.
+    /// The string is the literal, including the quotes.
+    /// The position is the position of the first use of the literal.
+    ExternLiteralTerminalNotDeclared(String, ByteIndex),
+    /// The nonterminal derives no finite string of terminals
+    /// (e.g., `struct A { a: A }`),
+    /// so its generated type cannot be constructed.
+    /// The position is the position of the nonterminal's name.
+    NonproductiveNonterminal(String, ByteIndex),
+    /// The start symbol derives no finite string of terminals,
+    /// so the parser would reject every input.
+    /// The position is the position of the start symbol's name
+    /// in its struct or enum declaration.
+    EmptyStartLanguage(String, ByteIndex),
+    /// The nonterminals form a cycle of unit rules
+    /// (i.e., rules with exactly one symbol, which is a nonterminal),
+    /// such as `enum A { B(B) ... }` and `enum B { A(A) ... }`.
+    /// Such a grammar is ambiguous,
+    /// since the cycle can be repeated any number of times.
+    /// Each entry is a nonterminal in the cycle,
+    /// along with the position of its unit rule
+    /// that leads to the next entry
+    /// (the last entry leads back to the first).
+    UnitCycle(Vec<(String, ByteIndex)>),
+    /// An `allow(...)` declaration names a lint that does not exist.
+    /// The position is the position of the lint name.
+    UnknownLint(String, ByteIndex),
     TableConflict(Box<TableConflictErr>),
 }
 
+/// A problem that does not prevent Kiki from generating a parser,
+/// but probably indicates a mistake in the grammar.
+///
+/// Every warning belongs to a lint (see `KikiWarning::lint_name`).
+/// To allow a lint, declare `allow(lint_name)` in the grammar.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KikiWarning {
+    /// No rule uses the terminal.
+    /// (Using it in a `prec` override counts.)
+    /// The position is the position of the terminal's declaration.
+    UnusedTerminal(DollarlessTerminalName, ByteIndex),
+    /// The nonterminal cannot be reached from any start symbol,
+    /// so it can never be parsed.
+    /// The position is the position of the nonterminal's name.
+    UnreachableNonterminal(String, ByteIndex),
+    /// A field skipped with `_` holds a terminal
+    /// whose type is not `()`,
+    /// so the terminal's value is silently discarded.
+    /// The position is the position of the terminal in the field.
+    /// If the field is sugared (e.g., `_: $Num*` or `_: $Num?`),
+    /// the position is the position of the operator instead.
+    SkippedTerminalValue(DollarlessTerminalName, ByteIndex),
+    /// The nonterminal is a right-recursive list
+    /// (e.g., `enum List { Nil Cons(Item List) }`).
+    /// The parser cannot reduce any element of such a list
+    /// until it has shifted the whole list,
+    /// so the parse stack grows with the list's length.
+    /// A left-recursive list (or `Item*`) uses constant stack space.
+    /// The position is the position of the recursive rule.
+    RightRecursiveList(String, ByteIndex),
+}
+
+impl KikiWarning {
+    /// The names of all lints.
+    /// `allow(...)` declarations may only name these lints.
+    pub const LINT_NAMES: [&'static str; 4] = [
+        "unused_terminal",
+        "unreachable_nonterminal",
+        "skipped_terminal_value",
+        "right_recursive_list",
+    ];
+
+    pub fn lint_name(&self) -> &'static str {
+        match self {
+            KikiWarning::UnusedTerminal(_, _) => "unused_terminal",
+            KikiWarning::UnreachableNonterminal(_, _) => "unreachable_nonterminal",
+            KikiWarning::SkippedTerminalValue(_, _) => "skipped_terminal_value",
+            KikiWarning::RightRecursiveList(_, _) => "right_recursive_list",
+        }
+    }
+
+    pub fn position(&self) -> ByteIndex {
+        match self {
+            KikiWarning::UnusedTerminal(_, position)
+            | KikiWarning::UnreachableNonterminal(_, position)
+            | KikiWarning::SkippedTerminalValue(_, position)
+            | KikiWarning::RightRecursiveList(_, position) => *position,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct TableConflictErr {
+    pub kind: ConflictKind,
     pub state_index: machine::StateIndex,
+    /// For a shift/reduce conflict, the first item is the shift item,
+    /// and the second item is the reduce item.
     pub items: (machine::StateItem, machine::StateItem),
+    /// The lookahead on which the two actions conflict.
+    /// See `TableConflictErr::quasiterminal`.
+    pub lookahead: machine::Lookahead,
+    /// The shortest string of terminals that takes the parser
+    /// from a start state to the conflicting state.
+    pub prefix: Vec<DollarlessTerminalName>,
+    /// An input that demonstrates the conflict.
+    /// This is `None` if no such input could be found
+    /// (e.g., because the conflicting state is unreachable).
+    pub counterexample: Option<Counterexample>,
+    /// If the conflict only exists because LALR(1) merged
+    /// LR(1) states with equal cores, this describes those states.
+    pub lalr_merge: Option<LalrMerge>,
     pub file: validated_file::File,
     pub machine: machine::Machine,
 }
 
+/// The LR(1) states that LALR(1) merged into a conflicting state.
+/// Each LR(1) state is described by the shortest input that leads to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LalrMerge {
+    /// The inputs that lead to the LR(1) states
+    /// that reduce the first conflicting item on the lookahead.
+    pub first_prefixes: Vec<Vec<DollarlessTerminalName>>,
+    /// Likewise, for the second conflicting item.
+    /// No LR(1) state reduces both items on the lookahead.
+    pub second_prefixes: Vec<Vec<DollarlessTerminalName>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConflictKind {
+    ShiftReduce,
+    /// Accepting counts as reducing the augmented start rule,
+    /// so an accept/reduce conflict is a reduce/reduce conflict.
+    ReduceReduce,
+}
+
+impl std::fmt::Display for ConflictKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConflictKind::ShiftReduce => write!(f, "shift/reduce"),
+            ConflictKind::ReduceReduce => write!(f, "reduce/reduce"),
+        }
+    }
+}
+
+impl TableConflictErr {
+    pub fn quasiterminal(&self) -> table::Quasiterminal<'_> {
+        self.lookahead.as_quasiterminal()
+    }
+
+    /// Returns the item in grammar notation
+    /// (e.g., `Expr -> Expr . $Plus Expr`).
+    /// Literal terminals are written as literals (e.g., `"+"`),
+    /// and helpers are written as the sugar they implement (e.g., `Bar*`).
+    /// The augmented start rule of `Foo` is written `Foo' -> Foo`.
+    pub fn format_item(&self, item: &machine::StateItem) -> String {
+        let (name, symbols) = match item.rule_index {
+            machine::RuleIndex::Original(rule_index) => {
+                let rule = self.file.get_rules().nth(rule_index).unwrap();
+                let symbols = (0..rule.fieldset.len())
+                    .map(|i| self.format_symbol(rule.fieldset.get_symbol_ident(i)))
+                    .collect();
+                (
+                    self.format_nonterminal(rule.constructor_name.type_name()),
+                    symbols,
+                )
+            }
+            machine::RuleIndex::Augmented(start_index) => {
+                let start = &self.file.starts[start_index];
+                (format!("{start}'"), vec![start.clone()])
+            }
+        };
+
+        let mut right: Vec<&str> = symbols.iter().map(String::as_str).collect();
+        right.insert(item.dot, ".");
+        format!("{name} -> {}", right.join(" "))
+    }
+
+    /// Returns the conflicting lookahead
+    /// (e.g., `$Plus`, `"+"`, or `end of input`).
+    pub fn format_lookahead(&self) -> String {
+        match &self.lookahead {
+            machine::Lookahead::Terminal(terminal) => self.format_terminal(terminal),
+            machine::Lookahead::Eof => "end of input".to_owned(),
+        }
+    }
+
+    /// Returns the prefix, with terminals separated by spaces.
+    pub fn format_prefix(&self) -> String {
+        self.format_terminals(&self.prefix)
+    }
+
+    /// Returns the terminals, separated by spaces.
+    pub fn format_terminals(&self, terminals: &[DollarlessTerminalName]) -> String {
+        terminals
+            .iter()
+            .map(|terminal| self.format_terminal(terminal))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn format_symbol(&self, symbol: &validated_file::IdentOrTerminalIdent) -> String {
+        match symbol {
+            validated_file::IdentOrTerminalIdent::Ident(ident) => {
+                self.format_nonterminal(&ident.name)
+            }
+            validated_file::IdentOrTerminalIdent::Terminal(terminal) => {
+                self.format_terminal(&terminal.name)
+            }
+        }
+    }
+
+    /// Helpers are written as the sugar they implement
+    /// (e.g., `Bar?` instead of `BarOpt`).
+    fn format_nonterminal(&self, name: &str) -> String {
+        let Some(helper) = self.file.get_helper(name) else {
+            return name.to_owned();
+        };
+
+        let element = self.format_symbol(&helper.element);
+        match &helper.sugar {
+            validated_file::HelperSugar::Repetition(validated_file::RepetitionKind::ZeroOrMore) => {
+                format!("{element}*")
+            }
+            validated_file::HelperSugar::Repetition(validated_file::RepetitionKind::OneOrMore) => {
+                format!("{element}+")
+            }
+            validated_file::HelperSugar::Optional => format!("{element}?"),
+            validated_file::HelperSugar::SeparatedList {
+                separator,
+                nonempty,
+                trailing,
+            } => {
+                let separator = self.format_symbol(separator);
+                let flags: String = [(*nonempty, "nonempty"), (*trailing, "trailing")]
+                    .into_iter()
+                    .filter(|(is_set, _)| *is_set)
+                    .map(|(_, flag)| format!(", {flag}"))
+                    .collect();
+                format!("sep({element}, {separator}{flags})")
+            }
+        }
+    }
+
+    fn format_terminal(&self, name: &DollarlessTerminalName) -> String {
+        if name.is_error() {
+            return name.to_string();
+        }
+
+        let literal = self
+            .file
+            .terminal_enum
+            .variants
+            .iter()
+            .find(|variant| variant.dollarless_name == *name)
+            .and_then(|variant| variant.literal.as_ref());
+        match literal {
+            Some(literal) => format!("{literal:?}"),
+            None => format!("${name}"),
+        }
+    }
+
+    /// Returns the positions of the rules of the two conflicting items.
+    /// A position is `None` if the item belongs to the augmented start rule,
+    /// since that rule does not appear in the source.
+    pub fn rule_positions(&self) -> (Option<ByteIndex>, Option<ByteIndex>) {
+        (
+            self.rule_position(&self.items.0),
+            self.rule_position(&self.items.1),
+        )
+    }
+
+    fn rule_position(&self, item: &machine::StateItem) -> Option<ByteIndex> {
+        match item.rule_index {
+            machine::RuleIndex::Original(rule_index) => self
+                .file
+                .get_rules()
+                .nth(rule_index)
+                .map(|rule| rule.position),
+            machine::RuleIndex::Augmented(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct RustSrc(pub String);
 
 impl RustSrc {
-    pub fn as_ref(&self) -> RustSrcRef {
+    pub fn as_ref(&self) -> RustSrcRef<'_> {
         RustSrcRef(&self.0)
     }
 }
@@ -59,6 +436,38 @@ pub struct RustSrcRef<'a>(pub &'a str);
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ByteIndex(pub usize);
 
+impl ByteIndex {
+    /// Returns the line and column of this index in `src`.
+    /// An index past the end of `src` is treated as the end of `src`.
+    pub fn to_line_and_column(self, src: &str) -> LineAndColumn {
+        let mut index = self.0.min(src.len());
+        while !src.is_char_boundary(index) {
+            index -= 1;
+        }
+
+        let before = &src[..index];
+        let line_start = before.rfind('\n').map_or(0, |i| i + "\n".len());
+        LineAndColumn {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+/// A 1-based line and column.
+/// Columns count `char`s, not bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineAndColumn {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for LineAndColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct DollarlessTerminalName(String);
 
@@ -70,11 +479,38 @@ impl DollarlessTerminalName {
     pub fn raw(&self) -> &str {
         &self.0
     }
+
+    /// Returns the name of the `error` pseudo-terminal.
+    /// Since it starts with a lowercase letter,
+    /// it cannot clash with the name of a user-defined terminal.
+    pub fn error() -> Self {
+        Self("error".to_owned())
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.0 == "error"
+    }
+
+    /// Returns the provisional name of a literal terminal
+    /// (e.g., `"("` for the literal `"("`).
+    /// Since it starts with a `"`,
+    /// it cannot clash with the name of a user-defined terminal.
+    /// Validation replaces every provisional name
+    /// with a generated identifier.
+    pub fn literal(value: &str) -> Self {
+        Self(format!("\"{value}\""))
+    }
+
+    /// If this is the provisional name of a literal terminal,
+    /// returns the literal's value.
+    pub fn literal_value(&self) -> Option<&str> {
+        self.0.strip_prefix('"')?.strip_suffix('"')
+    }
 }
 
-impl ToString for DollarlessTerminalName {
-    fn to_string(&self) -> String {
-        self.raw().to_string()
+impl std::fmt::Display for DollarlessTerminalName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw())
     }
 }
 
@@ -84,11 +520,11 @@ pub enum Symbol {
     Nonterminal(String),
 }
 
-impl From<cst::IdentOrTerminalIdent> for Symbol {
-    fn from(ident: cst::IdentOrTerminalIdent) -> Self {
+impl From<ast::IdentOrTerminalIdent> for Symbol {
+    fn from(ident: ast::IdentOrTerminalIdent) -> Self {
         match ident {
-            cst::IdentOrTerminalIdent::Ident(ident) => Symbol::Nonterminal(ident.name),
-            cst::IdentOrTerminalIdent::Terminal(ident) => Symbol::Terminal(ident.name),
+            ast::IdentOrTerminalIdent::Ident(ident) => Symbol::Nonterminal(ident.name),
+            ast::IdentOrTerminalIdent::Terminal(ident) => Symbol::Terminal(ident.name),
         }
     }
 }
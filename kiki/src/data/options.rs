@@ -0,0 +1,82 @@
+/// Options that control how Kiki generates a parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GenerateOptions {
+    pub parser_algorithm: ParserAlgorithm,
+    pub table_encoding: TableEncoding,
+    pub default_reductions: DefaultReductions,
+}
+
+/// The algorithm used to build the parser's state machine.
+///
+/// All three algorithms accept the same grammars as long as
+/// those grammars are LALR(1).
+/// They only differ for grammars that are LR(1) but not LALR(1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ParserAlgorithm {
+    /// Canonical LR(1).
+    /// States are never merged, so no conflicts are introduced
+    /// by merging.
+    /// However, the machine can have many more states than LALR(1).
+    CanonicalLr1,
+    /// LALR(1).
+    /// States with equal cores are always merged.
+    /// This produces the smallest machine,
+    /// but merging can introduce reduce/reduce conflicts
+    /// that canonical LR(1) would not have.
+    #[default]
+    Lalr1,
+    /// Minimal LR(1).
+    /// Like IELR(1) and Pager's algorithm,
+    /// this merges states with equal cores,
+    /// except where the merge would introduce a reduce/reduce conflict.
+    /// For LALR(1) grammars, the machine is identical to the LALR(1) machine.
+    /// Otherwise, the conflicting states are split greedily,
+    /// so the machine may have a few more states than strictly necessary.
+    MinimalLr1,
+}
+
+/// How the generated parser stores its action and goto tables.
+///
+/// Both encodings produce parsers that behave identically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TableEncoding {
+    /// Each table is a two-dimensional array
+    /// with one cell per state and (quasi)terminal or nonterminal.
+    /// Lookups are a single index,
+    /// but the tables grow with `states * symbols`.
+    #[default]
+    Dense,
+    /// Each action and goto is encoded as an integer.
+    /// Each state gets a default (its most common reduction for actions,
+    /// and its most common goto for gotos),
+    /// and the remaining cells of all states are packed
+    /// into shared arrays using row displacement.
+    /// This is usually much smaller than `Dense`,
+    /// at the cost of a few extra operations per lookup.
+    Compressed,
+}
+
+/// Whether the action table fills some error cells with reductions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DefaultReductions {
+    /// Every lookahead that a state cannot handle is an error in that state.
+    #[default]
+    Off,
+    /// In each state whose reductions all use the same rule,
+    /// every lookahead that would otherwise be an error
+    /// reduces by that rule instead.
+    ///
+    /// In a state that reduces by the same rule on every lookahead,
+    /// the parser reduces without reading the next token.
+    ///
+    /// Reducing never consumes a token,
+    /// so the parser still detects the error before the next shift.
+    /// However, it may detect it a few reductions later,
+    /// in a state that expects fewer tokens,
+    /// so `ParseError::expected` may be shorter.
+    ///
+    /// States with `nonassoc` errors are left as they are,
+    /// and so is the `error` pseudo-terminal's column,
+    /// so precedence and error recovery behave the same.
+    SingleReductionStates,
+}
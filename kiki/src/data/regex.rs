@@ -0,0 +1,137 @@
+/// A regular expression from a `lexer` rule.
+/// String literals are also represented as regexes
+/// (i.e., as a concatenation of single-character classes).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Regex {
+    /// Matches only the empty string.
+    Empty,
+    /// Matches any single character in the class.
+    Class(CharClass),
+    Concat(Vec<Regex>),
+    Alternation(Vec<Regex>),
+    /// Matches between `min` and `max` (inclusive) repetitions of `regex`.
+    /// If `max` is `None`, there is no upper bound.
+    Repeat {
+        regex: Box<Regex>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+impl Regex {
+    /// Returns a regex that matches exactly `s`.
+    pub fn literal(s: &str) -> Regex {
+        let mut chars = s.chars().map(|c| Regex::Class(CharClass::single(c)));
+        match (chars.next(), chars.next()) {
+            (None, _) => Regex::Empty,
+            (Some(only), None) => only,
+            (Some(first), Some(second)) => {
+                Regex::Concat([first, second].into_iter().chain(chars).collect())
+            }
+        }
+    }
+
+    pub fn matches_empty_string(&self) -> bool {
+        match self {
+            Regex::Empty => true,
+            Regex::Class(_) => false,
+            Regex::Concat(regexes) => regexes.iter().all(Regex::matches_empty_string),
+            Regex::Alternation(regexes) => regexes.iter().any(Regex::matches_empty_string),
+            Regex::Repeat { regex, min, .. } => *min == 0 || regex.matches_empty_string(),
+        }
+    }
+}
+
+/// A set of characters.
+/// Internally, it is a sorted list of
+/// disjoint, non-adjacent, inclusive ranges.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharClass {
+    ranges: Vec<(char, char)>,
+}
+
+impl CharClass {
+    /// The ranges may be unsorted, overlapping, or adjacent.
+    /// Ranges whose start is greater than their end are ignored.
+    pub fn new(mut ranges: Vec<(char, char)>) -> Self {
+        ranges.retain(|(start, end)| start <= end);
+        ranges.sort_unstable();
+
+        let mut normalized: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            if let Some(last) = normalized.last_mut() {
+                if next_char(last.1).is_none_or(|after_last| start <= after_last) {
+                    last.1 = last.1.max(end);
+                    continue;
+                }
+            }
+            normalized.push((start, end));
+        }
+
+        CharClass { ranges: normalized }
+    }
+
+    pub fn single(c: char) -> Self {
+        CharClass {
+            ranges: vec![(c, c)],
+        }
+    }
+
+    pub fn ranges(&self) -> &[(char, char)] {
+        &self.ranges
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(start, end)| start <= c && c <= end)
+    }
+
+    /// Returns the class of every character _not_ in this class.
+    pub fn negate(&self) -> Self {
+        let mut negated = Vec::with_capacity(self.ranges.len() + 1);
+        let mut next_start = Some(char::MIN);
+        for &(start, end) in &self.ranges {
+            if let (Some(gap_start), Some(gap_end)) = (next_start, prev_char(start)) {
+                if gap_start <= gap_end {
+                    negated.push((gap_start, gap_end));
+                }
+            }
+            next_start = next_char(end);
+        }
+        if let Some(gap_start) = next_start {
+            negated.push((gap_start, char::MAX));
+        }
+        CharClass { ranges: negated }
+    }
+
+    pub fn union(&self, other: &CharClass) -> Self {
+        CharClass::new(
+            self.ranges
+                .iter()
+                .chain(other.ranges.iter())
+                .copied()
+                .collect(),
+        )
+    }
+}
+
+/// Returns the smallest `char` greater than `c`, if there is one.
+/// Surrogates are not `char`s, so they are skipped.
+pub fn next_char(c: char) -> Option<char> {
+    match c {
+        '\u{D7FF}' => Some('\u{E000}'),
+        char::MAX => None,
+        _ => char::from_u32(c as u32 + 1),
+    }
+}
+
+/// Returns the largest `char` less than `c`, if there is one.
+/// Surrogates are not `char`s, so they are skipped.
+pub fn prev_char(c: char) -> Option<char> {
+    match c {
+        '\u{E000}' => Some('\u{D7FF}'),
+        char::MIN => None,
+        _ => char::from_u32(c as u32 - 1),
+    }
+}
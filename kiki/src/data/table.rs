@@ -2,7 +2,9 @@ use crate::data::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Table {
-    pub start: StateIndex,
+    /// The start state of each start symbol,
+    /// in the same order as `File::starts`.
+    pub starts: Vec<StateIndex>,
     pub terminals: Vec<DollarlessTerminalName>,
     pub nonterminals: Vec<String>,
     pub actions: Vec<Action>,
@@ -44,6 +46,27 @@ impl Table {
         self.actions[i]
     }
 
+    /// Returns the state's actions,
+    /// in the same order as `terminals`, followed by the action on EOF.
+    /// Unlike `action`, this does not search `terminals`,
+    /// so it is much faster for reading a whole row.
+    ///
+    /// ## Panics
+    /// Panics if the state is too large.
+    pub fn action_row(&self, StateIndex(state_index): StateIndex) -> &[Action] {
+        let row_len = self.terminals.len() + 1;
+        &self.actions[state_index * row_len..(state_index + 1) * row_len]
+    }
+
+    /// Like `action_row`, but mutable.
+    ///
+    /// ## Panics
+    /// Panics if the state is too large.
+    pub fn action_row_mut(&mut self, StateIndex(state_index): StateIndex) -> &mut [Action] {
+        let row_len = self.terminals.len() + 1;
+        &mut self.actions[state_index * row_len..(state_index + 1) * row_len]
+    }
+
     /// ## Panics
     /// 1. Panics if the terminal is not in the table.
     /// 2. Panics if the state is too large.
@@ -85,6 +108,18 @@ impl Table {
         self.gotos[i]
     }
 
+    /// Returns the state's gotos,
+    /// in the same order as `nonterminals`.
+    /// Unlike `goto`, this does not search `nonterminals`,
+    /// so it is much faster for reading a whole row.
+    ///
+    /// ## Panics
+    /// Panics if the state is too large.
+    pub fn goto_row(&self, StateIndex(state_index): StateIndex) -> &[Goto] {
+        let row_len = self.nonterminals.len();
+        &self.gotos[state_index * row_len..(state_index + 1) * row_len]
+    }
+
     /// ## Panics
     /// 1. Panics if the nonterminal is not in the table.
     /// 2. Panics if the state is too large.
@@ -111,3 +146,114 @@ impl Table {
         state_index * self.nonterminals.len() + nonterminal_index
     }
 }
+
+/// A `Table` whose rows are compressed.
+/// Each action and goto is encoded as an integer,
+/// and each row stores only the cells that differ from the row's default.
+/// The remaining cells of all rows are packed into shared arrays
+/// using row displacement (also known as comb packing).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedTable {
+    pub state_count: usize,
+    /// The rows are states, and the columns are quasiterminals
+    /// (in the same order as `Table::terminals`, followed by EOF).
+    /// See `Action::encode`.
+    pub actions: CompressedRows,
+    /// The rows are states, and the columns are nonterminals
+    /// (in the same order as `Table::nonterminals`).
+    /// See `Goto::encode`.
+    pub gotos: CompressedRows,
+}
+
+/// To look up column `c` of row `r`, let `i = bases[r] + c`.
+/// If `checks[i] == r`, the code is `values[i]`.
+/// Otherwise, the code is `defaults[r]`.
+///
+/// `checks` and `values` are long enough
+/// that `i` is always in bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedRows {
+    pub defaults: Vec<u32>,
+    pub bases: Vec<u32>,
+    /// Unused slots are `CompressedRows::UNUSED`.
+    pub checks: Vec<u32>,
+    /// Unused slots are `0`.
+    pub values: Vec<u32>,
+}
+
+impl CompressedRows {
+    pub const UNUSED: u32 = u32::MAX;
+
+    pub fn get(&self, row: usize, column: usize) -> u32 {
+        let i = self.bases[row] as usize + column;
+        if self.checks[i] as usize == row {
+            self.values[i]
+        } else {
+            self.defaults[row]
+        }
+    }
+}
+
+impl CompressedTable {
+    /// The quasiterminal index of EOF is `Table::terminals.len()`.
+    pub fn action(
+        &self,
+        StateIndex(state_index): StateIndex,
+        quasiterminal_index: usize,
+    ) -> Action {
+        Action::decode(
+            self.actions.get(state_index, quasiterminal_index),
+            self.state_count,
+        )
+    }
+
+    pub fn goto(&self, StateIndex(state_index): StateIndex, nonterminal_index: usize) -> Goto {
+        Goto::decode(self.gotos.get(state_index, nonterminal_index))
+    }
+}
+
+impl Action {
+    /// - `Err` is `0`.
+    /// - `Accept` is `1`.
+    /// - `Shift(s)` is `2 + s`.
+    /// - `Reduce(r)` is `2 + state_count + r`.
+    pub fn encode(self, state_count: usize) -> u32 {
+        let code = match self {
+            Action::Err => 0,
+            Action::Accept => 1,
+            Action::Shift(StateIndex(state_index)) => 2 + state_index,
+            Action::Reduce(rule_index) => 2 + state_count + rule_index,
+        };
+        u32::try_from(code).expect("Action code does not fit in a u32")
+    }
+
+    /// The inverse of `Action::encode`.
+    pub fn decode(code: u32, state_count: usize) -> Action {
+        match code as usize {
+            0 => Action::Err,
+            1 => Action::Accept,
+            code if code < 2 + state_count => Action::Shift(StateIndex(code - 2)),
+            code => Action::Reduce(code - 2 - state_count),
+        }
+    }
+}
+
+impl Goto {
+    /// - `Err` is `0`.
+    /// - `State(s)` is `1 + s`.
+    pub fn encode(self) -> u32 {
+        let code = match self {
+            Goto::Err => 0,
+            Goto::State(StateIndex(state_index)) => 1 + state_index,
+        };
+        u32::try_from(code).expect("Goto code does not fit in a u32")
+    }
+
+    /// The inverse of `Goto::encode`.
+    pub fn decode(code: u32) -> Goto {
+        match code {
+            0 => Goto::Err,
+            code => Goto::State(StateIndex(code as usize - 1)),
+        }
+    }
+}
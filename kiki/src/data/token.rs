@@ -19,3 +19,63 @@ pub struct Attribute {
     pub src: String,
     pub position: ByteIndex,
 }
+
+/// A doc comment, such as `/// Hello`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DocComment {
+    /// Everything after the `///`,
+    /// excluding the line terminator.
+    pub text: String,
+    /// The position of the first `/`.
+    pub position: ByteIndex,
+}
+
+impl DocComment {
+    /// Returns the equivalent `#[doc = "..."]` attribute.
+    pub fn to_attribute_src(&self) -> String {
+        format!("#[doc = {:?}]", self.text)
+    }
+}
+
+/// A semantic action, such as `=> { left + right }`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SemanticAction {
+    /// This includes the leading `=>`
+    /// and the trailing `}`.
+    pub src: String,
+    /// The position of the `=>`.
+    pub position: ByteIndex,
+}
+
+impl SemanticAction {
+    /// Returns the block of Rust code
+    /// (i.e., everything after the `=>`),
+    /// including the enclosing curly braces.
+    pub fn code(&self) -> &str {
+        self.src["=>".len()..].trim_start()
+    }
+}
+
+/// A string literal, such as `"while"`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StringLiteral {
+    /// The literal as it appears in the source,
+    /// including the quotes.
+    pub src: String,
+    /// The string's contents, with escape sequences resolved.
+    pub value: String,
+    /// The position of the opening `"`.
+    pub position: ByteIndex,
+}
+
+/// A regex literal, such as `r"[0-9]+"` or `r#"".*""#`.
+/// Like a Rust raw string, it has no escape sequences.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RegexLiteral {
+    /// Everything between the quotes.
+    pub pattern: String,
+    /// The position of the `r`.
+    pub position: ByteIndex,
+    /// The position of the first byte of `pattern`.
+    pub pattern_position: ByteIndex,
+}
@@ -1,10 +1,12 @@
-use crate::data::machine::{State, Transition};
+use crate::data::machine::{State, StateIndex, Transition};
 
 use std::collections::HashSet;
 
 #[derive(Debug, Clone)]
 pub struct UnnormalizedMachine {
-    /// The first state must be the start state.
     pub states: Vec<State>,
+    /// The start state of each start symbol,
+    /// in the same order as `File::starts`.
+    pub starts: Vec<StateIndex>,
     pub transitions: HashSet<Transition>,
 }
@@ -1,3 +1,4 @@
+use crate::data::regex::Regex;
 use crate::data::*;
 
 use std::collections::HashSet;
@@ -5,13 +6,22 @@ use std::fmt::{self, Display, Formatter};
 
 #[derive(Debug, Clone)]
 pub struct File {
-    pub start: String,
+    /// The start symbols, in the order they were declared.
+    /// There is always at least one.
+    pub starts: Vec<String>,
     pub terminal_enum: TerminalEnum,
     pub nonterminals: Vec<Nonterminal>,
+    /// The levels are ordered from lowest to highest precedence.
+    pub precedence_levels: Vec<PrecedenceLevel>,
+    /// This is `Some` if the grammar has a `lexer { ... }` section.
+    pub lexer: Option<Lexer>,
+    /// The names of the lints allowed by `allow(...)` declarations.
+    /// Every name is in `KikiWarning::LINT_NAMES`.
+    pub allowed_lints: HashSet<&'static str>,
 }
 
 impl File {
-    pub fn get_rules(&self) -> impl Iterator<Item = Rule> {
+    pub fn get_rules(&self) -> impl Iterator<Item = Rule<'_>> {
         self.nonterminals
             .iter()
             .flat_map(|nonterminal| match nonterminal {
@@ -19,6 +29,9 @@ impl File {
                     vec![Rule {
                         constructor_name: ConstructorName::Struct(&s.name.name),
                         fieldset: &s.fieldset,
+                        precedence_override: None,
+                        action: s.action.as_ref(),
+                        position: s.name.position,
                     }]
                 }
                 Nonterminal::Enum(e) => e
@@ -33,17 +46,58 @@ impl File {
                                 variant_name,
                             },
                             fieldset: &v.fieldset,
+                            precedence_override: v.precedence.as_ref(),
+                            action: v.action.as_ref(),
+                            position: v.name.position,
                         }
                     })
                     .collect(),
+                Nonterminal::Helper(h) => h
+                    .rules
+                    .iter()
+                    .map(|rule| Rule {
+                        constructor_name: ConstructorName::Helper {
+                            helper_name: &h.name,
+                            reduction: rule.reduction,
+                        },
+                        fieldset: &rule.fieldset,
+                        precedence_override: None,
+                        action: None,
+                        position: h.operator_position,
+                    })
+                    .collect(),
             })
     }
 }
 
+impl File {
+    /// Returns `true` if some rule uses the `error` pseudo-terminal.
+    pub fn uses_error_terminal(&self) -> bool {
+        self.get_rules().any(|rule| {
+            (0..rule.fieldset.len()).any(|i| {
+                matches!(
+                    rule.fieldset.get_symbol_ident(i),
+                    IdentOrTerminalIdent::Terminal(terminal) if terminal.name.is_error()
+                )
+            })
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Rule<'a> {
     pub constructor_name: ConstructorName<'a>,
     pub fieldset: &'a Fieldset,
+    /// This is the terminal named by the rule's `prec` override, if any.
+    pub precedence_override: Option<&'a TerminalIdent>,
+    /// This is `Some` if and only if the rule's nonterminal
+    /// has a declared result type.
+    pub action: Option<&'a SemanticAction>,
+    /// Errors about this rule should point here.
+    /// For structs and enum variants, this is the position of the name.
+    /// For helper rules, this is the position of the operator
+    /// (e.g., `?`) that the user wrote.
+    pub position: ByteIndex,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -53,6 +107,10 @@ pub enum ConstructorName<'a> {
         enum_name: &'a str,
         variant_name: &'a str,
     },
+    Helper {
+        helper_name: &'a str,
+        reduction: HelperReduction,
+    },
 }
 
 impl Display for ConstructorName<'_> {
@@ -63,15 +121,17 @@ impl Display for ConstructorName<'_> {
                 enum_name,
                 variant_name,
             } => write!(f, "{}::{}", enum_name, variant_name),
+            ConstructorName::Helper { helper_name, .. } => write!(f, "{}", helper_name),
         }
     }
 }
 
-impl ConstructorName<'_> {
-    pub fn type_name(&self) -> &str {
+impl<'a> ConstructorName<'a> {
+    pub fn type_name(&self) -> &'a str {
         match self {
             ConstructorName::Struct(name) => name,
             ConstructorName::EnumVariant { enum_name, .. } => enum_name,
+            ConstructorName::Helper { helper_name, .. } => helper_name,
         }
     }
 }
@@ -80,18 +140,14 @@ impl File {
     pub fn get_defined_identifiers(&self) -> HashSet<String> {
         self.get_nonterminal_names()
             .chain(self.get_terminal_enum_variant_names())
-            .chain(std::iter::once(self.terminal_enum.name.clone()))
+            .chain(std::iter::once(self.terminal_enum.scope_name().to_owned()))
             .collect()
     }
 
     fn get_nonterminal_names(&self) -> impl Iterator<Item = String> + '_ {
         self.nonterminals
             .iter()
-            .map(|nonterminal| match nonterminal {
-                Nonterminal::Struct(s) => &s.name.name,
-                Nonterminal::Enum(e) => &e.name.name,
-            })
-            .cloned()
+            .map(|nonterminal| nonterminal.name().to_owned())
     }
 
     fn get_terminal_enum_variant_names(&self) -> impl Iterator<Item = String> + '_ {
@@ -104,8 +160,20 @@ impl File {
 
 #[derive(Debug, Clone)]
 pub struct TerminalEnum {
+    pub doc: Vec<DocComment>,
     pub attributes: Vec<Attribute>,
+    /// For an extern enum, this is the enum's path
+    /// (e.g., `crate::lexer::Token`).
     pub name: String,
+    /// If `true`, the enum is defined outside the grammar,
+    /// so Kiki does not generate it.
+    /// In this case, every variant has a pattern.
+    pub is_extern: bool,
+    /// If this is `Some`, the parser takes spanned tokens
+    /// (i.e., `(Token, std::ops::Range<Position>)`),
+    /// and every generated struct and enum variant
+    /// has a `span` field.
+    pub span_position_type: Option<String>,
     pub variants: Vec<TerminalVariant>,
 }
 
@@ -116,18 +184,93 @@ impl TerminalEnum {
             .find(|variant| variant.dollarless_name == *variant_name)
             .map(|variant| -> &str { &variant.type_ })
     }
+
+    /// Returns the identifier that the generated code
+    /// looks up in its own scope.
+    /// For an extern enum, this is the first segment of the path.
+    pub fn scope_name(&self) -> &str {
+        self.name.split("::").next().unwrap()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct TerminalVariant {
+    pub doc: Vec<DocComment>,
     pub dollarless_name: DollarlessTerminalName,
+    /// The position of the variant's name (or literal).
+    /// For a literal that the terminal enum does not declare,
+    /// this is the position of the literal's first use.
+    pub position: ByteIndex,
     pub type_: String,
+    /// This is `Some` if and only if the terminal enum is extern.
+    pub pattern: Option<TerminalPattern>,
+    /// If this terminal is a literal (e.g., `"("`),
+    /// this is the literal's value.
+    pub literal: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Lexer {
+    /// The rules are in declaration order.
+    /// If two rules match the same longest prefix,
+    /// the earlier rule wins.
+    pub rules: Vec<LexerRule>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LexerRule {
+    /// If this is `None`, the rule is a skip rule
+    /// (i.e., the lexer discards the text it matches).
+    pub terminal: Option<DollarlessTerminalName>,
+    /// This never matches the empty string.
+    pub regex: Regex,
+    /// The position of the rule's `$Terminal` or `_`.
+    pub position: ByteIndex,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrecedenceLevel {
+    pub associativity: Associativity,
+    pub terminals: Vec<DollarlessTerminalName>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Associativity {
+    Left,
+    Right,
+    Nonassoc,
+    /// The level was declared without an associativity.
+    /// Conflicts between two members of the level
+    /// cannot be resolved.
+    None,
+}
+
+impl File {
+    /// Returns the index of the terminal's precedence level
+    /// (higher indices bind more tightly),
+    /// along with that level's associativity.
+    pub fn get_terminal_precedence(
+        &self,
+        terminal: &DollarlessTerminalName,
+    ) -> Option<(usize, Associativity)> {
+        self.precedence_levels
+            .iter()
+            .enumerate()
+            .find_map(|(level_index, level)| {
+                if level.terminals.contains(terminal) {
+                    Some((level_index, level.associativity))
+                } else {
+                    None
+                }
+            })
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum Nonterminal {
     Struct(Struct),
     Enum(Enum),
+    Helper(Helper),
 }
 
 impl Nonterminal {
@@ -135,12 +278,232 @@ impl Nonterminal {
         match self {
             Nonterminal::Struct(s) => &s.name.name,
             Nonterminal::Enum(e) => &e.name.name,
+            Nonterminal::Helper(h) => &h.name,
+        }
+    }
+
+    /// Returns the declared result type (e.g., the `i64` in `enum Expr: i64`),
+    /// if any.
+    /// Nonterminals with a result type have no type definition
+    /// in the generated code.
+    /// Instead, their values are computed by semantic actions.
+    pub fn result_type(&self) -> Option<&str> {
+        match self {
+            Nonterminal::Struct(s) => s.result_type.as_deref(),
+            Nonterminal::Enum(e) => e.result_type.as_deref(),
+            Nonterminal::Helper(_) => None,
+        }
+    }
+}
+
+impl File {
+    pub fn get_helper(&self, name: &str) -> Option<&Helper> {
+        self.nonterminals
+            .iter()
+            .find_map(|nonterminal| match nonterminal {
+                Nonterminal::Helper(h) if h.name == name => Some(h),
+                _ => None,
+            })
+    }
+
+    pub fn get_result_type(&self, nonterminal_name: &str) -> Option<&str> {
+        self.nonterminals
+            .iter()
+            .find(|nonterminal| nonterminal.name() == nonterminal_name)
+            .and_then(Nonterminal::result_type)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Struct {
+    pub doc: Vec<DocComment>,
+    pub attributes: Vec<Attribute>,
+    pub name: Ident,
+    pub result_type: Option<String>,
+    pub fieldset: Fieldset,
+    pub action: Option<SemanticAction>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Enum {
+    pub doc: Vec<DocComment>,
+    pub attributes: Vec<Attribute>,
+    pub name: Ident,
+    pub result_type: Option<String>,
+    pub variants: Vec<EnumVariant>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EnumVariant {
+    pub doc: Vec<DocComment>,
+    pub name: Ident,
+    pub fieldset: Fieldset,
+    pub precedence: Option<TerminalIdent>,
+    pub action: Option<SemanticAction>,
+}
+
+/// A nonterminal that Kiki synthesizes
+/// to implement syntactic sugar (e.g., `Foo*`).
+///
+/// Unlike structs and enums, a helper has no type definition
+/// in the generated code.
+/// Instead, its value is a standard library type
+/// (e.g., `Vec<Foo>`) determined by its `kind` and `element`.
+#[derive(Clone, Debug)]
+pub struct Helper {
+    pub name: String,
+    pub kind: HelperKind,
+    pub element: IdentOrTerminalIdent,
+    pub sugar: HelperSugar,
+    pub rules: Vec<HelperRule>,
+    /// The position of the operator (e.g., `*`) of the first
+    /// field symbol that uses this helper.
+    pub operator_position: ByteIndex,
+}
+
+/// The sugared field symbol that a helper implements.
+/// Diagnostics use this to show the helper the way the user wrote it
+/// (e.g., `Bar*` instead of `BarStar`).
+#[derive(Clone, Debug)]
+pub enum HelperSugar {
+    /// `element*` or `element+`
+    Repetition(RepetitionKind),
+    /// `element?`
+    Optional,
+    /// `sep(element, separator)`, followed by any flags.
+    SeparatedList {
+        separator: IdentOrTerminalIdent,
+        nonempty: bool,
+        trailing: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HelperKind {
+    /// The helper's value is a `Vec` of its element.
+    Vec,
+    /// The helper's value is an `Option` of its element.
+    /// Like ordinary fields, nonterminal elements are boxed.
+    Option,
+}
+
+#[derive(Clone, Debug)]
+pub struct HelperRule {
+    pub fieldset: Fieldset,
+    pub reduction: HelperReduction,
+}
+
+/// Describes how a helper rule's used fields
+/// are combined into the helper's value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HelperReduction {
+    /// Produces an empty `Vec`.
+    /// The rule has no used fields.
+    EmptyVec,
+    /// Produces a `Vec` containing the rule's only used field.
+    SingletonVec,
+    /// Pushes the rule's second used field
+    /// onto the `Vec` held by its first used field.
+    Push,
+    /// Produces `None`.
+    /// The rule has no used fields.
+    None,
+    /// Produces `Some` containing the rule's only used field.
+    Some,
+    /// Produces the value of the rule's only used field, unchanged.
+    Identity,
+}
+
+#[derive(Clone, Debug)]
+pub enum Fieldset {
+    Empty,
+    Named(NamedFieldset),
+    Tuple(TupleFieldset),
+}
+
+impl Fieldset {
+    pub fn len(&self) -> usize {
+        match self {
+            Fieldset::Empty => 0,
+            Fieldset::Named(named) => named.fields.len(),
+            Fieldset::Tuple(tuple) => tuple.fields.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get_symbol_ident(&self, i: usize) -> &IdentOrTerminalIdent {
+        match self {
+            Fieldset::Empty => panic!("Called Fieldset::get_symbol_ident on Fieldset::Empty"),
+            Fieldset::Named(named) => &named.fields[i].symbol,
+            Fieldset::Tuple(tuple) => tuple.fields[i].symbol(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NamedFieldset {
+    pub fields: Vec<NamedField>,
+}
+
+impl NamedFieldset {
+    pub fn has_used_field(&self) -> bool {
+        self.fields.iter().any(NamedField::is_used)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NamedField {
+    pub doc: Vec<DocComment>,
+    pub name: IdentOrUnderscore,
+    pub symbol: IdentOrTerminalIdent,
+}
+
+impl NamedField {
+    pub fn is_used(&self) -> bool {
+        match self.name {
+            IdentOrUnderscore::Ident(_) => true,
+            IdentOrUnderscore::Underscore(_) => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TupleFieldset {
+    pub fields: Vec<TupleField>,
+}
+
+impl TupleFieldset {
+    pub fn has_used_field(&self) -> bool {
+        self.fields.iter().any(TupleField::is_used)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TupleField {
+    Used(IdentOrTerminalIdent),
+    Skipped(IdentOrTerminalIdent),
+}
+
+impl TupleField {
+    pub fn is_used(&self) -> bool {
+        match self {
+            TupleField::Used(_) => true,
+            TupleField::Skipped(_) => false,
+        }
+    }
+
+    pub fn symbol(&self) -> &IdentOrTerminalIdent {
+        match self {
+            TupleField::Used(symbol) => symbol,
+            TupleField::Skipped(symbol) => symbol,
         }
     }
 }
 
 pub use crate::data::ast::{
-    Attribute, ComplexType, Enum, EnumVariant, Fieldset, NamedField, NamedFieldset, Struct,
-    TupleField, TupleFieldset, Type,
+    Attribute, ComplexType, DocComment, RepetitionKind, SemanticAction, TerminalPattern, Type,
 };
 pub use crate::data::ast::{Ident, IdentOrTerminalIdent, IdentOrUnderscore, TerminalIdent, Token};
@@ -16,17 +16,47 @@ pub use data::*;
 use pipeline::prelude::*;
 
 pub fn generate(src: &str) -> Result<RustSrc, KikiErr> {
+    generate_with_diagnostics(src).map(|(rust_src, _)| rust_src)
+}
+
+/// Like `generate`, but also returns the grammar's warnings,
+/// sorted by position.
+/// See `KikiWarning` for the lints.
+pub fn generate_with_diagnostics(src: &str) -> Result<(RustSrc, Vec<KikiWarning>), KikiErr> {
+    generate_with_options(src, &GenerateOptions::default())
+}
+
+/// Like `generate_with_diagnostics`, but lets you configure generation.
+/// See `GenerateOptions` for the options.
+pub fn generate_with_options(
+    src: &str,
+    options: &GenerateOptions,
+) -> Result<(RustSrc, Vec<KikiWarning>), KikiErr> {
     let tokens = tokenize(src)?;
     let cst = parse(tokens)
         .map_err(|unexpected| unexpected_token_or_eof_to_kiki_err(unexpected.as_ref(), src))?;
     let ast: data::ast::File = cst.into();
     let validated = validate_ast(ast)?;
-    let machine = validated_ast_to_machine(&validated);
-    let table = machine_to_table(&machine, &validated)?;
-    Ok(table_to_rust(&table, &validated, src))
+    let warnings = lint(&validated);
+    let (machine, lalr_merges) = validated_ast_to_machine(&validated, options.parser_algorithm);
+    let table = machine_to_table(
+        &machine,
+        lalr_merges.as_ref(),
+        &validated,
+        options.default_reductions,
+    )?;
+    let dfa = validated.lexer.as_ref().map(lexer_to_dfa);
+    let rust_src = table_to_rust(
+        &table,
+        &validated,
+        dfa.as_ref(),
+        src,
+        options.table_encoding,
+    );
+    Ok((rust_src, warnings))
 }
 
-pub fn get_grammar_hash(src: RustSrcRef) -> Option<&str> {
+pub fn get_grammar_hash(src: RustSrcRef<'_>) -> Option<&str> {
     const HASH_PREFIX: &str = "// @sha256 ";
     for line in src.0.lines() {
         if !line.starts_with("//") {
@@ -3,7 +3,7 @@
 // You can read more at https://crates.io/crates/kiki
 //
 // This code was generated from a grammar with the following hash:
-// @sha256 f5515a070bee39f2c4814f2939108a718e489e1940112ade895b72e1cba7887f
+// @sha256 75f8e5cc175909112d402d2f3027a243ee5610950c31b00377ff4fd86a3ba42e
 
 // Since this code is automatically generated,
 // some parts may be unidiomatic.
@@ -19,19 +19,30 @@ pub enum Token {
     Ident(crate::data::token::Ident),
     TerminalIdent(crate::data::token::TerminalIdent),
     OuterAttribute(crate::data::token::Attribute),
+    DocComment(crate::data::token::DocComment),
+    SemanticAction(crate::data::token::SemanticAction),
+    StringLiteral(crate::data::token::StringLiteral),
+    RegexLiteral(crate::data::token::RegexLiteral),
     StartKw(crate::data::ByteIndex),
     StructKw(crate::data::ByteIndex),
     EnumKw(crate::data::ByteIndex),
     TerminalKw(crate::data::ByteIndex),
+    PrecedenceKw(crate::data::ByteIndex),
+    PrecKw(crate::data::ByteIndex),
+    ExternKw(crate::data::ByteIndex),
     Colon(crate::data::ByteIndex),
     DoubleColon(crate::data::ByteIndex),
     Comma(crate::data::ByteIndex),
+    Equals(crate::data::ByteIndex),
     LParen(crate::data::ByteIndex),
     RParen(crate::data::ByteIndex),
     LCurly(crate::data::ByteIndex),
     RCurly(crate::data::ByteIndex),
     LAngle(crate::data::ByteIndex),
     RAngle(crate::data::ByteIndex),
+    Star(crate::data::ByteIndex),
+    Plus(crate::data::ByteIndex),
+    Question(crate::data::ByteIndex),
 }
 
 pub struct File {
@@ -59,26 +70,101 @@ pub enum FileItem {
     Terminal(
         Box<TerminalEnum>,
     ),
+    Precedence(
+        Box<PrecedenceDeclaration>,
+    ),
+    Lexer(
+        Box<Lexer>,
+    ),
+    Allow(
+        Box<Allow>,
+    ),
 }
 
 pub struct Struct {
+    pub doc: Box<OptDocComments>,
     pub attributes: Box<OptOuterAttributes>,
     pub name: crate::data::token::Ident,
+    pub params: Box<OptGenericParams>,
+    pub result_type: Box<OptResultType>,
     pub fieldset: Box<Fieldset>,
+    pub action: Box<OptSemanticAction>,
 }
 
 pub struct Enum {
+    pub doc: Box<OptDocComments>,
     pub attributes: Box<OptOuterAttributes>,
     pub name: crate::data::token::Ident,
+    pub params: Box<OptGenericParams>,
+    pub result_type: Box<OptResultType>,
     pub variants: Box<OptEnumVariants>,
 }
 
 pub struct TerminalEnum {
+    pub doc: Box<OptDocComments>,
     pub attributes: Box<OptOuterAttributes>,
-    pub name: crate::data::token::Ident,
+    pub header: Box<TerminalEnumHeader>,
     pub variants: Box<OptTerminalEnumVariants>,
 }
 
+pub enum TerminalEnumHeader {
+    Unspanned(
+        Box<TerminalEnumName>,
+    ),
+    Spanned(
+        Box<TerminalEnumName>,
+        Box<Type>,
+    ),
+}
+
+pub enum TerminalEnumName {
+    Local(
+        crate::data::token::Ident,
+    ),
+    Extern(
+        Box<Path>,
+    ),
+}
+
+pub enum OptGenericParams {
+    None,
+    Some(
+        Box<GenericParams>,
+    ),
+}
+
+pub enum GenericParams {
+    One(
+        crate::data::token::Ident,
+    ),
+    Cons(
+        Box<GenericParams>,
+        crate::data::token::Ident,
+    ),
+}
+
+pub enum OptResultType {
+    None,
+    Some(
+        Box<Type>,
+    ),
+}
+
+pub enum OptSemanticAction {
+    None,
+    Some(
+        crate::data::token::SemanticAction,
+    ),
+}
+
+pub enum OptDocComments {
+    Nil,
+    Cons(
+        Box<OptDocComments>,
+        crate::data::token::DocComment,
+    ),
+}
+
 pub enum OptOuterAttributes {
     Nil,
     Cons(
@@ -112,8 +198,9 @@ pub enum NamedFields {
 }
 
 pub struct NamedField {
+    pub doc: Box<OptDocComments>,
     pub name: Box<IdentOrUnderscore>,
-    pub symbol: Box<IdentOrTerminalIdent>,
+    pub symbol: Box<FieldSymbol>,
 }
 
 pub struct TupleFieldset {
@@ -132,11 +219,65 @@ pub enum TupleFields {
 
 pub enum TupleField {
     Used(
-        Box<IdentOrTerminalIdent>,
+        Box<FieldSymbol>,
     ),
     Skipped(
+        Box<FieldSymbol>,
+    ),
+}
+
+pub enum FieldSymbol {
+    Plain(
+        Box<IdentOrTerminalIdent>,
+    ),
+    ZeroOrMore(
+        Box<IdentOrTerminalIdent>,
+        crate::data::ByteIndex,
+    ),
+    OneOrMore(
+        Box<IdentOrTerminalIdent>,
+        crate::data::ByteIndex,
+    ),
+    Optional(
+        Box<IdentOrTerminalIdent>,
+        crate::data::ByteIndex,
+    ),
+    Call(
+        Box<FieldSymbolCall>,
+    ),
+    Instantiation(
+        Box<Instantiation>,
+    ),
+}
+
+pub struct Instantiation {
+    pub callee: crate::data::token::Ident,
+    pub args: Box<GenericArgs>,
+}
+
+pub enum GenericArgs {
+    One(
         Box<IdentOrTerminalIdent>,
     ),
+    Cons(
+        Box<GenericArgs>,
+        Box<IdentOrTerminalIdent>,
+    ),
+}
+
+pub struct FieldSymbolCall {
+    pub callee: crate::data::token::Ident,
+    pub element: Box<IdentOrTerminalIdent>,
+    pub separator: Box<IdentOrTerminalIdent>,
+    pub flags: Box<OptCallFlags>,
+}
+
+pub enum OptCallFlags {
+    Nil,
+    Cons(
+        Box<OptCallFlags>,
+        crate::data::token::Ident,
+    ),
 }
 
 pub enum OptEnumVariants {
@@ -148,8 +289,18 @@ pub enum OptEnumVariants {
 }
 
 pub struct EnumVariant {
+    pub doc: Box<OptDocComments>,
     pub name: crate::data::token::Ident,
     pub fieldset: Box<Fieldset>,
+    pub precedence_override: Box<OptPrecedenceOverride>,
+    pub action: Box<OptSemanticAction>,
+}
+
+pub enum OptPrecedenceOverride {
+    None,
+    Some(
+        Box<TerminalIdentOrLiteral>,
+    ),
 }
 
 pub enum OptTerminalEnumVariants {
@@ -161,8 +312,114 @@ pub enum OptTerminalEnumVariants {
 }
 
 pub struct TerminalEnumVariant {
-    pub name: crate::data::token::TerminalIdent,
+    pub doc: Box<OptDocComments>,
+    pub name: Box<TerminalIdentOrLiteral>,
     pub type_: Box<Type>,
+    pub pattern: Box<OptTerminalPattern>,
+}
+
+pub enum OptTerminalPattern {
+    None,
+    Some(
+        Box<TerminalPattern>,
+    ),
+}
+
+pub struct TerminalPattern {
+    pub variant: crate::data::token::Ident,
+    pub fields: Box<OptTerminalPatternFields>,
+}
+
+pub enum OptTerminalPatternFields {
+    None,
+    Some(
+        Box<TerminalPatternFields>,
+    ),
+}
+
+pub enum TerminalPatternFields {
+    One(
+        Box<IdentOrUnderscore>,
+    ),
+    Cons(
+        Box<TerminalPatternFields>,
+        Box<IdentOrUnderscore>,
+    ),
+}
+
+pub struct PrecedenceDeclaration {
+    pub associativity: Box<OptAssociativity>,
+    pub terminals: Box<TerminalIdents>,
+}
+
+pub enum OptAssociativity {
+    None,
+    Some(
+        crate::data::token::Ident,
+    ),
+}
+
+pub enum TerminalIdents {
+    One(
+        Box<TerminalIdentOrLiteral>,
+    ),
+    Cons(
+        Box<TerminalIdents>,
+        Box<TerminalIdentOrLiteral>,
+    ),
+}
+
+pub struct Lexer {
+    pub keyword: crate::data::token::Ident,
+    pub rules: Box<OptLexerRules>,
+}
+
+pub enum OptLexerRules {
+    Nil,
+    Cons(
+        Box<OptLexerRules>,
+        Box<LexerRule>,
+    ),
+}
+
+pub struct LexerRule {
+    pub name: Box<TerminalIdentOrUnderscore>,
+    pub pattern: Box<LexerPattern>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalIdentOrUnderscore {
+    Terminal(
+        crate::data::token::TerminalIdent,
+    ),
+    Underscore(
+        crate::data::ByteIndex,
+    ),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LexerPattern {
+    Literal(
+        crate::data::token::StringLiteral,
+    ),
+    Regex(
+        crate::data::token::RegexLiteral,
+    ),
+}
+
+pub struct Allow {
+    pub keyword: crate::data::token::Ident,
+    pub lints: Box<LintNames>,
+}
+
+pub enum LintNames {
+    One(
+        crate::data::token::Ident,
+    ),
+    Cons(
+        Box<LintNames>,
+        crate::data::token::Ident,
+    ),
 }
 
 pub enum Type {
@@ -218,6 +475,19 @@ pub enum IdentOrTerminalIdent {
     Terminal(
         crate::data::token::TerminalIdent,
     ),
+    Literal(
+        crate::data::token::StringLiteral,
+    ),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalIdentOrLiteral {
+    Terminal(
+        crate::data::token::TerminalIdent,
+    ),
+    Literal(
+        crate::data::token::StringLiteral,
+    ),
 }
 
 /// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
@@ -271,20 +541,31 @@ enum QuasiterminalKind {
     Ident = 1,
     TerminalIdent = 2,
     OuterAttribute = 3,
-    StartKw = 4,
-    StructKw = 5,
-    EnumKw = 6,
-    TerminalKw = 7,
-    Colon = 8,
-    DoubleColon = 9,
-    Comma = 10,
-    LParen = 11,
-    RParen = 12,
-    LCurly = 13,
-    RCurly = 14,
-    LAngle = 15,
-    RAngle = 16,
-    Eof = 17,
+    DocComment = 4,
+    SemanticAction = 5,
+    StringLiteral = 6,
+    RegexLiteral = 7,
+    StartKw = 8,
+    StructKw = 9,
+    EnumKw = 10,
+    TerminalKw = 11,
+    PrecedenceKw = 12,
+    PrecKw = 13,
+    ExternKw = 14,
+    Colon = 15,
+    DoubleColon = 16,
+    Comma = 17,
+    Equals = 18,
+    LParen = 19,
+    RParen = 20,
+    LCurly = 21,
+    RCurly = 22,
+    LAngle = 23,
+    RAngle = 24,
+    Star = 25,
+    Plus = 26,
+    Question = 27,
+    Eof = 28,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -295,24 +576,52 @@ enum NonterminalKind {
     Struct = 3,
     Enum = 4,
     TerminalEnum = 5,
-    OptOuterAttributes = 6,
-    Fieldset = 7,
-    NamedFieldset = 8,
-    NamedFields = 9,
-    NamedField = 10,
-    TupleFieldset = 11,
-    TupleFields = 12,
-    TupleField = 13,
-    OptEnumVariants = 14,
-    EnumVariant = 15,
-    OptTerminalEnumVariants = 16,
-    TerminalEnumVariant = 17,
-    Type = 18,
-    Path = 19,
-    ComplexType = 20,
-    CommaSeparatedTypes = 21,
-    IdentOrUnderscore = 22,
-    IdentOrTerminalIdent = 23,
+    TerminalEnumHeader = 6,
+    TerminalEnumName = 7,
+    OptGenericParams = 8,
+    GenericParams = 9,
+    OptResultType = 10,
+    OptSemanticAction = 11,
+    OptDocComments = 12,
+    OptOuterAttributes = 13,
+    Fieldset = 14,
+    NamedFieldset = 15,
+    NamedFields = 16,
+    NamedField = 17,
+    TupleFieldset = 18,
+    TupleFields = 19,
+    TupleField = 20,
+    FieldSymbol = 21,
+    Instantiation = 22,
+    GenericArgs = 23,
+    FieldSymbolCall = 24,
+    OptCallFlags = 25,
+    OptEnumVariants = 26,
+    EnumVariant = 27,
+    OptPrecedenceOverride = 28,
+    OptTerminalEnumVariants = 29,
+    TerminalEnumVariant = 30,
+    OptTerminalPattern = 31,
+    TerminalPattern = 32,
+    OptTerminalPatternFields = 33,
+    TerminalPatternFields = 34,
+    PrecedenceDeclaration = 35,
+    OptAssociativity = 36,
+    TerminalIdents = 37,
+    Lexer = 38,
+    OptLexerRules = 39,
+    LexerRule = 40,
+    TerminalIdentOrUnderscore = 41,
+    LexerPattern = 42,
+    Allow = 43,
+    LintNames = 44,
+    Type = 45,
+    Path = 46,
+    ComplexType = 47,
+    CommaSeparatedTypes = 48,
+    IdentOrUnderscore = 49,
+    IdentOrTerminalIdent = 50,
+    TerminalIdentOrLiteral = 51,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -384,6 +693,100 @@ enum State {
     S64 = 64,
     S65 = 65,
     S66 = 66,
+    S67 = 67,
+    S68 = 68,
+    S69 = 69,
+    S70 = 70,
+    S71 = 71,
+    S72 = 72,
+    S73 = 73,
+    S74 = 74,
+    S75 = 75,
+    S76 = 76,
+    S77 = 77,
+    S78 = 78,
+    S79 = 79,
+    S80 = 80,
+    S81 = 81,
+    S82 = 82,
+    S83 = 83,
+    S84 = 84,
+    S85 = 85,
+    S86 = 86,
+    S87 = 87,
+    S88 = 88,
+    S89 = 89,
+    S90 = 90,
+    S91 = 91,
+    S92 = 92,
+    S93 = 93,
+    S94 = 94,
+    S95 = 95,
+    S96 = 96,
+    S97 = 97,
+    S98 = 98,
+    S99 = 99,
+    S100 = 100,
+    S101 = 101,
+    S102 = 102,
+    S103 = 103,
+    S104 = 104,
+    S105 = 105,
+    S106 = 106,
+    S107 = 107,
+    S108 = 108,
+    S109 = 109,
+    S110 = 110,
+    S111 = 111,
+    S112 = 112,
+    S113 = 113,
+    S114 = 114,
+    S115 = 115,
+    S116 = 116,
+    S117 = 117,
+    S118 = 118,
+    S119 = 119,
+    S120 = 120,
+    S121 = 121,
+    S122 = 122,
+    S123 = 123,
+    S124 = 124,
+    S125 = 125,
+    S126 = 126,
+    S127 = 127,
+    S128 = 128,
+    S129 = 129,
+    S130 = 130,
+    S131 = 131,
+    S132 = 132,
+    S133 = 133,
+    S134 = 134,
+    S135 = 135,
+    S136 = 136,
+    S137 = 137,
+    S138 = 138,
+    S139 = 139,
+    S140 = 140,
+    S141 = 141,
+    S142 = 142,
+    S143 = 143,
+    S144 = 144,
+    S145 = 145,
+    S146 = 146,
+    S147 = 147,
+    S148 = 148,
+    S149 = 149,
+    S150 = 150,
+    S151 = 151,
+    S152 = 152,
+    S153 = 153,
+    S154 = 154,
+    S155 = 155,
+    S156 = 156,
+    S157 = 157,
+    S158 = 158,
+    S159 = 159,
+    S160 = 160,
 }
 
 enum Node {
@@ -393,6 +796,13 @@ enum Node {
     Struct(Struct),
     Enum(Enum),
     TerminalEnum(TerminalEnum),
+    TerminalEnumHeader(TerminalEnumHeader),
+    TerminalEnumName(TerminalEnumName),
+    OptGenericParams(OptGenericParams),
+    GenericParams(GenericParams),
+    OptResultType(OptResultType),
+    OptSemanticAction(OptSemanticAction),
+    OptDocComments(OptDocComments),
     OptOuterAttributes(OptOuterAttributes),
     Fieldset(Fieldset),
     NamedFieldset(NamedFieldset),
@@ -401,33 +811,65 @@ enum Node {
     TupleFieldset(TupleFieldset),
     TupleFields(TupleFields),
     TupleField(TupleField),
+    FieldSymbol(FieldSymbol),
+    Instantiation(Instantiation),
+    GenericArgs(GenericArgs),
+    FieldSymbolCall(FieldSymbolCall),
+    OptCallFlags(OptCallFlags),
     OptEnumVariants(OptEnumVariants),
     EnumVariant(EnumVariant),
+    OptPrecedenceOverride(OptPrecedenceOverride),
     OptTerminalEnumVariants(OptTerminalEnumVariants),
     TerminalEnumVariant(TerminalEnumVariant),
+    OptTerminalPattern(OptTerminalPattern),
+    TerminalPattern(TerminalPattern),
+    OptTerminalPatternFields(OptTerminalPatternFields),
+    TerminalPatternFields(TerminalPatternFields),
+    PrecedenceDeclaration(PrecedenceDeclaration),
+    OptAssociativity(OptAssociativity),
+    TerminalIdents(TerminalIdents),
+    Lexer(Lexer),
+    OptLexerRules(OptLexerRules),
+    LexerRule(LexerRule),
+    TerminalIdentOrUnderscore(TerminalIdentOrUnderscore),
+    LexerPattern(LexerPattern),
+    Allow(Allow),
+    LintNames(LintNames),
     Type(Type),
     Path(Path),
     ComplexType(ComplexType),
     CommaSeparatedTypes(CommaSeparatedTypes),
     IdentOrUnderscore(IdentOrUnderscore),
     IdentOrTerminalIdent(IdentOrTerminalIdent),
+    TerminalIdentOrLiteral(TerminalIdentOrLiteral),
     Underscore(crate::data::ByteIndex),
     Ident(crate::data::token::Ident),
     TerminalIdent(crate::data::token::TerminalIdent),
     OuterAttribute(crate::data::token::Attribute),
+    DocComment(crate::data::token::DocComment),
+    SemanticAction(crate::data::token::SemanticAction),
+    StringLiteral(crate::data::token::StringLiteral),
+    RegexLiteral(crate::data::token::RegexLiteral),
     StartKw(crate::data::ByteIndex),
     StructKw(crate::data::ByteIndex),
     EnumKw(crate::data::ByteIndex),
     TerminalKw(crate::data::ByteIndex),
+    PrecedenceKw(crate::data::ByteIndex),
+    PrecKw(crate::data::ByteIndex),
+    ExternKw(crate::data::ByteIndex),
     Colon(crate::data::ByteIndex),
     DoubleColon(crate::data::ByteIndex),
     Comma(crate::data::ByteIndex),
+    Equals(crate::data::ByteIndex),
     LParen(crate::data::ByteIndex),
     RParen(crate::data::ByteIndex),
     LCurly(crate::data::ByteIndex),
     RCurly(crate::data::ByteIndex),
     LAngle(crate::data::ByteIndex),
     RAngle(crate::data::ByteIndex),
+    Star(crate::data::ByteIndex),
+    Plus(crate::data::ByteIndex),
+    Question(crate::data::ByteIndex),
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -482,6 +924,63 @@ enum RuleKind {
     R39 = 39,
     R40 = 40,
     R41 = 41,
+    R42 = 42,
+    R43 = 43,
+    R44 = 44,
+    R45 = 45,
+    R46 = 46,
+    R47 = 47,
+    R48 = 48,
+    R49 = 49,
+    R50 = 50,
+    R51 = 51,
+    R52 = 52,
+    R53 = 53,
+    R54 = 54,
+    R55 = 55,
+    R56 = 56,
+    R57 = 57,
+    R58 = 58,
+    R59 = 59,
+    R60 = 60,
+    R61 = 61,
+    R62 = 62,
+    R63 = 63,
+    R64 = 64,
+    R65 = 65,
+    R66 = 66,
+    R67 = 67,
+    R68 = 68,
+    R69 = 69,
+    R70 = 70,
+    R71 = 71,
+    R72 = 72,
+    R73 = 73,
+    R74 = 74,
+    R75 = 75,
+    R76 = 76,
+    R77 = 77,
+    R78 = 78,
+    R79 = 79,
+    R80 = 80,
+    R81 = 81,
+    R82 = 82,
+    R83 = 83,
+    R84 = 84,
+    R85 = 85,
+    R86 = 86,
+    R87 = 87,
+    R88 = 88,
+    R89 = 89,
+    R90 = 90,
+    R91 = 91,
+    R92 = 92,
+    R93 = 93,
+    R94 = 94,
+    R95 = 95,
+    R96 = 96,
+    R97 = 97,
+    R98 = 98,
 }
 
 fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
@@ -568,1871 +1067,13097 @@ fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: Rul
             )
         }
         RuleKind::R7 => {
-            let fieldset_3 = Box::new(Fieldset::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            let t0 = Box::new(PrecedenceDeclaration::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::FileItem(FileItem::Precedence(
+                    t0,
+                )),
+                NonterminalKind::FileItem,
+            )
+        }
+        RuleKind::R8 => {
+            let t0 = Box::new(Lexer::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::FileItem(FileItem::Lexer(
+                    t0,
+                )),
+                NonterminalKind::FileItem,
+            )
+        }
+        RuleKind::R9 => {
+            let t0 = Box::new(Allow::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::FileItem(FileItem::Allow(
+                    t0,
+                )),
+                NonterminalKind::FileItem,
+            )
+        }
+        RuleKind::R10 => {
+            let action_7 = Box::new(OptSemanticAction::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let fieldset_6 = Box::new(Fieldset::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let result_type_5 = Box::new(OptResultType::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let params_4 = Box::new(OptGenericParams::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let name_3 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
             nodes.pop().unwrap();
-            let attributes_0 = Box::new(OptOuterAttributes::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let attributes_1 = Box::new(OptOuterAttributes::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let doc_0 = Box::new(OptDocComments::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 4);
+            states.truncate(states.len() - 8);
             
             (
                 Node::Struct(Struct {
-                    attributes: attributes_0,
-                    name: name_2,
-                    fieldset: fieldset_3,
+                    doc: doc_0,
+                    attributes: attributes_1,
+                    name: name_3,
+                    params: params_4,
+                    result_type: result_type_5,
+                    fieldset: fieldset_6,
+                    action: action_7,
                 }),
                 NonterminalKind::Struct,
             )
         }
-        RuleKind::R8 => {
+        RuleKind::R11 => {
             nodes.pop().unwrap();
-            let variants_4 = Box::new(OptEnumVariants::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let variants_7 = Box::new(OptEnumVariants::try_from(nodes.pop().unwrap()).ok().unwrap());
             nodes.pop().unwrap();
-            let name_2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            let result_type_5 = Box::new(OptResultType::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let params_4 = Box::new(OptGenericParams::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let name_3 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
             nodes.pop().unwrap();
-            let attributes_0 = Box::new(OptOuterAttributes::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let attributes_1 = Box::new(OptOuterAttributes::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let doc_0 = Box::new(OptDocComments::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 6);
+            states.truncate(states.len() - 9);
             
             (
                 Node::Enum(Enum {
-                    attributes: attributes_0,
-                    name: name_2,
-                    variants: variants_4,
+                    doc: doc_0,
+                    attributes: attributes_1,
+                    name: name_3,
+                    params: params_4,
+                    result_type: result_type_5,
+                    variants: variants_7,
                 }),
                 NonterminalKind::Enum,
             )
         }
-        RuleKind::R9 => {
+        RuleKind::R12 => {
             nodes.pop().unwrap();
-            let variants_4 = Box::new(OptTerminalEnumVariants::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let variants_5 = Box::new(OptTerminalEnumVariants::try_from(nodes.pop().unwrap()).ok().unwrap());
             nodes.pop().unwrap();
-            let name_2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            let header_3 = Box::new(TerminalEnumHeader::try_from(nodes.pop().unwrap()).ok().unwrap());
             nodes.pop().unwrap();
-            let attributes_0 = Box::new(OptOuterAttributes::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let attributes_1 = Box::new(OptOuterAttributes::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let doc_0 = Box::new(OptDocComments::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 6);
+            states.truncate(states.len() - 7);
             
             (
                 Node::TerminalEnum(TerminalEnum {
-                    attributes: attributes_0,
-                    name: name_2,
-                    variants: variants_4,
+                    doc: doc_0,
+                    attributes: attributes_1,
+                    header: header_3,
+                    variants: variants_5,
                 }),
                 NonterminalKind::TerminalEnum,
             )
         }
-        RuleKind::R10 => {
-            (
-                Node::OptOuterAttributes(OptOuterAttributes::Nil),
-                NonterminalKind::OptOuterAttributes,
-            )
-        }
-        RuleKind::R11 => {
-            let t1 = nodes.pop().unwrap().try_into_outer_attribute_3().ok().unwrap();
-            let t0 = Box::new(OptOuterAttributes::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R13 => {
+            let t0 = Box::new(TerminalEnumName::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 2);
+            states.truncate(states.len() - 1);
             
             (
-                Node::OptOuterAttributes(OptOuterAttributes::Cons(
+                Node::TerminalEnumHeader(TerminalEnumHeader::Unspanned(
                     t0,
-                    t1,
                 )),
-                NonterminalKind::OptOuterAttributes,
+                NonterminalKind::TerminalEnumHeader,
             )
         }
-        RuleKind::R12 => {
+        RuleKind::R14 => {
+            nodes.pop().unwrap();
+            let t3 = Box::new(Type::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let t1 = Box::new(TerminalEnumName::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            
+            states.truncate(states.len() - 5);
+            
             (
-                Node::Fieldset(Fieldset::Empty),
-                NonterminalKind::Fieldset,
+                Node::TerminalEnumHeader(TerminalEnumHeader::Spanned(
+                    t1,
+                    t3,
+                )),
+                NonterminalKind::TerminalEnumHeader,
             )
         }
-        RuleKind::R13 => {
-            let t0 = Box::new(NamedFieldset::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R15 => {
+            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
             
             states.truncate(states.len() - 1);
             
             (
-                Node::Fieldset(Fieldset::Named(
+                Node::TerminalEnumName(TerminalEnumName::Local(
                     t0,
                 )),
-                NonterminalKind::Fieldset,
+                NonterminalKind::TerminalEnumName,
             )
         }
-        RuleKind::R14 => {
-            let t0 = Box::new(TupleFieldset::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R16 => {
+            let t1 = Box::new(Path::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
             
-            states.truncate(states.len() - 1);
+            states.truncate(states.len() - 2);
             
             (
-                Node::Fieldset(Fieldset::Tuple(
-                    t0,
+                Node::TerminalEnumName(TerminalEnumName::Extern(
+                    t1,
                 )),
-                NonterminalKind::Fieldset,
+                NonterminalKind::TerminalEnumName,
             )
         }
-        RuleKind::R15 => {
+        RuleKind::R17 => {
+            (
+                Node::OptGenericParams(OptGenericParams::None),
+                NonterminalKind::OptGenericParams,
+            )
+        }
+        RuleKind::R18 => {
             nodes.pop().unwrap();
-            let fields_1 = Box::new(NamedFields::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let t1 = Box::new(GenericParams::try_from(nodes.pop().unwrap()).ok().unwrap());
             nodes.pop().unwrap();
             
             states.truncate(states.len() - 3);
             
             (
-                Node::NamedFieldset(NamedFieldset {
-                    fields: fields_1,
-                }),
-                NonterminalKind::NamedFieldset,
+                Node::OptGenericParams(OptGenericParams::Some(
+                    t1,
+                )),
+                NonterminalKind::OptGenericParams,
             )
         }
-        RuleKind::R16 => {
-            let t0 = Box::new(NamedField::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R19 => {
+            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
             
             states.truncate(states.len() - 1);
             
             (
-                Node::NamedFields(NamedFields::One(
+                Node::GenericParams(GenericParams::One(
                     t0,
                 )),
-                NonterminalKind::NamedFields,
+                NonterminalKind::GenericParams,
             )
         }
-        RuleKind::R17 => {
-            let t1 = Box::new(NamedField::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(NamedFields::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R20 => {
+            let t2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            nodes.pop().unwrap();
+            let t0 = Box::new(GenericParams::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 2);
+            states.truncate(states.len() - 3);
             
             (
-                Node::NamedFields(NamedFields::Cons(
+                Node::GenericParams(GenericParams::Cons(
                     t0,
-                    t1,
+                    t2,
                 )),
-                NonterminalKind::NamedFields,
+                NonterminalKind::GenericParams,
             )
         }
-        RuleKind::R18 => {
-            let symbol_2 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R21 => {
+            (
+                Node::OptResultType(OptResultType::None),
+                NonterminalKind::OptResultType,
+            )
+        }
+        RuleKind::R22 => {
+            let t1 = Box::new(Type::try_from(nodes.pop().unwrap()).ok().unwrap());
             nodes.pop().unwrap();
-            let name_0 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 3);
+            states.truncate(states.len() - 2);
             
             (
-                Node::NamedField(NamedField {
-                    name: name_0,
-                    symbol: symbol_2,
-                }),
-                NonterminalKind::NamedField,
+                Node::OptResultType(OptResultType::Some(
+                    t1,
+                )),
+                NonterminalKind::OptResultType,
             )
         }
-        RuleKind::R19 => {
-            nodes.pop().unwrap();
-            let fields_1 = Box::new(TupleFields::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
+        RuleKind::R23 => {
             (
-                Node::TupleFieldset(TupleFieldset {
-                    fields: fields_1,
-                }),
-                NonterminalKind::TupleFieldset,
+                Node::OptSemanticAction(OptSemanticAction::None),
+                NonterminalKind::OptSemanticAction,
             )
         }
-        RuleKind::R20 => {
-            let t0 = Box::new(TupleField::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R24 => {
+            let t0 = nodes.pop().unwrap().try_into_semantic_action_5().ok().unwrap();
             
             states.truncate(states.len() - 1);
             
             (
-                Node::TupleFields(TupleFields::One(
+                Node::OptSemanticAction(OptSemanticAction::Some(
                     t0,
                 )),
-                NonterminalKind::TupleFields,
+                NonterminalKind::OptSemanticAction,
             )
         }
-        RuleKind::R21 => {
-            let t1 = Box::new(TupleField::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(TupleFields::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R25 => {
+            (
+                Node::OptDocComments(OptDocComments::Nil),
+                NonterminalKind::OptDocComments,
+            )
+        }
+        RuleKind::R26 => {
+            let t1 = nodes.pop().unwrap().try_into_doc_comment_4().ok().unwrap();
+            let t0 = Box::new(OptDocComments::try_from(nodes.pop().unwrap()).ok().unwrap());
             
             states.truncate(states.len() - 2);
             
             (
-                Node::TupleFields(TupleFields::Cons(
+                Node::OptDocComments(OptDocComments::Cons(
                     t0,
                     t1,
                 )),
-                NonterminalKind::TupleFields,
+                NonterminalKind::OptDocComments,
             )
         }
-        RuleKind::R22 => {
-            let t0 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R27 => {
+            (
+                Node::OptOuterAttributes(OptOuterAttributes::Nil),
+                NonterminalKind::OptOuterAttributes,
+            )
+        }
+        RuleKind::R28 => {
+            let t1 = nodes.pop().unwrap().try_into_outer_attribute_3().ok().unwrap();
+            let t0 = Box::new(OptOuterAttributes::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 1);
+            states.truncate(states.len() - 2);
             
             (
-                Node::TupleField(TupleField::Used(
+                Node::OptOuterAttributes(OptOuterAttributes::Cons(
                     t0,
+                    t1,
                 )),
-                NonterminalKind::TupleField,
+                NonterminalKind::OptOuterAttributes,
             )
         }
-        RuleKind::R23 => {
-            let t2 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            nodes.pop().unwrap();
-            
-            states.truncate(states.len() - 3);
-            
+        RuleKind::R29 => {
             (
-                Node::TupleField(TupleField::Skipped(
-                    t2,
-                )),
-                NonterminalKind::TupleField,
+                Node::Fieldset(Fieldset::Empty),
+                NonterminalKind::Fieldset,
             )
         }
-        RuleKind::R24 => {
+        RuleKind::R30 => {
+            let t0 = Box::new(NamedFieldset::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
             (
-                Node::OptEnumVariants(OptEnumVariants::Nil),
-                NonterminalKind::OptEnumVariants,
+                Node::Fieldset(Fieldset::Named(
+                    t0,
+                )),
+                NonterminalKind::Fieldset,
             )
         }
-        RuleKind::R25 => {
-            let t1 = Box::new(EnumVariant::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(OptEnumVariants::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R31 => {
+            let t0 = Box::new(TupleFieldset::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 2);
+            states.truncate(states.len() - 1);
             
             (
-                Node::OptEnumVariants(OptEnumVariants::Cons(
+                Node::Fieldset(Fieldset::Tuple(
                     t0,
-                    t1,
                 )),
-                NonterminalKind::OptEnumVariants,
+                NonterminalKind::Fieldset,
             )
         }
-        RuleKind::R26 => {
-            let fieldset_1 = Box::new(Fieldset::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let name_0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+        RuleKind::R32 => {
+            nodes.pop().unwrap();
+            let fields_1 = Box::new(NamedFields::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
             
-            states.truncate(states.len() - 2);
+            states.truncate(states.len() - 3);
             
             (
-                Node::EnumVariant(EnumVariant {
-                    name: name_0,
-                    fieldset: fieldset_1,
+                Node::NamedFieldset(NamedFieldset {
+                    fields: fields_1,
                 }),
-                NonterminalKind::EnumVariant,
+                NonterminalKind::NamedFieldset,
             )
         }
-        RuleKind::R27 => {
+        RuleKind::R33 => {
+            let t0 = Box::new(NamedField::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
             (
-                Node::OptTerminalEnumVariants(OptTerminalEnumVariants::Nil),
-                NonterminalKind::OptTerminalEnumVariants,
+                Node::NamedFields(NamedFields::One(
+                    t0,
+                )),
+                NonterminalKind::NamedFields,
             )
         }
-        RuleKind::R28 => {
-            let t1 = Box::new(TerminalEnumVariant::try_from(nodes.pop().unwrap()).ok().unwrap());
-            let t0 = Box::new(OptTerminalEnumVariants::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R34 => {
+            let t1 = Box::new(NamedField::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let t0 = Box::new(NamedFields::try_from(nodes.pop().unwrap()).ok().unwrap());
             
             states.truncate(states.len() - 2);
             
             (
-                Node::OptTerminalEnumVariants(OptTerminalEnumVariants::Cons(
+                Node::NamedFields(NamedFields::Cons(
                     t0,
                     t1,
                 )),
-                NonterminalKind::OptTerminalEnumVariants,
+                NonterminalKind::NamedFields,
             )
         }
-        RuleKind::R29 => {
-            let type__2 = Box::new(Type::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R35 => {
+            let symbol_3 = Box::new(FieldSymbol::try_from(nodes.pop().unwrap()).ok().unwrap());
             nodes.pop().unwrap();
-            let name_0 = nodes.pop().unwrap().try_into_terminal_ident_2().ok().unwrap();
+            let name_1 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let doc_0 = Box::new(OptDocComments::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 3);
+            states.truncate(states.len() - 4);
             
             (
-                Node::TerminalEnumVariant(TerminalEnumVariant {
-                    name: name_0,
-                    type_: type__2,
+                Node::NamedField(NamedField {
+                    doc: doc_0,
+                    name: name_1,
+                    symbol: symbol_3,
                 }),
-                NonterminalKind::TerminalEnumVariant,
+                NonterminalKind::NamedField,
             )
         }
-        RuleKind::R30 => {
+        RuleKind::R36 => {
             nodes.pop().unwrap();
+            let fields_1 = Box::new(TupleFields::try_from(nodes.pop().unwrap()).ok().unwrap());
             nodes.pop().unwrap();
             
-            states.truncate(states.len() - 2);
+            states.truncate(states.len() - 3);
             
             (
-                Node::Type(Type::Unit),
-                NonterminalKind::Type,
+                Node::TupleFieldset(TupleFieldset {
+                    fields: fields_1,
+                }),
+                NonterminalKind::TupleFieldset,
             )
         }
-        RuleKind::R31 => {
-            let t0 = Box::new(Path::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R37 => {
+            let t0 = Box::new(TupleField::try_from(nodes.pop().unwrap()).ok().unwrap());
             
             states.truncate(states.len() - 1);
             
             (
-                Node::Type(Type::Path(
+                Node::TupleFields(TupleFields::One(
                     t0,
                 )),
-                NonterminalKind::Type,
+                NonterminalKind::TupleFields,
             )
         }
-        RuleKind::R32 => {
-            let t0 = Box::new(ComplexType::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R38 => {
+            let t1 = Box::new(TupleField::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let t0 = Box::new(TupleFields::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 1);
+            states.truncate(states.len() - 2);
             
             (
-                Node::Type(Type::Complex(
+                Node::TupleFields(TupleFields::Cons(
                     t0,
+                    t1,
                 )),
-                NonterminalKind::Type,
+                NonterminalKind::TupleFields,
             )
         }
-        RuleKind::R33 => {
-            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+        RuleKind::R39 => {
+            let t0 = Box::new(FieldSymbol::try_from(nodes.pop().unwrap()).ok().unwrap());
             
             states.truncate(states.len() - 1);
             
             (
-                Node::Path(Path::One(
+                Node::TupleField(TupleField::Used(
                     t0,
                 )),
-                NonterminalKind::Path,
+                NonterminalKind::TupleField,
             )
         }
-        RuleKind::R34 => {
-            let t2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+        RuleKind::R40 => {
+            let t2 = Box::new(FieldSymbol::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
             nodes.pop().unwrap();
-            let t0 = Box::new(Path::try_from(nodes.pop().unwrap()).ok().unwrap());
             
             states.truncate(states.len() - 3);
             
             (
-                Node::Path(Path::Cons(
-                    t0,
+                Node::TupleField(TupleField::Skipped(
                     t2,
                 )),
-                NonterminalKind::Path,
+                NonterminalKind::TupleField,
             )
         }
-        RuleKind::R35 => {
-            nodes.pop().unwrap();
-            let args_2 = Box::new(CommaSeparatedTypes::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let callee_0 = Box::new(Path::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R41 => {
+            let t0 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 4);
+            states.truncate(states.len() - 1);
             
             (
-                Node::ComplexType(ComplexType {
-                    callee: callee_0,
-                    args: args_2,
-                }),
-                NonterminalKind::ComplexType,
+                Node::FieldSymbol(FieldSymbol::Plain(
+                    t0,
+                )),
+                NonterminalKind::FieldSymbol,
             )
         }
-        RuleKind::R36 => {
-            let t0 = Box::new(Type::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R42 => {
+            let t1 = nodes.pop().unwrap().try_into_star_25().ok().unwrap();
+            let t0 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 1);
+            states.truncate(states.len() - 2);
             
             (
-                Node::CommaSeparatedTypes(CommaSeparatedTypes::One(
+                Node::FieldSymbol(FieldSymbol::ZeroOrMore(
                     t0,
+                    t1,
                 )),
-                NonterminalKind::CommaSeparatedTypes,
+                NonterminalKind::FieldSymbol,
             )
         }
-        RuleKind::R37 => {
-            let t2 = Box::new(Type::try_from(nodes.pop().unwrap()).ok().unwrap());
-            nodes.pop().unwrap();
-            let t0 = Box::new(CommaSeparatedTypes::try_from(nodes.pop().unwrap()).ok().unwrap());
+        RuleKind::R43 => {
+            let t1 = nodes.pop().unwrap().try_into_plus_26().ok().unwrap();
+            let t0 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 3);
+            states.truncate(states.len() - 2);
             
             (
-                Node::CommaSeparatedTypes(CommaSeparatedTypes::Cons(
+                Node::FieldSymbol(FieldSymbol::OneOrMore(
                     t0,
-                    t2,
+                    t1,
                 )),
-                NonterminalKind::CommaSeparatedTypes,
+                NonterminalKind::FieldSymbol,
             )
         }
-        RuleKind::R38 => {
-            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+        RuleKind::R44 => {
+            let t1 = nodes.pop().unwrap().try_into_question_27().ok().unwrap();
+            let t0 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
             
-            states.truncate(states.len() - 1);
+            states.truncate(states.len() - 2);
             
             (
-                Node::IdentOrUnderscore(IdentOrUnderscore::Ident(
+                Node::FieldSymbol(FieldSymbol::Optional(
                     t0,
+                    t1,
                 )),
-                NonterminalKind::IdentOrUnderscore,
+                NonterminalKind::FieldSymbol,
             )
         }
-        RuleKind::R39 => {
-            let t0 = nodes.pop().unwrap().try_into_underscore_0().ok().unwrap();
+        RuleKind::R45 => {
+            let t0 = Box::new(FieldSymbolCall::try_from(nodes.pop().unwrap()).ok().unwrap());
             
             states.truncate(states.len() - 1);
             
             (
-                Node::IdentOrUnderscore(IdentOrUnderscore::Underscore(
+                Node::FieldSymbol(FieldSymbol::Call(
                     t0,
                 )),
-                NonterminalKind::IdentOrUnderscore,
+                NonterminalKind::FieldSymbol,
             )
         }
-        RuleKind::R40 => {
-            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+        RuleKind::R46 => {
+            let t0 = Box::new(Instantiation::try_from(nodes.pop().unwrap()).ok().unwrap());
             
             states.truncate(states.len() - 1);
             
             (
-                Node::IdentOrTerminalIdent(IdentOrTerminalIdent::Ident(
+                Node::FieldSymbol(FieldSymbol::Instantiation(
                     t0,
                 )),
-                NonterminalKind::IdentOrTerminalIdent,
+                NonterminalKind::FieldSymbol,
             )
         }
-        RuleKind::R41 => {
-            let t0 = nodes.pop().unwrap().try_into_terminal_ident_2().ok().unwrap();
+        RuleKind::R47 => {
+            nodes.pop().unwrap();
+            let args_2 = Box::new(GenericArgs::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let callee_0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 4);
+            
+            (
+                Node::Instantiation(Instantiation {
+                    callee: callee_0,
+                    args: args_2,
+                }),
+                NonterminalKind::Instantiation,
+            )
+        }
+        RuleKind::R48 => {
+            let t0 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
             
             states.truncate(states.len() - 1);
             
             (
-                Node::IdentOrTerminalIdent(IdentOrTerminalIdent::Terminal(
+                Node::GenericArgs(GenericArgs::One(
                     t0,
                 )),
-                NonterminalKind::IdentOrTerminalIdent,
+                NonterminalKind::GenericArgs,
             )
         }
-    }
-}
-
-impl QuasiterminalKind {
-    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
-        match quasiterminal {
-            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
-            Quasiterminal::Eof => Self::Eof,
+        RuleKind::R49 => {
+            let t2 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let t0 = Box::new(GenericArgs::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 3);
+            
+            (
+                Node::GenericArgs(GenericArgs::Cons(
+                    t0,
+                    t2,
+                )),
+                NonterminalKind::GenericArgs,
+            )
         }
-    }
-
-    fn from_terminal(terminal: &Token) -> Self {
-        match terminal {
-            Token::Underscore(_) => Self::Underscore,
-            Token::Ident(_) => Self::Ident,
-            Token::TerminalIdent(_) => Self::TerminalIdent,
-            Token::OuterAttribute(_) => Self::OuterAttribute,
-            Token::StartKw(_) => Self::StartKw,
-            Token::StructKw(_) => Self::StructKw,
-            Token::EnumKw(_) => Self::EnumKw,
-            Token::TerminalKw(_) => Self::TerminalKw,
-            Token::Colon(_) => Self::Colon,
-            Token::DoubleColon(_) => Self::DoubleColon,
-            Token::Comma(_) => Self::Comma,
-            Token::LParen(_) => Self::LParen,
-            Token::RParen(_) => Self::RParen,
-            Token::LCurly(_) => Self::LCurly,
-            Token::RCurly(_) => Self::RCurly,
-            Token::LAngle(_) => Self::LAngle,
-            Token::RAngle(_) => Self::RAngle,
+        RuleKind::R50 => {
+            nodes.pop().unwrap();
+            let flags_5 = Box::new(OptCallFlags::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let separator_4 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let element_2 = Box::new(IdentOrTerminalIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let callee_0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 7);
+            
+            (
+                Node::FieldSymbolCall(FieldSymbolCall {
+                    callee: callee_0,
+                    element: element_2,
+                    separator: separator_4,
+                    flags: flags_5,
+                }),
+                NonterminalKind::FieldSymbolCall,
+            )
         }
-    }
-}
-
-impl Node {
-    fn from_terminal(terminal: Token) -> Self {
-        match terminal {
-            Token::Underscore(t) => Self::Underscore(t),
-            Token::Ident(t) => Self::Ident(t),
-            Token::TerminalIdent(t) => Self::TerminalIdent(t),
-            Token::OuterAttribute(t) => Self::OuterAttribute(t),
-            Token::StartKw(t) => Self::StartKw(t),
-            Token::StructKw(t) => Self::StructKw(t),
-            Token::EnumKw(t) => Self::EnumKw(t),
-            Token::TerminalKw(t) => Self::TerminalKw(t),
-            Token::Colon(t) => Self::Colon(t),
-            Token::DoubleColon(t) => Self::DoubleColon(t),
-            Token::Comma(t) => Self::Comma(t),
-            Token::LParen(t) => Self::LParen(t),
-            Token::RParen(t) => Self::RParen(t),
-            Token::LCurly(t) => Self::LCurly(t),
-            Token::RCurly(t) => Self::RCurly(t),
-            Token::LAngle(t) => Self::LAngle(t),
-            Token::RAngle(t) => Self::RAngle(t),
+        RuleKind::R51 => {
+            (
+                Node::OptCallFlags(OptCallFlags::Nil),
+                NonterminalKind::OptCallFlags,
+            )
         }
-    }
-}
-
-impl Quasiterminal {
-    fn try_into_terminal(self) -> Result<Token, ()> {
-        match self {
-            Self::Terminal(terminal) => Ok(terminal),
-            Self::Eof => Err(()),
+        RuleKind::R52 => {
+            let t2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            nodes.pop().unwrap();
+            let t0 = Box::new(OptCallFlags::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 3);
+            
+            (
+                Node::OptCallFlags(OptCallFlags::Cons(
+                    t0,
+                    t2,
+                )),
+                NonterminalKind::OptCallFlags,
+            )
         }
-    }
-}
-
-const ACTION_TABLE: [[Action; 18]; 67] = [
+        RuleKind::R53 => {
+            (
+                Node::OptEnumVariants(OptEnumVariants::Nil),
+                NonterminalKind::OptEnumVariants,
+            )
+        }
+        RuleKind::R54 => {
+            let t1 = Box::new(EnumVariant::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let t0 = Box::new(OptEnumVariants::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 2);
+            
+            (
+                Node::OptEnumVariants(OptEnumVariants::Cons(
+                    t0,
+                    t1,
+                )),
+                NonterminalKind::OptEnumVariants,
+            )
+        }
+        RuleKind::R55 => {
+            let action_4 = Box::new(OptSemanticAction::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let precedence_override_3 = Box::new(OptPrecedenceOverride::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let fieldset_2 = Box::new(Fieldset::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let name_1 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            let doc_0 = Box::new(OptDocComments::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 5);
+            
+            (
+                Node::EnumVariant(EnumVariant {
+                    doc: doc_0,
+                    name: name_1,
+                    fieldset: fieldset_2,
+                    precedence_override: precedence_override_3,
+                    action: action_4,
+                }),
+                NonterminalKind::EnumVariant,
+            )
+        }
+        RuleKind::R56 => {
+            (
+                Node::OptPrecedenceOverride(OptPrecedenceOverride::None),
+                NonterminalKind::OptPrecedenceOverride,
+            )
+        }
+        RuleKind::R57 => {
+            let t1 = Box::new(TerminalIdentOrLiteral::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            
+            states.truncate(states.len() - 2);
+            
+            (
+                Node::OptPrecedenceOverride(OptPrecedenceOverride::Some(
+                    t1,
+                )),
+                NonterminalKind::OptPrecedenceOverride,
+            )
+        }
+        RuleKind::R58 => {
+            (
+                Node::OptTerminalEnumVariants(OptTerminalEnumVariants::Nil),
+                NonterminalKind::OptTerminalEnumVariants,
+            )
+        }
+        RuleKind::R59 => {
+            let t1 = Box::new(TerminalEnumVariant::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let t0 = Box::new(OptTerminalEnumVariants::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 2);
+            
+            (
+                Node::OptTerminalEnumVariants(OptTerminalEnumVariants::Cons(
+                    t0,
+                    t1,
+                )),
+                NonterminalKind::OptTerminalEnumVariants,
+            )
+        }
+        RuleKind::R60 => {
+            let pattern_4 = Box::new(OptTerminalPattern::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let type__3 = Box::new(Type::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let name_1 = Box::new(TerminalIdentOrLiteral::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let doc_0 = Box::new(OptDocComments::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 5);
+            
+            (
+                Node::TerminalEnumVariant(TerminalEnumVariant {
+                    doc: doc_0,
+                    name: name_1,
+                    type_: type__3,
+                    pattern: pattern_4,
+                }),
+                NonterminalKind::TerminalEnumVariant,
+            )
+        }
+        RuleKind::R61 => {
+            (
+                Node::OptTerminalPattern(OptTerminalPattern::None),
+                NonterminalKind::OptTerminalPattern,
+            )
+        }
+        RuleKind::R62 => {
+            let t1 = Box::new(TerminalPattern::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            
+            states.truncate(states.len() - 2);
+            
+            (
+                Node::OptTerminalPattern(OptTerminalPattern::Some(
+                    t1,
+                )),
+                NonterminalKind::OptTerminalPattern,
+            )
+        }
+        RuleKind::R63 => {
+            let fields_1 = Box::new(OptTerminalPatternFields::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let variant_0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 2);
+            
+            (
+                Node::TerminalPattern(TerminalPattern {
+                    variant: variant_0,
+                    fields: fields_1,
+                }),
+                NonterminalKind::TerminalPattern,
+            )
+        }
+        RuleKind::R64 => {
+            (
+                Node::OptTerminalPatternFields(OptTerminalPatternFields::None),
+                NonterminalKind::OptTerminalPatternFields,
+            )
+        }
+        RuleKind::R65 => {
+            nodes.pop().unwrap();
+            let t1 = Box::new(TerminalPatternFields::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            
+            states.truncate(states.len() - 3);
+            
+            (
+                Node::OptTerminalPatternFields(OptTerminalPatternFields::Some(
+                    t1,
+                )),
+                NonterminalKind::OptTerminalPatternFields,
+            )
+        }
+        RuleKind::R66 => {
+            let t0 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::TerminalPatternFields(TerminalPatternFields::One(
+                    t0,
+                )),
+                NonterminalKind::TerminalPatternFields,
+            )
+        }
+        RuleKind::R67 => {
+            let t2 = Box::new(IdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let t0 = Box::new(TerminalPatternFields::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 3);
+            
+            (
+                Node::TerminalPatternFields(TerminalPatternFields::Cons(
+                    t0,
+                    t2,
+                )),
+                NonterminalKind::TerminalPatternFields,
+            )
+        }
+        RuleKind::R68 => {
+            let terminals_2 = Box::new(TerminalIdents::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let associativity_1 = Box::new(OptAssociativity::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            
+            states.truncate(states.len() - 3);
+            
+            (
+                Node::PrecedenceDeclaration(PrecedenceDeclaration {
+                    associativity: associativity_1,
+                    terminals: terminals_2,
+                }),
+                NonterminalKind::PrecedenceDeclaration,
+            )
+        }
+        RuleKind::R69 => {
+            (
+                Node::OptAssociativity(OptAssociativity::None),
+                NonterminalKind::OptAssociativity,
+            )
+        }
+        RuleKind::R70 => {
+            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::OptAssociativity(OptAssociativity::Some(
+                    t0,
+                )),
+                NonterminalKind::OptAssociativity,
+            )
+        }
+        RuleKind::R71 => {
+            let t0 = Box::new(TerminalIdentOrLiteral::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::TerminalIdents(TerminalIdents::One(
+                    t0,
+                )),
+                NonterminalKind::TerminalIdents,
+            )
+        }
+        RuleKind::R72 => {
+            let t1 = Box::new(TerminalIdentOrLiteral::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let t0 = Box::new(TerminalIdents::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 2);
+            
+            (
+                Node::TerminalIdents(TerminalIdents::Cons(
+                    t0,
+                    t1,
+                )),
+                NonterminalKind::TerminalIdents,
+            )
+        }
+        RuleKind::R73 => {
+            nodes.pop().unwrap();
+            let rules_2 = Box::new(OptLexerRules::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let keyword_0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 4);
+            
+            (
+                Node::Lexer(Lexer {
+                    keyword: keyword_0,
+                    rules: rules_2,
+                }),
+                NonterminalKind::Lexer,
+            )
+        }
+        RuleKind::R74 => {
+            (
+                Node::OptLexerRules(OptLexerRules::Nil),
+                NonterminalKind::OptLexerRules,
+            )
+        }
+        RuleKind::R75 => {
+            let t1 = Box::new(LexerRule::try_from(nodes.pop().unwrap()).ok().unwrap());
+            let t0 = Box::new(OptLexerRules::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 2);
+            
+            (
+                Node::OptLexerRules(OptLexerRules::Cons(
+                    t0,
+                    t1,
+                )),
+                NonterminalKind::OptLexerRules,
+            )
+        }
+        RuleKind::R76 => {
+            let pattern_2 = Box::new(LexerPattern::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let name_0 = Box::new(TerminalIdentOrUnderscore::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 3);
+            
+            (
+                Node::LexerRule(LexerRule {
+                    name: name_0,
+                    pattern: pattern_2,
+                }),
+                NonterminalKind::LexerRule,
+            )
+        }
+        RuleKind::R77 => {
+            let t0 = nodes.pop().unwrap().try_into_terminal_ident_2().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::TerminalIdentOrUnderscore(TerminalIdentOrUnderscore::Terminal(
+                    t0,
+                )),
+                NonterminalKind::TerminalIdentOrUnderscore,
+            )
+        }
+        RuleKind::R78 => {
+            let t0 = nodes.pop().unwrap().try_into_underscore_0().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::TerminalIdentOrUnderscore(TerminalIdentOrUnderscore::Underscore(
+                    t0,
+                )),
+                NonterminalKind::TerminalIdentOrUnderscore,
+            )
+        }
+        RuleKind::R79 => {
+            let t0 = nodes.pop().unwrap().try_into_string_literal_6().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::LexerPattern(LexerPattern::Literal(
+                    t0,
+                )),
+                NonterminalKind::LexerPattern,
+            )
+        }
+        RuleKind::R80 => {
+            let t0 = nodes.pop().unwrap().try_into_regex_literal_7().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::LexerPattern(LexerPattern::Regex(
+                    t0,
+                )),
+                NonterminalKind::LexerPattern,
+            )
+        }
+        RuleKind::R81 => {
+            nodes.pop().unwrap();
+            let lints_2 = Box::new(LintNames::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let keyword_0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 4);
+            
+            (
+                Node::Allow(Allow {
+                    keyword: keyword_0,
+                    lints: lints_2,
+                }),
+                NonterminalKind::Allow,
+            )
+        }
+        RuleKind::R82 => {
+            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::LintNames(LintNames::One(
+                    t0,
+                )),
+                NonterminalKind::LintNames,
+            )
+        }
+        RuleKind::R83 => {
+            let t2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            nodes.pop().unwrap();
+            let t0 = Box::new(LintNames::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 3);
+            
+            (
+                Node::LintNames(LintNames::Cons(
+                    t0,
+                    t2,
+                )),
+                NonterminalKind::LintNames,
+            )
+        }
+        RuleKind::R84 => {
+            nodes.pop().unwrap();
+            nodes.pop().unwrap();
+            
+            states.truncate(states.len() - 2);
+            
+            (
+                Node::Type(Type::Unit),
+                NonterminalKind::Type,
+            )
+        }
+        RuleKind::R85 => {
+            let t0 = Box::new(Path::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::Type(Type::Path(
+                    t0,
+                )),
+                NonterminalKind::Type,
+            )
+        }
+        RuleKind::R86 => {
+            let t0 = Box::new(ComplexType::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::Type(Type::Complex(
+                    t0,
+                )),
+                NonterminalKind::Type,
+            )
+        }
+        RuleKind::R87 => {
+            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::Path(Path::One(
+                    t0,
+                )),
+                NonterminalKind::Path,
+            )
+        }
+        RuleKind::R88 => {
+            let t2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            nodes.pop().unwrap();
+            let t0 = Box::new(Path::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 3);
+            
+            (
+                Node::Path(Path::Cons(
+                    t0,
+                    t2,
+                )),
+                NonterminalKind::Path,
+            )
+        }
+        RuleKind::R89 => {
+            nodes.pop().unwrap();
+            let args_2 = Box::new(CommaSeparatedTypes::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let callee_0 = Box::new(Path::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 4);
+            
+            (
+                Node::ComplexType(ComplexType {
+                    callee: callee_0,
+                    args: args_2,
+                }),
+                NonterminalKind::ComplexType,
+            )
+        }
+        RuleKind::R90 => {
+            let t0 = Box::new(Type::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::CommaSeparatedTypes(CommaSeparatedTypes::One(
+                    t0,
+                )),
+                NonterminalKind::CommaSeparatedTypes,
+            )
+        }
+        RuleKind::R91 => {
+            let t2 = Box::new(Type::try_from(nodes.pop().unwrap()).ok().unwrap());
+            nodes.pop().unwrap();
+            let t0 = Box::new(CommaSeparatedTypes::try_from(nodes.pop().unwrap()).ok().unwrap());
+            
+            states.truncate(states.len() - 3);
+            
+            (
+                Node::CommaSeparatedTypes(CommaSeparatedTypes::Cons(
+                    t0,
+                    t2,
+                )),
+                NonterminalKind::CommaSeparatedTypes,
+            )
+        }
+        RuleKind::R92 => {
+            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::IdentOrUnderscore(IdentOrUnderscore::Ident(
+                    t0,
+                )),
+                NonterminalKind::IdentOrUnderscore,
+            )
+        }
+        RuleKind::R93 => {
+            let t0 = nodes.pop().unwrap().try_into_underscore_0().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::IdentOrUnderscore(IdentOrUnderscore::Underscore(
+                    t0,
+                )),
+                NonterminalKind::IdentOrUnderscore,
+            )
+        }
+        RuleKind::R94 => {
+            let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::IdentOrTerminalIdent(IdentOrTerminalIdent::Ident(
+                    t0,
+                )),
+                NonterminalKind::IdentOrTerminalIdent,
+            )
+        }
+        RuleKind::R95 => {
+            let t0 = nodes.pop().unwrap().try_into_terminal_ident_2().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::IdentOrTerminalIdent(IdentOrTerminalIdent::Terminal(
+                    t0,
+                )),
+                NonterminalKind::IdentOrTerminalIdent,
+            )
+        }
+        RuleKind::R96 => {
+            let t0 = nodes.pop().unwrap().try_into_string_literal_6().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::IdentOrTerminalIdent(IdentOrTerminalIdent::Literal(
+                    t0,
+                )),
+                NonterminalKind::IdentOrTerminalIdent,
+            )
+        }
+        RuleKind::R97 => {
+            let t0 = nodes.pop().unwrap().try_into_terminal_ident_2().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::TerminalIdentOrLiteral(TerminalIdentOrLiteral::Terminal(
+                    t0,
+                )),
+                NonterminalKind::TerminalIdentOrLiteral,
+            )
+        }
+        RuleKind::R98 => {
+            let t0 = nodes.pop().unwrap().try_into_string_literal_6().ok().unwrap();
+            
+            states.truncate(states.len() - 1);
+            
+            (
+                Node::TerminalIdentOrLiteral(TerminalIdentOrLiteral::Literal(
+                    t0,
+                )),
+                NonterminalKind::TerminalIdentOrLiteral,
+            )
+        }
+    }
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::Underscore(_) => Self::Underscore,
+            Token::Ident(_) => Self::Ident,
+            Token::TerminalIdent(_) => Self::TerminalIdent,
+            Token::OuterAttribute(_) => Self::OuterAttribute,
+            Token::DocComment(_) => Self::DocComment,
+            Token::SemanticAction(_) => Self::SemanticAction,
+            Token::StringLiteral(_) => Self::StringLiteral,
+            Token::RegexLiteral(_) => Self::RegexLiteral,
+            Token::StartKw(_) => Self::StartKw,
+            Token::StructKw(_) => Self::StructKw,
+            Token::EnumKw(_) => Self::EnumKw,
+            Token::TerminalKw(_) => Self::TerminalKw,
+            Token::PrecedenceKw(_) => Self::PrecedenceKw,
+            Token::PrecKw(_) => Self::PrecKw,
+            Token::ExternKw(_) => Self::ExternKw,
+            Token::Colon(_) => Self::Colon,
+            Token::DoubleColon(_) => Self::DoubleColon,
+            Token::Comma(_) => Self::Comma,
+            Token::Equals(_) => Self::Equals,
+            Token::LParen(_) => Self::LParen,
+            Token::RParen(_) => Self::RParen,
+            Token::LCurly(_) => Self::LCurly,
+            Token::RCurly(_) => Self::RCurly,
+            Token::LAngle(_) => Self::LAngle,
+            Token::RAngle(_) => Self::RAngle,
+            Token::Star(_) => Self::Star,
+            Token::Plus(_) => Self::Plus,
+            Token::Question(_) => Self::Question,
+        }
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::Underscore(t) => Self::Underscore(t),
+            Token::Ident(t) => Self::Ident(t),
+            Token::TerminalIdent(t) => Self::TerminalIdent(t),
+            Token::OuterAttribute(t) => Self::OuterAttribute(t),
+            Token::DocComment(t) => Self::DocComment(t),
+            Token::SemanticAction(t) => Self::SemanticAction(t),
+            Token::StringLiteral(t) => Self::StringLiteral(t),
+            Token::RegexLiteral(t) => Self::RegexLiteral(t),
+            Token::StartKw(t) => Self::StartKw(t),
+            Token::StructKw(t) => Self::StructKw(t),
+            Token::EnumKw(t) => Self::EnumKw(t),
+            Token::TerminalKw(t) => Self::TerminalKw(t),
+            Token::PrecedenceKw(t) => Self::PrecedenceKw(t),
+            Token::PrecKw(t) => Self::PrecKw(t),
+            Token::ExternKw(t) => Self::ExternKw(t),
+            Token::Colon(t) => Self::Colon(t),
+            Token::DoubleColon(t) => Self::DoubleColon(t),
+            Token::Comma(t) => Self::Comma(t),
+            Token::Equals(t) => Self::Equals(t),
+            Token::LParen(t) => Self::LParen(t),
+            Token::RParen(t) => Self::RParen(t),
+            Token::LCurly(t) => Self::LCurly(t),
+            Token::RCurly(t) => Self::RCurly(t),
+            Token::LAngle(t) => Self::LAngle(t),
+            Token::RAngle(t) => Self::RAngle(t),
+            Token::Star(t) => Self::Star(t),
+            Token::Plus(t) => Self::Plus(t),
+            Token::Question(t) => Self::Question(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+const ACTION_TABLE: [[Action; 29]; 161] = [
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S122),
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S3),
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Shift(State::S116),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S4),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R27),
+        Action::Shift(State::S55),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R27),
+        Action::Reduce(RuleKind::R27),
+        Action::Reduce(RuleKind::R27),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S56),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S13),
+        Action::Shift(State::S19),
+        Action::Shift(State::S26),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
+        Action::Shift(State::S40),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R21),
+        Action::Err,
+        Action::Reduce(RuleKind::R21),
+        Action::Reduce(RuleKind::R21),
+        Action::Reduce(RuleKind::R21),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R21),
+        Action::Reduce(RuleKind::R21),
+        Action::Reduce(RuleKind::R21),
+        Action::Reduce(RuleKind::R21),
+        Action::Reduce(RuleKind::R21),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S46),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R21),
+        Action::Err,
+        Action::Reduce(RuleKind::R21),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R21),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R29),
+        Action::Err,
+        Action::Reduce(RuleKind::R29),
+        Action::Reduce(RuleKind::R29),
+        Action::Reduce(RuleKind::R29),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R29),
+        Action::Reduce(RuleKind::R29),
+        Action::Reduce(RuleKind::R29),
+        Action::Reduce(RuleKind::R29),
+        Action::Reduce(RuleKind::R29),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S66),
+        Action::Err,
+        Action::Shift(State::S50),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R29),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Reduce(RuleKind::R23),
+        Action::Shift(State::S49),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Reduce(RuleKind::R23),
+        Action::Reduce(RuleKind::R23),
+        Action::Reduce(RuleKind::R23),
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S20),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R17),
+        Action::Err,
+        Action::Shift(State::S40),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S46),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R21),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S23),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R53),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R53),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S25),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S37),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S38),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S32),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S28),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R58),
+        Action::Err,
+        Action::Reduce(RuleKind::R58),
+        Action::Err,
+        Action::Reduce(RuleKind::R58),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R58),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S30),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S37),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S38),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S34),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S146),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S142),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S36),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R14),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S146),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S147),
+        Action::Reduce(RuleKind::R16),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R16),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S43),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S44),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S42),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R18),
+        Action::Err,
+        Action::Reduce(RuleKind::R18),
+        Action::Reduce(RuleKind::R18),
+        Action::Reduce(RuleKind::R18),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R18),
+        Action::Reduce(RuleKind::R18),
+        Action::Reduce(RuleKind::R18),
+        Action::Reduce(RuleKind::R18),
+        Action::Reduce(RuleKind::R18),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R18),
+        Action::Err,
+        Action::Reduce(RuleKind::R18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R18),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R19),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R19),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S45),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R20),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R20),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S146),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S142),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R22),
+        Action::Err,
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Reduce(RuleKind::R22),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R22),
+        Action::Err,
+        Action::Reduce(RuleKind::R22),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R22),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Shift(State::S49),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R23),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R24),
+        Action::Err,
+        Action::Reduce(RuleKind::R24),
+        Action::Reduce(RuleKind::R24),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R24),
+        Action::Reduce(RuleKind::R24),
+        Action::Reduce(RuleKind::R24),
+        Action::Reduce(RuleKind::R24),
+        Action::Reduce(RuleKind::R24),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R24),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R24),
+    ],
+    [
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R25),
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R25),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S60),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S154),
+        Action::Shift(State::S153),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S55),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S57),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S55),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S158),
+        Action::Err,
+        Action::Shift(State::S55),
+        Action::Err,
+        Action::Shift(State::S159),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R26),
+        Action::Reduce(RuleKind::R26),
+        Action::Reduce(RuleKind::R26),
+        Action::Reduce(RuleKind::R26),
+        Action::Reduce(RuleKind::R26),
+        Action::Err,
+        Action::Reduce(RuleKind::R26),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R26),
+        Action::Reduce(RuleKind::R26),
+        Action::Reduce(RuleKind::R26),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R28),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R28),
+        Action::Reduce(RuleKind::R28),
+        Action::Reduce(RuleKind::R28),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R29),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R29),
+        Action::Reduce(RuleKind::R29),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R29),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S66),
+        Action::Err,
+        Action::Shift(State::S50),
+        Action::Reduce(RuleKind::R29),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R30),
+        Action::Err,
+        Action::Reduce(RuleKind::R30),
+        Action::Reduce(RuleKind::R30),
+        Action::Reduce(RuleKind::R30),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R30),
+        Action::Reduce(RuleKind::R30),
+        Action::Reduce(RuleKind::R30),
+        Action::Reduce(RuleKind::R30),
+        Action::Reduce(RuleKind::R30),
+        Action::Reduce(RuleKind::R30),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R30),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R30),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R31),
+        Action::Err,
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Reduce(RuleKind::R31),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R31),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R31),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R32),
+        Action::Err,
+        Action::Reduce(RuleKind::R32),
+        Action::Reduce(RuleKind::R32),
+        Action::Reduce(RuleKind::R32),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R32),
+        Action::Reduce(RuleKind::R32),
+        Action::Reduce(RuleKind::R32),
+        Action::Reduce(RuleKind::R32),
+        Action::Reduce(RuleKind::R32),
+        Action::Reduce(RuleKind::R32),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R32),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R32),
+    ],
+    [
+        Action::Reduce(RuleKind::R33),
+        Action::Reduce(RuleKind::R33),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R33),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R33),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R34),
+        Action::Reduce(RuleKind::R34),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R34),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R34),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S64),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S81),
+        Action::Shift(State::S156),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S157),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R35),
+        Action::Reduce(RuleKind::R35),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R35),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R35),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S72),
+        Action::Shift(State::S81),
+        Action::Shift(State::S156),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S157),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S72),
+        Action::Shift(State::S81),
+        Action::Shift(State::S156),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S157),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S68),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R36),
+        Action::Err,
+        Action::Reduce(RuleKind::R36),
+        Action::Reduce(RuleKind::R36),
+        Action::Reduce(RuleKind::R36),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R36),
+        Action::Reduce(RuleKind::R36),
+        Action::Reduce(RuleKind::R36),
+        Action::Reduce(RuleKind::R36),
+        Action::Reduce(RuleKind::R36),
+        Action::Reduce(RuleKind::R36),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R36),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R36),
+    ],
+    [
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Reduce(RuleKind::R37),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R37),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R37),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Reduce(RuleKind::R38),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R38),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R38),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Reduce(RuleKind::R39),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R39),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R39),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S73),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S81),
+        Action::Shift(State::S156),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S157),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Reduce(RuleKind::R40),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R40),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R40),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Reduce(RuleKind::R41),
+        Action::Err,
+        Action::Reduce(RuleKind::R41),
+        Action::Err,
+        Action::Reduce(RuleKind::R41),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R41),
+        Action::Err,
+        Action::Reduce(RuleKind::R41),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S76),
+        Action::Shift(State::S77),
+        Action::Shift(State::S78),
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
+        Action::Reduce(RuleKind::R42),
+        Action::Err,
+        Action::Reduce(RuleKind::R42),
+        Action::Err,
+        Action::Reduce(RuleKind::R42),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R42),
+        Action::Err,
+        Action::Reduce(RuleKind::R42),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
+        Action::Reduce(RuleKind::R43),
+        Action::Err,
+        Action::Reduce(RuleKind::R43),
+        Action::Err,
+        Action::Reduce(RuleKind::R43),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R43),
+        Action::Err,
+        Action::Reduce(RuleKind::R43),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
+        Action::Reduce(RuleKind::R44),
+        Action::Err,
+        Action::Reduce(RuleKind::R44),
+        Action::Err,
+        Action::Reduce(RuleKind::R44),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R44),
+        Action::Err,
+        Action::Reduce(RuleKind::R44),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
+        Action::Reduce(RuleKind::R45),
+        Action::Err,
+        Action::Reduce(RuleKind::R45),
+        Action::Err,
+        Action::Reduce(RuleKind::R45),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R45),
+        Action::Err,
+        Action::Reduce(RuleKind::R45),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+        Action::Reduce(RuleKind::R46),
+        Action::Err,
+        Action::Reduce(RuleKind::R46),
+        Action::Err,
+        Action::Reduce(RuleKind::R46),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R46),
+        Action::Err,
+        Action::Reduce(RuleKind::R46),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R94),
+        Action::Reduce(RuleKind::R94),
+        Action::Reduce(RuleKind::R94),
+        Action::Err,
+        Action::Reduce(RuleKind::R94),
+        Action::Err,
+        Action::Reduce(RuleKind::R94),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S88),
+        Action::Reduce(RuleKind::R94),
+        Action::Err,
+        Action::Reduce(RuleKind::R94),
+        Action::Shift(State::S82),
+        Action::Err,
+        Action::Reduce(RuleKind::R94),
+        Action::Reduce(RuleKind::R94),
+        Action::Reduce(RuleKind::R94),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S155),
+        Action::Shift(State::S156),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S157),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S86),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S84),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
+        Action::Reduce(RuleKind::R47),
+        Action::Err,
+        Action::Reduce(RuleKind::R47),
+        Action::Err,
+        Action::Reduce(RuleKind::R47),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R47),
+        Action::Err,
+        Action::Reduce(RuleKind::R47),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R48),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R48),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S155),
+        Action::Shift(State::S156),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S157),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R49),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R49),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S155),
+        Action::Shift(State::S156),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S157),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S90),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S155),
+        Action::Shift(State::S156),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S157),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R51),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R51),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S94),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S93),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R50),
+        Action::Reduce(RuleKind::R50),
+        Action::Reduce(RuleKind::R50),
+        Action::Err,
+        Action::Reduce(RuleKind::R50),
+        Action::Err,
+        Action::Reduce(RuleKind::R50),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R50),
+        Action::Err,
+        Action::Reduce(RuleKind::R50),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S95),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R52),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R52),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R54),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R54),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R54),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R56),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R56),
+        Action::Reduce(RuleKind::R56),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S99),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R56),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R55),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R55),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R55),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S158),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S159),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R57),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R57),
+        Action::Reduce(RuleKind::R57),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R57),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R59),
+        Action::Err,
+        Action::Reduce(RuleKind::R59),
+        Action::Err,
+        Action::Reduce(RuleKind::R59),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R59),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S103),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S146),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S142),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R61),
+        Action::Err,
+        Action::Reduce(RuleKind::R61),
+        Action::Err,
+        Action::Reduce(RuleKind::R61),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S106),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R61),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R60),
+        Action::Err,
+        Action::Reduce(RuleKind::R60),
+        Action::Err,
+        Action::Reduce(RuleKind::R60),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R60),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S108),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R62),
+        Action::Err,
+        Action::Reduce(RuleKind::R62),
+        Action::Err,
+        Action::Reduce(RuleKind::R62),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R62),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R64),
+        Action::Err,
+        Action::Reduce(RuleKind::R64),
+        Action::Err,
+        Action::Reduce(RuleKind::R64),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S110),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R64),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R63),
+        Action::Err,
+        Action::Reduce(RuleKind::R63),
+        Action::Err,
+        Action::Reduce(RuleKind::R63),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R63),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S154),
+        Action::Shift(State::S153),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S114),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S112),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R65),
+        Action::Err,
+        Action::Reduce(RuleKind::R65),
+        Action::Err,
+        Action::Reduce(RuleKind::R65),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R65),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R66),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R66),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S154),
+        Action::Shift(State::S153),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R67),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R67),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S119),
+        Action::Reduce(RuleKind::R69),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R69),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S158),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S159),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R68),
+        Action::Shift(State::S158),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Err,
+        Action::Shift(State::S159),
+        Action::Err,
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Reduce(RuleKind::R68),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R68),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R70),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R70),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R71),
+        Action::Reduce(RuleKind::R71),
+        Action::Reduce(RuleKind::R71),
+        Action::Reduce(RuleKind::R71),
+        Action::Err,
+        Action::Reduce(RuleKind::R71),
+        Action::Err,
+        Action::Reduce(RuleKind::R71),
+        Action::Reduce(RuleKind::R71),
+        Action::Reduce(RuleKind::R71),
+        Action::Reduce(RuleKind::R71),
+        Action::Reduce(RuleKind::R71),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R71),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R72),
+        Action::Reduce(RuleKind::R72),
+        Action::Reduce(RuleKind::R72),
+        Action::Reduce(RuleKind::R72),
+        Action::Err,
+        Action::Reduce(RuleKind::R72),
+        Action::Err,
+        Action::Reduce(RuleKind::R72),
+        Action::Reduce(RuleKind::R72),
+        Action::Reduce(RuleKind::R72),
+        Action::Reduce(RuleKind::R72),
+        Action::Reduce(RuleKind::R72),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R72),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S134),
+        Action::Err,
+        Action::Shift(State::S123),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R74),
+        Action::Err,
+        Action::Reduce(RuleKind::R74),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R74),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S131),
+        Action::Err,
+        Action::Shift(State::S130),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S125),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R73),
+        Action::Err,
+        Action::Reduce(RuleKind::R73),
+        Action::Reduce(RuleKind::R73),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R73),
+        Action::Reduce(RuleKind::R73),
+        Action::Reduce(RuleKind::R73),
+        Action::Reduce(RuleKind::R73),
+        Action::Reduce(RuleKind::R73),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R73),
+    ],
+    [
+        Action::Reduce(RuleKind::R75),
+        Action::Err,
+        Action::Reduce(RuleKind::R75),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R75),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S128),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S132),
+        Action::Shift(State::S133),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R76),
+        Action::Err,
+        Action::Reduce(RuleKind::R76),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R76),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R77),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R78),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R79),
+        Action::Err,
+        Action::Reduce(RuleKind::R79),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R79),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R80),
+        Action::Err,
+        Action::Reduce(RuleKind::R80),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R80),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S137),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S138),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S136),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R81),
+        Action::Err,
+        Action::Reduce(RuleKind::R81),
+        Action::Reduce(RuleKind::R81),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R81),
+        Action::Reduce(RuleKind::R81),
+        Action::Reduce(RuleKind::R81),
+        Action::Reduce(RuleKind::R81),
+        Action::Reduce(RuleKind::R81),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R81),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R82),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R82),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S139),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R83),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R83),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S146),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S142),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S146),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S142),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S143),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Err,
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Reduce(RuleKind::R84),
+        Action::Err,
+        Action::Reduce(RuleKind::R84),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R84),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Err,
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S147),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Reduce(RuleKind::R85),
+        Action::Shift(State::S140),
+        Action::Reduce(RuleKind::R85),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R85),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Err,
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Reduce(RuleKind::R86),
+        Action::Err,
+        Action::Reduce(RuleKind::R86),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R86),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Err,
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Reduce(RuleKind::R87),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R87),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S148),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Err,
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Reduce(RuleKind::R88),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R88),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S141),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S150),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Err,
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Reduce(RuleKind::R89),
+        Action::Err,
+        Action::Reduce(RuleKind::R89),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R89),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R90),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R90),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R91),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R91),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R92),
+        Action::Err,
+        Action::Reduce(RuleKind::R92),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R92),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R93),
+        Action::Err,
+        Action::Reduce(RuleKind::R93),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R93),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R94),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R94),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R94),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R95),
+        Action::Reduce(RuleKind::R95),
+        Action::Reduce(RuleKind::R95),
+        Action::Err,
+        Action::Reduce(RuleKind::R95),
+        Action::Err,
+        Action::Reduce(RuleKind::R95),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R95),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R95),
+        Action::Err,
+        Action::Reduce(RuleKind::R95),
+        Action::Err,
+        Action::Reduce(RuleKind::R95),
+        Action::Reduce(RuleKind::R95),
+        Action::Reduce(RuleKind::R95),
+        Action::Reduce(RuleKind::R95),
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R96),
+        Action::Reduce(RuleKind::R96),
+        Action::Reduce(RuleKind::R96),
+        Action::Err,
+        Action::Reduce(RuleKind::R96),
+        Action::Err,
+        Action::Reduce(RuleKind::R96),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R96),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R96),
+        Action::Err,
+        Action::Reduce(RuleKind::R96),
+        Action::Err,
+        Action::Reduce(RuleKind::R96),
+        Action::Reduce(RuleKind::R96),
+        Action::Reduce(RuleKind::R96),
+        Action::Reduce(RuleKind::R96),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R97),
+        Action::Reduce(RuleKind::R97),
+        Action::Reduce(RuleKind::R97),
+        Action::Reduce(RuleKind::R97),
+        Action::Reduce(RuleKind::R97),
+        Action::Reduce(RuleKind::R97),
+        Action::Err,
+        Action::Reduce(RuleKind::R97),
+        Action::Reduce(RuleKind::R97),
+        Action::Reduce(RuleKind::R97),
+        Action::Reduce(RuleKind::R97),
+        Action::Reduce(RuleKind::R97),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R97),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R97),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R97),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R98),
+        Action::Reduce(RuleKind::R98),
+        Action::Reduce(RuleKind::R98),
+        Action::Reduce(RuleKind::R98),
+        Action::Reduce(RuleKind::R98),
+        Action::Reduce(RuleKind::R98),
+        Action::Err,
+        Action::Reduce(RuleKind::R98),
+        Action::Reduce(RuleKind::R98),
+        Action::Reduce(RuleKind::R98),
+        Action::Reduce(RuleKind::R98),
+        Action::Reduce(RuleKind::R98),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R98),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R98),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R98),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+const GOTO_TABLE: [[Option<State>; 52]; 161] = [
+    [
+        Some(State::S160),
+        Some(State::S1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S2),
+        Some(State::S5),
+        Some(State::S6),
+        Some(State::S7),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S11),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S8),
+        None,
+        None,
+        Some(State::S9),
+        None,
+        None,
+        None,
+        None,
+        Some(State::S10),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S12),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S15),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S16),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S17),
+        Some(State::S58),
+        None,
+        None,
+        Some(State::S59),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S21),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S22),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S24),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S53),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S96),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S27),
+        Some(State::S31),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S29),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S54),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S101),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S33),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S35),
+        Some(State::S144),
+        Some(State::S145),
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S39),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S41),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S47),
+        Some(State::S144),
+        Some(State::S145),
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S98),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Reduce(RuleKind::R1),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S52),
+        None,
+        None,
+        None,
+        Some(State::S51),
+        Some(State::S61),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R10),
-        Action::Shift(State::S3),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Reduce(RuleKind::R10),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R0),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S52),
+        None,
+        None,
+        None,
+        None,
+        Some(State::S62),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Reduce(RuleKind::R2),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R2),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S63),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S4),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S102),
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Reduce(RuleKind::R3),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R3),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Reduce(RuleKind::R4),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R4),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Reduce(RuleKind::R5),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R5),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S97),
+        Some(State::S58),
+        None,
+        None,
+        Some(State::S59),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Reduce(RuleKind::R6),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R6),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S22),
-        Action::Err,
-        Action::Shift(State::S9),
-        Action::Shift(State::S12),
-        Action::Shift(State::S17),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S10),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Reduce(RuleKind::R12),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S34),
-        Action::Err,
-        Action::Shift(State::S26),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R12),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Reduce(RuleKind::R7),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R7),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S65),
+        Some(State::S80),
+        None,
+        Some(State::S79),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S75),
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S13),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S14),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S67),
+        Some(State::S69),
+        Some(State::S71),
+        Some(State::S80),
+        None,
+        Some(State::S79),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S75),
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R24),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R24),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S70),
+        Some(State::S71),
+        Some(State::S80),
+        None,
+        Some(State::S79),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S75),
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S23),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S16),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Reduce(RuleKind::R8),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R8),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S18),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S19),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R27),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R27),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S74),
+        Some(State::S80),
+        None,
+        Some(State::S79),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S75),
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S46),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S21),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Reduce(RuleKind::R9),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R9),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R11),
-        Action::Err,
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Reduce(RuleKind::R11),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R12),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S34),
-        Action::Err,
-        Action::Shift(State::S26),
-        Action::Reduce(RuleKind::R12),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R13),
-        Action::Err,
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
-        Action::Reduce(RuleKind::R13),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R13),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R13),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R14),
-        Action::Err,
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
-        Action::Reduce(RuleKind::R14),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R14),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R14),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Shift(State::S63),
-        Action::Shift(State::S62),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Shift(State::S63),
-        Action::Shift(State::S62),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S28),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R15),
-        Action::Err,
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
-        Action::Reduce(RuleKind::R15),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R15),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R15),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S83),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S85),
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R16),
-        Action::Reduce(RuleKind::R16),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R16),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R17),
-        Action::Reduce(RuleKind::R17),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R17),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S32),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S87),
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S64),
-        Action::Shift(State::S65),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S89),
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R18),
-        Action::Reduce(RuleKind::R18),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R18),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Shift(State::S40),
-        Action::Shift(State::S64),
-        Action::Shift(State::S65),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S91),
+        None,
     ],
     [
-        Action::Shift(State::S40),
-        Action::Shift(State::S64),
-        Action::Shift(State::S65),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S36),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S92),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R19),
-        Action::Err,
-        Action::Reduce(RuleKind::R19),
-        Action::Reduce(RuleKind::R19),
-        Action::Reduce(RuleKind::R19),
-        Action::Reduce(RuleKind::R19),
-        Action::Reduce(RuleKind::R19),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R19),
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R19),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R20),
-        Action::Reduce(RuleKind::R20),
-        Action::Reduce(RuleKind::R20),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R20),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R21),
-        Action::Reduce(RuleKind::R21),
-        Action::Reduce(RuleKind::R21),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R21),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
-    [
-        Action::Reduce(RuleKind::R22),
-        Action::Reduce(RuleKind::R22),
-        Action::Reduce(RuleKind::R22),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R22),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S41),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S64),
-        Action::Shift(State::S65),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S48),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R23),
-        Action::Reduce(RuleKind::R23),
-        Action::Reduce(RuleKind::R23),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R23),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R25),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R25),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S100),
     ],
     [
-        Action::Err,
-        Action::Reduce(RuleKind::R26),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R26),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R28),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R28),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S47),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S104),
+        Some(State::S144),
+        Some(State::S145),
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S55),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S51),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S105),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R29),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R29),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S55),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S51),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S107),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S55),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S51),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S52),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S109),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R30),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R30),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R30),
-        Action::Err,
-        Action::Reduce(RuleKind::R30),
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R31),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S56),
-        Action::Reduce(RuleKind::R31),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R31),
-        Action::Shift(State::S49),
-        Action::Reduce(RuleKind::R31),
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S111),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S113),
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R32),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R32),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R32),
-        Action::Err,
-        Action::Reduce(RuleKind::R32),
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R33),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Reduce(RuleKind::R33),
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Shift(State::S57),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R34),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Reduce(RuleKind::R34),
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S115),
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S50),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Shift(State::S59),
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R35),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R35),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R35),
-        Action::Err,
-        Action::Reduce(RuleKind::R35),
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S117),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S118),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S120),
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R36),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R36),
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S121),
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R37),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R37),
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R38),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R39),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R40),
-        Action::Reduce(RuleKind::R40),
-        Action::Reduce(RuleKind::R40),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R40),
-        Action::Err,
-        Action::Reduce(RuleKind::R40),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S124),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Reduce(RuleKind::R41),
-        Action::Reduce(RuleKind::R41),
-        Action::Reduce(RuleKind::R41),
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Reduce(RuleKind::R41),
-        Action::Err,
-        Action::Reduce(RuleKind::R41),
-        Action::Err,
-        Action::Err,
-        Action::Err,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S126),
+        Some(State::S127),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Err,
-        Action::Accept,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
-];
-
-fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
-    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
-}
-
-const GOTO_TABLE: [[Option<State>; 24]; 67] = [
     [
-        Some(State::S66),
-        Some(State::S1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2459,11 +14184,39 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
     [
         None,
         None,
-        Some(State::S2),
-        Some(State::S5),
-        Some(State::S6),
-        Some(State::S7),
-        Some(State::S8),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2507,6 +14260,34 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S129),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2533,6 +14314,34 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2559,6 +14368,34 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2585,6 +14422,34 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -2611,8 +14476,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2637,8 +14500,46 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -2657,14 +14558,14 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2689,8 +14590,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2698,16 +14597,14 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S11),
-        Some(State::S24),
         None,
         None,
-        Some(State::S25),
         None,
         None,
         None,
         None,
         None,
+        Some(State::S135),
         None,
         None,
         None,
@@ -2741,8 +14638,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2767,12 +14662,12 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -2793,8 +14688,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2809,7 +14702,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S15),
         None,
         None,
         None,
@@ -2819,8 +14711,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2830,13 +14720,14 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
         None,
         None,
         None,
-        Some(State::S43),
         None,
         None,
         None,
@@ -2845,8 +14736,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2871,8 +14760,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2887,6 +14774,8 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -2897,8 +14786,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2923,8 +14810,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2941,16 +14826,15 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S20),
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -2968,15 +14852,12 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S45),
         None,
         None,
         None,
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3027,8 +14908,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3036,11 +14915,8 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S44),
-        Some(State::S24),
         None,
         None,
-        Some(State::S25),
         None,
         None,
         None,
@@ -3053,6 +14929,13 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+        Some(State::S151),
+        Some(State::S144),
+        Some(State::S145),
+        Some(State::S149),
+        None,
+        None,
+        None,
     ],
     [
         None,
@@ -3079,8 +14962,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3102,6 +14983,10 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+        Some(State::S152),
+        Some(State::S144),
+        Some(State::S145),
+        None,
         None,
         None,
         None,
@@ -3116,8 +15001,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S27),
-        Some(State::S29),
         None,
         None,
         None,
@@ -3129,10 +15012,7 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S31),
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3143,7 +15023,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S30),
         None,
         None,
         None,
@@ -3155,10 +15034,7 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S31),
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3168,6 +15044,8 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -3183,8 +15061,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3209,8 +15085,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3224,6 +15098,8 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -3235,8 +15111,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3261,8 +15135,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3280,15 +15152,15 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
+        None,
         None,
         None,
         None,
         None,
         None,
         None,
-        Some(State::S33),
-    ],
-    [
         None,
         None,
         None,
@@ -3313,8 +15185,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3327,8 +15197,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S35),
-        Some(State::S37),
         None,
         None,
         None,
@@ -3338,7 +15206,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S39),
     ],
     [
         None,
@@ -3354,7 +15221,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S38),
         None,
         None,
         None,
@@ -3363,10 +15229,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        None,
-        Some(State::S39),
-    ],
-    [
         None,
         None,
         None,
@@ -3391,8 +15253,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3400,6 +15260,8 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -3417,8 +15279,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3443,8 +15303,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3456,6 +15314,8 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -3469,8 +15329,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3495,8 +15353,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3512,6 +15368,8 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -3520,9 +15378,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S42),
-    ],
-    [
         None,
         None,
         None,
@@ -3547,8 +15402,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3569,12 +15422,12 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3599,8 +15452,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3651,10 +15502,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
-        None,
-        None,
         None,
         None,
         None,
@@ -3671,14 +15518,9 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S48),
-        Some(State::S53),
-        Some(State::S54),
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3688,6 +15530,8 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
         None,
         None,
@@ -3703,8 +15547,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3723,14 +15565,8 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-        Some(State::S60),
-        Some(State::S53),
-        Some(State::S54),
-        Some(State::S58),
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3748,15 +15584,12 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
+    ],
+    [
         None,
-        Some(State::S61),
-        Some(State::S53),
-        Some(State::S54),
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3781,8 +15614,6 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
         None,
         None,
         None,
@@ -3833,8 +15664,10 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -3885,8 +15718,10 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -3937,8 +15772,10 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -3989,8 +15826,10 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -4041,8 +15880,10 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -4093,8 +15934,10 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -4145,8 +15988,10 @@ const GOTO_TABLE: [[Option<State>; 24]; 67] = [
         None,
         None,
         None,
-    ],
-    [
+        None,
+        None,
+        None,
+        None,
         None,
         None,
         None,
@@ -4178,67 +16023,144 @@ fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
     GOTO_TABLE[top_state as usize][new_node_kind as usize]
 }
 
-impl TryFrom<Node> for File {
+impl TryFrom<Node> for File {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::File(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for OptItems {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptItems(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for FileItem {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::FileItem(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Struct {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::Struct(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Enum {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::Enum(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for TerminalEnum {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::TerminalEnum(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for TerminalEnumHeader {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::TerminalEnumHeader(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for TerminalEnumName {
     type Error = Node;
 
     fn try_from(node: Node) -> Result<Self, Self::Error> {
         match node {
-            Node::File(n) => Ok(n),
+            Node::TerminalEnumName(n) => Ok(n),
             _ => Err(node),
         }
     }
 }
 
-impl TryFrom<Node> for OptItems {
+impl TryFrom<Node> for OptGenericParams {
     type Error = Node;
 
     fn try_from(node: Node) -> Result<Self, Self::Error> {
         match node {
-            Node::OptItems(n) => Ok(n),
+            Node::OptGenericParams(n) => Ok(n),
             _ => Err(node),
         }
     }
 }
 
-impl TryFrom<Node> for FileItem {
+impl TryFrom<Node> for GenericParams {
     type Error = Node;
 
     fn try_from(node: Node) -> Result<Self, Self::Error> {
         match node {
-            Node::FileItem(n) => Ok(n),
+            Node::GenericParams(n) => Ok(n),
             _ => Err(node),
         }
     }
 }
 
-impl TryFrom<Node> for Struct {
+impl TryFrom<Node> for OptResultType {
     type Error = Node;
 
     fn try_from(node: Node) -> Result<Self, Self::Error> {
         match node {
-            Node::Struct(n) => Ok(n),
+            Node::OptResultType(n) => Ok(n),
             _ => Err(node),
         }
     }
 }
 
-impl TryFrom<Node> for Enum {
+impl TryFrom<Node> for OptSemanticAction {
     type Error = Node;
 
     fn try_from(node: Node) -> Result<Self, Self::Error> {
         match node {
-            Node::Enum(n) => Ok(n),
+            Node::OptSemanticAction(n) => Ok(n),
             _ => Err(node),
         }
     }
 }
 
-impl TryFrom<Node> for TerminalEnum {
+impl TryFrom<Node> for OptDocComments {
     type Error = Node;
 
     fn try_from(node: Node) -> Result<Self, Self::Error> {
         match node {
-            Node::TerminalEnum(n) => Ok(n),
+            Node::OptDocComments(n) => Ok(n),
             _ => Err(node),
         }
     }
@@ -4332,6 +16254,61 @@ impl TryFrom<Node> for TupleField {
     }
 }
 
+impl TryFrom<Node> for FieldSymbol {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::FieldSymbol(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Instantiation {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::Instantiation(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for GenericArgs {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::GenericArgs(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for FieldSymbolCall {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::FieldSymbolCall(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for OptCallFlags {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptCallFlags(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for OptEnumVariants {
     type Error = Node;
 
@@ -4354,6 +16331,17 @@ impl TryFrom<Node> for EnumVariant {
     }
 }
 
+impl TryFrom<Node> for OptPrecedenceOverride {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptPrecedenceOverride(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for OptTerminalEnumVariants {
     type Error = Node;
 
@@ -4376,6 +16364,160 @@ impl TryFrom<Node> for TerminalEnumVariant {
     }
 }
 
+impl TryFrom<Node> for OptTerminalPattern {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptTerminalPattern(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for TerminalPattern {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::TerminalPattern(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for OptTerminalPatternFields {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptTerminalPatternFields(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for TerminalPatternFields {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::TerminalPatternFields(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for PrecedenceDeclaration {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::PrecedenceDeclaration(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for OptAssociativity {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptAssociativity(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for TerminalIdents {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::TerminalIdents(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Lexer {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::Lexer(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for OptLexerRules {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::OptLexerRules(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for LexerRule {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::LexerRule(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for TerminalIdentOrUnderscore {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::TerminalIdentOrUnderscore(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for LexerPattern {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::LexerPattern(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Allow {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::Allow(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for LintNames {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::LintNames(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl TryFrom<Node> for Type {
     type Error = Node;
 
@@ -4442,6 +16584,17 @@ impl TryFrom<Node> for IdentOrTerminalIdent {
     }
 }
 
+impl TryFrom<Node> for TerminalIdentOrLiteral {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        match node {
+            Node::TerminalIdentOrLiteral(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
 impl Node {
     fn try_into_underscore_0(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
@@ -4471,94 +16624,171 @@ impl Node {
         }
     }
     
-    fn try_into_start_kw_4(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_doc_comment_4(self) -> Result<crate::data::token::DocComment, Self> {
+        match self {
+            Self::DocComment(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_semantic_action_5(self) -> Result<crate::data::token::SemanticAction, Self> {
+        match self {
+            Self::SemanticAction(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_string_literal_6(self) -> Result<crate::data::token::StringLiteral, Self> {
+        match self {
+            Self::StringLiteral(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_regex_literal_7(self) -> Result<crate::data::token::RegexLiteral, Self> {
+        match self {
+            Self::RegexLiteral(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_start_kw_8(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::StartKw(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_struct_kw_5(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_struct_kw_9(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::StructKw(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_enum_kw_6(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_enum_kw_10(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::EnumKw(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_terminal_kw_7(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_terminal_kw_11(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::TerminalKw(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_colon_8(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_precedence_kw_12(self) -> Result<crate::data::ByteIndex, Self> {
+        match self {
+            Self::PrecedenceKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_prec_kw_13(self) -> Result<crate::data::ByteIndex, Self> {
+        match self {
+            Self::PrecKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_extern_kw_14(self) -> Result<crate::data::ByteIndex, Self> {
+        match self {
+            Self::ExternKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_colon_15(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::Colon(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_double_colon_9(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_double_colon_16(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::DoubleColon(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_comma_10(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_comma_17(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::Comma(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_l_paren_11(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_equals_18(self) -> Result<crate::data::ByteIndex, Self> {
+        match self {
+            Self::Equals(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_l_paren_19(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::LParen(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_r_paren_12(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_r_paren_20(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::RParen(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_l_curly_13(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_l_curly_21(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::LCurly(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_r_curly_14(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_r_curly_22(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::RCurly(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_l_angle_15(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_l_angle_23(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::LAngle(t) => Ok(t),
             _ => Err(self),
         }
     }
     
-    fn try_into_r_angle_16(self) -> Result<crate::data::ByteIndex, Self> {
+    fn try_into_r_angle_24(self) -> Result<crate::data::ByteIndex, Self> {
         match self {
             Self::RAngle(t) => Ok(t),
             _ => Err(self),
         }
     }
+    
+    fn try_into_star_25(self) -> Result<crate::data::ByteIndex, Self> {
+        match self {
+            Self::Star(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_plus_26(self) -> Result<crate::data::ByteIndex, Self> {
+        match self {
+            Self::Plus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+    
+    fn try_into_question_27(self) -> Result<crate::data::ByteIndex, Self> {
+        match self {
+            Self::Question(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
 }
@@ -0,0 +1,243 @@
+use crate::data::table::*;
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+pub fn compress_table(table: &Table) -> CompressedTable {
+    let state_count = table.state_count();
+
+    let action_rows: Vec<Vec<u32>> = get_rows(&table.actions, state_count)
+        .map(|row| {
+            row.iter()
+                .map(|action| action.encode(state_count))
+                .collect()
+        })
+        .collect();
+    let action_defaults = action_rows
+        .iter()
+        .map(|row| get_default_action_code(row, state_count))
+        .collect();
+
+    let goto_rows: Vec<Vec<u32>> = get_rows(&table.gotos, state_count)
+        .map(|row| row.iter().map(|goto| goto.encode()).collect())
+        .collect();
+    let goto_defaults = goto_rows.iter().map(|row| get_most_common(row)).collect();
+
+    CompressedTable {
+        state_count,
+        actions: pack_rows(&action_rows, action_defaults, table.terminals.len() + 1),
+        gotos: pack_rows(&goto_rows, goto_defaults, table.nonterminals.len()),
+    }
+}
+
+fn get_rows<T>(cells: &[T], state_count: usize) -> impl Iterator<Item = &[T]> {
+    let width = cells.len().checked_div(state_count).unwrap_or(0);
+    (0..state_count).map(move |i| &cells[i * width..(i + 1) * width])
+}
+
+/// Returns the code of the row's most common reduction,
+/// or the code of `Action::Err` if the row has no reductions.
+///
+/// Cells that differ from the default (including `Err` cells)
+/// are still stored explicitly,
+/// so lookups return exactly what the dense table would.
+fn get_default_action_code(row: &[u32], state_count: usize) -> u32 {
+    let reduce_codes: Vec<u32> = row
+        .iter()
+        .copied()
+        .filter(|&code| matches!(Action::decode(code, state_count), Action::Reduce(_)))
+        .collect();
+    if reduce_codes.is_empty() {
+        return Action::Err.encode(state_count);
+    }
+    get_most_common(&reduce_codes)
+}
+
+/// Ties are broken in favor of the smallest code.
+fn get_most_common(codes: &[u32]) -> u32 {
+    let mut counts: BTreeMap<u32, usize> = BTreeMap::new();
+    for &code in codes {
+        *counts.entry(code).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(code, count)| (count, Reverse(code)))
+        .map(|(code, _)| code)
+        .unwrap_or(0)
+}
+
+/// Packs each row's non-default cells into shared arrays.
+/// Rows are placed from most to fewest non-default cells,
+/// each at the lowest base where none of its cells
+/// collide with a previously placed cell.
+fn pack_rows(rows: &[Vec<u32>], defaults: Vec<u32>, width: usize) -> CompressedRows {
+    let entries: Vec<Vec<(usize, u32)>> = rows
+        .iter()
+        .zip(&defaults)
+        .map(|(row, &default)| {
+            row.iter()
+                .copied()
+                .enumerate()
+                .filter(|&(_, code)| code != default)
+                .collect()
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    order.sort_by_key(|&row| Reverse(entries[row].len()));
+
+    let mut bases = vec![0; rows.len()];
+    let mut checks: Vec<u32> = vec![];
+    let mut values: Vec<u32> = vec![];
+    // Every slot before `first_free` is used.
+    let mut first_free: usize = 0;
+    for row in order {
+        let row_entries = &entries[row];
+        let Some(&(first_column, _)) = row_entries.first() else {
+            continue;
+        };
+
+        let is_free = |i: usize| {
+            checks
+                .get(i)
+                .is_none_or(|&check| check == CompressedRows::UNUSED)
+        };
+        let base = (first_free.saturating_sub(first_column)..)
+            .find(|&base| {
+                row_entries
+                    .iter()
+                    .all(|&(column, _)| is_free(base + column))
+            })
+            .unwrap();
+
+        for &(column, code) in row_entries {
+            let i = base + column;
+            if i >= checks.len() {
+                checks.resize(i + 1, CompressedRows::UNUSED);
+                values.resize(i + 1, 0);
+            }
+            checks[i] = to_u32(row);
+            values[i] = code;
+        }
+        bases[row] = to_u32(base);
+
+        while checks
+            .get(first_free)
+            .is_some_and(|&check| check != CompressedRows::UNUSED)
+        {
+            first_free += 1;
+        }
+    }
+
+    // Pad the arrays so that every lookup is in bounds.
+    let len = bases.iter().map(|&base| base as usize).max().unwrap_or(0) + width;
+    if checks.len() < len {
+        checks.resize(len, CompressedRows::UNUSED);
+        values.resize(len, 0);
+    }
+
+    CompressedRows {
+        defaults,
+        bases,
+        checks,
+        values,
+    }
+}
+
+fn to_u32(n: usize) -> u32 {
+    u32::try_from(n).expect("Table is too large to compress")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pipeline::prelude::*;
+    use crate::{DefaultReductions, ParserAlgorithm};
+
+    #[test]
+    fn lookups_equal_dense_lookups() {
+        use ParserAlgorithm::*;
+        for (src, algorithm) in [
+            (include_str!("../examples/balanced_parens.kiki"), Lalr1),
+            (include_str!("../examples/json.kiki"), Lalr1),
+            (include_str!("../examples/kiki.kiki"), Lalr1),
+            (include_str!("../examples/kiki.kiki"), CanonicalLr1),
+            (include_str!("../examples/lr1_not_lalr1.kiki"), CanonicalLr1),
+            (include_str!("../examples/lr1_not_lalr1.kiki"), MinimalLr1),
+        ] {
+            let table = get_table(src, algorithm);
+            let compressed = compress_table(&table);
+            assert_lookups_equal(&table, &compressed);
+        }
+    }
+
+    #[test]
+    fn compressed_is_smaller_than_dense() {
+        let table = get_table(
+            include_str!("../examples/kiki.kiki"),
+            ParserAlgorithm::Lalr1,
+        );
+        let compressed = compress_table(&table);
+        let dense_len = table.actions.len() + table.gotos.len();
+        let compressed_len = [&compressed.actions, &compressed.gotos]
+            .into_iter()
+            .map(|rows| rows.defaults.len() + rows.bases.len() + 2 * rows.checks.len())
+            .sum::<usize>();
+        assert!(
+            compressed_len < dense_len,
+            "compressed: {compressed_len}, dense: {dense_len}"
+        );
+    }
+
+    #[test]
+    fn no_shift_is_a_default() {
+        let table = get_table(
+            include_str!("../examples/kiki.kiki"),
+            ParserAlgorithm::Lalr1,
+        );
+        let compressed = compress_table(&table);
+        for &code in &compressed.actions.defaults {
+            let action = Action::decode(code, compressed.state_count);
+            assert!(
+                matches!(action, Action::Reduce(_) | Action::Err),
+                "unexpected default: {action:?}"
+            );
+        }
+    }
+
+    fn assert_lookups_equal(table: &Table, compressed: &CompressedTable) {
+        for state_index in (0..table.state_count()).map(StateIndex) {
+            let quasiterminals = table
+                .terminals
+                .iter()
+                .map(Quasiterminal::Terminal)
+                .chain(std::iter::once(Quasiterminal::Eof));
+            for (i, quasiterminal) in quasiterminals.enumerate() {
+                assert_eq!(
+                    table.action(state_index, quasiterminal),
+                    compressed.action(state_index, i),
+                    "action mismatch at {state_index:?}, {quasiterminal:?}"
+                );
+            }
+
+            for (i, nonterminal) in table.nonterminals.iter().enumerate() {
+                assert_eq!(
+                    table.goto(state_index, nonterminal),
+                    compressed.goto(state_index, i),
+                    "goto mismatch at {state_index:?}, {nonterminal}"
+                );
+            }
+        }
+    }
+
+    fn get_table(src: &str, algorithm: ParserAlgorithm) -> Table {
+        let tokens = tokenize(src).expect("should tokenize correctly");
+        let cst = parse(tokens).expect("should parse correctly");
+        let ast: crate::data::ast::File = cst.into();
+        let validated = validate_ast(ast).expect("should be valid");
+        let (machine, _) = validated_ast_to_machine(&validated, algorithm);
+        machine_to_table(&machine, None, &validated, DefaultReductions::Off)
+            .expect("should have no conflicts")
+    }
+}
@@ -0,0 +1,748 @@
+use crate::data::{
+    machine::*, validated_file::*, Counterexample, CounterexampleKind, Derivation,
+    DollarlessTerminalName, Example, Symbol,
+};
+use crate::pipeline::validated_ast_to_machine::{get_first_sets, FirstSet};
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Finds an input that demonstrates the conflict
+/// between two items of the given state.
+///
+/// For each item, we search backwards through the machine
+/// for the shortest chain of items that leads from a start item
+/// to the conflicting item.
+/// We then complete that chain into an example sentence,
+/// so that the conflicting lookahead follows the conflict.
+///
+/// If either example sentence (trying the shorter one first) has two derivations,
+/// the grammar is ambiguous, and we return a unifying counterexample.
+/// Otherwise, we return the two examples as a non-unifying counterexample.
+///
+/// Returns `None` if either item cannot be reached from a start item,
+/// which only happens if the machine is malformed.
+pub fn find_counterexample(
+    machine: &Machine,
+    file: &File,
+    state_index: StateIndex,
+    items: (&StateItem, &StateItem),
+    lookahead: &Lookahead,
+) -> Option<Counterexample> {
+    let context = Context::new(machine, file, lookahead);
+    let first = context.get_example(state_index, items.0)?;
+    let second = context.get_example(state_index, items.1)?;
+
+    let mut examples = [&first, &second];
+    examples.sort_by_key(|example| example.sentence.len());
+    for example in examples {
+        if let Some(derivations) = context.get_two_derivations(example) {
+            return Some(Counterexample {
+                kind: CounterexampleKind::Unifying,
+                examples: derivations,
+            });
+        }
+    }
+
+    Some(Counterexample {
+        kind: CounterexampleKind::NonUnifying,
+        examples: (first, second),
+    })
+}
+
+struct Context<'a> {
+    machine: &'a Machine,
+    rules: Vec<Rule<'a>>,
+    rule_symbols: Vec<Vec<Symbol>>,
+    /// The right-hand side of the augmented rule `S' -> S`
+    /// for each start symbol `S`.
+    augmented_rule_symbols: Vec<Vec<Symbol>>,
+    incoming_transitions: HashMap<StateIndex, Vec<&'a Transition>>,
+    first_sets: HashMap<String, FirstSet>,
+    /// The conflicting lookahead.
+    lookahead: &'a Lookahead,
+    /// For each nonterminal, the index of the rule
+    /// that begins its shortest derivation,
+    /// along with the length of that derivation's yield.
+    shortest: HashMap<String, (usize, usize)>,
+    /// For each nonterminal, the index of the rule
+    /// that begins its shortest derivation whose yield
+    /// starts with the conflicting lookahead terminal,
+    /// the index of the symbol in that rule
+    /// whose yield starts with the terminal,
+    /// and the length of the yield.
+    /// This is empty if the lookahead is the end of input.
+    shortest_starting_with_lookahead: HashMap<String, (usize, usize, usize)>,
+}
+
+/// A node of the backward search.
+type Node = (StateIndex, StateItem);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Step {
+    /// The item was reached by advancing the dot of the previous item
+    /// across a transition.
+    Transition,
+    /// The item was added to the previous item's state by closure,
+    /// since the previous item's dot is before the item's nonterminal.
+    Production,
+}
+
+impl Context<'_> {
+    fn new<'a>(machine: &'a Machine, file: &'a File, lookahead: &'a Lookahead) -> Context<'a> {
+        let rules: Vec<Rule> = file.get_rules().collect();
+        let rule_symbols = rules.iter().map(get_rule_symbols).collect();
+        let augmented_rule_symbols = file
+            .starts
+            .iter()
+            .map(|start| vec![Symbol::Nonterminal(start.clone())])
+            .collect();
+        let mut incoming_transitions: HashMap<StateIndex, Vec<&Transition>> = HashMap::new();
+        for transition in &machine.transitions {
+            incoming_transitions
+                .entry(transition.to)
+                .or_default()
+                .push(transition);
+        }
+        let first_sets = get_first_sets(&rules);
+        let mut context = Context {
+            machine,
+            rules,
+            rule_symbols,
+            augmented_rule_symbols,
+            incoming_transitions,
+            first_sets,
+            lookahead,
+            shortest: HashMap::new(),
+            shortest_starting_with_lookahead: HashMap::new(),
+        };
+        context.shortest = context.get_shortest_map();
+        if let Lookahead::Terminal(terminal) = lookahead {
+            context.shortest_starting_with_lookahead =
+                context.get_shortest_starting_with_map(terminal);
+        }
+        context
+    }
+}
+
+impl Context<'_> {
+    fn get_example(&self, state_index: StateIndex, item: &StateItem) -> Option<Example> {
+        let path = self.get_path_to_item(state_index, item)?;
+        Some(self.complete_path(&path))
+    }
+
+    /// Returns the shortest path from a start item to the given item,
+    /// found by breadth-first search backwards through the machine.
+    /// The first step of the path is always `Step::Production`,
+    /// and it leads to the augmented start item.
+    ///
+    /// If the item is a shift item, the conflicting lookahead
+    /// is the symbol after its dot, so the item's own lookahead is irrelevant.
+    /// In that case, the path may end at any item in the state
+    /// that only differs from the given item by its lookahead.
+    ///
+    /// Returns `None` if the item is unreachable.
+    fn get_path_to_item(
+        &self,
+        state_index: StateIndex,
+        item: &StateItem,
+    ) -> Option<Vec<(Step, Node)>> {
+        let is_shift = item.dot < self.get_item_symbols(item.rule_index).len();
+        let mut successors: HashMap<Node, (Step, Node)> = HashMap::new();
+        let mut visited: HashSet<Node> = HashSet::new();
+        let mut queue: VecDeque<Node> = VecDeque::new();
+        let targets = self.machine.states[state_index.0]
+            .items
+            .iter()
+            .filter(|other| {
+                *other == item
+                    || (is_shift && other.rule_index == item.rule_index && other.dot == item.dot)
+            });
+        for target in targets {
+            let target: Node = (state_index, target.clone());
+            visited.insert(target.clone());
+            queue.push_back(target);
+        }
+
+        while let Some(node) = queue.pop_front() {
+            if self.is_start_node(&node) {
+                let mut path = vec![(Step::Production, node.clone())];
+                let mut current = node;
+                while let Some((step, next)) = successors.get(&current) {
+                    path.push((*step, next.clone()));
+                    current = next.clone();
+                }
+                return Some(path);
+            }
+
+            for (step, predecessor) in self.get_predecessors(&node) {
+                if visited.insert(predecessor.clone()) {
+                    successors.insert(predecessor.clone(), (step, node.clone()));
+                    queue.push_back(predecessor);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn is_start_node(&self, (state_index, item): &Node) -> bool {
+        match item.rule_index {
+            RuleIndex::Augmented(start_index) => {
+                item.dot == 0 && self.machine.starts[start_index] == *state_index
+            }
+            RuleIndex::Original(_) => false,
+        }
+    }
+
+    /// Returns the nodes that lead to `node` in one step,
+    /// along with the kind of that step.
+    fn get_predecessors(&self, (state_index, item): &Node) -> Vec<(Step, Node)> {
+        if item.dot > 0 {
+            let symbol = &self.get_item_symbols(item.rule_index)[item.dot - 1];
+            let previous_item = StateItem {
+                rule_index: item.rule_index,
+                lookahead: item.lookahead.clone(),
+                dot: item.dot - 1,
+            };
+            return self
+                .incoming_transitions
+                .get(state_index)
+                .into_iter()
+                .flatten()
+                .filter(|transition| transition.symbol == *symbol)
+                .filter(|transition| {
+                    self.machine.states[transition.from.0]
+                        .items
+                        .contains(&previous_item)
+                })
+                .map(|transition| (Step::Transition, (transition.from, previous_item.clone())))
+                .collect();
+        }
+
+        let RuleIndex::Original(rule_index) = item.rule_index else {
+            return vec![];
+        };
+        let nonterminal = self.rules[rule_index].constructor_name.type_name();
+        self.machine.states[state_index.0]
+            .items
+            .iter()
+            .filter(|parent| self.is_closure_parent(parent, nonterminal, &item.lookahead))
+            .map(|parent| (Step::Production, (*state_index, parent.clone())))
+            .collect()
+    }
+
+    /// Returns true if `parent` is `B -> alpha . A beta`,
+    /// where `A` is `nonterminal`,
+    /// and `lookahead` is in the first set of `beta`
+    /// followed by the parent's lookahead.
+    fn is_closure_parent(
+        &self,
+        parent: &StateItem,
+        nonterminal: &str,
+        lookahead: &Lookahead,
+    ) -> bool {
+        let symbols = self.get_item_symbols(parent.rule_index);
+        if !matches!(symbols.get(parent.dot), Some(Symbol::Nonterminal(name)) if name == nonterminal)
+        {
+            return false;
+        }
+
+        let first = self.get_first_of_symbol_sequence(&symbols[parent.dot + 1..]);
+        let is_in_first = match lookahead {
+            Lookahead::Terminal(terminal) => first.terminals.contains(terminal),
+            Lookahead::Eof => false,
+        };
+        is_in_first || (first.contains_epsilon && parent.lookahead == *lookahead)
+    }
+
+    fn get_first_of_symbol_sequence(&self, symbols: &[Symbol]) -> FirstSet {
+        let mut out = FirstSet {
+            terminals: Default::default(),
+            contains_epsilon: true,
+        };
+        for symbol in symbols {
+            match symbol {
+                Symbol::Terminal(terminal) => {
+                    out.terminals.insert(terminal.clone());
+                    out.contains_epsilon = false;
+                    return out;
+                }
+                Symbol::Nonterminal(name) => {
+                    let first = &self.first_sets[name];
+                    out.terminals.extend(first.terminals.iter().cloned());
+                    if !first.contains_epsilon {
+                        out.contains_epsilon = false;
+                        return out;
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+/// A partially derived rule.
+struct Frame {
+    rule_index: RuleIndex,
+    children: Vec<Derivation>,
+}
+
+impl Context<'_> {
+    /// Walks the path forwards, deriving every symbol
+    /// to the left of each dot with its shortest derivation.
+    /// Then, completes every rule on the path,
+    /// so that the conflicting lookahead immediately follows
+    /// the terminals derived so far.
+    fn complete_path(&self, path: &[(Step, Node)]) -> Example {
+        let mut frames: Vec<Frame> = vec![];
+        let mut dot = 0;
+        for (step, (_, item)) in path {
+            match step {
+                Step::Production => frames.push(Frame {
+                    rule_index: item.rule_index,
+                    children: vec![],
+                }),
+                Step::Transition => {
+                    let symbols = self.get_item_symbols(item.rule_index);
+                    let derivation = self.get_shortest_derivation(&symbols[item.dot - 1]);
+                    dot += derivation.get_yield().len();
+                    frames.last_mut().unwrap().children.push(derivation);
+                }
+            }
+        }
+
+        let mut is_lookahead_derived = false;
+        let mut completed: Option<Derivation> = None;
+        while let Some(mut frame) = frames.pop() {
+            frame.children.extend(completed.take());
+            let symbols = self.get_item_symbols(frame.rule_index);
+            let rest = &symbols[frame.children.len()..];
+            if is_lookahead_derived {
+                frame.children.extend(
+                    rest.iter()
+                        .map(|symbol| self.get_shortest_derivation(symbol)),
+                );
+            } else if let Some(derivations) = self.get_sequence_starting_with_lookahead(rest) {
+                frame.children.extend(derivations);
+                is_lookahead_derived = true;
+            } else {
+                // The lookahead comes from a rule further down the path
+                // (or it is the end of input),
+                // so the rest of this rule must derive the empty string.
+                frame.children.extend(
+                    rest.iter()
+                        .map(|symbol| self.get_shortest_derivation(symbol)),
+                );
+            }
+
+            completed = Some(match frame.rule_index {
+                RuleIndex::Original(rule_index) => Derivation::Rule {
+                    rule_index,
+                    children: frame.children,
+                },
+                // The augmented rule `S' -> S` has exactly one child,
+                // which is the derivation of the start symbol.
+                RuleIndex::Augmented(_) => frame.children.pop().unwrap(),
+            });
+        }
+
+        let derivation = completed.unwrap();
+        Example {
+            sentence: derivation.get_yield().into_iter().cloned().collect(),
+            dot,
+            derivation,
+        }
+    }
+
+    /// Returns derivations of `symbols` whose combined yield
+    /// starts with the conflicting lookahead terminal,
+    /// or `None` if there are no such derivations.
+    fn get_sequence_starting_with_lookahead(&self, symbols: &[Symbol]) -> Option<Vec<Derivation>> {
+        for (i, symbol) in symbols.iter().enumerate() {
+            if let Some(derivation) = self.get_shortest_derivation_starting_with_lookahead(symbol) {
+                let mut out: Vec<Derivation> = symbols[..i]
+                    .iter()
+                    .map(|symbol| self.get_shortest_derivation(symbol))
+                    .collect();
+                out.push(derivation);
+                out.extend(
+                    symbols[i + 1..]
+                        .iter()
+                        .map(|symbol| self.get_shortest_derivation(symbol)),
+                );
+                return Some(out);
+            }
+
+            if self.get_shortest_len(symbol) != 0 {
+                return None;
+            }
+        }
+        None
+    }
+
+    fn get_shortest_derivation(&self, symbol: &Symbol) -> Derivation {
+        match symbol {
+            Symbol::Terminal(terminal) => Derivation::Terminal(terminal.clone()),
+            Symbol::Nonterminal(name) => {
+                let (rule_index, _) = self.shortest[name];
+                Derivation::Rule {
+                    rule_index,
+                    children: self
+                        .get_rule_symbols(rule_index)
+                        .iter()
+                        .map(|symbol| self.get_shortest_derivation(symbol))
+                        .collect(),
+                }
+            }
+        }
+    }
+
+    fn get_shortest_derivation_starting_with_lookahead(
+        &self,
+        symbol: &Symbol,
+    ) -> Option<Derivation> {
+        match symbol {
+            Symbol::Terminal(terminal) => match self.lookahead {
+                Lookahead::Terminal(lookahead) if lookahead == terminal => {
+                    Some(Derivation::Terminal(terminal.clone()))
+                }
+                _ => None,
+            },
+            Symbol::Nonterminal(name) => {
+                let &(rule_index, split, _) = self.shortest_starting_with_lookahead.get(name)?;
+                let symbols = self.get_rule_symbols(rule_index);
+                let children = symbols
+                    .iter()
+                    .enumerate()
+                    .map(|(i, symbol)| {
+                        if i == split {
+                            self.get_shortest_derivation_starting_with_lookahead(symbol)
+                                .unwrap()
+                        } else {
+                            self.get_shortest_derivation(symbol)
+                        }
+                    })
+                    .collect();
+                Some(Derivation::Rule {
+                    rule_index,
+                    children,
+                })
+            }
+        }
+    }
+
+    fn get_shortest_len(&self, symbol: &Symbol) -> usize {
+        match symbol {
+            Symbol::Terminal(_) => 1,
+            Symbol::Nonterminal(name) => self.shortest[name].1,
+        }
+    }
+
+    /// Since lengths only ever decrease,
+    /// the rules in the resulting map never form a cycle.
+    fn get_shortest_map(&self) -> HashMap<String, (usize, usize)> {
+        let mut out: HashMap<String, (usize, usize)> = HashMap::new();
+        loop {
+            let mut changed = false;
+            for (rule_index, rule) in self.rules.iter().enumerate() {
+                let len: Option<usize> = self
+                    .get_rule_symbols(rule_index)
+                    .iter()
+                    .map(|symbol| match symbol {
+                        Symbol::Terminal(_) => Some(1),
+                        Symbol::Nonterminal(name) => out.get(name).map(|(_, len)| *len),
+                    })
+                    .sum();
+                let Some(len) = len else {
+                    continue;
+                };
+
+                let name = rule.constructor_name.type_name();
+                if out.get(name).is_none_or(|(_, existing)| len < *existing) {
+                    out.insert(name.to_owned(), (rule_index, len));
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                return out;
+            }
+        }
+    }
+
+    /// Like `get_shortest_map`, but only considers
+    /// derivations whose yield starts with `terminal`.
+    fn get_shortest_starting_with_map(
+        &self,
+        terminal: &DollarlessTerminalName,
+    ) -> HashMap<String, (usize, usize, usize)> {
+        let mut out: HashMap<String, (usize, usize, usize)> = HashMap::new();
+        loop {
+            let mut changed = false;
+            for (rule_index, rule) in self.rules.iter().enumerate() {
+                let Some((split, len)) =
+                    self.get_len_starting_with(self.get_rule_symbols(rule_index), terminal, &out)
+                else {
+                    continue;
+                };
+
+                let name = rule.constructor_name.type_name();
+                if out.get(name).is_none_or(|(_, _, existing)| len < *existing) {
+                    out.insert(name.to_owned(), (rule_index, split, len));
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                return out;
+            }
+        }
+    }
+
+    /// Returns the index of the first symbol whose yield
+    /// can start with `terminal` (given the current map of
+    /// shortest derivations starting with `terminal`),
+    /// along with the length of the shortest such yield
+    /// of the whole sequence.
+    /// The symbols before that symbol must derive the empty string.
+    fn get_len_starting_with(
+        &self,
+        symbols: &[Symbol],
+        terminal: &DollarlessTerminalName,
+        starting_with: &HashMap<String, (usize, usize, usize)>,
+    ) -> Option<(usize, usize)> {
+        for (i, symbol) in symbols.iter().enumerate() {
+            let len = match symbol {
+                Symbol::Terminal(t) if t == terminal => Some(1),
+                Symbol::Terminal(_) => None,
+                Symbol::Nonterminal(name) => starting_with.get(name).map(|(_, _, len)| *len),
+            };
+            if let Some(len) = len {
+                let rest_len: usize = symbols[i + 1..]
+                    .iter()
+                    .map(|symbol| self.get_shortest_len(symbol))
+                    .sum();
+                return Some((i, len + rest_len));
+            }
+
+            if self.get_shortest_len(symbol) != 0 {
+                return None;
+            }
+        }
+        None
+    }
+}
+
+impl Context<'_> {
+    /// If the example's sentence has two derivations,
+    /// returns them as examples.
+    /// The example's own derivation comes first, if it is one of the two.
+    fn get_two_derivations(&self, example: &Example) -> Option<(Example, Example)> {
+        let Derivation::Rule { rule_index, .. } = &example.derivation else {
+            return None;
+        };
+        let start = self.rules[*rule_index].constructor_name.type_name();
+        let mut parser = AmbiguityParser {
+            context: self,
+            sentence: &example.sentence,
+            nonterminal_memo: HashMap::new(),
+            sequence_memo: HashMap::new(),
+            in_progress: HashMap::new(),
+            lowest_in_progress_hit: None,
+        };
+        let mut derivations = parser.get_nonterminal_derivations(start, 0, example.sentence.len());
+        if derivations.len() < 2 {
+            return None;
+        }
+
+        if derivations[1] == example.derivation {
+            derivations.swap(0, 1);
+        }
+        let mut derivations = derivations.into_iter().map(|derivation| Example {
+            sentence: example.sentence.clone(),
+            dot: example.dot,
+            derivation,
+        });
+        Some((derivations.next().unwrap(), derivations.next().unwrap()))
+    }
+}
+
+/// Finds up to two derivations of each part of a sentence,
+/// using memoized top-down search.
+///
+/// A nonterminal that derives itself without consuming input
+/// (e.g., through `B -> E A` with a nullable `E`)
+/// would make the search recurse forever.
+/// So, while a nonterminal's derivations are being computed,
+/// a recursive request for them returns no derivations,
+/// which excludes cyclic derivations.
+/// A result computed with the help of such a truncated answer
+/// is not memoized (unless the truncated answer was its own),
+/// since it may be incomplete when requested from elsewhere.
+struct AmbiguityParser<'a, 'b> {
+    context: &'b Context<'a>,
+    sentence: &'b [DollarlessTerminalName],
+    nonterminal_memo: HashMap<(String, usize, usize), Vec<Derivation>>,
+    sequence_memo: HashMap<(usize, usize, usize, usize), Vec<Vec<Derivation>>>,
+    /// Maps each nonterminal entry whose derivations are being computed
+    /// to its depth in the stack of such entries.
+    in_progress: HashMap<(String, usize, usize), usize>,
+    /// The lowest depth of an in-progress entry that was requested
+    /// since this was last reset.
+    lowest_in_progress_hit: Option<usize>,
+}
+
+const MAX_DERIVATIONS: usize = 2;
+
+impl AmbiguityParser<'_, '_> {
+    /// Returns up to two derivations of `sentence[start..end]`
+    /// from the nonterminal.
+    fn get_nonterminal_derivations(
+        &mut self,
+        name: &str,
+        start: usize,
+        end: usize,
+    ) -> Vec<Derivation> {
+        let key = (name.to_owned(), start, end);
+        if let Some(derivations) = self.nonterminal_memo.get(&key) {
+            return derivations.clone();
+        }
+        if let Some(&depth) = self.in_progress.get(&key) {
+            self.lowest_in_progress_hit = min_depth(self.lowest_in_progress_hit, Some(depth));
+            return vec![];
+        }
+        let depth = self.in_progress.len();
+        self.in_progress.insert(key.clone(), depth);
+        let outer_hit = self.lowest_in_progress_hit.take();
+
+        let mut out = vec![];
+        for (rule_index, rule) in self.context.rules.iter().enumerate() {
+            if rule.constructor_name.type_name() != name {
+                continue;
+            }
+
+            for children in self.get_sequence_derivations(rule_index, 0, start, end) {
+                out.push(Derivation::Rule {
+                    rule_index,
+                    children,
+                });
+                if out.len() == MAX_DERIVATIONS {
+                    break;
+                }
+            }
+            if out.len() == MAX_DERIVATIONS {
+                break;
+            }
+        }
+
+        self.in_progress.remove(&key);
+        // Every computation of this entry excludes the cycles back to it,
+        // so depending on itself does not make the result incomplete.
+        let hit = self
+            .lowest_in_progress_hit
+            .take()
+            .filter(|&hit_depth| hit_depth < depth);
+        if hit.is_none() {
+            self.nonterminal_memo.insert(key, out.clone());
+        }
+        self.lowest_in_progress_hit = min_depth(outer_hit, hit);
+        out
+    }
+
+    /// Returns up to two derivations of `sentence[start..end]`
+    /// from the symbols of the rule from `symbol_index` onwards.
+    fn get_sequence_derivations(
+        &mut self,
+        rule_index: usize,
+        symbol_index: usize,
+        start: usize,
+        end: usize,
+    ) -> Vec<Vec<Derivation>> {
+        let key = (rule_index, symbol_index, start, end);
+        if let Some(derivations) = self.sequence_memo.get(&key) {
+            return derivations.clone();
+        }
+        let outer_hit = self.lowest_in_progress_hit.take();
+
+        let symbols = self.context.get_rule_symbols(rule_index);
+        let mut out: Vec<Vec<Derivation>> = vec![];
+        if symbol_index == symbols.len() {
+            if start == end {
+                out.push(vec![]);
+            }
+        } else {
+            let min_rest_len: usize = symbols[symbol_index + 1..]
+                .iter()
+                .map(|symbol| self.context.get_shortest_len(symbol))
+                .sum();
+            'splits: for split in start..=end.saturating_sub(min_rest_len).max(start) {
+                let firsts = self.get_symbol_derivations(&symbols[symbol_index], start, split);
+                if firsts.is_empty() {
+                    continue;
+                }
+                let rests = self.get_sequence_derivations(rule_index, symbol_index + 1, split, end);
+                for first in &firsts {
+                    for rest in &rests {
+                        let mut derivations = vec![first.clone()];
+                        derivations.extend(rest.iter().cloned());
+                        out.push(derivations);
+                        if out.len() == MAX_DERIVATIONS {
+                            break 'splits;
+                        }
+                    }
+                }
+            }
+        }
+
+        let hit = self.lowest_in_progress_hit.take();
+        if hit.is_none() {
+            self.sequence_memo.insert(key, out.clone());
+        }
+        self.lowest_in_progress_hit = min_depth(outer_hit, hit);
+        out
+    }
+
+    fn get_symbol_derivations(
+        &mut self,
+        symbol: &Symbol,
+        start: usize,
+        end: usize,
+    ) -> Vec<Derivation> {
+        match symbol {
+            Symbol::Terminal(terminal) => {
+                if end == start + 1 && self.sentence[start] == *terminal {
+                    vec![Derivation::Terminal(terminal.clone())]
+                } else {
+                    vec![]
+                }
+            }
+            Symbol::Nonterminal(name) => self.get_nonterminal_derivations(name, start, end),
+        }
+    }
+}
+
+fn min_depth(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+    a.into_iter().chain(b).min()
+}
+
+impl Context<'_> {
+    fn get_item_symbols(&self, rule_index: RuleIndex) -> &[Symbol] {
+        match rule_index {
+            RuleIndex::Original(rule_index) => self.get_rule_symbols(rule_index),
+            RuleIndex::Augmented(start_index) => &self.augmented_rule_symbols[start_index],
+        }
+    }
+
+    fn get_rule_symbols(&self, rule_index: usize) -> &[Symbol] {
+        &self.rule_symbols[rule_index]
+    }
+}
+
+fn get_rule_symbols(rule: &Rule) -> Vec<Symbol> {
+    (0..rule.fieldset.len())
+        .map(|i| match rule.fieldset.get_symbol_ident(i) {
+            IdentOrTerminalIdent::Ident(ident) => Symbol::Nonterminal(ident.name.clone()),
+            IdentOrTerminalIdent::Terminal(terminal) => Symbol::Terminal(terminal.name.clone()),
+        })
+        .collect()
+}
@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+
+/// Returns `preferred_name` if it is not in `used`.
+/// Otherwise, returns `preferred_name` followed by the smallest
+/// integer (starting at 2) that produces an unused name.
+///
+/// The returned name is added to `used`.
+pub fn create_unique_identifier(preferred_name: &str, used: &mut HashSet<String>) -> String {
+    if !used.contains(preferred_name) {
+        used.insert(preferred_name.to_string());
+        return preferred_name.to_string();
+    }
+
+    let mut i = 2;
+    loop {
+        let name = format!("{}{}", preferred_name, i);
+        if !used.contains(&name) {
+            used.insert(name.clone());
+            return name;
+        }
+        i += 1;
+    }
+}
@@ -15,7 +15,7 @@
 // we will have to manually remove them again each time
 // we use Copilot to update the code in the future.
 
-use crate::data::{ast, cst};
+use crate::data::{ast, cst, DollarlessTerminalName};
 
 impl From<cst::File> for ast::File {
     fn from(cst: cst::File) -> Self {
@@ -45,6 +45,11 @@ impl From<cst::FileItem> for ast::FileItem {
             cst::FileItem::Struct(struct_) => ast::FileItem::Struct((*struct_).into()),
             cst::FileItem::Enum(enum_) => ast::FileItem::Enum((*enum_).into()),
             cst::FileItem::Terminal(terminal) => ast::FileItem::Terminal((*terminal).into()),
+            cst::FileItem::Precedence(precedence) => {
+                ast::FileItem::Precedence((*precedence).into())
+            }
+            cst::FileItem::Lexer(lexer) => ast::FileItem::Lexer((*lexer).into()),
+            cst::FileItem::Allow(allow) => ast::FileItem::Allow((*allow).into()),
         }
     }
 }
@@ -52,9 +57,13 @@ impl From<cst::FileItem> for ast::FileItem {
 impl From<cst::Struct> for ast::Struct {
     fn from(cst: cst::Struct) -> Self {
         ast::Struct {
+            doc: (*cst.doc).into(),
             attributes: (*cst.attributes).into(),
             name: cst.name.into(),
+            params: (*cst.params).into(),
+            result_type: (*cst.result_type).into(),
             fieldset: (*cst.fieldset).into(),
+            action: (*cst.action).into(),
         }
     }
 }
@@ -62,8 +71,11 @@ impl From<cst::Struct> for ast::Struct {
 impl From<cst::Enum> for ast::Enum {
     fn from(cst: cst::Enum) -> Self {
         ast::Enum {
+            doc: (*cst.doc).into(),
             attributes: (*cst.attributes).into(),
             name: cst.name.into(),
+            params: (*cst.params).into(),
+            result_type: (*cst.result_type).into(),
             variants: (*cst.variants).into(),
         }
     }
@@ -71,14 +83,84 @@ impl From<cst::Enum> for ast::Enum {
 
 impl From<cst::TerminalEnum> for ast::TerminalEnum {
     fn from(cst: cst::TerminalEnum) -> Self {
+        let (name, span_position_type) = match *cst.header {
+            cst::TerminalEnumHeader::Unspanned(name) => ((*name).into(), None),
+            cst::TerminalEnumHeader::Spanned(name, position_type) => {
+                ((*name).into(), Some((*position_type).into()))
+            }
+        };
         ast::TerminalEnum {
+            doc: (*cst.doc).into(),
             attributes: (*cst.attributes).into(),
-            name: cst.name.into(),
+            name,
+            span_position_type,
             variants: (*cst.variants).into(),
         }
     }
 }
 
+impl From<cst::TerminalEnumName> for ast::TerminalEnumName {
+    fn from(cst: cst::TerminalEnumName) -> Self {
+        match cst {
+            cst::TerminalEnumName::Local(ident) => ast::TerminalEnumName::Local(ident.into()),
+            cst::TerminalEnumName::Extern(path) => ast::TerminalEnumName::Extern((*path).into()),
+        }
+    }
+}
+
+impl From<cst::OptGenericParams> for Vec<ast::Ident> {
+    fn from(cst: cst::OptGenericParams) -> Self {
+        match cst {
+            cst::OptGenericParams::None => vec![],
+            cst::OptGenericParams::Some(params) => (*params).into(),
+        }
+    }
+}
+
+impl From<cst::GenericParams> for Vec<ast::Ident> {
+    fn from(cst: cst::GenericParams) -> Self {
+        match cst {
+            cst::GenericParams::One(ident) => vec![ident.into()],
+            cst::GenericParams::Cons(left, right) => {
+                let mut params: Vec<ast::Ident> = (*left).into();
+                params.push(right.into());
+                params
+            }
+        }
+    }
+}
+
+impl From<cst::OptResultType> for Option<ast::Type> {
+    fn from(cst: cst::OptResultType) -> Self {
+        match cst {
+            cst::OptResultType::None => None,
+            cst::OptResultType::Some(type_) => Some((*type_).into()),
+        }
+    }
+}
+
+impl From<cst::OptSemanticAction> for Option<ast::SemanticAction> {
+    fn from(cst: cst::OptSemanticAction) -> Self {
+        match cst {
+            cst::OptSemanticAction::None => None,
+            cst::OptSemanticAction::Some(action) => Some(action),
+        }
+    }
+}
+
+impl From<cst::OptDocComments> for Vec<ast::DocComment> {
+    fn from(cst: cst::OptDocComments) -> Self {
+        match cst {
+            cst::OptDocComments::Nil => vec![],
+            cst::OptDocComments::Cons(left, right) => {
+                let mut doc: Vec<ast::DocComment> = (*left).into();
+                doc.push(right);
+                doc
+            }
+        }
+    }
+}
+
 impl From<cst::OptOuterAttributes> for Vec<ast::Attribute> {
     fn from(cst: cst::OptOuterAttributes) -> Self {
         match cst {
@@ -126,6 +208,7 @@ impl From<cst::NamedFields> for Vec<ast::NamedField> {
 impl From<cst::NamedField> for ast::NamedField {
     fn from(cst: cst::NamedField) -> Self {
         ast::NamedField {
+            doc: (*cst.doc).into(),
             name: (*cst.name).into(),
             symbol: (*cst.symbol).into(),
         }
@@ -164,6 +247,118 @@ impl From<cst::TupleField> for ast::TupleField {
     }
 }
 
+impl From<cst::FieldSymbol> for ast::FieldSymbol {
+    fn from(cst: cst::FieldSymbol) -> Self {
+        match cst {
+            cst::FieldSymbol::Plain(symbol) => ast::FieldSymbol::Plain((*symbol).into()),
+            cst::FieldSymbol::ZeroOrMore(element, operator_position) => {
+                ast::FieldSymbol::Repetition(ast::Repetition {
+                    element: (*element).into(),
+                    kind: ast::RepetitionKind::ZeroOrMore,
+                    operator_position,
+                })
+            }
+            cst::FieldSymbol::OneOrMore(element, operator_position) => {
+                ast::FieldSymbol::Repetition(ast::Repetition {
+                    element: (*element).into(),
+                    kind: ast::RepetitionKind::OneOrMore,
+                    operator_position,
+                })
+            }
+            cst::FieldSymbol::Optional(element, operator_position) => {
+                ast::FieldSymbol::Optional(ast::Optional {
+                    element: (*element).into(),
+                    operator_position,
+                })
+            }
+            cst::FieldSymbol::Call(call) => ast::FieldSymbol::Call((*call).into()),
+            cst::FieldSymbol::Instantiation(instantiation) => {
+                ast::FieldSymbol::Instantiation((*instantiation).into())
+            }
+        }
+    }
+}
+
+impl From<cst::FieldSymbolCall> for ast::FieldSymbolCall {
+    fn from(cst: cst::FieldSymbolCall) -> Self {
+        ast::FieldSymbolCall {
+            callee: cst.callee.into(),
+            element: (*cst.element).into(),
+            separator: (*cst.separator).into(),
+            flags: (*cst.flags).into(),
+        }
+    }
+}
+
+impl From<cst::Instantiation> for ast::Instantiation {
+    fn from(cst: cst::Instantiation) -> Self {
+        ast::Instantiation {
+            callee: cst.callee.into(),
+            args: (*cst.args).into(),
+        }
+    }
+}
+
+impl From<cst::GenericArgs> for Vec<ast::IdentOrTerminalIdent> {
+    fn from(cst: cst::GenericArgs) -> Self {
+        match cst {
+            cst::GenericArgs::One(arg) => vec![(*arg).into()],
+            cst::GenericArgs::Cons(left, right) => {
+                let mut args: Vec<ast::IdentOrTerminalIdent> = (*left).into();
+                args.push((*right).into());
+                args
+            }
+        }
+    }
+}
+
+impl From<cst::IdentOrTerminalIdent> for ast::IdentOrTerminalIdent {
+    fn from(cst: cst::IdentOrTerminalIdent) -> Self {
+        match cst {
+            cst::IdentOrTerminalIdent::Ident(ident) => ast::IdentOrTerminalIdent::Ident(ident),
+            cst::IdentOrTerminalIdent::Terminal(terminal) => {
+                ast::IdentOrTerminalIdent::Terminal(terminal)
+            }
+            cst::IdentOrTerminalIdent::Literal(literal) => {
+                ast::IdentOrTerminalIdent::Terminal(literal.into())
+            }
+        }
+    }
+}
+
+impl From<cst::TerminalIdentOrLiteral> for ast::TerminalIdent {
+    fn from(cst: cst::TerminalIdentOrLiteral) -> Self {
+        match cst {
+            cst::TerminalIdentOrLiteral::Terminal(terminal) => terminal,
+            cst::TerminalIdentOrLiteral::Literal(literal) => literal.into(),
+        }
+    }
+}
+
+/// Gives the literal its provisional name.
+/// Validation replaces it with a generated one.
+impl From<ast::StringLiteral> for ast::TerminalIdent {
+    fn from(literal: ast::StringLiteral) -> Self {
+        ast::TerminalIdent {
+            name: DollarlessTerminalName::literal(&literal.value),
+            dollarless_position: literal.position,
+        }
+    }
+}
+
+impl From<cst::OptCallFlags> for Vec<ast::Ident> {
+    fn from(cst: cst::OptCallFlags) -> Self {
+        match cst {
+            cst::OptCallFlags::Nil => vec![],
+            cst::OptCallFlags::Cons(left, right) => {
+                let mut flags: Vec<ast::Ident> = (*left).into();
+                flags.push(right.into());
+                flags
+            }
+        }
+    }
+}
+
 impl From<cst::OptEnumVariants> for Vec<ast::EnumVariant> {
     fn from(cst: cst::OptEnumVariants) -> Self {
         match cst {
@@ -180,8 +375,20 @@ impl From<cst::OptEnumVariants> for Vec<ast::EnumVariant> {
 impl From<cst::EnumVariant> for ast::EnumVariant {
     fn from(cst: cst::EnumVariant) -> Self {
         ast::EnumVariant {
+            doc: (*cst.doc).into(),
             name: cst.name.into(),
             fieldset: (*cst.fieldset).into(),
+            precedence: (*cst.precedence_override).into(),
+            action: (*cst.action).into(),
+        }
+    }
+}
+
+impl From<cst::OptPrecedenceOverride> for Option<ast::TerminalIdent> {
+    fn from(cst: cst::OptPrecedenceOverride) -> Self {
+        match cst {
+            cst::OptPrecedenceOverride::None => None,
+            cst::OptPrecedenceOverride::Some(terminal) => Some((*terminal).into()),
         }
     }
 }
@@ -202,8 +409,135 @@ impl From<cst::OptTerminalEnumVariants> for Vec<ast::TerminalEnumVariant> {
 impl From<cst::TerminalEnumVariant> for ast::TerminalEnumVariant {
     fn from(cst: cst::TerminalEnumVariant) -> Self {
         ast::TerminalEnumVariant {
-            name: cst.name.into(),
+            doc: (*cst.doc).into(),
+            name: (*cst.name).into(),
             type_: (*cst.type_).into(),
+            pattern: (*cst.pattern).into(),
+            literal: None,
+        }
+    }
+}
+
+impl From<cst::OptTerminalPattern> for Option<ast::TerminalPattern> {
+    fn from(cst: cst::OptTerminalPattern) -> Self {
+        match cst {
+            cst::OptTerminalPattern::None => None,
+            cst::OptTerminalPattern::Some(pattern) => Some((*pattern).into()),
+        }
+    }
+}
+
+impl From<cst::TerminalPattern> for ast::TerminalPattern {
+    fn from(cst: cst::TerminalPattern) -> Self {
+        ast::TerminalPattern {
+            variant: cst.variant.into(),
+            fields: (*cst.fields).into(),
+        }
+    }
+}
+
+impl From<cst::OptTerminalPatternFields> for Option<Vec<ast::IdentOrUnderscore>> {
+    fn from(cst: cst::OptTerminalPatternFields) -> Self {
+        match cst {
+            cst::OptTerminalPatternFields::None => None,
+            cst::OptTerminalPatternFields::Some(fields) => Some((*fields).into()),
+        }
+    }
+}
+
+impl From<cst::TerminalPatternFields> for Vec<ast::IdentOrUnderscore> {
+    fn from(cst: cst::TerminalPatternFields) -> Self {
+        match cst {
+            cst::TerminalPatternFields::One(field) => vec![(*field).into()],
+            cst::TerminalPatternFields::Cons(left, right) => {
+                let mut fields: Vec<ast::IdentOrUnderscore> = (*left).into();
+                fields.push((*right).into());
+                fields
+            }
+        }
+    }
+}
+
+impl From<cst::PrecedenceDeclaration> for ast::PrecedenceDeclaration {
+    fn from(cst: cst::PrecedenceDeclaration) -> Self {
+        ast::PrecedenceDeclaration {
+            associativity: (*cst.associativity).into(),
+            terminals: (*cst.terminals).into(),
+        }
+    }
+}
+
+impl From<cst::OptAssociativity> for Option<ast::Ident> {
+    fn from(cst: cst::OptAssociativity) -> Self {
+        match cst {
+            cst::OptAssociativity::None => None,
+            cst::OptAssociativity::Some(ident) => Some(ident.into()),
+        }
+    }
+}
+
+impl From<cst::TerminalIdents> for Vec<ast::TerminalIdent> {
+    fn from(cst: cst::TerminalIdents) -> Self {
+        match cst {
+            cst::TerminalIdents::One(terminal) => vec![(*terminal).into()],
+            cst::TerminalIdents::Cons(left, right) => {
+                let mut terminals: Vec<ast::TerminalIdent> = (*left).into();
+                terminals.push((*right).into());
+                terminals
+            }
+        }
+    }
+}
+
+impl From<cst::Lexer> for ast::Lexer {
+    fn from(cst: cst::Lexer) -> Self {
+        ast::Lexer {
+            keyword: cst.keyword,
+            rules: (*cst.rules).into(),
+        }
+    }
+}
+
+impl From<cst::Allow> for ast::Allow {
+    fn from(cst: cst::Allow) -> Self {
+        ast::Allow {
+            keyword: cst.keyword,
+            lints: (*cst.lints).into(),
+        }
+    }
+}
+
+impl From<cst::LintNames> for Vec<ast::Ident> {
+    fn from(cst: cst::LintNames) -> Self {
+        match cst {
+            cst::LintNames::One(ident) => vec![ident.into()],
+            cst::LintNames::Cons(left, right) => {
+                let mut names: Vec<ast::Ident> = (*left).into();
+                names.push(right.into());
+                names
+            }
+        }
+    }
+}
+
+impl From<cst::OptLexerRules> for Vec<ast::LexerRule> {
+    fn from(cst: cst::OptLexerRules) -> Self {
+        match cst {
+            cst::OptLexerRules::Nil => vec![],
+            cst::OptLexerRules::Cons(left, right) => {
+                let mut rules: Vec<ast::LexerRule> = (*left).into();
+                rules.push((*right).into());
+                rules
+            }
+        }
+    }
+}
+
+impl From<cst::LexerRule> for ast::LexerRule {
+    fn from(cst: cst::LexerRule) -> Self {
+        ast::LexerRule {
+            name: *cst.name,
+            pattern: *cst.pattern,
         }
     }
 }
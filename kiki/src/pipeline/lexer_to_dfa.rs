@@ -0,0 +1,400 @@
+use crate::data::{
+    dfa::*,
+    regex::{next_char, prev_char, CharClass, Regex},
+    validated_file::Lexer,
+};
+
+use std::collections::{BTreeSet, HashMap};
+
+/// Compiles the lexer's rules into a single DFA.
+/// We first build a Thompson NFA,
+/// and then convert it into a DFA using the subset construction.
+///
+/// The DFA does not implement longest-match by itself.
+/// Rather, the generated lexer runs the DFA as far as it can,
+/// and then backs up to the last accepting state it passed through.
+pub fn lexer_to_dfa(lexer: &Lexer) -> Dfa {
+    let nfa = Nfa::new(lexer);
+    let states = SubsetConstruction::new(&nfa).get_states();
+    Dfa {
+        states,
+        rule_terminals: lexer
+            .rules
+            .iter()
+            .map(|rule| rule.terminal.clone())
+            .collect(),
+    }
+}
+
+#[derive(Debug)]
+struct Nfa {
+    /// The start state is always state `0`.
+    states: Vec<NfaState>,
+}
+
+#[derive(Debug, Default)]
+struct NfaState {
+    epsilon_targets: Vec<usize>,
+    transitions: Vec<(CharClass, usize)>,
+    accepted_rule: Option<usize>,
+}
+
+impl Nfa {
+    fn new(lexer: &Lexer) -> Self {
+        let mut nfa = Nfa {
+            states: vec![NfaState::default()],
+        };
+        for (rule_index, rule) in lexer.rules.iter().enumerate() {
+            let rule_start = nfa.add_state();
+            nfa.add_epsilon(0, rule_start);
+            let rule_end = nfa.add_regex(&rule.regex, rule_start);
+            nfa.states[rule_end].accepted_rule = Some(rule_index);
+        }
+        nfa
+    }
+
+    fn add_state(&mut self) -> usize {
+        self.states.push(NfaState::default());
+        self.states.len() - 1
+    }
+
+    fn add_epsilon(&mut self, from: usize, to: usize) {
+        self.states[from].epsilon_targets.push(to);
+    }
+
+    /// Adds states that match `regex`, starting from `from`.
+    /// Returns the state reached after matching `regex`.
+    ///
+    /// Apart from `from` itself, every state this function
+    /// connects is a fresh state.
+    /// Furthermore, the returned state is always
+    /// either `from` or a fresh state with no outgoing edges.
+    /// This ensures that the caller can safely
+    /// add outgoing edges to the returned state.
+    fn add_regex(&mut self, regex: &Regex, from: usize) -> usize {
+        match regex {
+            Regex::Empty => from,
+
+            Regex::Class(class) => {
+                let to = self.add_state();
+                self.states[from].transitions.push((class.clone(), to));
+                to
+            }
+
+            Regex::Concat(regexes) => regexes
+                .iter()
+                .fold(from, |current, regex| self.add_regex(regex, current)),
+
+            Regex::Alternation(regexes) => {
+                let end = self.add_state();
+                for regex in regexes {
+                    let alternative_start = self.add_state();
+                    self.add_epsilon(from, alternative_start);
+                    let alternative_end = self.add_regex(regex, alternative_start);
+                    self.add_epsilon(alternative_end, end);
+                }
+                end
+            }
+
+            Regex::Repeat { regex, min, max } => {
+                let mut current = from;
+                for _ in 0..*min {
+                    current = self.add_regex(regex, current);
+                }
+
+                let Some(max) = max else {
+                    return self.add_kleene_star(regex, current);
+                };
+
+                for _ in *min..*max {
+                    let optional_start = self.add_state();
+                    self.add_epsilon(current, optional_start);
+                    let optional_end = self.add_regex(regex, optional_start);
+                    let end = self.add_state();
+                    self.add_epsilon(current, end);
+                    self.add_epsilon(optional_end, end);
+                    current = end;
+                }
+                current
+            }
+        }
+    }
+
+    fn add_kleene_star(&mut self, regex: &Regex, from: usize) -> usize {
+        let loop_start = self.add_state();
+        self.add_epsilon(from, loop_start);
+        let body_start = self.add_state();
+        self.add_epsilon(loop_start, body_start);
+        let body_end = self.add_regex(regex, body_start);
+        self.add_epsilon(body_end, loop_start);
+
+        let end = self.add_state();
+        self.add_epsilon(loop_start, end);
+        end
+    }
+
+    fn get_epsilon_closure(&self, states: impl IntoIterator<Item = usize>) -> BTreeSet<usize> {
+        let mut closure = BTreeSet::new();
+        let mut stack: Vec<usize> = states.into_iter().collect();
+        while let Some(state) = stack.pop() {
+            if closure.insert(state) {
+                stack.extend(self.states[state].epsilon_targets.iter().copied());
+            }
+        }
+        closure
+    }
+}
+
+struct SubsetConstruction<'a> {
+    nfa: &'a Nfa,
+    /// The `i`th set is the set of NFA states
+    /// that corresponds to the `i`th DFA state.
+    sets: Vec<BTreeSet<usize>>,
+    set_indices: HashMap<BTreeSet<usize>, usize>,
+}
+
+impl SubsetConstruction<'_> {
+    fn new(nfa: &Nfa) -> SubsetConstruction<'_> {
+        let start = nfa.get_epsilon_closure([0]);
+        SubsetConstruction {
+            nfa,
+            sets: vec![start.clone()],
+            set_indices: [(start, 0)].into_iter().collect(),
+        }
+    }
+
+    fn get_states(mut self) -> Vec<DfaState> {
+        let mut states = vec![];
+        // `self.sets` grows as we discover new DFA states,
+        // so we cannot use an iterator.
+        while states.len() < self.sets.len() {
+            let set = self.sets[states.len()].clone();
+            states.push(DfaState {
+                transitions: self.get_transitions(&set),
+                accepted_rule: set
+                    .iter()
+                    .filter_map(|&state| self.nfa.states[state].accepted_rule)
+                    .min(),
+            });
+        }
+        states
+    }
+
+    /// We split the characters into intervals,
+    /// such that every character in an interval
+    /// leads to the same set of NFA states.
+    fn get_transitions(&mut self, set: &BTreeSet<usize>) -> Vec<DfaTransition> {
+        let nfa_transitions: Vec<&(CharClass, usize)> = set
+            .iter()
+            .flat_map(|&state| &self.nfa.states[state].transitions)
+            .collect();
+
+        let interval_starts = get_interval_starts(nfa_transitions.iter().map(|(class, _)| class));
+
+        let mut transitions: Vec<DfaTransition> = vec![];
+        for (i, &start) in interval_starts.iter().enumerate() {
+            let end = match interval_starts.get(i + 1) {
+                Some(&next_start) => prev_char(next_start).unwrap(),
+                None => char::MAX,
+            };
+
+            let targets = self.nfa.get_epsilon_closure(
+                nfa_transitions
+                    .iter()
+                    .filter(|(class, _)| class.contains(start))
+                    .map(|(_, target)| *target),
+            );
+            if targets.is_empty() {
+                continue;
+            }
+            let target = self.get_or_add_set_index(targets);
+
+            if let Some(last) = transitions.last_mut() {
+                if last.target == target && next_char(last.end) == Some(start) {
+                    last.end = end;
+                    continue;
+                }
+            }
+            transitions.push(DfaTransition { start, end, target });
+        }
+        transitions
+    }
+
+    fn get_or_add_set_index(&mut self, set: BTreeSet<usize>) -> usize {
+        if let Some(&index) = self.set_indices.get(&set) {
+            return index;
+        }
+        let index = self.sets.len();
+        self.sets.push(set.clone());
+        self.set_indices.insert(set, index);
+        index
+    }
+}
+
+/// Returns the sorted starts of the intervals,
+/// such that every class either contains
+/// all of an interval or none of it.
+/// The first interval always starts at `char::MIN`,
+/// and the last interval always ends at `char::MAX`.
+fn get_interval_starts<'a>(classes: impl Iterator<Item = &'a CharClass>) -> Vec<char> {
+    let mut starts = BTreeSet::from([char::MIN]);
+    for class in classes {
+        for &(start, end) in class.ranges() {
+            starts.insert(start);
+            if let Some(after_end) = next_char(end) {
+                starts.insert(after_end);
+            }
+        }
+    }
+    starts.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{ast, ByteIndex, KikiErr},
+        pipeline::prelude::*,
+    };
+
+    /// Returns the index of the rule that matches the longest prefix of `src`,
+    /// along with the length of that prefix.
+    fn longest_match(dfa: &Dfa, src: &str) -> Option<(usize, usize)> {
+        let mut state = 0;
+        let mut last_accept = None;
+        for (i, c) in src.char_indices() {
+            let Some(next) = dfa.transition(state, c) else {
+                break;
+            };
+            state = next;
+            if let Some(rule_index) = dfa.states[state].accepted_rule {
+                last_accept = Some((rule_index, i + c.len_utf8()));
+            }
+        }
+        last_accept
+    }
+
+    fn get_grammar_src(patterns: &[&str]) -> String {
+        let rules: Vec<String> = patterns
+            .iter()
+            .map(|pattern| format!("_ = {pattern}"))
+            .collect();
+        let rules = rules.join("\n");
+        format!("start Foo struct Foo {{ _: $A }} terminal Token {{ $A: () }} lexer {{ {rules} }}")
+    }
+
+    fn get_lexer(src: &str) -> Result<Lexer, KikiErr> {
+        let cst = parse(tokenize(src)?).unwrap();
+        let ast: ast::File = cst.into();
+        Ok(validate_ast(ast)?.lexer.unwrap())
+    }
+
+    /// Each pattern is a string literal or regex literal.
+    fn get_dfa(patterns: &[&str]) -> Dfa {
+        lexer_to_dfa(&get_lexer(&get_grammar_src(patterns)).unwrap())
+    }
+
+    #[test]
+    fn longest_match_wins() {
+        let dfa = get_dfa(&[r#""=""#, r#""==""#]);
+        assert_eq!(Some((1, 2)), longest_match(&dfa, "==="));
+        assert_eq!(Some((0, 1)), longest_match(&dfa, "=x"));
+    }
+
+    #[test]
+    fn earlier_rule_wins_ties() {
+        let dfa = get_dfa(&[r#""if""#, r#"r"[a-z]+""#]);
+        assert_eq!(Some((0, 2)), longest_match(&dfa, "if "));
+        assert_eq!(Some((1, 3)), longest_match(&dfa, "iff"));
+
+        let dfa = get_dfa(&[r#"r"[a-z]+""#, r#""if""#]);
+        assert_eq!(Some((0, 2)), longest_match(&dfa, "if"));
+    }
+
+    #[test]
+    fn no_match() {
+        let dfa = get_dfa(&[r#"r"[0-9]+""#]);
+        assert_eq!(None, longest_match(&dfa, "x1"));
+        assert_eq!(None, longest_match(&dfa, ""));
+    }
+
+    #[test]
+    fn alternation_and_groups() {
+        let dfa = get_dfa(&[r#"r"(?:ab|c)+d?""#]);
+        assert_eq!(Some((0, 6)), longest_match(&dfa, "abcabd"));
+        assert_eq!(Some((0, 1)), longest_match(&dfa, "ca"));
+        assert_eq!(None, longest_match(&dfa, "d"));
+    }
+
+    #[test]
+    fn counted_repetition() {
+        let dfa = get_dfa(&[r#"r"a{2,3}""#, r#""a""#]);
+        assert_eq!(Some((0, 3)), longest_match(&dfa, "aaaa"));
+        assert_eq!(Some((0, 2)), longest_match(&dfa, "aa"));
+        assert_eq!(Some((1, 1)), longest_match(&dfa, "ab"));
+
+        let dfa = get_dfa(&[r#"r"x{2}y{1,}""#]);
+        assert_eq!(Some((0, 5)), longest_match(&dfa, "xxyyy"));
+        assert_eq!(None, longest_match(&dfa, "xyy"));
+    }
+
+    #[test]
+    fn negated_class_spans_surrogate_gap() {
+        let dfa = get_dfa(&[r##"r#"[^"\\]+"#"##]);
+        let src = "a\u{D7FF}\u{E000}\u{10FFFF}\"";
+        assert_eq!(Some((0, src.len() - 1)), longest_match(&dfa, src));
+    }
+
+    #[test]
+    fn dot_does_not_match_newline() {
+        let dfa = get_dfa(&[r#"r"//.*""#]);
+        assert_eq!(Some((0, 5)), longest_match(&dfa, "// hi\nx"));
+    }
+
+    #[test]
+    fn escapes_and_perl_classes() {
+        let dfa = get_dfa(&[r#"r"\d+\.\d+""#, r#"r"\w+""#, r#"r"\s+""#]);
+        assert_eq!(Some((0, 4)), longest_match(&dfa, "3.14."));
+        assert_eq!(Some((1, 5)), longest_match(&dfa, "foo_1 "));
+        assert_eq!(Some((2, 3)), longest_match(&dfa, "\t\r\nx"));
+    }
+
+    #[test]
+    fn transitions_are_sorted_and_disjoint() {
+        let dfa = get_dfa(&[r#"r"[a-z]+""#, r#"r"[^b]""#, r#"r"[0-9a]""#]);
+        for state in &dfa.states {
+            for pair in state.transitions.windows(2) {
+                assert!(pair[0].end < pair[1].start);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_regexes_are_rejected() {
+        for (pattern, expected_offset) in [
+            ("a)", 1),
+            ("(a", 0),
+            ("[a", 0),
+            ("*a", 0),
+            ("a{3,2}", 1),
+            ("a{1001}", 2),
+            ("[z-a]", 1),
+            (r"[\d-z]", 1),
+            (r"\q", 0),
+            ("^a", 0),
+        ] {
+            let regex_literal = format!("r\"{pattern}\"");
+            let src = get_grammar_src(&[&regex_literal]);
+            let pattern_position = src.find(&regex_literal).unwrap() + "r\"".len();
+            let err = get_lexer(&src).unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    KikiErr::InvalidRegex(ByteIndex(position), _)
+                        if position == pattern_position + expected_offset
+                ),
+                "{pattern}: {err:?}"
+            );
+        }
+    }
+}
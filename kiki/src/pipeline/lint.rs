@@ -0,0 +1,387 @@
+use crate::data::{
+    validated_file::{self as validated, IdentOrTerminalIdent, IdentOrUnderscore, Rule},
+    ByteIndex, DollarlessTerminalName, KikiWarning,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Returns the warnings for a validated grammar,
+/// sorted by position.
+/// Warnings of lints allowed by `allow(...)` declarations are omitted.
+///
+/// See `KikiWarning` for the lints.
+pub fn lint(file: &validated::File) -> Vec<KikiWarning> {
+    let mut warnings = vec![];
+    warnings.extend(get_unused_terminal_warnings(file));
+    warnings.extend(get_unreachable_nonterminal_warnings(file));
+    warnings.extend(get_skipped_terminal_value_warnings(file));
+    warnings.extend(get_right_recursive_list_warnings(file));
+    warnings.retain(|warning| !file.allowed_lints.contains(warning.lint_name()));
+
+    // A generic nonterminal's fields are validated once per instantiation,
+    // so the same warning may be reported more than once.
+    warnings.sort_by_key(|warning| (warning.position(), warning.lint_name()));
+    warnings.dedup();
+    warnings
+}
+
+fn get_unused_terminal_warnings(file: &validated::File) -> Vec<KikiWarning> {
+    let mut used: HashSet<&DollarlessTerminalName> = HashSet::new();
+    for rule in file.get_rules() {
+        used.extend(get_rule_symbols(&rule).filter_map(|symbol| match symbol {
+            IdentOrTerminalIdent::Terminal(terminal) => Some(&terminal.name),
+            IdentOrTerminalIdent::Ident(_) => None,
+        }));
+        used.extend(rule.precedence_override.map(|terminal| &terminal.name));
+    }
+
+    file.terminal_enum
+        .variants
+        .iter()
+        .filter(|variant| !used.contains(&variant.dollarless_name))
+        .map(|variant| {
+            KikiWarning::UnusedTerminal(variant.dollarless_name.clone(), variant.position)
+        })
+        .collect()
+}
+
+fn get_unreachable_nonterminal_warnings(file: &validated::File) -> Vec<KikiWarning> {
+    let mut successors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for rule in file.get_rules() {
+        let nonterminals = get_rule_symbols(&rule).filter_map(|symbol| match symbol {
+            IdentOrTerminalIdent::Ident(ident) => Some(ident.name.as_str()),
+            IdentOrTerminalIdent::Terminal(_) => None,
+        });
+        successors
+            .entry(rule.constructor_name.type_name())
+            .or_default()
+            .extend(nonterminals);
+    }
+
+    let mut reachable: HashSet<&str> = HashSet::new();
+    let mut stack: Vec<&str> = file.starts.iter().map(String::as_str).collect();
+    while let Some(name) = stack.pop() {
+        if !reachable.insert(name) {
+            continue;
+        }
+        stack.extend(successors.get(name).into_iter().flatten());
+    }
+
+    file.nonterminals
+        .iter()
+        .filter_map(|nonterminal| match nonterminal {
+            validated::Nonterminal::Struct(s) => Some(&s.name),
+            validated::Nonterminal::Enum(e) => Some(&e.name),
+            // A helper is only used by the field symbol it implements,
+            // so if it is unreachable, so is that field's nonterminal.
+            validated::Nonterminal::Helper(_) => None,
+        })
+        .filter(|name| !reachable.contains(name.name.as_str()))
+        .map(|name| KikiWarning::UnreachableNonterminal(name.name.clone(), name.position))
+        .collect()
+}
+
+fn get_skipped_terminal_value_warnings(file: &validated::File) -> Vec<KikiWarning> {
+    let skipped_symbols = file.nonterminals.iter().flat_map(|nonterminal| {
+        let fieldsets: Vec<&validated::Fieldset> = match nonterminal {
+            validated::Nonterminal::Struct(s) => vec![&s.fieldset],
+            validated::Nonterminal::Enum(e) => e.variants.iter().map(|v| &v.fieldset).collect(),
+            // Helpers skip separators by design.
+            validated::Nonterminal::Helper(_) => vec![],
+        };
+        fieldsets.into_iter().flat_map(get_skipped_symbols)
+    });
+
+    skipped_symbols
+        .filter_map(|symbol| get_skipped_terminal(file, symbol))
+        .filter(|(terminal, _)| {
+            file.terminal_enum
+                .get_type(terminal)
+                .is_some_and(|type_| type_ != "()")
+        })
+        .map(|(terminal, position)| KikiWarning::SkippedTerminalValue(terminal.clone(), position))
+        .collect()
+}
+
+/// Returns the terminal whose values a skipped field discards,
+/// and the position to report.
+///
+/// A sugared symbol (e.g., `$Num*` or `$Num?`) refers to a helper,
+/// so we report the helper's element,
+/// at the position of the sugar's operator (or `sep`).
+fn get_skipped_terminal<'a>(
+    file: &'a validated::File,
+    symbol: &'a IdentOrTerminalIdent,
+) -> Option<(&'a DollarlessTerminalName, ByteIndex)> {
+    match symbol {
+        IdentOrTerminalIdent::Terminal(terminal) => {
+            Some((&terminal.name, terminal.dollarless_position))
+        }
+        IdentOrTerminalIdent::Ident(ident) => match &file.get_helper(&ident.name)?.element {
+            IdentOrTerminalIdent::Terminal(terminal) => Some((&terminal.name, ident.position)),
+            IdentOrTerminalIdent::Ident(_) => None,
+        },
+    }
+}
+
+fn get_skipped_symbols(fieldset: &validated::Fieldset) -> Vec<&IdentOrTerminalIdent> {
+    match fieldset {
+        validated::Fieldset::Empty => vec![],
+        validated::Fieldset::Named(named) => named
+            .fields
+            .iter()
+            .filter(|field| matches!(field.name, IdentOrUnderscore::Underscore(_)))
+            .map(|field| &field.symbol)
+            .collect(),
+        validated::Fieldset::Tuple(tuple) => tuple
+            .fields
+            .iter()
+            .filter(|field| !field.is_used())
+            .map(validated::TupleField::symbol)
+            .collect(),
+    }
+}
+
+/// A nonterminal is considered a right-recursive list if:
+/// 1. At least one of its rules refers to it, and
+///    at least one does not.
+/// 2. Every rule that refers to it does so exactly once,
+///    as the last of two or more symbols.
+///
+/// The second condition excludes nonterminals like
+/// `enum Expr { Num($Num) Pow(Expr _: $Caret Expr) Neg(_: $Minus Expr) Paren(...) }`,
+/// which are right-recursive, but not lists.
+fn get_right_recursive_list_warnings(file: &validated::File) -> Vec<KikiWarning> {
+    let mut rules_by_nonterminal: HashMap<&str, Vec<Rule>> = HashMap::new();
+    for rule in file.get_rules() {
+        if matches!(
+            rule.constructor_name,
+            validated::ConstructorName::Helper { .. }
+        ) {
+            continue;
+        }
+        rules_by_nonterminal
+            .entry(rule.constructor_name.type_name())
+            .or_default()
+            .push(rule);
+    }
+
+    file.nonterminals
+        .iter()
+        .filter_map(|nonterminal| {
+            let name = nonterminal.name();
+            let rules = rules_by_nonterminal.get(name)?;
+            let recursive_rules: Vec<&Rule> = rules
+                .iter()
+                .filter(|rule| get_rule_symbols(rule).any(|symbol| is_nonterminal(symbol, name)))
+                .collect();
+
+            let is_list = !recursive_rules.is_empty()
+                && recursive_rules.len() < rules.len()
+                && recursive_rules.iter().all(|rule| {
+                    let symbols: Vec<_> = get_rule_symbols(rule).collect();
+                    let (last, init) = symbols.split_last().unwrap();
+                    !init.is_empty()
+                        && is_nonterminal(last, name)
+                        && !init.iter().any(|symbol| is_nonterminal(symbol, name))
+                });
+            if !is_list {
+                return None;
+            }
+
+            Some(KikiWarning::RightRecursiveList(
+                name.to_owned(),
+                recursive_rules[0].position,
+            ))
+        })
+        .collect()
+}
+
+fn get_rule_symbols<'a>(rule: &Rule<'a>) -> impl Iterator<Item = &'a IdentOrTerminalIdent> {
+    let fieldset = rule.fieldset;
+    (0..fieldset.len()).map(move |i| fieldset.get_symbol_ident(i))
+}
+
+fn is_nonterminal(symbol: &IdentOrTerminalIdent, name: &str) -> bool {
+    matches!(symbol, IdentOrTerminalIdent::Ident(ident) if ident.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data::ast, pipeline::prelude::*};
+
+    fn get_warnings(src: &str) -> Vec<KikiWarning> {
+        let cst = parse(tokenize(src).unwrap()).unwrap();
+        let ast: ast::File = cst.into();
+        lint(&validate_ast(ast).unwrap())
+    }
+
+    fn position_of(src: &str, needle: &str) -> ByteIndex {
+        ByteIndex(src.find(needle).unwrap())
+    }
+
+    #[test]
+    fn clean_grammar_has_no_warnings() {
+        let src = r#"
+start File
+struct File { items: Item* }
+enum Item {
+    Num($Num)
+    Neg(_: "-" Item)
+    Paren(_: "(" Item _: ")")
+}
+terminal Token { $Num: i32 }
+"#;
+        assert_eq!(Vec::<KikiWarning>::new(), get_warnings(src));
+    }
+
+    #[test]
+    fn unused_terminal() {
+        let src = "start Foo struct Foo { _: $A } terminal Token { $A: () $Unused: () }";
+        let expected = vec![KikiWarning::UnusedTerminal(
+            DollarlessTerminalName::remove_dollars("Unused"),
+            position_of(src, "Unused"),
+        )];
+        assert_eq!(expected, get_warnings(src));
+    }
+
+    #[test]
+    fn allowed_lint_is_omitted() {
+        let src = r#"
+allow(unused_terminal, skipped_terminal_value)
+start Foo
+struct Foo(_: $Num _: $A)
+struct Bar { _: $A }
+terminal Token { $Num: i32 $A: () $Unused: () }
+"#;
+        let expected = vec![KikiWarning::UnreachableNonterminal(
+            "Bar".to_owned(),
+            position_of(src, "Bar"),
+        )];
+        assert_eq!(expected, get_warnings(src));
+    }
+
+    #[test]
+    fn terminal_used_only_in_prec_override_is_used() {
+        let src = r#"
+start Expr
+precedence left $Minus
+precedence $UnaryMinus
+enum Expr {
+    Num($Num)
+    Sub(Expr _: $Minus Expr)
+    Neg(_: $Minus Expr) prec $UnaryMinus
+}
+terminal Token { $Num: i32 $Minus: () $UnaryMinus: () }
+"#;
+        assert_eq!(Vec::<KikiWarning>::new(), get_warnings(src));
+    }
+
+    #[test]
+    fn unreachable_nonterminal() {
+        let src = r#"
+start Foo
+struct Foo { _: $A }
+struct Bar { baz: Baz }
+struct Baz { _: $A }
+terminal Token { $A: () }
+"#;
+        let expected = vec![
+            KikiWarning::UnreachableNonterminal("Bar".to_owned(), position_of(src, "Bar")),
+            KikiWarning::UnreachableNonterminal("Baz".to_owned(), position_of(src, "Baz {")),
+        ];
+        assert_eq!(expected, get_warnings(src));
+    }
+
+    #[test]
+    fn nonterminal_reachable_through_sugar_is_reachable() {
+        let src = r#"
+start Foo
+struct Foo { bars: Bar* baz: Baz? }
+struct Bar { _: $A }
+struct Baz { _: $A }
+terminal Token { $A: () }
+"#;
+        assert_eq!(Vec::<KikiWarning>::new(), get_warnings(src));
+    }
+
+    #[test]
+    fn skipped_terminal_value() {
+        let src = "start Foo struct Foo(_: $Num _: $A) terminal Token { $Num: i32 $A: () }";
+        let expected = vec![KikiWarning::SkippedTerminalValue(
+            DollarlessTerminalName::remove_dollars("Num"),
+            position_of(src, "Num"),
+        )];
+        assert_eq!(expected, get_warnings(src));
+    }
+
+    #[test]
+    fn skipped_repeated_terminal_value() {
+        let src = "start Foo struct Foo(_: $Num* _: $A) terminal Token { $Num: i32 $A: () }";
+        let expected = vec![KikiWarning::SkippedTerminalValue(
+            DollarlessTerminalName::remove_dollars("Num"),
+            position_of(src, "*"),
+        )];
+        assert_eq!(expected, get_warnings(src));
+    }
+
+    #[test]
+    fn skipped_optional_terminal_value() {
+        let src = "start Foo struct Foo(_: $Num? _: $A) terminal Token { $Num: i32 $A: () }";
+        let expected = vec![KikiWarning::SkippedTerminalValue(
+            DollarlessTerminalName::remove_dollars("Num"),
+            position_of(src, "?"),
+        )];
+        assert_eq!(expected, get_warnings(src));
+    }
+
+    #[test]
+    fn skipped_repeated_unit_terminal_has_no_warning() {
+        let src = "start Foo struct Foo(_: $A* $Num) terminal Token { $Num: i32 $A: () }";
+        assert_eq!(Vec::<KikiWarning>::new(), get_warnings(src));
+    }
+
+    #[test]
+    fn right_recursive_list() {
+        let src = r#"
+start List
+enum List {
+    Nil
+    Cons($Num List)
+}
+terminal Token { $Num: i32 }
+"#;
+        let expected = vec![KikiWarning::RightRecursiveList(
+            "List".to_owned(),
+            position_of(src, "Cons"),
+        )];
+        assert_eq!(expected, get_warnings(src));
+    }
+
+    #[test]
+    fn left_recursive_list_is_not_right_recursive() {
+        let src = r#"
+start List
+enum List {
+    Nil
+    Cons(List $Num)
+}
+terminal Token { $Num: i32 }
+"#;
+        assert_eq!(Vec::<KikiWarning>::new(), get_warnings(src));
+    }
+
+    #[test]
+    fn right_associative_operator_is_not_a_list() {
+        let src = r#"
+start Expr
+precedence right $Caret
+enum Expr {
+    Num($Num)
+    Pow(Expr _: $Caret Expr)
+}
+terminal Token { $Num: i32 $Caret: () }
+"#;
+        assert_eq!(Vec::<KikiWarning>::new(), get_warnings(src));
+    }
+}
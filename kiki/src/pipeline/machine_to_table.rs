@@ -1,22 +1,42 @@
 use crate::data::{machine::*, table::*, validated_file::*, KikiErr, *};
-
-use std::collections::HashMap;
-
-pub fn machine_to_table(machine: &Machine, file: &File) -> Result<Table, KikiErr> {
-    ImmutContext::new(machine, file).get_table()
+use crate::pipeline::counterexample::find_counterexample;
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+/// Converts the machine to a table.
+///
+/// If the machine was built with LALR(1),
+/// `lalr_merges` should be the record of the states it merged,
+/// so that conflicts caused by merging can be explained.
+pub fn machine_to_table(
+    machine: &Machine,
+    lalr_merges: Option<&LalrMergeRecord>,
+    file: &File,
+    default_reductions: DefaultReductions,
+) -> Result<Table, KikiErr> {
+    ImmutContext::new(machine, lalr_merges, file).get_table(default_reductions)
 }
 
 #[derive(Debug)]
 struct ImmutContext<'a> {
     machine: &'a Machine,
+    lalr_merges: Option<&'a LalrMergeRecord>,
     file: &'a File,
     rules: Vec<Rule<'a>>,
 }
 
 impl ImmutContext<'_> {
-    fn new<'a>(machine: &'a Machine, file: &'a File) -> ImmutContext<'a> {
+    fn new<'a>(
+        machine: &'a Machine,
+        lalr_merges: Option<&'a LalrMergeRecord>,
+        file: &'a File,
+    ) -> ImmutContext<'a> {
         ImmutContext {
             machine,
+            lalr_merges,
             file,
             rules: file.get_rules().collect(),
         }
@@ -24,22 +44,47 @@ impl ImmutContext<'_> {
 }
 
 impl ImmutContext<'_> {
-    fn get_table(&self) -> Result<Table, KikiErr> {
+    fn get_table(&self, default_reductions: DefaultReductions) -> Result<Table, KikiErr> {
         let mut builder = TableBuilder::new(self);
         self.add_actions_to_table(&mut builder)?;
         self.add_gotos_to_table(&mut builder);
-        Ok(self.build_as_is(builder))
+        match default_reductions {
+            DefaultReductions::Off => self.build_as_is(builder),
+            DefaultReductions::SingleReductionStates => self.build_with_default_reductions(builder),
+        }
     }
 }
 
 #[derive(Debug)]
 struct TableBuilder<'a> {
-    actions: HashMap<(StateIndex, Quasiterminal<'a>), (&'a StateItem, Action)>,
+    actions: HashMap<(StateIndex, Quasiterminal<'a>), ActionCandidates<'a>>,
     gotos: HashMap<(StateIndex, &'a str), Goto>,
 
     context: &'a ImmutContext<'a>,
 }
 
+/// The actions proposed for a single table cell.
+///
+/// Reduce/reduce conflicts are reported as soon as they are found.
+/// Shift/reduce conflicts, on the other hand, are only resolved
+/// (using the precedence declarations) once every action
+/// has been proposed.
+#[derive(Debug, Default)]
+struct ActionCandidates<'a> {
+    shift: Option<(&'a StateItem, StateIndex)>,
+    reduce: Option<(&'a StateItem, usize)>,
+    accept: Option<&'a StateItem>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShiftReduceResolution {
+    Shift,
+    Reduce,
+    /// The conflicting operators are `nonassoc`,
+    /// so the input is a syntax error.
+    Err,
+}
+
 impl TableBuilder<'_> {
     fn new<'a>(context: &'a ImmutContext<'a>) -> TableBuilder<'a> {
         TableBuilder {
@@ -78,7 +123,7 @@ impl<'a> ImmutContext<'a> {
         item: &'a StateItem,
     ) -> Result<(), KikiErr> {
         match item.rule_index {
-            RuleIndex::Augmented => {
+            RuleIndex::Augmented(_) => {
                 self.add_augmented_item_action_to_table(builder, state_index, item)
             }
             RuleIndex::Original(rule_index) => {
@@ -160,35 +205,364 @@ impl<'a> TableBuilder<'a> {
         item: &'a StateItem,
         action: Action,
     ) -> Result<(), KikiErr> {
-        if let Some((existing_item, existing_action)) =
-            self.actions.get(&(state_index, quasiterminal))
-        {
-            // It is possible that we have two (or more)
-            // items that are identical except for
-            // their lookahead.
-            // If those items produce a Shift action,
-            // this will cause that action to get
-            // added twice (or more times).
-            // In that case, we simply ignore the second
-            // (or later) action(s).
-            //
-            // Since the actions are the same,
-            // there is no conflict.
-            if *existing_action == action {
-                return Ok(());
+        let candidates = self
+            .actions
+            .entry((state_index, quasiterminal))
+            .or_default();
+
+        match action {
+            Action::Shift(dest) => {
+                // It is possible that we have two (or more)
+                // items that are identical except for
+                // their lookahead.
+                // If those items produce a Shift action,
+                // this will cause that action to get
+                // added twice (or more times).
+                // In that case, we simply ignore the second
+                // (or later) action(s).
+                //
+                // Since the actions are the same,
+                // there is no conflict.
+                if candidates.shift.is_none() {
+                    candidates.shift = Some((item, dest));
+                }
+                Ok(())
+            }
+
+            Action::Reduce(rule_index) => {
+                if let Some((existing_item, existing_rule_index)) = candidates.reduce {
+                    if existing_rule_index == rule_index {
+                        return Ok(());
+                    }
+                    return Err(self.context.conflict_err(
+                        ConflictKind::ReduceReduce,
+                        state_index,
+                        quasiterminal,
+                        existing_item,
+                        item,
+                    ));
+                }
+
+                if let Some(existing_item) = candidates.accept {
+                    return Err(self.context.conflict_err(
+                        ConflictKind::ReduceReduce,
+                        state_index,
+                        quasiterminal,
+                        existing_item,
+                        item,
+                    ));
+                }
+
+                candidates.reduce = Some((item, rule_index));
+                Ok(())
             }
 
-            return Err(KikiErr::TableConflict(Box::new(TableConflictErr {
+            Action::Accept => {
+                if let Some((existing_item, _)) = candidates.reduce {
+                    return Err(self.context.conflict_err(
+                        ConflictKind::ReduceReduce,
+                        state_index,
+                        quasiterminal,
+                        existing_item,
+                        item,
+                    ));
+                }
+
+                candidates.accept = Some(item);
+                Ok(())
+            }
+
+            Action::Err => Ok(()),
+        }
+    }
+
+    fn get_resolved_actions(
+        &self,
+    ) -> Result<Vec<(StateIndex, Quasiterminal<'a>, Action)>, KikiErr> {
+        let mut keys: Vec<&(StateIndex, Quasiterminal<'a>)> = self.actions.keys().collect();
+        // We sort the keys so that if there are multiple
+        // unresolvable conflicts, the reported conflict is deterministic.
+        keys.sort_unstable();
+
+        keys.into_iter()
+            .map(|&(state_index, quasiterminal)| {
+                let candidates = &self.actions[&(state_index, quasiterminal)];
+                let action =
+                    self.context
+                        .resolve_candidates(state_index, quasiterminal, candidates)?;
+                Ok((state_index, quasiterminal, action))
+            })
+            .collect()
+    }
+}
+
+impl<'a> ImmutContext<'a> {
+    fn resolve_candidates(
+        &self,
+        state_index: StateIndex,
+        quasiterminal: Quasiterminal,
+        candidates: &ActionCandidates<'a>,
+    ) -> Result<Action, KikiErr> {
+        match (candidates.shift, candidates.reduce, candidates.accept) {
+            (_, _, Some(_)) => Ok(Action::Accept),
+            (Some((_, dest)), None, None) => Ok(Action::Shift(dest)),
+            (None, Some((_, rule_index)), None) => Ok(Action::Reduce(rule_index)),
+            (Some((shift_item, dest)), Some((reduce_item, rule_index)), None) => {
+                match self.resolve_shift_reduce_conflict(quasiterminal, rule_index) {
+                    Some(ShiftReduceResolution::Shift) => Ok(Action::Shift(dest)),
+                    Some(ShiftReduceResolution::Reduce) => Ok(Action::Reduce(rule_index)),
+                    Some(ShiftReduceResolution::Err) => Ok(Action::Err),
+                    None => Err(self.conflict_err(
+                        ConflictKind::ShiftReduce,
+                        state_index,
+                        quasiterminal,
+                        shift_item,
+                        reduce_item,
+                    )),
+                }
+            }
+            (None, None, None) => Ok(Action::Err),
+        }
+    }
+
+    /// Resolves a shift/reduce conflict the same way yacc does.
+    ///
+    /// If either the lookahead terminal or the rule has no precedence,
+    /// or if both have equal precedence that was declared without
+    /// an associativity, then the conflict cannot be resolved,
+    /// and this returns `None`.
+    fn resolve_shift_reduce_conflict(
+        &self,
+        quasiterminal: Quasiterminal,
+        rule_index: usize,
+    ) -> Option<ShiftReduceResolution> {
+        let Quasiterminal::Terminal(terminal) = quasiterminal else {
+            return None;
+        };
+        let (terminal_level, associativity) = self.file.get_terminal_precedence(terminal)?;
+        let (rule_level, _) = self.get_rule_precedence(rule_index)?;
+
+        if rule_level > terminal_level {
+            return Some(ShiftReduceResolution::Reduce);
+        }
+
+        if rule_level < terminal_level {
+            return Some(ShiftReduceResolution::Shift);
+        }
+
+        match associativity {
+            Associativity::Left => Some(ShiftReduceResolution::Reduce),
+            Associativity::Right => Some(ShiftReduceResolution::Shift),
+            Associativity::Nonassoc => Some(ShiftReduceResolution::Err),
+            Associativity::None => None,
+        }
+    }
+
+    /// A rule's precedence is the precedence of its `prec` override terminal,
+    /// if it has one.
+    /// Otherwise, it is the precedence of the rightmost terminal in the rule
+    /// that has a declared precedence.
+    fn get_rule_precedence(&self, rule_index: usize) -> Option<(usize, Associativity)> {
+        let rule = &self.rules[rule_index];
+
+        if let Some(override_) = rule.precedence_override {
+            return self.file.get_terminal_precedence(&override_.name);
+        }
+
+        (0..rule.fieldset.len())
+            .rev()
+            .find_map(|i| match rule.fieldset.get_symbol_ident(i) {
+                IdentOrTerminalIdent::Terminal(terminal) => {
+                    self.file.get_terminal_precedence(&terminal.name)
+                }
+                IdentOrTerminalIdent::Ident(_) => None,
+            })
+    }
+
+    fn conflict_err(
+        &self,
+        kind: ConflictKind,
+        state_index: StateIndex,
+        quasiterminal: Quasiterminal,
+        existing_item: &StateItem,
+        item: &StateItem,
+    ) -> KikiErr {
+        let lookahead = match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Lookahead::Terminal(terminal.clone()),
+            Quasiterminal::Eof => Lookahead::Eof,
+        };
+        let counterexample = find_counterexample(
+            self.machine,
+            self.file,
+            state_index,
+            (existing_item, item),
+            &lookahead,
+        );
+        let lalr_merge = match kind {
+            ConflictKind::ReduceReduce => self.get_lalr_merge(state_index, (existing_item, item)),
+            // Merging never causes a shift/reduce conflict,
+            // since every LR(1) state with the reduce item
+            // also has the shift item.
+            ConflictKind::ShiftReduce => None,
+        };
+        KikiErr::TableConflict(Box::new(TableConflictErr {
+            kind,
+            state_index,
+            items: (existing_item.clone(), item.clone()),
+            lookahead,
+            prefix: self.get_shortest_prefix(
+                &self.machine.starts,
+                &self.machine.transitions,
                 state_index,
-                items: ((*existing_item).clone(), item.clone()),
-                file: self.context.file.clone(),
-                machine: self.context.machine.clone(),
-            })));
+            ),
+            counterexample,
+            lalr_merge,
+            file: self.file.clone(),
+            machine: self.machine.clone(),
+        }))
+    }
+
+    /// Returns `Some` if the machine was built with LALR(1),
+    /// and no LR(1) state that was merged into the conflicting state
+    /// has both conflicting items.
+    /// In that case, the conflict only exists because of the merge.
+    fn get_lalr_merge(
+        &self,
+        state_index: StateIndex,
+        items: (&StateItem, &StateItem),
+    ) -> Option<LalrMerge> {
+        let record = self.lalr_merges?;
+        let merged: Vec<StateIndex> = record
+            .lr1_states
+            .iter()
+            .enumerate()
+            .filter(|(_, lr1_state)| lr1_state.merged_into == state_index)
+            .map(|(i, _)| StateIndex(i))
+            .collect();
+        let has_item =
+            |i: StateIndex, item: &StateItem| record.lr1_states[i.0].reductions.contains(item);
+
+        if merged
+            .iter()
+            .any(|&i| has_item(i, items.0) && has_item(i, items.1))
+        {
+            return None;
         }
 
-        self.actions
-            .insert((state_index, quasiterminal), (item, action));
-        Ok(())
+        let get_prefixes = |item: &StateItem| {
+            merged
+                .iter()
+                .filter(|&&i| has_item(i, item))
+                .map(|&i| self.get_shortest_prefix(&record.lr1_starts, &record.lr1_transitions, i))
+                .collect()
+        };
+        Some(LalrMerge {
+            first_prefixes: get_prefixes(items.0),
+            second_prefixes: get_prefixes(items.1),
+        })
+    }
+
+    /// Returns the shortest string of terminals that takes the parser
+    /// from one of the start states to the given state,
+    /// following the given transitions.
+    ///
+    /// Each transition costs the length of the shortest terminal string
+    /// its symbol derives, so we find the cheapest path
+    /// with Dijkstra's algorithm.
+    fn get_shortest_prefix(
+        &self,
+        starts: &[StateIndex],
+        transitions: &Oset<Transition>,
+        target: StateIndex,
+    ) -> Vec<DollarlessTerminalName> {
+        let yields = self.get_shortest_yields();
+        let mut successors: HashMap<StateIndex, Vec<&Transition>> = HashMap::new();
+        for transition in transitions {
+            successors
+                .entry(transition.from)
+                .or_default()
+                .push(transition);
+        }
+
+        let mut prefixes: HashMap<StateIndex, Vec<&DollarlessTerminalName>> = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        for &start in starts {
+            prefixes.insert(start, vec![]);
+            queue.push(Reverse((0, start)));
+        }
+
+        while let Some(Reverse((len, state_index))) = queue.pop() {
+            if state_index == target {
+                break;
+            }
+            if len > prefixes[&state_index].len() {
+                continue;
+            }
+
+            for transition in successors.get(&state_index).into_iter().flatten() {
+                let mut prefix = prefixes[&state_index].clone();
+                match &transition.symbol {
+                    Symbol::Terminal(terminal) => prefix.push(terminal),
+                    Symbol::Nonterminal(nonterminal) => {
+                        prefix.extend(yields[nonterminal.as_str()].iter().copied())
+                    }
+                }
+
+                let is_shorter = prefixes
+                    .get(&transition.to)
+                    .is_none_or(|existing| prefix.len() < existing.len());
+                if is_shorter {
+                    queue.push(Reverse((prefix.len(), transition.to)));
+                    prefixes.insert(transition.to, prefix);
+                }
+            }
+        }
+
+        // A well-formed machine has no unreachable states,
+        // but if the target is unreachable, we report an empty prefix
+        // rather than crash while reporting the conflict.
+        prefixes
+            .get(&target)
+            .into_iter()
+            .flatten()
+            .map(|&terminal| terminal.clone())
+            .collect()
+    }
+
+    /// Returns the shortest terminal string that each nonterminal derives.
+    /// Validation guarantees that every nonterminal derives one.
+    fn get_shortest_yields(&self) -> HashMap<&str, Vec<&DollarlessTerminalName>> {
+        let mut yields: HashMap<&str, Vec<&DollarlessTerminalName>> = HashMap::new();
+        loop {
+            let mut changed = false;
+            for rule in &self.rules {
+                let Some(candidate) = (0..rule.fieldset.len())
+                    .map(|i| match rule.fieldset.get_symbol_ident(i) {
+                        IdentOrTerminalIdent::Terminal(terminal) => Some(vec![&terminal.name]),
+                        IdentOrTerminalIdent::Ident(ident) => {
+                            yields.get(ident.name.as_str()).cloned()
+                        }
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .map(|parts| parts.concat())
+                else {
+                    continue;
+                };
+
+                let name = rule.constructor_name.type_name();
+                let is_shorter = yields
+                    .get(name)
+                    .is_none_or(|existing| candidate.len() < existing.len());
+                if is_shorter {
+                    yields.insert(name, candidate);
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                return yields;
+            }
+        }
     }
 }
 
@@ -214,10 +588,10 @@ impl<'a> TableBuilder<'a> {
 }
 
 impl ImmutContext<'_> {
-    fn build_as_is(&self, builder: TableBuilder) -> Table {
+    fn build_as_is(&self, builder: TableBuilder) -> Result<Table, KikiErr> {
         let mut table = get_empty_table(self.machine, self.file);
 
-        for ((state, quasiterminal), (_, action)) in builder.actions {
+        for (state, quasiterminal, action) in builder.get_resolved_actions()? {
             table.set_action(state, quasiterminal, action);
         }
 
@@ -225,7 +599,94 @@ impl ImmutContext<'_> {
             table.set_goto(state, nonterminal, goto);
         }
 
-        table
+        Ok(table)
+    }
+}
+
+impl ImmutContext<'_> {
+    /// In each state whose reductions all use the same rule,
+    /// fills the cells that no item proposed an action for
+    /// with that reduction.
+    ///
+    /// We skip states with cells that some item proposed an action for,
+    /// but that are `Err` anyway
+    /// (i.e., `nonassoc` shift/reduce conflicts).
+    /// Those errors are intended,
+    /// and the parser detects them in the state itself,
+    /// so the state's other `Err` cells must stay accurate
+    /// for `ParseError::expected`.
+    /// We also do not fill the `error` column,
+    /// since error recovery looks for states that shift `error`.
+    ///
+    /// Reducing on an unexpected lookahead only delays the error.
+    /// The reduction leaves a valid stack,
+    /// and if any state reachable from that stack could shift the lookahead,
+    /// the item that shifts it would have propagated the lookahead
+    /// to the original state.
+    /// So, the parser still detects the error before the next shift.
+    fn build_with_default_reductions(&self, builder: TableBuilder) -> Result<Table, KikiErr> {
+        let proposed: HashSet<(StateIndex, Quasiterminal)> =
+            builder.actions.keys().copied().collect();
+        let mut table = self.build_as_is(builder)?;
+
+        // This excludes the `error` pseudo-terminal.
+        // Each quasiterminal is paired with its column in `Table::action_row`,
+        // so we don't have to search `Table::terminals` for every cell.
+        let quasiterminals: Vec<(usize, Quasiterminal)> = self
+            .file
+            .terminal_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                let column = table
+                    .terminals
+                    .iter()
+                    .position(|terminal| *terminal == variant.dollarless_name)
+                    .unwrap();
+                (column, Quasiterminal::Terminal(&variant.dollarless_name))
+            })
+            .chain(std::iter::once((table.terminals.len(), Quasiterminal::Eof)))
+            .collect();
+
+        for state_index in (0..table.state_count()).map(StateIndex) {
+            let row = table.action_row_mut(state_index);
+            let has_intended_err = quasiterminals.iter().any(|&(column, quasiterminal)| {
+                proposed.contains(&(state_index, quasiterminal)) && row[column] == Action::Err
+            });
+            if has_intended_err {
+                continue;
+            }
+            let Some(rule_index) = get_single_reduction(row, &quasiterminals) else {
+                continue;
+            };
+            for &(column, quasiterminal) in &quasiterminals {
+                if !proposed.contains(&(state_index, quasiterminal)) {
+                    row[column] = Action::Reduce(rule_index);
+                }
+            }
+        }
+
+        Ok(table)
+    }
+}
+
+/// Returns the rule that the state reduces by,
+/// if the state reduces by exactly one rule.
+fn get_single_reduction(
+    row: &[Action],
+    quasiterminals: &[(usize, Quasiterminal)],
+) -> Option<usize> {
+    let mut rule_indices = quasiterminals
+        .iter()
+        .filter_map(|&(column, _)| match row[column] {
+            Action::Reduce(rule_index) => Some(rule_index),
+            _ => None,
+        });
+    let first = rule_indices.next()?;
+    if rule_indices.all(|rule_index| rule_index == first) {
+        Some(first)
+    } else {
+        None
     }
 }
 
@@ -235,7 +696,7 @@ fn get_empty_table(machine: &Machine, file: &File) -> Table {
     let actions = get_empty_action_table(&machine.states, &terminals);
     let gotos = get_empty_goto_table(&machine.states, &nonterminals);
     Table {
-        start: machine.start,
+        starts: machine.starts.clone(),
         terminals,
         nonterminals,
         actions,
@@ -243,11 +704,19 @@ fn get_empty_table(machine: &Machine, file: &File) -> Table {
     }
 }
 
+/// If the grammar uses the `error` pseudo-terminal,
+/// it gets its own column, after the terminal enum's variants.
 fn get_terminals(file: &File) -> Vec<DollarlessTerminalName> {
+    let error = if file.uses_error_terminal() {
+        Some(DollarlessTerminalName::error())
+    } else {
+        None
+    };
     file.terminal_enum
         .variants
         .iter()
         .map(|variant| variant.dollarless_name.clone())
+        .chain(error)
         .collect()
 }
 
@@ -280,14 +749,44 @@ mod tests {
     fn balanced_parens() {
         let file = ast_to_machine::balanced_parens_input();
         let machine = ast_to_machine::balanced_parens_expected_output();
-        let actual = machine_to_table(&machine, &file).unwrap();
+        let actual = machine_to_table(&machine, None, &file, DefaultReductions::Off).unwrap();
         let expected = balanced_parens_expected_output(&machine);
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn balanced_parens_with_default_reductions() {
+        let file = ast_to_machine::balanced_parens_input();
+        let machine = ast_to_machine::balanced_parens_expected_output();
+        let actual = machine_to_table(
+            &machine,
+            None,
+            &file,
+            DefaultReductions::SingleReductionStates,
+        )
+        .unwrap();
+        let expected = Table {
+            actions: {
+                use Action::*;
+                [
+                    [shift(0), Reduce(0), Reduce(0)],
+                    [shift(0), Reduce(0), Reduce(0)],
+                    [Err, shift(3), Err],
+                    [Reduce(1), Reduce(1), Reduce(1)],
+                    [Err, Err, Accept],
+                ]
+                .into_iter()
+                .flatten()
+                .collect()
+            },
+            ..balanced_parens_expected_output(&machine)
+        };
+        assert_eq!(expected, actual);
+    }
+
     fn balanced_parens_expected_output(machine: &Machine) -> Table {
         Table {
-            start: machine.start,
+            starts: machine.starts.clone(),
             terminals: vec!["LParen", "RParen"]
                 .into_iter()
                 .map(DollarlessTerminalName::remove_dollars)
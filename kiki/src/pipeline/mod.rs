@@ -1,6 +1,12 @@
+pub mod compress_table;
+pub mod counterexample;
+pub mod create_unique_identifier;
 pub mod cst_to_ast;
+pub mod lexer_to_dfa;
+pub mod lint;
 pub mod machine_to_table;
 pub mod normalize_machine;
+pub mod pascal_to_snake_case;
 pub mod sort_and_get_index_updater;
 pub mod table_to_rust;
 pub mod tokenize;
@@ -11,7 +17,8 @@ pub mod validated_ast_to_machine;
 pub(crate) use crate::parser;
 
 pub mod prelude {
-    pub use super::cst_to_ast::*;
+    pub use super::lexer_to_dfa::*;
+    pub use super::lint::*;
     pub use super::machine_to_table::*;
     pub use super::parser::parse;
     pub use super::table_to_rust::*;
@@ -1,22 +1,49 @@
 use crate::data::{
-    machine::{Machine, StateIndex, Transition},
+    machine::{LalrMergeRecord, Machine, MergedState, StateIndex, Transition},
     unnormalized_machine::UnnormalizedMachine,
     IndexUpdater, Oset,
 };
 
 use crate::pipeline::sort_and_get_index_updater::sort_and_get_index_updater;
 
-/// The first state must be the start state.
 pub fn normalize_machine(unnormalized: UnnormalizedMachine) -> Machine {
+    normalize_machine_and_get_index_updater(unnormalized).0
+}
+
+/// Like `normalize_machine`, but also returns the updater
+/// that maps each state's old index to its new index.
+pub fn normalize_machine_and_get_index_updater(
+    unnormalized: UnnormalizedMachine,
+) -> (Machine, IndexUpdater) {
     let states = unnormalized.states;
     let transitions: Oset<Transition> = unnormalized.transitions.into_iter().collect();
     let (states, updater) = sort_and_get_index_updater(states);
     let transitions = update_transitions(transitions, &updater);
-    let start = StateIndex(updater.update(0));
-    Machine {
-        start,
-        states: states.into_iter().collect(),
-        transitions,
+    let starts = unnormalized
+        .starts
+        .into_iter()
+        .map(|start| StateIndex(updater.update(start.0)))
+        .collect();
+    let machine = Machine::new(starts, states.into_iter().collect(), transitions);
+    (machine, updater)
+}
+
+/// Updates the indices of the LALR(1) states
+/// that the LR(1) states were merged into.
+pub fn update_lalr_merge_record(
+    record: LalrMergeRecord,
+    updater: &IndexUpdater,
+) -> LalrMergeRecord {
+    LalrMergeRecord {
+        lr1_states: record
+            .lr1_states
+            .into_iter()
+            .map(|state| MergedState {
+                merged_into: StateIndex(updater.update(state.merged_into.0)),
+                reductions: state.reductions,
+            })
+            .collect(),
+        ..record
     }
 }
 
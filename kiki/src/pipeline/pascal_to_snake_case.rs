@@ -0,0 +1,67 @@
+/// Converts a PascalCase name (e.g., `HttpRequest`) to snake_case
+/// (e.g., `http_request`).
+///
+/// A run of uppercase letters is treated as a single word,
+/// so `HTTPRequest` also becomes `http_request`.
+pub fn pascal_to_snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let is_inside_acronym = chars[i - 1].is_uppercase()
+                && !chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if !is_inside_acronym {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lower_x() {
+        assert_eq!("x", pascal_to_snake_case("x"));
+    }
+
+    #[test]
+    fn upper_x() {
+        assert_eq!("x", pascal_to_snake_case("X"));
+    }
+
+    #[test]
+    fn lower_hi() {
+        assert_eq!("hi", pascal_to_snake_case("hi"));
+    }
+
+    #[test]
+    fn titlecase_hi() {
+        assert_eq!("hi", pascal_to_snake_case("Hi"));
+    }
+
+    #[test]
+    fn uppercase_hi() {
+        assert_eq!("hi", pascal_to_snake_case("HI"));
+    }
+
+    #[test]
+    fn titlecase_hi_there() {
+        assert_eq!("hi_there", pascal_to_snake_case("HiThere"));
+    }
+
+    #[test]
+    fn uppercase_hi_titlecase_there() {
+        assert_eq!("hi_there", pascal_to_snake_case("HIThere"));
+    }
+
+    #[test]
+    fn titlecase_there_uppercase_hi() {
+        assert_eq!("there_hi", pascal_to_snake_case("ThereHI"));
+    }
+}
@@ -1,6 +1,15 @@
-use crate::data::{table::*, validated_file::*, DollarlessTerminalName, RustSrc};
+use crate::data::{
+    dfa::Dfa, table::*, validated_file::*, DollarlessTerminalName, RustSrc, TableEncoding,
+};
+use crate::pipeline::{
+    compress_table::compress_table, create_unique_identifier::create_unique_identifier,
+    pascal_to_snake_case::pascal_to_snake_case,
+};
 use std::collections::{HashMap, HashSet};
 
+mod lexer;
+use lexer::LexerNames;
+
 const STATE_VARIANT_PREFIX: &str = "S";
 const RULE_KIND_VARIANT_PREFIX: &str = "R";
 const ACTION_SHIFT_VARIANT_NAME: &str = "Shift";
@@ -8,8 +17,15 @@ const ACTION_REDUCE_VARIANT_NAME: &str = "Reduce";
 const ACTION_ACCEPT_VARIANT_NAME: &str = "Accept";
 const ACTION_ERR_VARIANT_NAME: &str = "Err";
 
-pub fn table_to_rust(table: &Table, file: &File, grammar_src: &str) -> RustSrc {
-    let builder = SrcBuilder::new(table, file, grammar_src);
+/// `dfa` must be `Some` if and only if the grammar has a `lexer` section.
+pub fn table_to_rust(
+    table: &Table,
+    file: &File,
+    dfa: Option<&Dfa>,
+    grammar_src: &str,
+    encoding: TableEncoding,
+) -> RustSrc {
+    let builder = SrcBuilder::new(table, file, dfa, grammar_src, encoding);
     builder.file_src()
 }
 
@@ -18,7 +34,19 @@ struct SrcBuilder<'a> {
     grammar_src: &'a str,
     table: &'a Table,
     file: &'a File,
-    start_type_name: String,
+    dfa: Option<&'a Dfa>,
+    /// The `i`th parse function parses `file.starts[i]`.
+    parse_fn_names: Vec<String>,
+    /// The `i`th verbose parse function parses `file.starts[i]`,
+    /// returning a `ParseError` if it fails.
+    verbose_parse_fn_names: Vec<String>,
+    /// The `i`th recovering parse function parses `file.starts[i]`,
+    /// recovering from errors using the grammar's `error` rules.
+    /// These functions are only generated if the grammar uses `error`.
+    recovering_parse_fn_names: Vec<String>,
+    parse_from_state_fn_name: String,
+    recovering_parse_from_state_fn_name: String,
+    parse_error_name: String,
     terminal_enum_name: String,
     eof_variant_name: String,
     quasiterminal_enum_name: String,
@@ -31,14 +59,42 @@ struct SrcBuilder<'a> {
     reduce_fn_prefix: String,
     action_table_name: String,
     goto_table_name: String,
+    quasiterminal_kinds_name: String,
+    /// If the terminal enum is spanned,
+    /// this is `Some("std::ops::Range<Position>")`.
+    span_type: Option<String>,
+    /// The type of the items the parse functions take.
+    /// This is `Token` if the terminal enum is unspanned,
+    /// and `(Token, std::ops::Range<Position>)` otherwise.
+    terminal_item_type: String,
+    /// If the grammar uses the `error` pseudo-terminal,
+    /// this is the name of its `QuasiterminalKind` and `Node` variants.
+    error_variant_name: Option<String>,
+    /// This is `Some` if and only if the grammar has a `lexer` section.
+    lexer_names: Option<LexerNames>,
+    /// This is `Some` if and only if the encoding is `TableEncoding::Compressed`.
+    compressed: Option<(CompressedTable, CompressedTableNames)>,
+    /// This is `Some` if and only if some state
+    /// reduces by the same rule on every lookahead.
+    default_reductions_name: Option<String>,
 
     node_to_terminal_method_names: HashMap<DollarlessTerminalName, String>,
+    /// Some nonterminals' values have types that Kiki does not generate
+    /// (i.e., helpers and nonterminals with declared result types),
+    /// so we cannot implement `TryFrom<Node>` for them.
+    /// Instead, each of these nonterminals gets a `Node` method.
+    node_to_nonterminal_method_names: HashMap<String, String>,
 }
 
 impl SrcBuilder<'_> {
-    fn new<'a>(table: &'a Table, file: &'a File, grammar_src: &'a str) -> SrcBuilder<'a> {
+    fn new<'a>(
+        table: &'a Table,
+        file: &'a File,
+        dfa: Option<&'a Dfa>,
+        grammar_src: &'a str,
+        encoding: TableEncoding,
+    ) -> SrcBuilder<'a> {
         let used_identifiers = &mut file.get_defined_identifiers();
-        let start_type_name = file.start.to_owned();
         let terminal_enum_name = file.terminal_enum.name.to_owned();
         let eof_variant_name = create_unique_identifier("Eof", used_identifiers);
         let quasiterminal_enum_name = create_unique_identifier("Quasiterminal", used_identifiers);
@@ -53,25 +109,92 @@ impl SrcBuilder<'_> {
         let reduce_fn_prefix = create_unique_identifier("reduce", used_identifiers);
         let action_table_name = create_unique_identifier("ACTION_TABLE", used_identifiers);
         let goto_table_name = create_unique_identifier("GOTO_TABLE", used_identifiers);
+        let quasiterminal_kinds_name =
+            create_unique_identifier("QUASITERMINAL_KINDS", used_identifiers);
+        let parse_error_name = create_unique_identifier("ParseError", used_identifiers);
+        let span_type = file
+            .terminal_enum
+            .span_position_type
+            .as_ref()
+            .map(|position_type| format!("std::ops::Range<{position_type}>"));
+        let terminal_item_type = match &span_type {
+            Some(span_type) => format!("({terminal_enum_name}, {span_type})"),
+            None => terminal_enum_name.clone(),
+        };
+        let error_variant_name = if file.uses_error_terminal() {
+            Some(create_unique_identifier("Error", used_identifiers))
+        } else {
+            None
+        };
+        let lexer_names = dfa.map(|_| LexerNames::new(used_identifiers));
+        let compressed = match encoding {
+            TableEncoding::Dense => None,
+            TableEncoding::Compressed => Some((
+                compress_table(table),
+                CompressedTableNames::new(used_identifiers),
+            )),
+        };
+        let default_reductions_name = (0..table.state_count())
+            .any(|i| get_default_reduction(table, StateIndex(i)).is_some())
+            .then(|| create_unique_identifier("DEFAULT_REDUCTIONS", used_identifiers));
 
+        let error_name_and_variant_name = error_variant_name
+            .as_ref()
+            .map(|variant_name| (DollarlessTerminalName::error(), variant_name.as_str()));
         let node_to_terminal_method_names: HashMap<DollarlessTerminalName, String> = file
             .terminal_enum
             .variants
             .iter()
+            .map(|variant| {
+                (
+                    variant.dollarless_name.clone(),
+                    variant.dollarless_name.raw(),
+                )
+            })
+            .chain(error_name_and_variant_name)
             .enumerate()
-            .map(|(variant_index, variant)| {
-                let variant_name_snake_case = pascal_to_snake_case(variant.dollarless_name.raw());
-                let variant_name_original_case = variant.dollarless_name.clone();
+            .map(|(variant_index, (terminal_name, variant_name))| {
+                let variant_name_snake_case = pascal_to_snake_case(variant_name);
                 let method_name = format!("try_into_{variant_name_snake_case}_{variant_index}");
-                (variant_name_original_case, method_name)
+                (terminal_name, method_name)
+            })
+            .collect();
+
+        let used_method_names = &mut node_to_terminal_method_names.values().cloned().collect();
+        let node_to_nonterminal_method_names: HashMap<String, String> = file
+            .nonterminals
+            .iter()
+            .filter(|nonterminal| !has_generated_type(nonterminal))
+            .map(|nonterminal| {
+                let name = nonterminal.name();
+                let name_snake_case = pascal_to_snake_case(name);
+                let method_name = create_unique_identifier(
+                    &format!("try_into_{name_snake_case}"),
+                    used_method_names,
+                );
+                (name.to_owned(), method_name)
             })
             .collect();
 
+        let ParseFnNames {
+            parse_fn_names,
+            verbose_parse_fn_names,
+            recovering_parse_fn_names,
+            parse_from_state_fn_name,
+            recovering_parse_from_state_fn_name,
+        } = get_parse_fn_names(file);
+
         SrcBuilder {
             grammar_src,
             table,
             file,
-            start_type_name,
+            dfa,
+            parse_fn_names,
+            verbose_parse_fn_names,
+            recovering_parse_fn_names,
+            parse_from_state_fn_name,
+            recovering_parse_from_state_fn_name,
+            parse_error_name,
             terminal_enum_name,
             eof_variant_name,
             quasiterminal_enum_name,
@@ -84,7 +207,47 @@ impl SrcBuilder<'_> {
             reduce_fn_prefix,
             action_table_name,
             goto_table_name,
+            quasiterminal_kinds_name,
+            span_type,
+            terminal_item_type,
+            error_variant_name,
+            lexer_names,
+            compressed,
+            default_reductions_name,
             node_to_terminal_method_names,
+            node_to_nonterminal_method_names,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CompressedTableNames {
+    action_defaults_name: String,
+    action_bases_name: String,
+    action_checks_name: String,
+    action_values_name: String,
+    goto_defaults_name: String,
+    goto_bases_name: String,
+    goto_checks_name: String,
+    goto_values_name: String,
+    states_name: String,
+    rule_kinds_name: String,
+}
+
+impl CompressedTableNames {
+    fn new(used_identifiers: &mut HashSet<String>) -> Self {
+        let mut create = |preferred: &str| create_unique_identifier(preferred, used_identifiers);
+        CompressedTableNames {
+            action_defaults_name: create("ACTION_DEFAULTS"),
+            action_bases_name: create("ACTION_BASES"),
+            action_checks_name: create("ACTION_CHECKS"),
+            action_values_name: create("ACTION_VALUES"),
+            goto_defaults_name: create("GOTO_DEFAULTS"),
+            goto_bases_name: create("GOTO_BASES"),
+            goto_checks_name: create("GOTO_CHECKS"),
+            goto_values_name: create("GOTO_VALUES"),
+            states_name: create("STATES"),
+            rule_kinds_name: create("RULE_KINDS"),
         }
     }
 }
@@ -96,7 +259,6 @@ impl SrcBuilder<'_> {
         let Self {
             table,
             file,
-            start_type_name,
             terminal_enum_name,
             eof_variant_name,
             quasiterminal_enum_name,
@@ -107,42 +269,59 @@ impl SrcBuilder<'_> {
             action_enum_name,
             rule_kind_enum_name,
             reduce_fn_prefix: _,
-            action_table_name,
-            goto_table_name,
+            quasiterminal_kinds_name,
+            parse_error_name,
+            terminal_item_type,
             ..
         } = self;
 
-        let StateIndex(start_state_index) = table.start;
-        let terminal_enum_attributes =
-            get_attributes_src_with_newline_after_each_attribute(&file.terminal_enum.attributes);
-        let terminal_enum_variants_indent_1 = self.get_terminal_enum_variants_src().indent(1);
+        // Empty rules' reduce functions only read `spans`,
+        // and the position type may be `Copy`.
+        // Similarly, the recovering parse functions clone tokens,
+        // and the terminal enum may be `Copy`.
+        let extra_lint_allows = match (self.span_type.is_some(), self.error_variant_name.is_some())
+        {
+            (true, _) => "\n#![allow(clippy::ptr_arg)]\n#![allow(clippy::clone_on_copy)]",
+            (false, true) => "\n#![allow(clippy::clone_on_copy)]",
+            (false, false) => "",
+        };
+        let parse_fns = self.get_parse_fns_src();
+        let recovering_parse_fns = self.get_recovering_parse_fns_src();
+        let lexer = self.get_lexer_src();
+        let terminal_enum_def = self.get_terminal_enum_def_src();
         let nonterminal_type_defs = self.get_nonterminal_type_defs_src();
         let terminal_kind_enum_variants_indent_1 =
             self.get_terminal_kind_enum_variants_src().indent(1);
-        let num_of_terminal_variants = file.terminal_enum.variants.len();
+        let quasiterminal_kinds_indent_1 = self.get_quasiterminal_kinds_src().indent(1);
+        let num_of_terminal_kind_variants = table.terminals.len();
         let nonterminal_kind_enum_variants_indent_1 =
             self.get_nonterminal_kind_enum_variants_src().indent(1);
         let state_enum_variants_indent_1 = self.get_state_enum_variants_src().indent(1);
         let node_enum_variants_indent_1 = self.get_node_enum_variants_src().indent(1);
         let rule_kind_enum_variants_indent_1 = self.get_rule_kind_enum_variants_src().indent(1);
+        let pop_and_reduce_span_params = self.get_span_params_src(true);
+        let reduce_return_type = self.get_reduce_return_type_src();
         let pop_and_reduce_match_arms_indent_2 = self.get_pop_and_reduce_match_arms_src().indent(2);
         let reduce_fns = self.get_reduce_fns_src();
-        let quasiterminal_kind_from_terminal_match_arms_indent_3 = self
-            .get_quasiterminal_kind_from_terminal_match_arms_src()
-            .indent(3);
+        let get_empty_span_fn = self.get_get_empty_span_fn_src();
+        let impl_quasiterminal_kind = self.get_impl_quasiterminal_kind_src();
+        let impl_display_quasiterminal_kind = self.get_impl_display_quasiterminal_kind_src();
+        let quasiterminal_span_fn_indent_1 = self.get_quasiterminal_span_fn_src().indent(1);
         let node_from_terminal_match_arms_indent_3 =
             self.get_node_from_terminal_match_arms_src().indent(3);
-        let action_table_rows_indent_1 = self.get_action_table_rows_src().indent(1);
-        let goto_table_rows_indent_1 = self.get_goto_table_rows_src().indent(1);
+        let action_table = self.get_action_table_src();
+        let default_reduction_table = self.get_default_reduction_table_src();
+        let goto_table = self.get_goto_table_src();
         let impl_try_from_node_for_each_nonterminal =
             self.get_impl_try_from_node_for_each_nonterminal_src();
         let node_try_into_terminal_variant_name_variant_index_fns_indent_1 = self
             .get_node_try_into_terminal_variant_name_variant_index_fns_src()
             .indent(1);
+        let node_try_into_nonterminal_fns = self.get_node_try_into_nonterminal_fns_src();
 
-        let num_of_quasiterminal_kind_variants = file.terminal_enum.variants.len() + 1;
-        let num_of_nonterminal_kind_variants = file.nonterminals.len();
-        let num_of_state_variants = table.state_count();
+        // The parser never expects the `error` pseudo-terminal,
+        // so it is not listed.
+        let num_of_expectable_quasiterminal_kinds = file.terminal_enum.variants.len() + 1;
 
         RustSrc(format!(
             r#"// This code was generated by Kiki.
@@ -159,62 +338,52 @@ impl SrcBuilder<'_> {
 // Therefore, we disable certain lints for this file.
 #![allow(non_snake_case)]
 #![allow(dead_code)]
-
-{terminal_enum_attributes}pub enum {terminal_enum_name} {{
-{terminal_enum_variants_indent_1}
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]{extra_lint_allows}
+
+{terminal_enum_def}{nonterminal_type_defs}
+
+{parse_fns}{recovering_parse_fns}{lexer}
+
+/// An error that the parser encountered.
+pub struct {parse_error_name} {{
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<{terminal_item_type}>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<{quasiterminal_kind_enum_name}>,
 }}
 
-{nonterminal_type_defs}
-
-/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
-/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
-pub fn parse<S>(src: S) -> Result<{start_type_name}, Option<{terminal_enum_name}>>
-where S: IntoIterator<Item = {terminal_enum_name}> {{
-    let mut quasiterminals = src.into_iter()
-        .map({quasiterminal_enum_name}::Terminal)
-        .chain(std::iter::once({quasiterminal_enum_name}::Eof))
-        .peekable();
-    let mut states = vec![{state_enum_name}::{STATE_VARIANT_PREFIX}{start_state_index}];
-    let mut nodes: Vec<{node_enum_name}> = vec![];
-    loop {{
-        let top_state = *states.last().unwrap();
-        let next_quasiterminal_kind = {quasiterminal_kind_enum_name}::from_quasiterminal(quasiterminals.peek().unwrap());
-        match get_action(top_state, next_quasiterminal_kind) {{
-            {action_enum_name}::{ACTION_SHIFT_VARIANT_NAME}(new_state) => {{
-                states.push(new_state);
-                nodes.push({node_enum_name}::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
-            }}
-
-            {action_enum_name}::{ACTION_REDUCE_VARIANT_NAME}(rule_kind) => {{
-                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
-                nodes.push(new_node);
-                let temp_top_state = *states.last().unwrap();
-                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {{
-                    return Err(quasiterminals.next().unwrap().try_into_terminal().ok());
-                }};
-                states.push(new_state);
-            }}
-
-            {action_enum_name}::{ACTION_ACCEPT_VARIANT_NAME} => {{
-                return Ok({start_type_name}::try_from(nodes.pop().unwrap()).ok().unwrap());
-            }}
-
-            {action_enum_name}::{ACTION_ERR_VARIANT_NAME} => {{
-                return Err(quasiterminals.next().unwrap().try_into_terminal().ok());
-            }}
+impl {parse_error_name} {{
+    fn new(unexpected: {quasiterminal_enum_name}, token_index: usize, state: {state_enum_name}) -> Self {{
+        let expected = {quasiterminal_kinds_name}
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), {action_enum_name}::{ACTION_ERR_VARIANT_NAME}))
+            .collect();
+        Self {{
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
         }}
     }}
 }}
 
 enum {quasiterminal_enum_name} {{
-    Terminal({terminal_enum_name}),
+    Terminal({terminal_item_type}),
     {eof_variant_name},
 }}
 
-#[derive(Clone, Copy, Debug)]
-enum {quasiterminal_kind_enum_name} {{
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum {quasiterminal_kind_enum_name} {{
 {terminal_kind_enum_variants_indent_1}
-    {eof_variant_name} = {num_of_terminal_variants},
+    /// The end of input.
+    {eof_variant_name} = {num_of_terminal_kind_variants},
 }}
 
 #[derive(Clone, Copy, Debug)]
@@ -244,28 +413,17 @@ enum {rule_kind_enum_name} {{
 {rule_kind_enum_variants_indent_1}
 }}
 
-fn pop_and_reduce(states: &mut Vec<{state_enum_name}>, nodes: &mut Vec<{node_enum_name}>, rule_kind: {rule_kind_enum_name}) -> ({node_enum_name}, {nonterminal_kind_enum_name}) {{
+fn pop_and_reduce(states: &mut Vec<{state_enum_name}>, nodes: &mut Vec<{node_enum_name}>{pop_and_reduce_span_params}, rule_kind: {rule_kind_enum_name}) -> {reduce_return_type} {{
     match rule_kind {{
 {pop_and_reduce_match_arms_indent_2}
     }}
 }}
 
 {reduce_fns}
+{get_empty_span_fn}
+{impl_quasiterminal_kind}
 
-impl {quasiterminal_kind_enum_name} {{
-    fn from_quasiterminal(quasiterminal: &{quasiterminal_enum_name}) -> Self {{
-        match quasiterminal {{
-            {quasiterminal_enum_name}::Terminal(terminal) => Self::from_terminal(terminal),
-            {quasiterminal_enum_name}::{eof_variant_name} => Self::{eof_variant_name},
-        }}
-    }}
-
-    fn from_terminal(terminal: &{terminal_enum_name}) -> Self {{
-        match terminal {{
-{quasiterminal_kind_from_terminal_match_arms_indent_3}
-        }}
-    }}
-}}
+{impl_display_quasiterminal_kind}
 
 impl {node_enum_name} {{
     fn from_terminal(terminal: {terminal_enum_name}) -> Self {{
@@ -276,48 +434,524 @@ impl {node_enum_name} {{
 }}
 
 impl {quasiterminal_enum_name} {{
-    fn try_into_terminal(self) -> Result<{terminal_enum_name}, ()> {{
+    fn try_into_terminal(self) -> Result<{terminal_item_type}, ()> {{
         match self {{
             Self::Terminal(terminal) => Ok(terminal),
             Self::{eof_variant_name} => Err(()),
         }}
-    }}
+    }}{quasiterminal_span_fn_indent_1}
 }}
 
-static {action_table_name}: [[{action_enum_name}; {num_of_quasiterminal_kind_variants}]; {num_of_state_variants}] = [
-{action_table_rows_indent_1}
-];
+{action_table}{default_reduction_table}
 
-fn get_action(top_state: {state_enum_name}, next_quasiterminal_kind: {quasiterminal_kind_enum_name}) -> {action_enum_name} {{
-    {action_table_name}[top_state as usize][next_quasiterminal_kind as usize]
-}}
-
-static {goto_table_name}: [[Option<{state_enum_name}>; {num_of_nonterminal_kind_variants}]; {num_of_state_variants}] = [
-{goto_table_rows_indent_1}
+static {quasiterminal_kinds_name}: [{quasiterminal_kind_enum_name}; {num_of_expectable_quasiterminal_kinds}] = [
+{quasiterminal_kinds_indent_1}
 ];
 
-fn get_goto(top_state: {state_enum_name}, new_node_kind: {nonterminal_kind_enum_name}) -> Option<{state_enum_name}> {{
-    {goto_table_name}[top_state as usize][new_node_kind as usize]
-}}
+{goto_table}
 
 {impl_try_from_node_for_each_nonterminal}
 
 impl {node_enum_name} {{
 {node_try_into_terminal_variant_name_variant_index_fns_indent_1}
 }}
-"#
+{node_try_into_nonterminal_fns}"#
         ))
     }
 
+    fn get_parse_fns_src(&self) -> String {
+        let Self {
+            table,
+            file,
+            state_enum_name,
+            parse_fn_names,
+            verbose_parse_fn_names,
+            parse_from_state_fn_name,
+            parse_error_name,
+            terminal_item_type,
+            ..
+        } = self;
+
+        if let ([start_type_name], [StateIndex(start_state_index)]) =
+            (&file.starts[..], &table.starts[..])
+        {
+            let verbose_parse_fn_name = &verbose_parse_fn_names[0];
+            let initial_state =
+                format!("{state_enum_name}::{STATE_VARIANT_PREFIX}{start_state_index}");
+            let start_value_type = self.get_nonterminal_value_type_src(start_type_name);
+            let accepted_value =
+                self.get_node_to_nonterminal_value_src(start_type_name, "nodes.pop().unwrap()");
+            let parse_loop_indent_1 = self
+                .get_parse_loop_src(&initial_state, &accepted_value)
+                .indent(1);
+            let parse_fn = self.get_compatible_parse_fn_src(
+                &parse_fn_names[0],
+                verbose_parse_fn_name,
+                &start_value_type,
+            );
+            return format!(
+                r#"{parse_fn}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `{parse_error_name}` describing where the error occurred
+/// and which tokens were expected.
+pub fn {verbose_parse_fn_name}<S>(src: S) -> Result<{start_value_type}, {parse_error_name}>
+where S: IntoIterator<Item = {terminal_item_type}> {{
+{parse_loop_indent_1}
+}}"#
+            );
+        }
+
+        let public_fns = file
+            .starts
+            .iter()
+            .zip(&table.starts)
+            .zip(parse_fn_names.iter().zip(verbose_parse_fn_names))
+            .map(
+                |(
+                    (start_type_name, StateIndex(start_state_index)),
+                    (parse_fn_name, verbose_parse_fn_name),
+                )| {
+                    let start_value_type = self.get_nonterminal_value_type_src(start_type_name);
+                    let start_value =
+                        self.get_node_to_nonterminal_value_src(start_type_name, "node");
+                    let parse_fn = self.get_compatible_parse_fn_src(
+                        parse_fn_name,
+                        verbose_parse_fn_name,
+                        &start_value_type,
+                    );
+                    format!(
+                        r#"{parse_fn}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `{parse_error_name}` describing where the error occurred
+/// and which tokens were expected.
+pub fn {verbose_parse_fn_name}<S>(src: S) -> Result<{start_value_type}, {parse_error_name}>
+where S: IntoIterator<Item = {terminal_item_type}> {{
+    let node = {parse_from_state_fn_name}(src, {state_enum_name}::{STATE_VARIANT_PREFIX}{start_state_index})?;
+    Ok({start_value})
+}}"#
+                    )
+                },
+            )
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let node_enum_name = &self.node_enum_name;
+        let parse_loop_indent_1 = self
+            .get_parse_loop_src("start_state", "nodes.pop().unwrap()")
+            .indent(1);
+        format!(
+            r#"{public_fns}
+
+fn {parse_from_state_fn_name}<S>(src: S, start_state: {state_enum_name}) -> Result<{node_enum_name}, {parse_error_name}>
+where S: IntoIterator<Item = {terminal_item_type}> {{
+{parse_loop_indent_1}
+}}"#
+        )
+    }
+
+    /// The parse functions that return `Option<Token>` errors
+    /// predate `ParseError`.
+    /// They are kept for compatibility,
+    /// and simply discard everything but the unexpected token.
+    fn get_compatible_parse_fn_src(
+        &self,
+        parse_fn_name: &str,
+        verbose_parse_fn_name: &str,
+        start_value_type: &str,
+    ) -> String {
+        let terminal_item_type = &self.terminal_item_type;
+        format!(
+            r#"/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `{verbose_parse_fn_name}`.
+pub fn {parse_fn_name}<S>(src: S) -> Result<{start_value_type}, Option<{terminal_item_type}>>
+where S: IntoIterator<Item = {terminal_item_type}> {{
+    {verbose_parse_fn_name}(src).map_err(|err| err.unexpected)
+}}"#
+        )
+    }
+
+    fn get_parse_loop_src(&self, initial_state: &str, accepted_value: &str) -> String {
+        let Self {
+            quasiterminal_enum_name,
+            quasiterminal_kind_enum_name,
+            node_enum_name,
+            action_enum_name,
+            parse_error_name,
+            ..
+        } = self;
+        let next_quasiterminal_kind_decl = if self.file.terminal_enum.is_extern {
+            format!(
+                r#"let Some(next_quasiterminal_kind) = {quasiterminal_kind_enum_name}::from_quasiterminal(quasiterminals.peek().unwrap()) else {{
+    return Err({parse_error_name}::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+}};"#
+            )
+        } else {
+            format!("let next_quasiterminal_kind = {quasiterminal_kind_enum_name}::from_quasiterminal(quasiterminals.peek().unwrap());")
+        };
+        let (action_decl_indent_1, action) = if self.default_reductions_name.is_some() {
+            let action_decl = self.get_default_reduction_or_else_src(&format!(
+                "{next_quasiterminal_kind_decl}\nget_action(top_state, next_quasiterminal_kind)"
+            ));
+            (action_decl.indent(1), "action".to_owned())
+        } else {
+            (
+                next_quasiterminal_kind_decl.indent(1),
+                "get_action(top_state, next_quasiterminal_kind)".to_owned(),
+            )
+        };
+        let (spans_decl, shift_src, reduce_src) = self.get_parse_loop_stack_ops_src();
+        let shift_indent_3 = shift_src.indent(3);
+        let reduce_indent_3 = reduce_src.indent(3);
+
+        format!(
+            r#"let mut quasiterminals = src.into_iter()
+    .map({quasiterminal_enum_name}::Terminal)
+    .chain(std::iter::once({quasiterminal_enum_name}::Eof))
+    .peekable();
+let mut states = vec![{initial_state}];
+let mut nodes: Vec<{node_enum_name}> = vec![];{spans_decl}
+let mut next_token_index = 0;
+loop {{
+    let top_state = *states.last().unwrap();
+{action_decl_indent_1}
+    match {action} {{
+        {action_enum_name}::{ACTION_SHIFT_VARIANT_NAME}(new_state) => {{
+            states.push(new_state);
+{shift_indent_3}
+            next_token_index += 1;
+        }}
+
+        {action_enum_name}::{ACTION_REDUCE_VARIANT_NAME}(rule_kind) => {{
+{reduce_indent_3}
+            let temp_top_state = *states.last().unwrap();
+            let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {{
+                return Err({parse_error_name}::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }};
+            states.push(new_state);
+        }}
+
+        {action_enum_name}::{ACTION_ACCEPT_VARIANT_NAME} => {{
+            return Ok({accepted_value});
+        }}
+
+        {action_enum_name}::{ACTION_ERR_VARIANT_NAME} => {{
+            return Err({parse_error_name}::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+        }}
+    }}
+}}"#
+        )
+    }
+
+    /// Returns the declaration of the `spans` stack (if any),
+    /// the statements that shift the next token,
+    /// and the statements that pop and reduce the nodes of `rule_kind`.
+    ///
+    /// `get_empty_span` only uses the next token's span if `spans` is empty,
+    /// so the reduce statements only read the next token in that case.
+    /// This way, default reductions usually do not read it.
+    fn get_parse_loop_stack_ops_src(&self) -> (String, String, String) {
+        let node_enum_name = &self.node_enum_name;
+        match &self.span_type {
+            None => (
+                "".to_owned(),
+                format!("nodes.push({node_enum_name}::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));"),
+                r#"let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+nodes.push(new_node);"#
+                    .to_owned(),
+            ),
+            Some(span_type) => (
+                format!("\nlet mut spans: Vec<{span_type}> = vec![];"),
+                format!(
+                    r#"let (terminal, span) = quasiterminals.next().unwrap().try_into_terminal().unwrap();
+nodes.push({node_enum_name}::from_terminal(terminal));
+spans.push(span);"#
+                ),
+                r#"let next_span = if spans.is_empty() {
+    quasiterminals.peek().unwrap().span()
+} else {
+    None
+};
+let (new_node, new_node_kind, new_span) = pop_and_reduce(&mut states, &mut nodes, &mut spans, next_span, rule_kind);
+nodes.push(new_node);
+spans.push(new_span);"#
+                    .to_owned(),
+            ),
+        }
+    }
+
+    /// Returns the declaration of `action`,
+    /// which is the top state's default reduction (if any),
+    /// or else the value of `peeked_action`.
+    /// This way, the parser does not read the next token
+    /// until it needs to.
+    fn get_default_reduction_or_else_src(&self, peeked_action: &str) -> String {
+        let action_enum_name = &self.action_enum_name;
+        let peeked_action_indent_2 = peeked_action.indent(2);
+        format!(
+            r#"let action = match get_default_reduction(top_state) {{
+    Some(rule_kind) => {action_enum_name}::{ACTION_REDUCE_VARIANT_NAME}(rule_kind),
+    None => {{
+{peeked_action_indent_2}
+    }}
+}};"#
+        )
+    }
+
+    /// If the grammar does not use the `error` pseudo-terminal,
+    /// this returns the empty string.
+    fn get_recovering_parse_fns_src(&self) -> String {
+        if self.error_variant_name.is_none() {
+            return "".to_owned();
+        }
+
+        let Self {
+            table,
+            file,
+            state_enum_name,
+            node_enum_name,
+            recovering_parse_fn_names,
+            recovering_parse_from_state_fn_name,
+            parse_error_name,
+            terminal_item_type,
+            ..
+        } = self;
+
+        if let ([start_type_name], [StateIndex(start_state_index)]) =
+            (&file.starts[..], &table.starts[..])
+        {
+            let recovering_parse_fn_name = &recovering_parse_fn_names[0];
+            let initial_state =
+                format!("{state_enum_name}::{STATE_VARIANT_PREFIX}{start_state_index}");
+            let start_value_type = self.get_nonterminal_value_type_src(start_type_name);
+            let accepted_value =
+                self.get_node_to_nonterminal_value_src(start_type_name, "nodes.pop().unwrap()");
+            let parse_loop_indent_1 = self
+                .get_recovering_parse_loop_src(&initial_state, &accepted_value)
+                .indent(1);
+            let doc = get_recovering_parse_fn_doc_src();
+            return format!(
+                r#"
+
+{doc}
+pub fn {recovering_parse_fn_name}<S>(src: S) -> (Option<{start_value_type}>, Vec<{parse_error_name}>)
+where S: IntoIterator<Item = {terminal_item_type}>, S::Item: Clone {{
+{parse_loop_indent_1}
+}}"#
+            );
+        }
+
+        let public_fns = file
+            .starts
+            .iter()
+            .zip(&table.starts)
+            .zip(recovering_parse_fn_names)
+            .map(
+                |((start_type_name, StateIndex(start_state_index)), recovering_parse_fn_name)| {
+                    let start_value_type = self.get_nonterminal_value_type_src(start_type_name);
+                    let start_value =
+                        self.get_node_to_nonterminal_value_src(start_type_name, "node");
+                    let doc = get_recovering_parse_fn_doc_src();
+                    format!(
+                        r#"{doc}
+pub fn {recovering_parse_fn_name}<S>(src: S) -> (Option<{start_value_type}>, Vec<{parse_error_name}>)
+where S: IntoIterator<Item = {terminal_item_type}>, S::Item: Clone {{
+    let (node, errors) = {recovering_parse_from_state_fn_name}(src, {state_enum_name}::{STATE_VARIANT_PREFIX}{start_state_index});
+    (node.map(|node| {start_value}), errors)
+}}"#
+                    )
+                },
+            )
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let parse_loop_indent_1 = self
+            .get_recovering_parse_loop_src("start_state", "nodes.pop().unwrap()")
+            .indent(1);
+        format!(
+            r#"
+
+{public_fns}
+
+fn {recovering_parse_from_state_fn_name}<S>(src: S, start_state: {state_enum_name}) -> (Option<{node_enum_name}>, Vec<{parse_error_name}>)
+where S: IntoIterator<Item = {terminal_item_type}>, S::Item: Clone {{
+{parse_loop_indent_1}
+}}"#
+        )
+    }
+
+    /// The recovery strategy is the same as yacc's.
+    /// When the parser encounters an error, it records it,
+    /// pops states until it reaches one that can shift `error`,
+    /// and shifts `error`.
+    /// Then, it discards tokens until one can follow the `error`.
+    /// To avoid cascades of errors,
+    /// no new errors are recorded until three tokens have been shifted.
+    fn get_recovering_parse_loop_src(&self, initial_state: &str, accepted_value: &str) -> String {
+        let Self {
+            quasiterminal_enum_name,
+            quasiterminal_kind_enum_name,
+            node_enum_name,
+            action_enum_name,
+            parse_error_name,
+            eof_variant_name,
+            ..
+        } = self;
+        let error_variant_name = self.error_variant_name.as_ref().unwrap();
+        let peeked_action = if self.file.terminal_enum.is_extern {
+            format!(
+                r#"match {quasiterminal_kind_enum_name}::from_quasiterminal(quasiterminals.peek().unwrap()) {{
+    Some(next_quasiterminal_kind) => get_action(top_state, next_quasiterminal_kind),
+    // The grammar does not use the token, so the token is unexpected.
+    None => {action_enum_name}::{ACTION_ERR_VARIANT_NAME},
+}}"#
+            )
+        } else {
+            format!("get_action(top_state, {quasiterminal_kind_enum_name}::from_quasiterminal(quasiterminals.peek().unwrap()))")
+        };
+        let action_decl_indent_1 = if self.default_reductions_name.is_some() {
+            self.get_default_reduction_or_else_src(&peeked_action)
+        } else {
+            format!("let action = {peeked_action};")
+        }
+        .indent(1);
+        let (spans_decl, shift_src, reduce_src) = self.get_parse_loop_stack_ops_src();
+        let shift_indent_3 = shift_src.indent(3);
+        let reduce_indent_3 = reduce_src.indent(3);
+        let (popped_span_decl, pop_span, error_span_decl, push_error_span) = match &self.span_type
+        {
+            None => (
+                "".to_owned(),
+                "".to_owned(),
+                "".to_owned(),
+                "".to_owned(),
+            ),
+            // The `error` node spans the nodes it replaces.
+            // If it replaces no nodes, it is positioned like an empty rule.
+            Some(span_type) => (
+                format!("let mut popped_span: Option<{span_type}> = None;\n"),
+                r#"
+    let span = spans.pop().unwrap();
+    popped_span = Some(match popped_span {
+        Some(later_span) => span.start..later_span.end,
+        None => span,
+    });"#
+                    .to_owned(),
+                "let error_span = popped_span.unwrap_or_else(|| get_empty_span(&spans, quasiterminals.peek().unwrap().span()));\n".to_owned(),
+                "\nspans.push(error_span);".to_owned(),
+            ),
+        };
+        let recover_indent_3 = format!(
+            r#"{popped_span_decl}let error_state = loop {{
+    let top_state = *states.last().unwrap();
+    if let {action_enum_name}::{ACTION_SHIFT_VARIANT_NAME}(error_state) = get_action(top_state, {quasiterminal_kind_enum_name}::{error_variant_name}) {{
+        break error_state;
+    }}
+    if states.len() == 1 {{
+        return (None, errors);
+    }}
+    states.pop();
+    nodes.pop();{pop_span}
+}};
+{error_span_decl}states.push(error_state);
+nodes.push({node_enum_name}::{error_variant_name}(()));{push_error_span}"#
+        )
+        .indent(3);
+
+        format!(
+            r#"let mut quasiterminals = src.into_iter()
+    .map({quasiterminal_enum_name}::Terminal)
+    .chain(std::iter::once({quasiterminal_enum_name}::Eof))
+    .peekable();
+let mut states = vec![{initial_state}];
+let mut nodes: Vec<{node_enum_name}> = vec![];{spans_decl}
+let mut errors = vec![];
+let mut next_token_index = 0;
+// After an error, the parser must shift this many more tokens
+// before it records another error.
+let mut remaining_recovery_shifts = 0;
+loop {{
+    let top_state = *states.last().unwrap();
+{action_decl_indent_1}
+    match action {{
+        {action_enum_name}::{ACTION_SHIFT_VARIANT_NAME}(new_state) => {{
+            states.push(new_state);
+{shift_indent_3}
+            next_token_index += 1;
+            remaining_recovery_shifts = usize::saturating_sub(remaining_recovery_shifts, 1);
+        }}
+
+        {action_enum_name}::{ACTION_REDUCE_VARIANT_NAME}(rule_kind) => {{
+{reduce_indent_3}
+            let temp_top_state = *states.last().unwrap();
+            let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {{
+                errors.push({parse_error_name}::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                return (None, errors);
+            }};
+            states.push(new_state);
+        }}
+
+        {action_enum_name}::{ACTION_ACCEPT_VARIANT_NAME} => {{
+            return (Some({accepted_value}), errors);
+        }}
+
+        {action_enum_name}::{ACTION_ERR_VARIANT_NAME} => {{
+            if remaining_recovery_shifts == 0 {{
+                let unexpected = match quasiterminals.peek().unwrap() {{
+                    {quasiterminal_enum_name}::Terminal(terminal) => {quasiterminal_enum_name}::Terminal(terminal.clone()),
+                    {quasiterminal_enum_name}::{eof_variant_name} => {quasiterminal_enum_name}::{eof_variant_name},
+                }};
+                errors.push({parse_error_name}::new(unexpected, next_token_index, top_state));
+            }} else if remaining_recovery_shifts == 3 {{
+                // No token has been shifted since the last `error`,
+                // so the parser must discard the unexpected token to make progress.
+                if quasiterminals.next().unwrap().try_into_terminal().is_err() {{
+                    return (None, errors);
+                }}
+                next_token_index += 1;
+            }}
+            remaining_recovery_shifts = 3;
+{recover_indent_3}
+        }}
+    }}
+}}"#
+        )
+    }
+
+    /// Extern terminal enums are defined outside the generated code,
+    /// so this returns the empty string for them.
+    fn get_terminal_enum_def_src(&self) -> String {
+        let terminal_enum = &self.file.terminal_enum;
+        if terminal_enum.is_extern {
+            return String::new();
+        }
+
+        let terminal_enum_name = &self.terminal_enum_name;
+        let terminal_enum_doc = get_doc_src_with_newline_after_each_line(&terminal_enum.doc);
+        let terminal_enum_attributes =
+            get_attributes_src_with_newline_after_each_attribute(&terminal_enum.attributes);
+        let terminal_enum_variants_indent_1 = self.get_terminal_enum_variants_src().indent(1);
+        format!(
+            r#"{terminal_enum_doc}{terminal_enum_attributes}pub enum {terminal_enum_name} {{
+{terminal_enum_variants_indent_1}
+}}
+
+"#
+        )
+    }
+
     fn get_terminal_enum_variants_src(&self) -> String {
         self.file
             .terminal_enum
             .variants
             .iter()
             .map(|variant| {
+                let doc = get_terminal_variant_doc_src(variant);
                 let name = variant.dollarless_name.raw();
                 let type_ = &variant.type_;
-                format!("{name}({type_}),")
+                format!("{doc}{name}({type_}),")
             })
             .collect::<Vec<_>>()
             .join("\n")
@@ -327,8 +961,10 @@ impl {node_enum_name} {{
         self.file
             .nonterminals
             .iter()
-            .map(|nonterminal| match nonterminal {
+            .filter(|nonterminal| has_generated_type(nonterminal))
+            .filter_map(|nonterminal| match nonterminal {
                 Nonterminal::Struct(s) => {
+                    let doc = get_doc_src_with_newline_after_each_line(&s.doc);
                     let attributes =
                         get_attributes_src_with_newline_after_each_attribute(&s.attributes);
                     let nonterminal_name = &s.name.name;
@@ -339,9 +975,12 @@ impl {node_enum_name} {{
                             use_pub_on_named_fields: true,
                         },
                     );
-                    format!("{attributes}pub struct {nonterminal_name}{fieldset}")
+                    Some(format!(
+                        "{doc}{attributes}pub struct {nonterminal_name}{fieldset}"
+                    ))
                 }
                 Nonterminal::Enum(e) => {
+                    let doc = get_doc_src_with_newline_after_each_line(&e.doc);
                     let attributes =
                         get_attributes_src_with_newline_after_each_attribute(&e.attributes);
                     let nonterminal_name = &e.name.name;
@@ -349,6 +988,8 @@ impl {node_enum_name} {{
                         .variants
                         .iter()
                         .map(|variant| {
+                            let variant_doc =
+                                get_doc_src_with_newline_after_each_line(&variant.doc);
                             let variant_name = &variant.name.name;
                             let variant_fieldset = self.get_fieldset_src(
                                 &variant.fieldset,
@@ -357,13 +998,16 @@ impl {node_enum_name} {{
                                     use_pub_on_named_fields: false,
                                 },
                             );
-                            format!("{variant_name}{variant_fieldset},")
+                            format!("{variant_doc}{variant_name}{variant_fieldset},")
                         })
                         .collect::<Vec<_>>()
                         .join("\n")
                         .indent(1);
-                    format!("{attributes}pub enum {nonterminal_name} {{\n{variants_indent_1}\n}}")
+                    Some(format!(
+                        "{doc}{attributes}pub enum {nonterminal_name} {{\n{variants_indent_1}\n}}"
+                    ))
                 }
+                Nonterminal::Helper(_) => None,
             })
             .collect::<Vec<_>>()
             .join("\n\n")
@@ -377,7 +1021,15 @@ impl {node_enum_name} {{
         }
     }
 
+    /// If the terminal enum is spanned,
+    /// an empty fieldset still has a `span` field,
+    /// so it becomes a named fieldset.
     fn get_empty_fieldset_src(&self, options: GetFieldsetSrcOptions) -> String {
+        if let Some(span_type) = &self.span_type {
+            let pub_ = get_pub_src(options);
+            return format!(" {{\n    {pub_}span: {span_type},\n}}");
+        }
+
         if options.use_semicolon_if_unnamed {
             ";"
         } else {
@@ -395,31 +1047,24 @@ impl {node_enum_name} {{
             return self.get_empty_fieldset_src(options);
         }
 
-        let pub_ = if options.use_pub_on_named_fields {
-            "pub "
-        } else {
-            ""
-        };
+        let pub_ = get_pub_src(options);
         let fields_indent_1 = fieldset
             .fields
             .iter()
-            .filter_map(|field| match (&field.name, &field.symbol) {
-                (IdentOrUnderscore::Underscore(_), _) => None,
-                (IdentOrUnderscore::Ident(field_name), IdentOrTerminalIdent::Ident(field_type)) => {
-                    let field_name = &field_name.name;
-                    let field_type_name = &field_type.name;
-                    Some(format!("{pub_}{field_name}: Box<{field_type_name}>,"))
-                }
-                (
-                    IdentOrUnderscore::Ident(field_name),
-                    IdentOrTerminalIdent::Terminal(field_type),
-                ) => {
+            .filter_map(|field| match &field.name {
+                IdentOrUnderscore::Underscore(_) => None,
+                IdentOrUnderscore::Ident(field_name) => {
+                    let field_doc = get_doc_src_with_newline_after_each_line(&field.doc);
                     let field_name = &field_name.name;
-                    let field_type_name =
-                        self.file.terminal_enum.get_type(&field_type.name).unwrap();
-                    Some(format!("{pub_}{field_name}: {field_type_name},"))
+                    let field_type = self.get_field_type_src(&field.symbol);
+                    Some(format!("{field_doc}{pub_}{field_name}: {field_type},"))
                 }
             })
+            .chain(
+                self.span_type
+                    .iter()
+                    .map(|span_type| format!("{pub_}span: {span_type},")),
+            )
             .collect::<Vec<_>>()
             .join("\n")
             .indent(1);
@@ -440,16 +1085,16 @@ impl {node_enum_name} {{
             .iter()
             .filter_map(|field| match field {
                 TupleField::Skipped(_) => None,
-                TupleField::Used(IdentOrTerminalIdent::Ident(field_type)) => {
-                    let field_type_name = &field_type.name;
-                    Some(format!("Box<{field_type_name}>,"))
-                }
-                TupleField::Used(IdentOrTerminalIdent::Terminal(field_type)) => {
-                    let field_type_name =
-                        self.file.terminal_enum.get_type(&field_type.name).unwrap();
-                    Some(format!("{field_type_name},"))
+                TupleField::Used(symbol) => {
+                    let field_type = self.get_field_type_src(symbol);
+                    Some(format!("{field_type},"))
                 }
             })
+            .chain(
+                self.span_type
+                    .iter()
+                    .map(|span_type| format!("{span_type},")),
+            )
             .collect::<Vec<_>>()
             .join("\n")
             .indent(1);
@@ -461,16 +1106,111 @@ impl {node_enum_name} {{
         format!("(\n{fields_indent_1}\n){possible_semicolon}")
     }
 
+    /// Nonterminal fields are boxed,
+    /// since nonterminals may be recursive.
+    /// Helper fields are not boxed,
+    /// since their types (e.g., `Vec<Foo>` or `Option<Box<Foo>>`)
+    /// already provide indirection.
+    /// Fields of nonterminals with declared result types are not boxed either,
+    /// since the user chose those types.
+    fn get_field_type_src(&self, symbol: &IdentOrTerminalIdent) -> String {
+        match symbol {
+            IdentOrTerminalIdent::Ident(ident) if self.is_boxed_nonterminal(&ident.name) => {
+                format!("Box<{}>", ident.name)
+            }
+            IdentOrTerminalIdent::Ident(_) => self.get_unboxed_type_src(symbol),
+            IdentOrTerminalIdent::Terminal(terminal) => self.get_terminal_type_src(&terminal.name),
+        }
+    }
+
+    fn get_helper_type_src(&self, helper: &Helper) -> String {
+        match helper.kind {
+            HelperKind::Vec => {
+                let element_type = self.get_unboxed_type_src(&helper.element);
+                format!("Vec<{element_type}>")
+            }
+            HelperKind::Option => {
+                let element_type = self.get_field_type_src(&helper.element);
+                format!("Option<{element_type}>")
+            }
+        }
+    }
+
+    fn get_unboxed_type_src(&self, symbol: &IdentOrTerminalIdent) -> String {
+        match symbol {
+            IdentOrTerminalIdent::Ident(ident) => self.get_nonterminal_value_type_src(&ident.name),
+            IdentOrTerminalIdent::Terminal(terminal) => self.get_terminal_type_src(&terminal.name),
+        }
+    }
+
+    /// The `error` pseudo-terminal carries no data,
+    /// so its type is `()`.
+    fn get_terminal_type_src(&self, terminal_name: &DollarlessTerminalName) -> String {
+        if terminal_name.is_error() {
+            return "()".to_owned();
+        }
+
+        self.file
+            .terminal_enum
+            .get_type(terminal_name)
+            .unwrap()
+            .to_owned()
+    }
+
+    fn get_nonterminal_value_type_src(&self, nonterminal_name: &str) -> String {
+        if let Some(helper) = self.file.get_helper(nonterminal_name) {
+            return self.get_helper_type_src(helper);
+        }
+
+        match self.file.get_result_type(nonterminal_name) {
+            Some(result_type) => result_type.to_owned(),
+            None => nonterminal_name.to_owned(),
+        }
+    }
+
+    fn is_boxed_nonterminal(&self, nonterminal_name: &str) -> bool {
+        !self
+            .node_to_nonterminal_method_names
+            .contains_key(nonterminal_name)
+    }
+
+    /// If the grammar uses the `error` pseudo-terminal,
+    /// its kind comes after the terminal enum's variants' kinds.
     fn get_terminal_kind_enum_variants_src(&self) -> String {
         self.file
             .terminal_enum
             .variants
             .iter()
+            .map(|variant| {
+                let doc = get_terminal_variant_doc_src(variant);
+                let name = variant.dollarless_name.raw();
+                format!("{doc}{name}")
+            })
+            .chain(
+                self.error_variant_name
+                    .as_deref()
+                    .map(|name| format!("/// The `error` pseudo-terminal.\n{name}")),
+            )
             .enumerate()
-            .map(|(variant_index, variant)| {
+            .map(|(variant_index, variant)| format!("{variant} = {variant_index},"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn get_quasiterminal_kinds_src(&self) -> String {
+        let quasiterminal_kind_enum_name = &self.quasiterminal_kind_enum_name;
+        let eof_variant_name = &self.eof_variant_name;
+        self.file
+            .terminal_enum
+            .variants
+            .iter()
+            .map(|variant| {
                 let name = variant.dollarless_name.raw();
-                format!("{name} = {variant_index},")
+                format!("{quasiterminal_kind_enum_name}::{name},")
             })
+            .chain(std::iter::once(format!(
+                "{quasiterminal_kind_enum_name}::{eof_variant_name},"
+            )))
             .collect::<Vec<_>>()
             .join("\n")
     }
@@ -499,12 +1239,21 @@ impl {node_enum_name} {{
         self.file
             .nonterminals
             .iter()
-            .map(|nonterminal| format!("{name}({name}),", name = nonterminal.name()))
+            .map(|nonterminal| {
+                let name = nonterminal.name();
+                let type_ = self.get_nonterminal_value_type_src(name);
+                format!("{name}({type_}),")
+            })
             .chain(self.file.terminal_enum.variants.iter().map(|variant| {
                 let name = variant.dollarless_name.raw();
                 let type_ = &variant.type_;
                 format!("{name}({type_}),")
             }))
+            .chain(
+                self.error_variant_name
+                    .iter()
+                    .map(|name| format!("{name}(()),")),
+            )
             .collect::<Vec<_>>()
             .join("\n")
     }
@@ -517,14 +1266,7 @@ impl {node_enum_name} {{
     }
 
     fn get_number_of_rule_kinds(&self) -> usize {
-        self.file
-            .nonterminals
-            .iter()
-            .map(|nonterminal| match nonterminal {
-                Nonterminal::Struct(_) => 1,
-                Nonterminal::Enum(e) => e.variants.len(),
-            })
-            .sum()
+        self.file.get_rules().count()
     }
 
     fn get_pop_and_reduce_match_arms_src(&self) -> String {
@@ -534,8 +1276,13 @@ impl {node_enum_name} {{
             .enumerate()
             .map(|(rule_index, _)| {
                 let reduce_fn_name = self.get_reduce_fn_name(rule_index);
+                let span_args = if self.span_type.is_some() {
+                    ", spans, next_span"
+                } else {
+                    ""
+                };
                 format!(
-                    "{rule_kind_enum_name}::{RULE_KIND_VARIANT_PREFIX}{rule_index} => {reduce_fn_name}(states, nodes),"
+                    "{rule_kind_enum_name}::{RULE_KIND_VARIANT_PREFIX}{rule_index} => {reduce_fn_name}(states, nodes{span_args}),"
                 )
             })
             .collect::<Vec<_>>()
@@ -552,9 +1299,11 @@ impl {node_enum_name} {{
                     Rule {
                         constructor_name,
                         fieldset,
+                        action,
+                        ..
                     },
                 )| {
-                    self.get_reduce_fn_src(rule_index, constructor_name, fieldset)
+                    self.get_reduce_fn_src(rule_index, constructor_name, fieldset, action)
                 },
             )
             .collect::<Vec<_>>()
@@ -566,28 +1315,45 @@ impl {node_enum_name} {{
         rule_index: usize,
         constructor_name: ConstructorName,
         fieldset: &Fieldset,
+        action: Option<&SemanticAction>,
     ) -> String {
-        let reduction_code_indent_1 = match fieldset {
-            Fieldset::Empty => self.get_empty_fieldset_rule_reduction_src(constructor_name),
-            Fieldset::Named(NamedFieldset { fields }) => {
+        let reduction_code_indent_1 = match (constructor_name, fieldset, action) {
+            (_, _, Some(action)) => {
+                self.get_action_rule_reduction_src(constructor_name, fieldset, action)
+            }
+            (
+                ConstructorName::Helper {
+                    helper_name,
+                    reduction,
+                },
+                _,
+                None,
+            ) => self.get_helper_rule_reduction_src(helper_name, reduction, fieldset),
+            (_, Fieldset::Empty, None) => {
+                self.get_empty_fieldset_rule_reduction_src(constructor_name)
+            }
+            (_, Fieldset::Named(NamedFieldset { fields }), None) => {
                 self.get_named_fieldset_rule_reduction_src(constructor_name, fields)
             }
-            Fieldset::Tuple(TupleFieldset { fields }) => {
+            (_, Fieldset::Tuple(TupleFieldset { fields }), None) => {
                 self.get_tuple_fieldset_rule_reduction_src(constructor_name, fields)
             }
         }
         .indent(1);
         let state_enum_name = &self.state_enum_name;
         let node_enum_name = &self.node_enum_name;
-        let nonterminal_kind_enum_name = &self.nonterminal_kind_enum_name;
         let reduce_fn_name = self.get_reduce_fn_name(rule_index);
-        let (states_param_name, nodes_param_name) = match fieldset {
-            Fieldset::Empty => ("_states", "_nodes"),
-            Fieldset::Named(_) | Fieldset::Tuple(_) => ("states", "nodes"),
+        let is_empty = fieldset.is_empty();
+        let (states_param_name, nodes_param_name) = if is_empty {
+            ("_states", "_nodes")
+        } else {
+            ("states", "nodes")
         };
+        let span_params = self.get_span_params_src(is_empty);
+        let return_type = self.get_reduce_return_type_src();
 
         format!(
-            r#"fn {reduce_fn_name}({states_param_name}: &mut Vec<{state_enum_name}>, {nodes_param_name}: &mut Vec<{node_enum_name}>) -> ({node_enum_name}, {nonterminal_kind_enum_name}) {{
+            r#"fn {reduce_fn_name}({states_param_name}: &mut Vec<{state_enum_name}>, {nodes_param_name}: &mut Vec<{node_enum_name}>{span_params}) -> {return_type} {{
 {reduction_code_indent_1}
 }}"#
         )
@@ -598,17 +1364,15 @@ impl {node_enum_name} {{
     }
 
     fn get_empty_fieldset_rule_reduction_src(&self, constructor_name: ConstructorName) -> String {
-        let node_enum_name = &self.node_enum_name;
-        let nonterminal_kind_enum_name = &self.nonterminal_kind_enum_name;
         let parent_type_name = constructor_name.type_name();
         let constructor_name = constructor_name.to_string();
-
-        format!(
-            r#"(
-    {node_enum_name}::{parent_type_name}({constructor_name}),
-    {nonterminal_kind_enum_name}::{parent_type_name},
-)"#
-        )
+        let value = if self.span_type.is_some() {
+            format!("{constructor_name} {{ span: span.clone() }}")
+        } else {
+            constructor_name
+        };
+        let node_and_kind = self.get_node_and_kind_src(parent_type_name, &value);
+        self.get_empty_rule_reduction_src(&node_and_kind)
     }
 
     fn get_named_fieldset_rule_reduction_src(
@@ -616,30 +1380,24 @@ impl {node_enum_name} {{
         constructor_name: ConstructorName,
         fields: &[NamedField],
     ) -> String {
-        let node_enum_name = &self.node_enum_name;
-        let nonterminal_kind_enum_name = &self.nonterminal_kind_enum_name;
         let parent_type_name = constructor_name.type_name();
         let constructor_name = constructor_name.to_string();
         let child_vars: String = fields
             .iter()
             .enumerate()
             .rev()
-            .map(|(field_index, field)| match (&field.name, &field.symbol) {
-                (IdentOrUnderscore::Underscore(_), _) => "nodes.pop().unwrap();\n".to_owned(),
-                (IdentOrUnderscore::Ident(field_name), IdentOrTerminalIdent::Ident(field_type)) => {
-                    let field_name = &field_name.name;
-                    let field_type_name = &field_type.name;
-                    format!("let {field_name}_{field_index} = Box::new({field_type_name}::try_from(nodes.pop().unwrap()).ok().unwrap());\n")
-                },
-                (IdentOrUnderscore::Ident(field_name), IdentOrTerminalIdent::Terminal(field_type)) => {
+            .map(|(field_index, field)| match &field.name {
+                IdentOrUnderscore::Underscore(_) => "nodes.pop().unwrap();\n".to_owned(),
+                IdentOrUnderscore::Ident(field_name) => {
                     let field_name = &field_name.name;
-                    let try_into_method_name = self.node_to_terminal_method_names.get(&field_type.name).unwrap();
-                    format!("let {field_name}_{field_index} = nodes.pop().unwrap().{try_into_method_name}().ok().unwrap();\n")
+                    let child = self.get_popped_field_value_src(&field.symbol);
+                    format!("let {field_name}_{field_index} = {child};\n")
                 }
             })
             .collect();
 
         let num_fields = fields.len();
+        let span_decl = self.get_span_decl_src(num_fields);
 
         let parent_fields_indent_2 = fields
             .iter()
@@ -651,10 +1409,15 @@ impl {node_enum_name} {{
                     Some(format!("{field_name}: {field_name}_{field_index},"))
                 }
             })
+            .chain(
+                self.span_type
+                    .iter()
+                    .map(|_| "span: span.clone(),".to_owned()),
+            )
             .collect::<Vec<_>>()
             .join("\n")
             .indent(2);
-        let empty_str_or_curly_enclosed_fields = if fields.iter().any(NamedField::is_used) {
+        let empty_str_or_curly_enclosed_fields = if !parent_fields_indent_2.is_empty() {
             format!(
                 r#" {{
 {parent_fields_indent_2}
@@ -663,15 +1426,16 @@ impl {node_enum_name} {{
         } else {
             "".to_owned()
         };
+        let node_and_kind = self.get_node_and_kind_src(
+            parent_type_name,
+            &format!("{constructor_name}{empty_str_or_curly_enclosed_fields}"),
+        );
 
         format!(
             r#"{child_vars}
 states.truncate(states.len() - {num_fields});
-
-(
-    {node_enum_name}::{parent_type_name}({constructor_name}{empty_str_or_curly_enclosed_fields}),
-    {nonterminal_kind_enum_name}::{parent_type_name},
-)"#
+{span_decl}
+{node_and_kind}"#
         )
     }
 
@@ -681,8 +1445,6 @@ states.truncate(states.len() - {num_fields});
         fields: &[TupleField],
     ) -> String {
         const ANONYMOUS_FIELD_PREFIX: &str = "t";
-        let node_enum_name = &self.node_enum_name;
-        let nonterminal_kind_enum_name = &self.nonterminal_kind_enum_name;
         let parent_type_name = constructor_name.type_name();
         let constructor_name = constructor_name.to_string();
         let child_vars: String = fields
@@ -691,18 +1453,15 @@ states.truncate(states.len() - {num_fields});
             .rev()
             .map(|(field_index, field)| match field {
                 TupleField::Skipped(_) => "nodes.pop().unwrap();\n".to_owned(),
-                TupleField::Used(IdentOrTerminalIdent::Ident(field_type)) => {
-                    let field_type_name = &field_type.name;
-                    format!("let {ANONYMOUS_FIELD_PREFIX}{field_index} = Box::new({field_type_name}::try_from(nodes.pop().unwrap()).ok().unwrap());\n")
-                },
-                TupleField::Used(IdentOrTerminalIdent::Terminal(field_type)) => {
-                    let try_into_method_name = self.node_to_terminal_method_names.get(&field_type.name).unwrap();
-                    format!("let {ANONYMOUS_FIELD_PREFIX}{field_index} = nodes.pop().unwrap().{try_into_method_name}().ok().unwrap();\n")
-                },
+                TupleField::Used(symbol) => {
+                    let child = self.get_popped_field_value_src(symbol);
+                    format!("let {ANONYMOUS_FIELD_PREFIX}{field_index} = {child};\n")
+                }
             })
             .collect();
 
         let num_fields = fields.len();
+        let span_decl = self.get_span_decl_src(num_fields);
 
         let parent_fields_indent_2 = fields
             .iter()
@@ -711,27 +1470,490 @@ states.truncate(states.len() - {num_fields});
                 TupleField::Skipped(_) => None,
                 TupleField::Used(_) => Some(format!("{ANONYMOUS_FIELD_PREFIX}{field_index},")),
             })
+            .chain(self.span_type.iter().map(|_| "span.clone(),".to_owned()))
             .collect::<Vec<_>>()
             .join("\n")
             .indent(2);
+        // If every field is skipped, the fieldset is generated like an empty one
+        // (see `get_empty_fieldset_src`).
         let empty_str_or_parenthesized_fields = if fields.iter().any(TupleField::is_used) {
             format!(
                 r#"(
 {parent_fields_indent_2}
     )"#
             )
+        } else if self.span_type.is_some() {
+            " { span: span.clone() }".to_owned()
+        } else {
+            "".to_owned()
+        };
+        let node_and_kind = self.get_node_and_kind_src(
+            parent_type_name,
+            &format!("{constructor_name}{empty_str_or_parenthesized_fields}"),
+        );
+
+        format!(
+            r#"{child_vars}
+states.truncate(states.len() - {num_fields});
+{span_decl}
+{node_and_kind}"#
+        )
+    }
+
+    /// The popped children are bound to variables
+    /// that the user's code can reference.
+    /// Named fields are bound to their names,
+    /// and used tuple fields are bound to `_0`, `_1`, etc.
+    /// (numbered in the same way as the fields of a generated tuple struct).
+    fn get_action_rule_reduction_src(
+        &self,
+        constructor_name: ConstructorName,
+        fieldset: &Fieldset,
+        action: &SemanticAction,
+    ) -> String {
+        const ANONYMOUS_FIELD_PREFIX: &str = "t";
+        let parent_type_name = constructor_name.type_name();
+        let result_type = self.file.get_result_type(parent_type_name).unwrap();
+        let body_indent_1 = get_semantic_action_body_src(action).indent(1);
+
+        // Each item is `(symbol, Some((var, binding)))` for a used field
+        // or `(symbol, None)` for a skipped field.
+        let fields: Vec<(&IdentOrTerminalIdent, Option<(String, String)>)> = match fieldset {
+            Fieldset::Empty => vec![],
+            Fieldset::Named(NamedFieldset { fields }) => fields
+                .iter()
+                .enumerate()
+                .map(|(field_index, field)| match &field.name {
+                    IdentOrUnderscore::Underscore(_) => (&field.symbol, None),
+                    IdentOrUnderscore::Ident(field_name) => {
+                        let field_name = &field_name.name;
+                        let var = format!("{field_name}_{field_index}");
+                        (&field.symbol, Some((var, field_name.clone())))
+                    }
+                })
+                .collect(),
+            Fieldset::Tuple(TupleFieldset { fields }) => {
+                let mut used_field_count = 0;
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(|(field_index, field)| match field {
+                        TupleField::Skipped(symbol) => (symbol, None),
+                        TupleField::Used(symbol) => {
+                            let var = format!("{ANONYMOUS_FIELD_PREFIX}{field_index}");
+                            let binding = format!("_{used_field_count}");
+                            used_field_count += 1;
+                            (symbol, Some((var, binding)))
+                        }
+                    })
+                    .collect()
+            }
+        };
+
+        let node_and_kind = self.get_node_and_kind_src(parent_type_name, "value");
+
+        if fields.is_empty() {
+            return self.get_empty_rule_reduction_src(&format!(
+                r#"let value: {result_type} = {{
+{body_indent_1}
+}};
+
+{node_and_kind}"#
+            ));
+        }
+
+        let child_vars: String = fields
+            .iter()
+            .rev()
+            .map(|(symbol, var_and_binding)| match var_and_binding {
+                None => "nodes.pop().unwrap();\n".to_owned(),
+                Some((var, _)) => {
+                    let child = self.get_popped_field_value_src(symbol);
+                    format!("let {var} = {child};\n")
+                }
+            })
+            .collect();
+        let num_fields = fields.len();
+        let span_decl = self.get_span_decl_src(num_fields);
+        let bindings_indent_1 = fields
+            .iter()
+            .filter_map(|(_, var_and_binding)| {
+                var_and_binding
+                    .as_ref()
+                    .map(|(var, binding)| format!("let {binding} = {var};\n"))
+            })
+            .collect::<String>()
+            .indent(1);
+
+        format!(
+            r#"{child_vars}
+states.truncate(states.len() - {num_fields});
+{span_decl}
+let value: {result_type} = {{
+{bindings_indent_1}{body_indent_1}
+}};
+
+{node_and_kind}"#
+        )
+    }
+
+    fn get_helper_rule_reduction_src(
+        &self,
+        helper_name: &str,
+        reduction: HelperReduction,
+        fieldset: &Fieldset,
+    ) -> String {
+        const ANONYMOUS_FIELD_PREFIX: &str = "t";
+        let helper = self.file.get_helper(helper_name).unwrap();
+        let fields: &[TupleField] = match fieldset {
+            Fieldset::Empty => &[],
+            Fieldset::Tuple(TupleFieldset { fields }) => fields,
+            Fieldset::Named(_) => panic!("Helper rules never have named fieldsets."),
+        };
+        let used_field_vars: Vec<String> = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.is_used())
+            .map(|(field_index, _)| format!("{ANONYMOUS_FIELD_PREFIX}{field_index}"))
+            .collect();
+
+        let (statements, value) = match reduction {
+            HelperReduction::EmptyVec => ("".to_owned(), "vec![]".to_owned()),
+            HelperReduction::SingletonVec => {
+                ("".to_owned(), format!("vec![{}]", used_field_vars[0]))
+            }
+            HelperReduction::None => ("".to_owned(), "None".to_owned()),
+            HelperReduction::Some => ("".to_owned(), format!("Some({})", used_field_vars[0])),
+            HelperReduction::Identity => ("".to_owned(), used_field_vars[0].clone()),
+            HelperReduction::Push => (
+                format!("{}.push({});\n\n", used_field_vars[0], used_field_vars[1]),
+                used_field_vars[0].clone(),
+            ),
+        };
+        let node_and_kind = self.get_node_and_kind_src(helper_name, &value);
+
+        if fields.is_empty() {
+            return self.get_empty_rule_reduction_src(&node_and_kind);
+        }
+
+        let child_vars: String = fields
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(field_index, field)| match field {
+                TupleField::Skipped(_) => "nodes.pop().unwrap();\n".to_owned(),
+                TupleField::Used(symbol) => {
+                    let var = format!("{ANONYMOUS_FIELD_PREFIX}{field_index}");
+                    let mut_ = if reduction == HelperReduction::Push && var == used_field_vars[0] {
+                        "mut "
+                    } else {
+                        ""
+                    };
+                    let child = match helper.kind {
+                        HelperKind::Vec => self.get_popped_element_value_src(symbol),
+                        HelperKind::Option => self.get_popped_field_value_src(symbol),
+                    };
+                    format!("let {mut_}{var} = {child};\n")
+                }
+            })
+            .collect();
+        let num_fields = fields.len();
+        let span_decl = self.get_span_decl_src(num_fields);
+
+        format!(
+            r#"{child_vars}
+states.truncate(states.len() - {num_fields});
+{span_decl}
+{statements}{node_and_kind}"#
+        )
+    }
+
+    /// Returns the tuple that a reduce function returns.
+    /// If the terminal enum is spanned,
+    /// the tuple ends with the new node's span.
+    fn get_node_and_kind_src(&self, type_name: &str, value: &str) -> String {
+        let node_enum_name = &self.node_enum_name;
+        let nonterminal_kind_enum_name = &self.nonterminal_kind_enum_name;
+        let possible_span = if self.span_type.is_some() {
+            "\n    span,"
+        } else {
+            ""
+        };
+        format!(
+            r#"(
+    {node_enum_name}::{type_name}({value}),
+    {nonterminal_kind_enum_name}::{type_name},{possible_span}
+)"#
+        )
+    }
+
+    /// Returns the statements that pop the children's spans
+    /// and bind the new node's span to `span`.
+    /// The new node's span starts where its first child's span starts
+    /// and ends where its last child's span ends.
+    ///
+    /// If the terminal enum is unspanned, this returns the empty string.
+    fn get_span_decl_src(&self, num_fields: usize) -> String {
+        if self.span_type.is_none() {
+            return "".to_owned();
+        }
+
+        match num_fields {
+            0 => "let span = get_empty_span(spans, next_span);\n".to_owned(),
+            1 => "let span = spans.pop().unwrap();\n".to_owned(),
+            2 => r#"let span_end = spans.pop().unwrap().end;
+let span_start = spans.pop().unwrap().start;
+let span = span_start..span_end;
+"#
+            .to_owned(),
+            _ => {
+                let num_middle_fields = num_fields - 2;
+                format!(
+                    r#"let span_end = spans.pop().unwrap().end;
+spans.truncate(spans.len() - {num_middle_fields});
+let span_start = spans.pop().unwrap().start;
+let span = span_start..span_end;
+"#
+                )
+            }
+        }
+    }
+
+    /// Empty rules have no children to pop,
+    /// so the only thing a reduce function may need to do
+    /// before returning is compute the (empty) span.
+    fn get_empty_rule_reduction_src(&self, node_and_kind: &str) -> String {
+        let span_decl = self.get_span_decl_src(0);
+        if span_decl.is_empty() {
+            return node_and_kind.to_owned();
+        }
+
+        format!("{span_decl}\n{node_and_kind}")
+    }
+
+    /// Returns the extra parameters of the reduce functions
+    /// (including `pop_and_reduce`).
+    /// If the terminal enum is unspanned, this returns the empty string.
+    ///
+    /// Only `pop_and_reduce` and the reduce functions of empty rules
+    /// use `next_span`.
+    fn get_span_params_src(&self, uses_next_span: bool) -> String {
+        let Some(span_type) = &self.span_type else {
+            return "".to_owned();
+        };
+        let next_span_param_name = if uses_next_span {
+            "next_span"
+        } else {
+            "_next_span"
+        };
+        format!(", spans: &mut Vec<{span_type}>, {next_span_param_name}: Option<&{span_type}>")
+    }
+
+    fn get_reduce_return_type_src(&self) -> String {
+        let node_enum_name = &self.node_enum_name;
+        let nonterminal_kind_enum_name = &self.nonterminal_kind_enum_name;
+        match &self.span_type {
+            Some(span_type) => {
+                format!("({node_enum_name}, {nonterminal_kind_enum_name}, {span_type})")
+            }
+            None => format!("({node_enum_name}, {nonterminal_kind_enum_name})"),
+        }
+    }
+
+    /// An empty rule's span is empty.
+    /// It is positioned at the end of the previous node (if any),
+    /// or else at the start of the next token (if any).
+    /// If the input is empty, the position is `Default::default()`.
+    ///
+    /// The recovering parse functions also use this function
+    /// to position `error` nodes that replace no other nodes.
+    ///
+    /// If the terminal enum is unspanned,
+    /// or if there are no empty rules and no `error` rules,
+    /// this returns the empty string.
+    /// This way, the position type only needs to implement `Default`
+    /// if the grammar has empty rules or `error` rules.
+    fn get_get_empty_span_fn_src(&self) -> String {
+        let Some(span_type) = &self.span_type else {
+            return "".to_owned();
+        };
+        if !self.file.get_rules().any(|rule| rule.fieldset.is_empty())
+            && self.error_variant_name.is_none()
+        {
+            return "".to_owned();
+        }
+
+        format!(
+            r#"
+fn get_empty_span(spans: &[{span_type}], next_span: Option<&{span_type}>) -> {span_type} {{
+    let position = spans
+        .last()
+        .map(|span| span.end.clone())
+        .or_else(|| next_span.map(|span| span.start.clone()))
+        .unwrap_or_default();
+    position.clone()..position
+}}
+"#
+        )
+    }
+
+    /// If the terminal enum is spanned,
+    /// the parser needs the next token's span
+    /// to position empty rules.
+    /// Otherwise, this returns the empty string.
+    fn get_quasiterminal_span_fn_src(&self) -> String {
+        let Some(span_type) = &self.span_type else {
+            return "".to_owned();
+        };
+        let eof_variant_name = &self.eof_variant_name;
+        format!(
+            r#"
+
+fn span(&self) -> Option<&{span_type}> {{
+    match self {{
+        Self::Terminal((_, span)) => Some(span),
+        Self::{eof_variant_name} => None,
+    }}
+}}"#
+        )
+    }
+
+    /// Returns an expression that pops a node
+    /// and converts it into the type of a field with the given symbol.
+    fn get_popped_field_value_src(&self, symbol: &IdentOrTerminalIdent) -> String {
+        match symbol {
+            IdentOrTerminalIdent::Ident(ident) if self.is_boxed_nonterminal(&ident.name) => {
+                let unboxed = self.get_popped_element_value_src(symbol);
+                format!("Box::new({unboxed})")
+            }
+            _ => self.get_popped_element_value_src(symbol),
+        }
+    }
+
+    /// Returns an expression that pops a node
+    /// and converts it into the _unboxed_ value of the given symbol.
+    fn get_popped_element_value_src(&self, symbol: &IdentOrTerminalIdent) -> String {
+        match symbol {
+            IdentOrTerminalIdent::Ident(ident) => {
+                self.get_node_to_nonterminal_value_src(&ident.name, "nodes.pop().unwrap()")
+            }
+            IdentOrTerminalIdent::Terminal(terminal) => {
+                let try_into_method_name = self
+                    .node_to_terminal_method_names
+                    .get(&terminal.name)
+                    .unwrap();
+                format!("nodes.pop().unwrap().{try_into_method_name}().ok().unwrap()")
+            }
+        }
+    }
+
+    /// Returns an expression that converts the node `node_src`
+    /// into the value of the given nonterminal.
+    fn get_node_to_nonterminal_value_src(&self, nonterminal_name: &str, node_src: &str) -> String {
+        match self.node_to_nonterminal_method_names.get(nonterminal_name) {
+            Some(try_into_method_name) => {
+                format!("{node_src}.{try_into_method_name}().ok().unwrap()")
+            }
+            None => format!("{nonterminal_name}::try_from({node_src}).ok().unwrap()"),
+        }
+    }
+
+    /// An extern terminal enum may have variants
+    /// that the grammar does not map to any terminal.
+    /// Therefore, for extern enums, `from_quasiterminal` returns `None`
+    /// if the quasiterminal is an unmapped terminal.
+    fn get_impl_display_quasiterminal_kind_src(&self) -> String {
+        let quasiterminal_kind_enum_name = &self.quasiterminal_kind_enum_name;
+        let eof_variant_name = &self.eof_variant_name;
+        let match_arms_indent_3 = self
+            .file
+            .terminal_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                let name = variant.dollarless_name.raw();
+                let display_name = match &variant.literal {
+                    Some(literal) => format!("{literal:?}"),
+                    None => format!("${name}"),
+                };
+                format!("Self::{name} => {display_name:?},")
+            })
+            .chain(
+                self.error_variant_name
+                    .iter()
+                    .map(|name| format!("Self::{name} => \"error\",")),
+            )
+            .collect::<Vec<_>>()
+            .join("\n")
+            .indent(3);
+
+        format!(
+            r#"/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for {quasiterminal_kind_enum_name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.write_str(match self {{
+{match_arms_indent_3}
+            Self::{eof_variant_name} => "end of input",
+        }})
+    }}
+}}"#
+        )
+    }
+
+    fn get_impl_quasiterminal_kind_src(&self) -> String {
+        let Self {
+            terminal_enum_name,
+            eof_variant_name,
+            quasiterminal_enum_name,
+            quasiterminal_kind_enum_name,
+            ..
+        } = self;
+        let quasiterminal_kind_from_terminal_match_arms_indent_3 = self
+            .get_quasiterminal_kind_from_terminal_match_arms_src()
+            .indent(3);
+        let terminal_binding = if self.span_type.is_some() {
+            "(terminal, _)"
         } else {
-            "".to_owned()
+            "terminal"
         };
 
+        if !self.file.terminal_enum.is_extern {
+            return format!(
+                r#"impl {quasiterminal_kind_enum_name} {{
+    fn from_quasiterminal(quasiterminal: &{quasiterminal_enum_name}) -> Self {{
+        match quasiterminal {{
+            {quasiterminal_enum_name}::Terminal({terminal_binding}) => Self::from_terminal(terminal),
+            {quasiterminal_enum_name}::{eof_variant_name} => Self::{eof_variant_name},
+        }}
+    }}
+
+    fn from_terminal(terminal: &{terminal_enum_name}) -> Self {{
+        match terminal {{
+{quasiterminal_kind_from_terminal_match_arms_indent_3}
+        }}
+    }}
+}}"#
+            );
+        }
+
         format!(
-            r#"{child_vars}
-states.truncate(states.len() - {num_fields});
+            r#"impl {quasiterminal_kind_enum_name} {{
+    fn from_quasiterminal(quasiterminal: &{quasiterminal_enum_name}) -> Option<Self> {{
+        match quasiterminal {{
+            {quasiterminal_enum_name}::Terminal({terminal_binding}) => Self::from_terminal(terminal),
+            {quasiterminal_enum_name}::{eof_variant_name} => Some(Self::{eof_variant_name}),
+        }}
+    }}
 
-(
-    {node_enum_name}::{parent_type_name}({constructor_name}{empty_str_or_parenthesized_fields}),
-    {nonterminal_kind_enum_name}::{parent_type_name},
-)"#
+    fn from_terminal(terminal: &{terminal_enum_name}) -> Option<Self> {{
+        match terminal {{
+{quasiterminal_kind_from_terminal_match_arms_indent_3}
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }}
+    }}
+}}"#
         )
     }
 
@@ -743,7 +1965,13 @@ states.truncate(states.len() - {num_fields});
             .iter()
             .map(|variant| {
                 let name = variant.dollarless_name.raw();
-                format!("{terminal_enum_name}::{name}(_) => Self::{name},")
+                match &variant.pattern {
+                    Some(pattern) => {
+                        let pattern_src = get_terminal_pattern_src(pattern, false);
+                        format!("{terminal_enum_name}::{pattern_src} => Some(Self::{name}),")
+                    }
+                    None => format!("{terminal_enum_name}::{name}(_) => Self::{name},"),
+                }
             })
             .collect::<Vec<_>>()
             .join("\n")
@@ -751,16 +1979,269 @@ states.truncate(states.len() - {num_fields});
 
     fn get_node_from_terminal_match_arms_src(&self) -> String {
         let terminal_enum_name = &self.terminal_enum_name;
-        self.file
+        let arms = self
+            .file
             .terminal_enum
             .variants
             .iter()
             .map(|variant| {
                 let name = variant.dollarless_name.raw();
-                format!("{terminal_enum_name}::{name}(t) => Self::{name}(t),")
+                match &variant.pattern {
+                    Some(pattern) => {
+                        let pattern_src = get_terminal_pattern_src(pattern, true);
+                        let value_src = get_terminal_pattern_binding(pattern).unwrap_or("()");
+                        format!("{terminal_enum_name}::{pattern_src} => Self::{name}({value_src}),")
+                    }
+                    None => format!("{terminal_enum_name}::{name}(t) => Self::{name}(t),"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if !self.file.terminal_enum.is_extern {
+            return arms;
+        }
+
+        // The parser never shifts an unmapped terminal,
+        // so this arm is never reached.
+        format!(
+            r#"{arms}
+#[allow(unreachable_patterns)]
+_ => unreachable!(),"#
+        )
+    }
+
+    fn get_action_table_src(&self) -> String {
+        let Self {
+            table,
+            state_enum_name,
+            quasiterminal_kind_enum_name,
+            action_enum_name,
+            action_table_name,
+            ..
+        } = self;
+
+        if let Some((compressed, names)) = &self.compressed {
+            return self.get_compressed_action_table_src(compressed, names);
+        }
+
+        let num_of_quasiterminal_kind_variants = table.terminals.len() + 1;
+        let num_of_state_variants = table.state_count();
+        let action_table_rows_indent_1 = self.get_action_table_rows_src().indent(1);
+        format!(
+            r#"static {action_table_name}: [[{action_enum_name}; {num_of_quasiterminal_kind_variants}]; {num_of_state_variants}] = [
+{action_table_rows_indent_1}
+];
+
+fn get_action(top_state: {state_enum_name}, next_quasiterminal_kind: {quasiterminal_kind_enum_name}) -> {action_enum_name} {{
+    {action_table_name}[top_state as usize][next_quasiterminal_kind as usize]
+}}"#
+        )
+    }
+
+    /// If no state reduces by the same rule on every lookahead,
+    /// this returns the empty string.
+    fn get_default_reduction_table_src(&self) -> String {
+        let Some(default_reductions_name) = &self.default_reductions_name else {
+            return "".to_owned();
+        };
+        let Self {
+            table,
+            state_enum_name,
+            rule_kind_enum_name,
+            ..
+        } = self;
+        let num_of_state_variants = table.state_count();
+        let rows_indent_1 = (0..num_of_state_variants)
+            .map(|i| match get_default_reduction(table, StateIndex(i)) {
+                Some(rule_index) => {
+                    format!("Some({rule_kind_enum_name}::{RULE_KIND_VARIANT_PREFIX}{rule_index}),")
+                }
+                None => "None,".to_owned(),
             })
             .collect::<Vec<_>>()
             .join("\n")
+            .indent(1);
+        format!(
+            r#"
+
+static {default_reductions_name}: [Option<{rule_kind_enum_name}>; {num_of_state_variants}] = [
+{rows_indent_1}
+];
+
+/// If the state reduces by the same rule on every lookahead,
+/// the parser reduces without reading the next token.
+fn get_default_reduction(top_state: {state_enum_name}) -> Option<{rule_kind_enum_name}> {{
+    {default_reductions_name}[top_state as usize]
+}}"#
+        )
+    }
+
+    fn get_compressed_action_table_src(
+        &self,
+        compressed: &CompressedTable,
+        names: &CompressedTableNames,
+    ) -> String {
+        let Self {
+            state_enum_name,
+            quasiterminal_kind_enum_name,
+            action_enum_name,
+            ..
+        } = self;
+        let CompressedTableNames {
+            action_bases_name,
+            action_checks_name,
+            action_values_name,
+            action_defaults_name,
+            states_name,
+            rule_kinds_name,
+            ..
+        } = names;
+        let arrays = get_compressed_rows_arrays_src(
+            &compressed.actions,
+            [
+                action_defaults_name,
+                action_bases_name,
+                action_checks_name,
+                action_values_name,
+            ],
+        );
+        let lookup = get_compressed_rows_lookup_src(
+            "next_quasiterminal_kind",
+            [
+                action_defaults_name,
+                action_bases_name,
+                action_checks_name,
+                action_values_name,
+            ],
+        )
+        .indent(1);
+        let first_reduce_code = 2 + compressed.state_count;
+        format!(
+            r#"{arrays}
+
+fn get_action(top_state: {state_enum_name}, next_quasiterminal_kind: {quasiterminal_kind_enum_name}) -> {action_enum_name} {{
+{lookup}
+    decode_action(code)
+}}
+
+/// `0` is `Err`, `1` is `Accept`,
+/// `2 + s` is `Shift` to the `s`th state,
+/// and `{first_reduce_code} + r` is `Reduce` by the `r`th rule.
+fn decode_action(code: u32) -> {action_enum_name} {{
+    match code as usize {{
+        0 => {action_enum_name}::{ACTION_ERR_VARIANT_NAME},
+        1 => {action_enum_name}::{ACTION_ACCEPT_VARIANT_NAME},
+        code if code < {first_reduce_code} => {action_enum_name}::{ACTION_SHIFT_VARIANT_NAME}({states_name}[code - 2]),
+        code => {action_enum_name}::{ACTION_REDUCE_VARIANT_NAME}({rule_kinds_name}[code - {first_reduce_code}]),
+    }}
+}}"#
+        )
+    }
+
+    fn get_goto_table_src(&self) -> String {
+        let Self {
+            table,
+            file,
+            state_enum_name,
+            nonterminal_kind_enum_name,
+            goto_table_name,
+            ..
+        } = self;
+
+        if let Some((compressed, names)) = &self.compressed {
+            return self.get_compressed_goto_table_src(compressed, names);
+        }
+
+        let num_of_nonterminal_kind_variants = file.nonterminals.len();
+        let num_of_state_variants = table.state_count();
+        let goto_table_rows_indent_1 = self.get_goto_table_rows_src().indent(1);
+        format!(
+            r#"static {goto_table_name}: [[Option<{state_enum_name}>; {num_of_nonterminal_kind_variants}]; {num_of_state_variants}] = [
+{goto_table_rows_indent_1}
+];
+
+fn get_goto(top_state: {state_enum_name}, new_node_kind: {nonterminal_kind_enum_name}) -> Option<{state_enum_name}> {{
+    {goto_table_name}[top_state as usize][new_node_kind as usize]
+}}"#
+        )
+    }
+
+    fn get_compressed_goto_table_src(
+        &self,
+        compressed: &CompressedTable,
+        names: &CompressedTableNames,
+    ) -> String {
+        let Self {
+            state_enum_name,
+            nonterminal_kind_enum_name,
+            rule_kind_enum_name,
+            ..
+        } = self;
+        let CompressedTableNames {
+            goto_defaults_name,
+            goto_bases_name,
+            goto_checks_name,
+            goto_values_name,
+            states_name,
+            rule_kinds_name,
+            ..
+        } = names;
+        let arrays = get_compressed_rows_arrays_src(
+            &compressed.gotos,
+            [
+                goto_defaults_name,
+                goto_bases_name,
+                goto_checks_name,
+                goto_values_name,
+            ],
+        );
+        let lookup = get_compressed_rows_lookup_src(
+            "new_node_kind",
+            [
+                goto_defaults_name,
+                goto_bases_name,
+                goto_checks_name,
+                goto_values_name,
+            ],
+        )
+        .indent(1);
+        let num_of_state_variants = compressed.state_count;
+        let states_indent_1 = (0..num_of_state_variants)
+            .map(|i| format!("{state_enum_name}::{STATE_VARIANT_PREFIX}{i},"))
+            .collect::<Vec<_>>()
+            .join("\n")
+            .indent(1);
+        let num_of_rule_kinds = self.get_number_of_rule_kinds();
+        let rule_kinds_indent_1 = (0..num_of_rule_kinds)
+            .map(|i| format!("{rule_kind_enum_name}::{RULE_KIND_VARIANT_PREFIX}{i},"))
+            .collect::<Vec<_>>()
+            .join("\n")
+            .indent(1);
+        format!(
+            r#"{arrays}
+
+fn get_goto(top_state: {state_enum_name}, new_node_kind: {nonterminal_kind_enum_name}) -> Option<{state_enum_name}> {{
+{lookup}
+    decode_goto(code)
+}}
+
+/// `0` is `None`, and `1 + s` is the `s`th state.
+fn decode_goto(code: u32) -> Option<{state_enum_name}> {{
+    match code as usize {{
+        0 => None,
+        code => Some({states_name}[code - 1]),
+    }}
+}}
+
+static {states_name}: [{state_enum_name}; {num_of_state_variants}] = [
+{states_indent_1}
+];
+
+static {rule_kinds_name}: [{rule_kind_enum_name}; {num_of_rule_kinds}] = [
+{rule_kinds_indent_1}
+];"#
+        )
     }
 
     fn get_action_table_rows_src(&self) -> String {
@@ -774,12 +2255,9 @@ states.truncate(states.len() - {num_fields});
         let action_enum_name = &self.action_enum_name;
         let row_items_indent_1 = self
             .table
-            .terminals
+            .action_row(state_index)
             .iter()
-            .map(Quasiterminal::Terminal)
-            .chain(std::iter::once(Quasiterminal::Eof))
-            .map(|quasiterminal| {
-                let action = self.table.action(state_index, quasiterminal);
+            .map(|&action| {
                 let unqualified_variant = self.get_action_variant_unqualified_src(action);
                 format!("{action_enum_name}::{unqualified_variant},")
             })
@@ -814,10 +2292,9 @@ states.truncate(states.len() - {num_fields});
     fn get_goto_table_row_src(&self, state_index: StateIndex) -> String {
         let row_items_indent_1 = self
             .table
-            .nonterminals
+            .goto_row(state_index)
             .iter()
-            .map(|nonterminal| {
-                let goto = self.table.goto(state_index, nonterminal);
+            .map(|&goto| {
                 let qualified_variant = self.get_goto_variant_qualified_src(goto);
                 format!("{qualified_variant},")
             })
@@ -842,13 +2319,14 @@ states.truncate(states.len() - {num_fields});
         self.file
             .nonterminals
             .iter()
+            .filter(|nonterminal| has_generated_type(nonterminal))
             .map(|nonterminal| {
                 let nonterminal_name = nonterminal.name();
                 format!(
                     r#"impl TryFrom<{node_enum_name}> for {nonterminal_name} {{
     type Error = {node_enum_name};
 
-    fn try_from(node: {node_enum_name}) -> Result<Self, Self::Error> {{
+    fn try_from(node: {node_enum_name}) -> Result<Self, {node_enum_name}> {{
         match node {{
             {node_enum_name}::{nonterminal_name}(n) => Ok(n),
             _ => Err(node),
@@ -862,17 +2340,27 @@ states.truncate(states.len() - {num_fields});
     }
 
     fn get_node_try_into_terminal_variant_name_variant_index_fns_src(&self) -> String {
+        let error_name_and_variant_name = self
+            .error_variant_name
+            .as_ref()
+            .map(|variant_name| (DollarlessTerminalName::error(), variant_name.as_str()));
         self.file
             .terminal_enum
             .variants
             .iter()
             .map(|variant| {
-                let variant_name_original_case = variant.dollarless_name.raw();
+                (
+                    variant.dollarless_name.clone(),
+                    variant.dollarless_name.raw(),
+                )
+            })
+            .chain(error_name_and_variant_name)
+            .map(|(terminal_name, variant_name_original_case)| {
                 let method_name = self
                     .node_to_terminal_method_names
-                    .get(&variant.dollarless_name)
+                    .get(&terminal_name)
                     .unwrap();
-                let type_ = &variant.type_;
+                let type_ = self.get_terminal_type_src(&terminal_name);
                 format!(
                     r#"fn {method_name}(self) -> Result<{type_}, Self> {{
     match self {{
@@ -887,26 +2375,60 @@ states.truncate(states.len() - {num_fields});
     }
 }
 
+impl SrcBuilder<'_> {
+    /// Helper values (e.g., `Vec<Foo>`) are standard library types,
+    /// so we cannot implement `TryFrom<Node>` for them
+    /// (two helpers may even share the same type).
+    /// The same goes for nonterminals with declared result types.
+    /// Instead, we generate a method for each such nonterminal.
+    ///
+    /// If there are no such nonterminals, this returns the empty string.
+    fn get_node_try_into_nonterminal_fns_src(&self) -> String {
+        let node_enum_name = &self.node_enum_name;
+        let fns_indent_1 = self
+            .file
+            .nonterminals
+            .iter()
+            .filter(|nonterminal| !has_generated_type(nonterminal))
+            .map(|nonterminal| {
+                let nonterminal_name = nonterminal.name();
+                let method_name = self
+                    .node_to_nonterminal_method_names
+                    .get(nonterminal_name)
+                    .unwrap();
+                let type_ = self.get_nonterminal_value_type_src(nonterminal_name);
+                format!(
+                    r#"fn {method_name}(self) -> Result<{type_}, Self> {{
+    match self {{
+        Self::{nonterminal_name}(t) => Ok(t),
+        _ => Err(self),
+    }}
+}}"#
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+            .indent(1);
+
+        if fns_indent_1.is_empty() {
+            return "".to_owned();
+        }
+
+        format!("\nimpl {node_enum_name} {{\n{fns_indent_1}\n}}\n")
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct GetFieldsetSrcOptions {
     use_semicolon_if_unnamed: bool,
     use_pub_on_named_fields: bool,
 }
 
-fn create_unique_identifier(preferred_name: &str, used: &mut HashSet<String>) -> String {
-    if !used.contains(preferred_name) {
-        used.insert(preferred_name.to_string());
-        return preferred_name.to_string();
-    }
-
-    let mut i = 2;
-    loop {
-        let name = format!("{}{}", preferred_name, i);
-        if !used.contains(&name) {
-            used.insert(name.clone());
-            return name;
-        }
-        i += 1;
+fn get_pub_src(options: GetFieldsetSrcOptions) -> &'static str {
+    if options.use_pub_on_named_fields {
+        "pub "
+    } else {
+        ""
     }
 }
 
@@ -932,28 +2454,239 @@ impl Indent for str {
     }
 }
 
-fn pascal_to_snake_case(s: &str) -> String {
-    let mut out = String::new();
-    let mut chars = s.chars().fuse();
+/// Returns the code inside the semantic action's curly braces.
+/// The common indentation of all lines but the first is removed,
+/// so that the code can be reindented.
+fn get_semantic_action_body_src(action: &SemanticAction) -> String {
+    let code = action.code();
+    let body = code["{".len()..code.len() - "}".len()].trim();
+    let mut lines = body.lines();
+    let first_line = lines.next().unwrap_or("");
+    let rest: Vec<&str> = lines.collect();
+    let common_indent = rest
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    std::iter::once(first_line)
+        .chain(
+            rest.iter()
+                .map(|line| line.get(common_indent..).unwrap_or("").trim_end()),
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the source of `pattern`, without the enum path.
+/// If `keep_binding` is `false`, the binding (if any) is replaced by `_`.
+fn get_terminal_pattern_src(pattern: &TerminalPattern, keep_binding: bool) -> String {
+    let variant_name = &pattern.variant.name;
+    let Some(fields) = &pattern.fields else {
+        return variant_name.to_owned();
+    };
+    let fields_src = fields
+        .iter()
+        .map(|field| match field {
+            IdentOrUnderscore::Ident(ident) if keep_binding => ident.name.as_str(),
+            _ => "_",
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{variant_name}({fields_src})")
+}
 
-    if let Some(c) = chars.next() {
-        out.push(c.to_ascii_lowercase());
+/// Returns the rule that the state reduces by,
+/// if it reduces by that rule on every lookahead.
+/// The `error` pseudo-terminal is not a lookahead,
+/// since it never comes from the input.
+fn get_default_reduction(table: &Table, state_index: StateIndex) -> Option<usize> {
+    // The row ends with the action on EOF, which is a lookahead.
+    let is_lookahead = table
+        .terminals
+        .iter()
+        .map(|terminal| !terminal.is_error())
+        .chain([true]);
+    let mut actions = table
+        .action_row(state_index)
+        .iter()
+        .zip(is_lookahead)
+        .filter_map(|(&action, is_lookahead)| is_lookahead.then_some(action));
+    let Action::Reduce(rule_index) = actions.next()? else {
+        return None;
+    };
+    if actions.all(|action| action == Action::Reduce(rule_index)) {
+        Some(rule_index)
+    } else {
+        None
     }
+}
 
-    for c in chars {
-        if c.is_uppercase() {
-            out.push('_');
-        }
-        out.push(c.to_ascii_lowercase());
+fn get_recovering_parse_fn_doc_src() -> &'static str {
+    r#"/// Unlike the other parse functions, this function recovers from syntax errors
+/// using the grammar's `error` rules.
+/// It returns every error it recovered from (in the order they occurred),
+/// along with the parsed value if it reached the end of the input.
+/// The parts of the input that it recovered from are replaced by `error` values."#
+}
+
+fn get_terminal_pattern_binding(pattern: &TerminalPattern) -> Option<&str> {
+    pattern
+        .fields
+        .iter()
+        .flatten()
+        .find_map(|field| match field {
+            IdentOrUnderscore::Ident(ident) => Some(ident.name.as_str()),
+            IdentOrUnderscore::Underscore(_) => None,
+        })
+}
+
+/// `names` are the names of the defaults, bases, checks, and values arrays,
+/// in that order.
+fn get_compressed_rows_arrays_src(rows: &CompressedRows, names: [&String; 4]) -> String {
+    let [defaults_name, bases_name, checks_name, values_name] = names;
+    [
+        (defaults_name, &rows.defaults),
+        (bases_name, &rows.bases),
+        (checks_name, &rows.checks),
+        (values_name, &rows.values),
+    ]
+    .into_iter()
+    .map(|(name, values)| {
+        let len = values.len();
+        let items_indent_1 = get_u32_array_items_src(values).indent(1);
+        format!("static {name}: [u32; {len}] = [\n{items_indent_1}\n];")
+    })
+    .collect::<Vec<_>>()
+    .join("\n\n")
+}
+
+fn get_u32_array_items_src(values: &[u32]) -> String {
+    const VALUES_PER_LINE: usize = 16;
+    values
+        .chunks(VALUES_PER_LINE)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .map(|value| format!("{value},"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns statements that look up the `top_state` row
+/// and the `column_param_name` column,
+/// and bind the result to `code`.
+/// `names` are in the same order as in `get_compressed_rows_arrays_src`.
+fn get_compressed_rows_lookup_src(column_param_name: &str, names: [&String; 4]) -> String {
+    let [defaults_name, bases_name, checks_name, values_name] = names;
+    format!(
+        r#"let row = top_state as usize;
+let i = {bases_name}[row] as usize + {column_param_name} as usize;
+let code = if {checks_name}[i] as usize == row {{
+    {values_name}[i]
+}} else {{
+    {defaults_name}[row]
+}};"#
+    )
+}
+
+/// Returns true if Kiki generates a struct or enum definition
+/// for the nonterminal.
+fn has_generated_type(nonterminal: &Nonterminal) -> bool {
+    match nonterminal {
+        Nonterminal::Struct(_) | Nonterminal::Enum(_) => nonterminal.result_type().is_none(),
+        Nonterminal::Helper(_) => false,
     }
+}
 
-    out
+#[derive(Debug)]
+struct ParseFnNames {
+    /// The names of the public parse functions
+    /// (one per start symbol, in the same order as `file.starts`).
+    parse_fn_names: Vec<String>,
+    /// The names of the verbose counterparts of `parse_fn_names`
+    /// (in the same order).
+    verbose_parse_fn_names: Vec<String>,
+    /// The names of the recovering counterparts of `parse_fn_names`
+    /// (in the same order).
+    recovering_parse_fn_names: Vec<String>,
+    /// The name of the private function that the verbose functions delegate to.
+    parse_from_state_fn_name: String,
+    /// The name of the private function that the recovering functions delegate to.
+    recovering_parse_from_state_fn_name: String,
+}
+
+/// If there is only one start symbol, its parse function is simply named `parse`.
+fn get_parse_fn_names(file: &File) -> ParseFnNames {
+    let used_fn_names = &mut [
+        "parse",
+        "pop_and_reduce",
+        "get_action",
+        "get_goto",
+        "get_empty_span",
+        "lex",
+        "get_lexer_transition",
+    ]
+    .into_iter()
+    .map(str::to_owned)
+    .collect();
+
+    let parse_fn_names = if file.starts.len() == 1 {
+        vec!["parse".to_owned()]
+    } else {
+        file.starts
+            .iter()
+            .map(|start| {
+                let start_snake_case = pascal_to_snake_case(start);
+                create_unique_identifier(&format!("parse_{start_snake_case}"), used_fn_names)
+            })
+            .collect()
+    };
+    let verbose_parse_fn_names = parse_fn_names
+        .iter()
+        .map(|parse_fn_name| {
+            create_unique_identifier(&format!("{parse_fn_name}_verbose"), used_fn_names)
+        })
+        .collect();
+    let recovering_parse_fn_names = parse_fn_names
+        .iter()
+        .map(|parse_fn_name| {
+            create_unique_identifier(&format!("{parse_fn_name}_recovering"), used_fn_names)
+        })
+        .collect();
+    let parse_from_state_fn_name = create_unique_identifier("parse_from_state", used_fn_names);
+    let recovering_parse_from_state_fn_name =
+        create_unique_identifier("parse_recovering_from_state", used_fn_names);
+
+    ParseFnNames {
+        parse_fn_names,
+        verbose_parse_fn_names,
+        recovering_parse_fn_names,
+        parse_from_state_fn_name,
+        recovering_parse_from_state_fn_name,
+    }
 }
 
 fn get_attributes_src_with_newline_after_each_attribute(attributes: &[Attribute]) -> String {
     attributes.iter().map(|a| format!("{}\n", &a.src)).collect()
 }
 
+fn get_terminal_variant_doc_src(variant: &TerminalVariant) -> String {
+    match &variant.literal {
+        Some(literal) if variant.doc.is_empty() => format!("/// The literal `{literal:?}`.\n"),
+        _ => get_doc_src_with_newline_after_each_line(&variant.doc),
+    }
+}
+
+fn get_doc_src_with_newline_after_each_line(doc: &[DocComment]) -> String {
+    doc.iter()
+        .map(|line| format!("{}\n", line.to_attribute_src()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -962,7 +2695,10 @@ mod tests {
 
     #[test]
     fn indent_leaves_empty_lines_empty() {
-        assert_eq!("    let bar = 32;\n\n    bar", "let bar = 32;\n\nbar".indent(1));
+        assert_eq!(
+            "    let bar = 32;\n\n    bar",
+            "let bar = 32;\n\nbar".indent(1)
+        );
     }
 
     #[test]
@@ -990,7 +2726,7 @@ mod tests {
             vec![state(1), Err, state(3), Err, Err]
         };
         let table = Table {
-            start: StateIndex(0),
+            starts: vec![StateIndex(0)],
             terminals: vec![
                 DollarlessTerminalName::remove_dollars("LParen"),
                 DollarlessTerminalName::remove_dollars("RParen"),
@@ -1000,30 +2736,47 @@ mod tests {
             gotos,
         };
         let file = File {
-            start: "Expr".to_owned(),
+            starts: vec!["Expr".to_owned()],
             terminal_enum: TerminalEnum {
+                doc: vec![],
                 attributes: vec![positionless_attribute("#[derive(Clone, Debug)]")],
                 name: "Token".to_string(),
+                is_extern: false,
+                span_position_type: None,
                 variants: vec![
                     TerminalVariant {
+                        doc: vec![],
                         dollarless_name: DollarlessTerminalName::remove_dollars("LParen"),
+                        position: ByteIndex(0),
                         type_: "()".to_string(),
+                        pattern: None,
+                        literal: None,
                     },
                     TerminalVariant {
+                        doc: vec![],
                         dollarless_name: DollarlessTerminalName::remove_dollars("RParen"),
+                        position: ByteIndex(0),
                         type_: "()".to_string(),
+                        pattern: None,
+                        literal: None,
                     },
                 ],
             },
             nonterminals: vec![Nonterminal::Enum(Enum {
+                doc: vec![],
                 attributes: vec![positionless_attribute("#[derive(Clone, Debug)]")],
                 name: positionless_ident("Expr"),
+                result_type: None,
                 variants: vec![
                     EnumVariant {
+                        doc: vec![],
                         name: positionless_ident("Empty"),
                         fieldset: Fieldset::Empty,
+                        precedence: None,
+                        action: None,
                     },
                     EnumVariant {
+                        doc: vec![],
                         name: positionless_ident("Wrap"),
                         fieldset: Fieldset::Tuple(TupleFieldset {
                             fields: vec![
@@ -1042,15 +2795,21 @@ mod tests {
                                 )),
                             ],
                         }),
+                        precedence: None,
+                        action: None,
                     },
                 ],
             })],
+            precedence_levels: vec![],
+            lexer: None,
+            allowed_lints: HashSet::new(),
         };
 
         let grammar_src = include_str!("../examples/balanced_parens.kiki");
 
-        let RustSrc(rust_src) = table_to_rust(&table, &file, grammar_src);
-        insta::assert_display_snapshot!(rust_src);
+        let RustSrc(rust_src) =
+            table_to_rust(&table, &file, None, grammar_src, TableEncoding::Dense);
+        insta::assert_snapshot!(rust_src);
     }
 
     #[test]
@@ -1078,7 +2837,7 @@ mod tests {
             vec![state(1), Err, state(3), Err, Err]
         };
         let table = Table {
-            start: StateIndex(0),
+            starts: vec![StateIndex(0)],
             terminals: vec![
                 DollarlessTerminalName::remove_dollars("LParen"),
                 DollarlessTerminalName::remove_dollars("RParen"),
@@ -1088,34 +2847,52 @@ mod tests {
             gotos,
         };
         let file = File {
-            start: "Expr".to_owned(),
+            starts: vec!["Expr".to_owned()],
             terminal_enum: TerminalEnum {
+                doc: vec![],
                 attributes: vec![positionless_attribute("#[derive(Clone, Debug)]")],
                 name: "Token".to_string(),
+                is_extern: false,
+                span_position_type: None,
                 variants: vec![
                     TerminalVariant {
+                        doc: vec![],
                         dollarless_name: DollarlessTerminalName::remove_dollars("LParen"),
+                        position: ByteIndex(0),
                         type_: "()".to_string(),
+                        pattern: None,
+                        literal: None,
                     },
                     TerminalVariant {
+                        doc: vec![],
                         dollarless_name: DollarlessTerminalName::remove_dollars("RParen"),
+                        position: ByteIndex(0),
                         type_: "()".to_string(),
+                        pattern: None,
+                        literal: None,
                     },
                 ],
             },
             nonterminals: vec![Nonterminal::Enum(Enum {
+                doc: vec![],
                 attributes: vec![positionless_attribute("#[derive(Clone, Debug)]")],
                 name: positionless_ident("Expr"),
+                result_type: None,
                 variants: vec![
                     EnumVariant {
+                        doc: vec![],
                         name: positionless_ident("Empty"),
                         fieldset: Fieldset::Empty,
+                        precedence: None,
+                        action: None,
                     },
                     EnumVariant {
+                        doc: vec![],
                         name: positionless_ident("Wrap"),
                         fieldset: Fieldset::Named(NamedFieldset {
                             fields: vec![
                                 NamedField {
+                                    doc: vec![],
                                     name: IdentOrUnderscore::Underscore(ByteIndex(0)),
                                     symbol: IdentOrTerminalIdent::Terminal(
                                         positionless_terminal_ident(
@@ -1124,10 +2901,12 @@ mod tests {
                                     ),
                                 },
                                 NamedField {
+                                    doc: vec![],
                                     name: IdentOrUnderscore::Ident(positionless_ident("inner")),
                                     symbol: IdentOrTerminalIdent::Ident(positionless_ident("Expr")),
                                 },
                                 NamedField {
+                                    doc: vec![],
                                     name: IdentOrUnderscore::Ident(positionless_ident("right")),
                                     symbol: IdentOrTerminalIdent::Terminal(
                                         positionless_terminal_ident(
@@ -1137,57 +2916,24 @@ mod tests {
                                 },
                             ],
                         }),
+                        precedence: None,
+                        action: None,
                     },
                 ],
             })],
+            precedence_levels: vec![],
+            lexer: None,
+            allowed_lints: HashSet::new(),
         };
 
         let grammar_src = include_str!("../examples/balanced_parens_esoteric.kiki");
 
-        let RustSrc(rust_src) = table_to_rust(&table, &file, grammar_src);
-        insta::assert_display_snapshot!(rust_src);
+        let RustSrc(rust_src) =
+            table_to_rust(&table, &file, None, grammar_src, TableEncoding::Dense);
+        insta::assert_snapshot!(rust_src);
     }
 
     const fn shift(i: usize) -> Action {
         Action::Shift(StateIndex(i))
     }
-
-    mod pascal_to_snake_case {
-        use super::*;
-
-        #[test]
-        fn lower_x() {
-            assert_eq!("x", pascal_to_snake_case("x"));
-        }
-
-        #[test]
-        fn upper_x() {
-            assert_eq!("x", pascal_to_snake_case("X"));
-        }
-
-        #[test]
-        fn lower_hi() {
-            assert_eq!("hi", pascal_to_snake_case("hi"));
-        }
-
-        #[test]
-        fn titlecase_hi() {
-            assert_eq!("hi", pascal_to_snake_case("Hi"));
-        }
-
-        #[test]
-        fn uppercase_hi() {
-            assert_eq!("h_i", pascal_to_snake_case("HI"));
-        }
-
-        #[test]
-        fn titlecase_hi_there() {
-            assert_eq!("hi_there", pascal_to_snake_case("HiThere"));
-        }
-
-        #[test]
-        fn uppercase_hi_titlecase_there() {
-            assert_eq!("h_i_there", pascal_to_snake_case("HIThere"));
-        }
-    }
 }
@@ -0,0 +1,182 @@
+use super::*;
+use crate::data::dfa::*;
+
+/// The names of the items that only exist
+/// if the grammar has a `lexer` section.
+#[derive(Debug)]
+pub struct LexerNames {
+    pub lex_error_name: String,
+    pub lexer_struct_name: String,
+    pub accepted_rules_name: String,
+}
+
+impl LexerNames {
+    pub fn new(used_identifiers: &mut HashSet<String>) -> Self {
+        LexerNames {
+            lex_error_name: create_unique_identifier("LexError", used_identifiers),
+            lexer_struct_name: create_unique_identifier("Lexer", used_identifiers),
+            accepted_rules_name: create_unique_identifier("LEXER_ACCEPTED_RULES", used_identifiers),
+        }
+    }
+}
+
+impl SrcBuilder<'_> {
+    pub(super) fn get_lexer_src(&self) -> String {
+        let (Some(dfa), Some(names)) = (self.dfa, &self.lexer_names) else {
+            return "".to_owned();
+        };
+        let LexerNames {
+            lex_error_name,
+            lexer_struct_name,
+            accepted_rules_name,
+        } = names;
+        let terminal_item_type = &self.terminal_item_type;
+
+        let token = if self.span_type.is_some() {
+            "(token, start..end)"
+        } else {
+            "token"
+        };
+        let rule_match_arms_indent_4 = self.get_lexer_rule_match_arms_src(dfa).indent(4);
+        let transition_match_arms_indent_2 = get_lexer_transition_match_arms_src(dfa).indent(2);
+        let num_of_states = dfa.states.len();
+        // Without skip rules, every iteration of the loop returns.
+        let never_loop_allow = if dfa.rule_terminals.iter().any(Option::is_none) {
+            ""
+        } else {
+            "\n    #[allow(clippy::never_loop)]"
+        };
+        let accepted_rules_indent_1 = get_lexer_accepted_rules_src(dfa).indent(1);
+
+        format!(
+            r#"
+
+/// `span` is the byte range of the text that could not be lexed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct {lex_error_name} {{
+    pub span: std::ops::Range<usize>,
+}}
+
+/// Splits `src` into tokens using the grammar's `lexer` rules.
+/// At each position, the lexer uses the rule that matches
+/// the longest prefix of the remaining input.
+/// If several rules match that prefix, the rule declared first wins.
+/// Text matched by a skip rule (i.e., `_ = ...`) is discarded.
+///
+/// If no rule matches, the lexer yields a `{lex_error_name}`
+/// covering the next character, and resumes after it.
+/// If a token's text cannot be parsed into the terminal's type,
+/// the lexer yields a `{lex_error_name}` covering the text, and resumes after it.
+pub fn lex(src: &str) -> impl Iterator<Item = Result<{terminal_item_type}, {lex_error_name}>> + '_ {{
+    {lexer_struct_name} {{ src, offset: 0 }}
+}}
+
+struct {lexer_struct_name}<'a> {{
+    src: &'a str,
+    offset: usize,
+}}
+
+impl Iterator for {lexer_struct_name}<'_> {{
+    type Item = Result<{terminal_item_type}, {lex_error_name}>;
+{never_loop_allow}
+    fn next(&mut self) -> Option<Self::Item> {{
+        loop {{
+            let start = self.offset;
+            let rest = &self.src[start..];
+            let first_char = rest.chars().next()?;
+
+            let mut state = 0;
+            let mut longest_match = None;
+            for (i, c) in rest.char_indices() {{
+                let Some(next_state) = get_lexer_transition(state, c) else {{
+                    break;
+                }};
+                state = next_state;
+                if let Some(rule_index) = {accepted_rules_name}[state] {{
+                    longest_match = Some((rule_index, start + i + c.len_utf8()));
+                }}
+            }}
+
+            let Some((rule_index, end)) = longest_match else {{
+                self.offset = start + first_char.len_utf8();
+                return Some(Err({lex_error_name} {{ span: start..self.offset }}));
+            }};
+            self.offset = end;
+
+            let token = match rule_index {{
+{rule_match_arms_indent_4}
+                _ => unreachable!(),
+            }};
+            return Some(Ok({token}));
+        }}
+    }}
+}}
+
+fn get_lexer_transition(state: usize, c: char) -> Option<usize> {{
+    match (state, c) {{
+{transition_match_arms_indent_2}
+        _ => None,
+    }}
+}}
+
+static {accepted_rules_name}: [Option<usize>; {num_of_states}] = [
+{accepted_rules_indent_1}
+];"#
+        )
+    }
+
+    fn get_lexer_rule_match_arms_src(&self, dfa: &Dfa) -> String {
+        let terminal_enum_name = &self.terminal_enum_name;
+        let lex_error_name = &self.lexer_names.as_ref().unwrap().lex_error_name;
+        dfa.rule_terminals
+            .iter()
+            .enumerate()
+            .map(|(rule_index, terminal)| {
+                let Some(terminal) = terminal else {
+                    return format!("{rule_index} => continue,");
+                };
+                let variant_name = terminal.raw();
+                let type_ = self.file.terminal_enum.get_type(terminal).unwrap();
+                if type_ == "()" {
+                    return format!("{rule_index} => {terminal_enum_name}::{variant_name}(()),");
+                }
+                format!(
+                    r#"{rule_index} => match self.src[start..end].parse() {{
+    Ok(value) => {terminal_enum_name}::{variant_name}(value),
+    Err(_) => return Some(Err({lex_error_name} {{ span: start..end }})),
+}},"#
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn get_lexer_transition_match_arms_src(dfa: &Dfa) -> String {
+    dfa.states
+        .iter()
+        .enumerate()
+        .flat_map(|(state_index, state)| {
+            state.transitions.iter().map(move |transition| {
+                let DfaTransition { start, end, target } = transition;
+                if start == end {
+                    format!("({state_index}, {start:?}) => Some({target}),")
+                } else {
+                    format!("({state_index}, {start:?}..={end:?}) => Some({target}),")
+                }
+            })
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn get_lexer_accepted_rules_src(dfa: &Dfa) -> String {
+    dfa.states
+        .iter()
+        .map(|state| match state.accepted_rule {
+            Some(rule_index) => format!("Some({rule_index}),"),
+            None => "None,".to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,7 +1,10 @@
 use crate::{
     data::{
         token::Token,
-        token::{Attribute, Ident, TerminalIdent},
+        token::{
+            Attribute, DocComment, Ident, RegexLiteral, SemanticAction, StringLiteral,
+            TerminalIdent,
+        },
         ByteIndex, KikiErr,
     },
     DollarlessTerminalName,
@@ -38,16 +41,25 @@ impl Tokenizer<'_> {
 
         self.push_pending_token_and_reset_state(None, ByteIndex(self.src.len()))?;
 
-        Ok(self.out)
+        Ok(remove_unattached_doc_comments(self.out))
     }
 
     fn handle_char(&mut self, current: char, current_index: ByteIndex) -> Result<(), KikiErr> {
         match self.state {
             State::Main => self.handle_char_given_state_is_main(current, current_index),
             State::Slash(start) => self.handle_char_given_state_is_slash(current, start),
+            State::DoubleSlash => {
+                self.handle_char_given_state_is_double_slash(current, current_index)
+            }
             State::SingleLineComment => {
                 self.handle_char_given_state_is_single_line_comment(current)
             }
+            State::DocComment(start) => {
+                self.handle_char_given_state_is_doc_comment(current, current_index, start)
+            }
+            State::BlockComment(start, depth, comment_state) => {
+                self.handle_char_given_state_is_block_comment(current, start, depth, comment_state)
+            }
             State::Ident(start, end) => {
                 self.handle_char_given_state_is_ident(current, current_index, start, end)
             }
@@ -69,6 +81,42 @@ impl Tokenizer<'_> {
                     left_count,
                     end,
                 ),
+            State::Equals(start) => {
+                self.handle_char_given_state_is_equals(current, current_index, start)
+            }
+            State::FatArrow(start) => {
+                self.handle_char_given_state_is_fat_arrow(current, current_index, start)
+            }
+            State::SemanticAction(start, left_count, code_state) => self
+                .handle_char_given_state_is_semantic_action(
+                    current,
+                    current_index,
+                    start,
+                    left_count,
+                    code_state,
+                ),
+            State::StringLiteral(start, literal_state) => self
+                .handle_char_given_state_is_string_literal(
+                    current,
+                    current_index,
+                    start,
+                    literal_state,
+                ),
+            State::RegexLiteralPrefix(start, hash_count) => self
+                .handle_char_given_state_is_regex_literal_prefix(
+                    current,
+                    current_index,
+                    start,
+                    hash_count,
+                ),
+            State::RegexLiteral(start, hash_count, closing_hash_count) => self
+                .handle_char_given_state_is_regex_literal(
+                    current,
+                    current_index,
+                    start,
+                    hash_count,
+                    closing_hash_count,
+                ),
         }
     }
 
@@ -97,6 +145,12 @@ impl Tokenizer<'_> {
         } else if current == '#' {
             self.state = State::Pound(current_index);
             Ok(())
+        } else if current == '=' {
+            self.state = State::Equals(current_index);
+            Ok(())
+        } else if current == '"' {
+            self.state = State::StringLiteral(current_index, StringLiteralState::Text);
+            Ok(())
         } else if let Some(kind) = get_single_char_punctuation_kind(current) {
             self.out
                 .push(get_single_char_punctuation_token(kind, current_index));
@@ -112,13 +166,69 @@ impl Tokenizer<'_> {
         existing_slash_index: ByteIndex,
     ) -> Result<(), KikiErr> {
         if current == '/' {
-            self.state = State::SingleLineComment;
+            self.state = State::DoubleSlash;
+            Ok(())
+        } else if current == '*' {
+            self.state = State::BlockComment(
+                existing_slash_index,
+                BlockCommentDepth(NonZeroUsize::new(1).unwrap()),
+                BlockCommentState::Text,
+            );
             Ok(())
         } else {
             Err(KikiErr::Lex(existing_slash_index, Some('/')))
         }
     }
 
+    fn handle_char_given_state_is_double_slash(
+        &mut self,
+        current: char,
+        current_index: ByteIndex,
+    ) -> Result<(), KikiErr> {
+        if current == '/' {
+            let start = ByteIndex(current_index.0 - "//".len());
+            self.state = State::DocComment(start);
+            Ok(())
+        } else {
+            self.state = State::SingleLineComment;
+            self.handle_char_given_state_is_single_line_comment(current)
+        }
+    }
+
+    /// Like Rust, we treat comments starting with
+    /// four or more slashes as ordinary comments.
+    fn handle_char_given_state_is_doc_comment(
+        &mut self,
+        current: char,
+        current_index: ByteIndex,
+        start: ByteIndex,
+    ) -> Result<(), KikiErr> {
+        let is_fourth_slash = current == '/' && current_index.0 == start.0 + "///".len();
+        if is_fourth_slash {
+            self.state = State::SingleLineComment;
+        } else if current == '\n' {
+            self.finish_doc_comment(start, current_index);
+            self.state = State::Main;
+        }
+        Ok(())
+    }
+
+    /// A `///` that follows code on the same line
+    /// (e.g., `a: $A /// The a.`) is an ordinary comment.
+    /// Otherwise, it would silently document the next field.
+    fn finish_doc_comment(&mut self, start: ByteIndex, end: ByteIndex) {
+        let line_start = self.src[..start.0].rfind('\n').map_or(0, |i| i + 1);
+        if !self.src[line_start..start.0].trim().is_empty() {
+            return;
+        }
+
+        let text = &self.src[start.0 + "///".len()..end.0];
+        self.out.push(Token::DocComment(DocComment {
+            text: text.strip_suffix('\r').unwrap_or(text).to_string(),
+            position: start,
+        }));
+    }
+
     fn handle_char_given_state_is_single_line_comment(
         &mut self,
         current: char,
@@ -130,6 +240,36 @@ impl Tokenizer<'_> {
         Ok(())
     }
 
+    /// Like Rust, we allow block comments to be nested.
+    /// Therefore, every `/*` must be matched by its own `*/`.
+    fn handle_char_given_state_is_block_comment(
+        &mut self,
+        current: char,
+        start: ByteIndex,
+        depth: BlockCommentDepth,
+        comment_state: BlockCommentState,
+    ) -> Result<(), KikiErr> {
+        self.state = match (comment_state, current) {
+            (BlockCommentState::Slash, '*') => State::BlockComment(
+                start,
+                BlockCommentDepth(depth.0.saturating_add(1)),
+                BlockCommentState::Text,
+            ),
+            (BlockCommentState::Star, '/') => match NonZeroUsize::new(depth.0.get() - 1) {
+                Some(new_depth) => State::BlockComment(
+                    start,
+                    BlockCommentDepth(new_depth),
+                    BlockCommentState::Text,
+                ),
+                None => State::Main,
+            },
+            (_, '/') => State::BlockComment(start, depth, BlockCommentState::Slash),
+            (_, '*') => State::BlockComment(start, depth, BlockCommentState::Star),
+            (_, _) => State::BlockComment(start, depth, BlockCommentState::Text),
+        };
+        Ok(())
+    }
+
     fn handle_char_given_state_is_ident(
         &mut self,
         current: char,
@@ -140,6 +280,8 @@ impl Tokenizer<'_> {
         if current.is_ascii_alphanumeric() || current == '_' {
             self.state = State::Ident(start, ByteIndex(end.0 + current.len_utf8()));
             Ok(())
+        } else if (current == '"' || current == '#') && &self.src[start.0..end.0] == "r" {
+            self.handle_char_given_state_is_regex_literal_prefix(current, current_index, start, 0)
         } else {
             self.push_pending_token_and_reset_state(Some(current), current_index)?;
             self.handle_char(current, current_index)
@@ -265,10 +407,7 @@ impl Tokenizer<'_> {
 
                 ')' | ']' | '}' => {
                     let Some(top) = stack.pop() else {
-                        return Err(KikiErr::Lex(
-                            ByteIndex(current_index),
-                            Some(current),
-                        ));
+                        return Err(KikiErr::Lex(ByteIndex(current_index), Some(current)));
                     };
                     match (top, current) {
                         ('(', ')') | ('[', ']') | ('{', '}') => {}
@@ -291,6 +430,304 @@ impl Tokenizer<'_> {
         Ok(())
     }
 
+    fn handle_char_given_state_is_equals(
+        &mut self,
+        current: char,
+        current_index: ByteIndex,
+        start: ByteIndex,
+    ) -> Result<(), KikiErr> {
+        if current == '>' {
+            self.state = State::FatArrow(start);
+            Ok(())
+        } else {
+            self.push_pending_token_and_reset_state(Some(current), current_index)?;
+            self.handle_char(current, current_index)
+        }
+    }
+
+    fn handle_char_given_state_is_fat_arrow(
+        &mut self,
+        current: char,
+        current_index: ByteIndex,
+        start: ByteIndex,
+    ) -> Result<(), KikiErr> {
+        if current.is_whitespace() {
+            Ok(())
+        } else if current == '{' {
+            self.state = State::SemanticAction(
+                start,
+                LeftBracketCount(NonZeroUsize::new(1).unwrap()),
+                RustCodeState::Code,
+            );
+            Ok(())
+        } else {
+            Err(KikiErr::Lex(current_index, Some(current)))
+        }
+    }
+
+    /// We do not fully lex the Rust code.
+    /// We only track enough state to find the closing curly brace.
+    /// That is, we ignore curly braces that appear in
+    /// string literals (including raw string literals),
+    /// character literals, line comments, and block comments.
+    fn handle_char_given_state_is_semantic_action(
+        &mut self,
+        current: char,
+        current_index: ByteIndex,
+        start: ByteIndex,
+        left_count: LeftBracketCount,
+        code_state: RustCodeState,
+    ) -> Result<(), KikiErr> {
+        let next_code_state = match (code_state, current) {
+            (RustCodeState::Code, '{') => {
+                self.state = State::SemanticAction(
+                    start,
+                    LeftBracketCount(left_count.0.saturating_add(1)),
+                    RustCodeState::Code,
+                );
+                return Ok(());
+            }
+
+            (RustCodeState::Code, '}') => {
+                let Some(new_left_count) = NonZeroUsize::new(left_count.0.get() - 1) else {
+                    let end = ByteIndex(current_index.0 + current.len_utf8());
+                    self.finish_semantic_action(start, end);
+                    return Ok(());
+                };
+                self.state =
+                    State::SemanticAction(start, LeftBracketCount(new_left_count), code_state);
+                return Ok(());
+            }
+
+            (RustCodeState::Code, '"') => RustCodeState::StrLit,
+            (RustCodeState::Code, '\'') => RustCodeState::Quote,
+            (RustCodeState::Code, '/') => RustCodeState::Slash,
+            (RustCodeState::Code, 'r') => RustCodeState::RawStrHashes(0),
+            (RustCodeState::Code, 'b' | 'c') => RustCodeState::RawStrPrefix,
+            (RustCodeState::Code, c) if is_rust_ident_continue(c) => RustCodeState::Word,
+            (RustCodeState::Code, _) => RustCodeState::Code,
+
+            (RustCodeState::Word, c) if is_rust_ident_continue(c) => RustCodeState::Word,
+            (RustCodeState::Word, _) => {
+                self.state = State::SemanticAction(start, left_count, RustCodeState::Code);
+                return self.handle_char(current, current_index);
+            }
+
+            (RustCodeState::RawStrPrefix, 'r') => RustCodeState::RawStrHashes(0),
+            (RustCodeState::RawStrPrefix, c) if is_rust_ident_continue(c) => RustCodeState::Word,
+            (RustCodeState::RawStrPrefix, _) => {
+                self.state = State::SemanticAction(start, left_count, RustCodeState::Code);
+                return self.handle_char(current, current_index);
+            }
+
+            (RustCodeState::RawStrHashes(hash_count), '#') => {
+                RustCodeState::RawStrHashes(hash_count + 1)
+            }
+            (RustCodeState::RawStrHashes(hash_count), '"') => RustCodeState::RawStr(hash_count),
+            (RustCodeState::RawStrHashes(0), c) if is_rust_ident_continue(c) => RustCodeState::Word,
+            // The `r` started an identifier (e.g., `r`)
+            // or a raw identifier (e.g., `r#type`).
+            (RustCodeState::RawStrHashes(_), _) => {
+                self.state = State::SemanticAction(start, left_count, RustCodeState::Code);
+                return self.handle_char(current, current_index);
+            }
+
+            (RustCodeState::RawStr(0), '"') => RustCodeState::Code,
+            (RustCodeState::RawStr(hash_count), '"') => RustCodeState::RawStrClosing(hash_count, 0),
+            (RustCodeState::RawStr(hash_count), _) => RustCodeState::RawStr(hash_count),
+
+            (RustCodeState::RawStrClosing(hash_count, closing_hash_count), '#') => {
+                if closing_hash_count + 1 == hash_count {
+                    RustCodeState::Code
+                } else {
+                    RustCodeState::RawStrClosing(hash_count, closing_hash_count + 1)
+                }
+            }
+            (RustCodeState::RawStrClosing(hash_count, _), '"') => {
+                RustCodeState::RawStrClosing(hash_count, 0)
+            }
+            (RustCodeState::RawStrClosing(hash_count, _), _) => RustCodeState::RawStr(hash_count),
+
+            (RustCodeState::Slash, '/') => RustCodeState::LineComment,
+            (RustCodeState::Slash, '*') => RustCodeState::BlockComment(
+                BlockCommentDepth(NonZeroUsize::new(1).unwrap()),
+                BlockCommentState::Text,
+            ),
+            (RustCodeState::Slash, _) => {
+                self.state = State::SemanticAction(start, left_count, RustCodeState::Code);
+                return self.handle_char(current, current_index);
+            }
+
+            (RustCodeState::LineComment, '\n') => RustCodeState::Code,
+            (RustCodeState::LineComment, _) => RustCodeState::LineComment,
+
+            (RustCodeState::BlockComment(depth, BlockCommentState::Slash), '*') => {
+                RustCodeState::BlockComment(
+                    BlockCommentDepth(depth.0.saturating_add(1)),
+                    BlockCommentState::Text,
+                )
+            }
+            (RustCodeState::BlockComment(depth, BlockCommentState::Star), '/') => {
+                match NonZeroUsize::new(depth.0.get() - 1) {
+                    Some(new_depth) => RustCodeState::BlockComment(
+                        BlockCommentDepth(new_depth),
+                        BlockCommentState::Text,
+                    ),
+                    None => RustCodeState::Code,
+                }
+            }
+            (RustCodeState::BlockComment(depth, _), '/') => {
+                RustCodeState::BlockComment(depth, BlockCommentState::Slash)
+            }
+            (RustCodeState::BlockComment(depth, _), '*') => {
+                RustCodeState::BlockComment(depth, BlockCommentState::Star)
+            }
+            (RustCodeState::BlockComment(depth, _), _) => {
+                RustCodeState::BlockComment(depth, BlockCommentState::Text)
+            }
+
+            (RustCodeState::StrLit, '\\') => RustCodeState::StrLitEscape,
+            (RustCodeState::StrLit, '"') => RustCodeState::Code,
+            (RustCodeState::StrLit, _) => RustCodeState::StrLit,
+            (RustCodeState::StrLitEscape, _) => RustCodeState::StrLit,
+
+            (RustCodeState::Quote, '\\') => RustCodeState::CharLitEscape,
+            (RustCodeState::Quote, _) => RustCodeState::QuoteChar,
+
+            // The quote started a character literal (e.g., `'{'`).
+            (RustCodeState::QuoteChar, '\'') => RustCodeState::Code,
+            // The quote started a lifetime or label (e.g., `'a`).
+            (RustCodeState::QuoteChar, _) => {
+                self.state = State::SemanticAction(start, left_count, RustCodeState::Code);
+                return self.handle_char(current, current_index);
+            }
+
+            // The escaped character never closes the literal (e.g., `'\''`).
+            (RustCodeState::CharLitEscape, _) => RustCodeState::CharLitBody,
+
+            (RustCodeState::CharLitBody, '\'') => RustCodeState::Code,
+            (RustCodeState::CharLitBody, _) => RustCodeState::CharLitBody,
+        };
+
+        self.state = State::SemanticAction(start, left_count, next_code_state);
+        Ok(())
+    }
+
+    fn finish_semantic_action(&mut self, start: ByteIndex, end: ByteIndex) {
+        self.state = State::Main;
+        self.out.push(Token::SemanticAction(SemanticAction {
+            src: self.src[start.0..end.0].to_string(),
+            position: start,
+        }));
+    }
+
+    fn handle_char_given_state_is_string_literal(
+        &mut self,
+        current: char,
+        current_index: ByteIndex,
+        start: ByteIndex,
+        literal_state: StringLiteralState,
+    ) -> Result<(), KikiErr> {
+        self.state = match (literal_state, current) {
+            (StringLiteralState::Text, '"') => {
+                let end = ByteIndex(current_index.0 + current.len_utf8());
+                self.finish_string_literal(start, end);
+                return Ok(());
+            }
+            (StringLiteralState::Text, '\\') => {
+                State::StringLiteral(start, StringLiteralState::Escape)
+            }
+            (StringLiteralState::Text, _) => State::StringLiteral(start, StringLiteralState::Text),
+
+            (StringLiteralState::Escape, c) if unescape_char(c).is_some() => {
+                State::StringLiteral(start, StringLiteralState::Text)
+            }
+            (StringLiteralState::Escape, _) => {
+                return Err(KikiErr::Lex(current_index, Some(current)));
+            }
+        };
+        Ok(())
+    }
+
+    fn finish_string_literal(&mut self, start: ByteIndex, end: ByteIndex) {
+        let raw = &self.src[start.0 + "\"".len()..end.0 - "\"".len()];
+        let mut value = String::with_capacity(raw.len());
+        let mut chars = raw.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                // The escape was validated when we lexed it,
+                // so we can safely unwrap.
+                value.push(unescape_char(chars.next().unwrap()).unwrap());
+            } else {
+                value.push(c);
+            }
+        }
+
+        self.state = State::Main;
+        self.out.push(Token::StringLiteral(StringLiteral {
+            src: self.src[start.0..end.0].to_string(),
+            value,
+            position: start,
+        }));
+    }
+
+    /// Like Rust raw strings, regex literals can be
+    /// delimited by any number of `#`s (e.g., `r##"a"#b"##`).
+    fn handle_char_given_state_is_regex_literal_prefix(
+        &mut self,
+        current: char,
+        current_index: ByteIndex,
+        start: ByteIndex,
+        hash_count: usize,
+    ) -> Result<(), KikiErr> {
+        match current {
+            '#' => {
+                self.state = State::RegexLiteralPrefix(start, hash_count + 1);
+                Ok(())
+            }
+            '"' => {
+                self.state = State::RegexLiteral(start, hash_count, None);
+                Ok(())
+            }
+            _ => Err(KikiErr::Lex(current_index, Some(current))),
+        }
+    }
+
+    fn handle_char_given_state_is_regex_literal(
+        &mut self,
+        current: char,
+        current_index: ByteIndex,
+        start: ByteIndex,
+        hash_count: usize,
+        closing_hash_count: Option<usize>,
+    ) -> Result<(), KikiErr> {
+        let closing_hash_count = match (closing_hash_count, current) {
+            (_, '"') => Some(0),
+            (Some(closing_hash_count), '#') => Some(closing_hash_count + 1),
+            (_, _) => None,
+        };
+
+        if closing_hash_count == Some(hash_count) {
+            let end = ByteIndex(current_index.0 + current.len_utf8());
+            self.finish_regex_literal(start, hash_count, end);
+            return Ok(());
+        }
+
+        self.state = State::RegexLiteral(start, hash_count, closing_hash_count);
+        Ok(())
+    }
+
+    fn finish_regex_literal(&mut self, start: ByteIndex, hash_count: usize, end: ByteIndex) {
+        let delimiter_len = hash_count + "\"".len();
+        let pattern_position = ByteIndex(start.0 + "r".len() + delimiter_len);
+        self.state = State::Main;
+        self.out.push(Token::RegexLiteral(RegexLiteral {
+            pattern: self.src[pattern_position.0..end.0 - delimiter_len].to_string(),
+            position: start,
+            pattern_position,
+        }));
+    }
+
     /// This function only resets the state if the pending token is valid.
     fn push_pending_token_and_reset_state(
         &mut self,
@@ -302,8 +739,17 @@ impl Tokenizer<'_> {
 
             State::Slash(slash_index) => Err(KikiErr::Lex(slash_index, Some('/'))),
 
+            State::DoubleSlash => Ok(()),
+
             State::SingleLineComment => Ok(()),
 
+            State::DocComment(start) => {
+                self.finish_doc_comment(start, ByteIndex(self.src.len()));
+                Ok(())
+            }
+
+            State::BlockComment(start, _, _) => Err(KikiErr::UnterminatedBlockComment(start)),
+
             State::Ident(start, end) => {
                 let name = &self.src[start.0..end.0];
 
@@ -345,6 +791,20 @@ impl Tokenizer<'_> {
             State::Pound(start) => Err(KikiErr::Lex(start, Some('#'))),
 
             State::OuterAttribute(start, _, end) => self.finish_outer_attribute(start, end),
+
+            State::Equals(start) => {
+                self.out.push(Token::Equals(start));
+                self.state = State::Main;
+                Ok(())
+            }
+
+            State::FatArrow(_) => Err(KikiErr::Lex(current_index, current)),
+
+            State::SemanticAction(start, _, _) => Err(KikiErr::UnterminatedSemanticAction(start)),
+
+            State::StringLiteral(start, _)
+            | State::RegexLiteralPrefix(start, _)
+            | State::RegexLiteral(start, _, _) => Err(KikiErr::UnterminatedStringLiteral(start)),
         }?;
 
         self.state = State::Main;
@@ -356,18 +816,206 @@ impl Tokenizer<'_> {
 enum State {
     Main,
     Slash(ByteIndex),
+    DoubleSlash,
     SingleLineComment,
+    /// The index is the position of the first `/`.
+    DocComment(ByteIndex),
+    /// The index is the position of the `/` of the outermost `/*`.
+    BlockComment(ByteIndex, BlockCommentDepth, BlockCommentState),
     Ident(ByteIndex, ByteIndex),
     Dollar(ByteIndex),
     TerminalIdent(ByteIndex, ByteIndex),
     Colon(ByteIndex),
     Pound(ByteIndex),
     OuterAttribute(ByteIndex, LeftBracketCount, ByteIndex),
+    Equals(ByteIndex),
+    FatArrow(ByteIndex),
+    SemanticAction(ByteIndex, LeftBracketCount, RustCodeState),
+    /// The index is the position of the opening `"`.
+    StringLiteral(ByteIndex, StringLiteralState),
+    /// The index is the position of the `r`.
+    /// The count is the number of `#`s seen so far.
+    RegexLiteralPrefix(ByteIndex, usize),
+    /// The index is the position of the `r`.
+    /// The first count is the number of `#`s in the opening delimiter.
+    /// The second count is the number of `#`s that followed
+    /// the most recent `"`, if that `"` might be the closing delimiter.
+    RegexLiteral(ByteIndex, usize, Option<usize>),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum StringLiteralState {
+    Text,
+    /// We have seen a `\\`.
+    Escape,
+}
+
+/// What the tokens directly inside a pair of
+/// curly braces or parentheses are.
+#[derive(Clone, Copy, Debug)]
+enum DocCommentContext {
+    /// The fields of a named fieldset.
+    NamedFields,
+    /// The variants of an enum.
+    EnumVariants,
+    /// The variants of a terminal enum.
+    TerminalEnumVariants,
+    /// Anything else (e.g., a tuple fieldset or a `lexer` section),
+    /// where doc comments are not supported.
+    Other,
+}
+
+/// The state of the Rust code inside a semantic action.
+#[derive(Debug, Clone, Copy)]
+enum RustCodeState {
+    Code,
+    /// We are inside an identifier, keyword, or number
+    /// that cannot start a raw string literal.
+    Word,
+    Slash,
+    LineComment,
+    BlockComment(BlockCommentDepth, BlockCommentState),
+    StrLit,
+    StrLitEscape,
+    /// We have seen a `'`,
+    /// but we don't yet know whether it starts
+    /// a character literal or a lifetime.
+    Quote,
+    QuoteChar,
+    /// We have seen the `\` that starts an escape in a character literal.
+    CharLitEscape,
+    /// We have seen the character after the `\`.
+    /// The rest of the escape (e.g., `{7D}` in `'\u{7D}'`)
+    /// is everything up to the closing `'`.
+    CharLitBody,
+    /// We have seen a `b` or `c` at the start of a word,
+    /// which might be the prefix of a raw string literal (e.g., `br"..."`).
+    RawStrPrefix,
+    /// We have seen an `r` at the start of a word
+    /// (or after a `b` or `c` prefix).
+    /// The count is the number of `#`s seen so far.
+    RawStrHashes(usize),
+    /// The count is the number of `#`s in the opening delimiter.
+    RawStr(usize),
+    /// We have seen a `"` that might be the closing delimiter.
+    /// The first count is the number of `#`s in the opening delimiter.
+    /// The second count is the number of `#`s that followed the `"`.
+    RawStrClosing(usize, usize),
+}
+
+/// Doc comments are only supported before
+/// terminal enums, structs, enums, enum variants,
+/// terminal enum variants, and named fields.
+/// Before Kiki supported doc comments,
+/// `///` was an ordinary comment.
+/// So, to keep such grammars valid,
+/// we treat doc comments anywhere else
+/// (e.g., before `start`, or inside a tuple fieldset)
+/// as ordinary comments.
+fn remove_unattached_doc_comments(tokens: Vec<Token>) -> Vec<Token> {
+    let mut is_attached = vec![true; tokens.len()];
+    let mut contexts: Vec<DocCommentContext> = vec![];
+    let mut top_level_context = DocCommentContext::Other;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::DocComment(_) => {
+                let mut rest = tokens[i..]
+                    .iter()
+                    .filter(|token| !matches!(token, Token::DocComment(_)));
+                is_attached[i] = can_be_documented(contexts.last(), rest.next(), rest.next());
+            }
+            Token::StructKw(_) if contexts.is_empty() => {
+                top_level_context = DocCommentContext::NamedFields;
+            }
+            Token::EnumKw(_) if contexts.is_empty() => {
+                top_level_context = DocCommentContext::EnumVariants;
+            }
+            Token::TerminalKw(_) if contexts.is_empty() => {
+                top_level_context = DocCommentContext::TerminalEnumVariants;
+            }
+            Token::LCurly(_) => {
+                let is_lexer = matches!(
+                    i.checked_sub(1).map(|previous| &tokens[previous]),
+                    Some(Token::Ident(ident)) if ident.name == "lexer"
+                );
+                let context = match contexts.last() {
+                    None if is_lexer => DocCommentContext::Other,
+                    None => top_level_context,
+                    Some(DocCommentContext::EnumVariants) => DocCommentContext::NamedFields,
+                    Some(_) => DocCommentContext::Other,
+                };
+                contexts.push(context);
+            }
+            Token::LParen(_) => contexts.push(DocCommentContext::Other),
+            Token::RCurly(_) | Token::RParen(_) => {
+                contexts.pop();
+            }
+            _ => {}
+        }
+    }
+
+    tokens
+        .into_iter()
+        .zip(is_attached)
+        .filter_map(|(token, is_attached)| is_attached.then_some(token))
+        .collect()
+}
+
+/// `context` is `None` at the top level.
+/// `next` and `after_next` are the first two tokens
+/// after the doc comment that are not doc comments.
+fn can_be_documented(
+    context: Option<&DocCommentContext>,
+    next: Option<&Token>,
+    after_next: Option<&Token>,
+) -> bool {
+    let is_followed_by_colon = matches!(after_next, Some(Token::Colon(_)));
+    match context {
+        None => matches!(
+            next,
+            Some(
+                Token::StructKw(_)
+                    | Token::EnumKw(_)
+                    | Token::TerminalKw(_)
+                    | Token::OuterAttribute(_)
+            )
+        ),
+        Some(DocCommentContext::NamedFields) => {
+            matches!(next, Some(Token::Ident(_) | Token::Underscore(_))) && is_followed_by_colon
+        }
+        Some(DocCommentContext::EnumVariants) => matches!(next, Some(Token::Ident(_))),
+        Some(DocCommentContext::TerminalEnumVariants) => {
+            matches!(
+                next,
+                Some(Token::TerminalIdent(_) | Token::StringLiteral(_))
+            ) && is_followed_by_colon
+        }
+        Some(DocCommentContext::Other) => false,
+    }
+}
+
+fn is_rust_ident_continue(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
 }
 
 #[derive(Debug, Clone, Copy)]
 struct LeftBracketCount(NonZeroUsize);
 
+/// The number of `/*`s that have not yet been closed.
+#[derive(Debug, Clone, Copy)]
+struct BlockCommentDepth(NonZeroUsize);
+
+/// The state of the text inside a block comment.
+/// We only track the previous character,
+/// since that is enough to recognize `/*` and `*/`.
+#[derive(Debug, Clone, Copy)]
+enum BlockCommentState {
+    Text,
+    Slash,
+    Star,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum ReservedWordKind {
     Underscore,
@@ -375,6 +1023,9 @@ enum ReservedWordKind {
     Struct,
     Enum,
     Terminal,
+    Precedence,
+    Prec,
+    Extern,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -387,6 +1038,9 @@ enum SingleCharPunctuationKind {
     RCurly,
     LAngle,
     RAngle,
+    Star,
+    Plus,
+    Question,
 }
 
 fn get_reserved_word_kind(s: &str) -> Option<ReservedWordKind> {
@@ -396,6 +1050,9 @@ fn get_reserved_word_kind(s: &str) -> Option<ReservedWordKind> {
         "struct" => Some(ReservedWordKind::Struct),
         "enum" => Some(ReservedWordKind::Enum),
         "terminal" => Some(ReservedWordKind::Terminal),
+        "precedence" => Some(ReservedWordKind::Precedence),
+        "prec" => Some(ReservedWordKind::Prec),
+        "extern" => Some(ReservedWordKind::Extern),
         _ => None,
     }
 }
@@ -407,6 +1064,23 @@ fn get_reserved_word_token(kind: ReservedWordKind, index: ByteIndex) -> Token {
         ReservedWordKind::Struct => Token::StructKw(index),
         ReservedWordKind::Enum => Token::EnumKw(index),
         ReservedWordKind::Terminal => Token::TerminalKw(index),
+        ReservedWordKind::Precedence => Token::PrecedenceKw(index),
+        ReservedWordKind::Prec => Token::PrecKw(index),
+        ReservedWordKind::Extern => Token::ExternKw(index),
+    }
+}
+
+/// Returns the character denoted by the escape sequence
+/// `\\` followed by `c`, if it is a valid escape sequence.
+fn unescape_char(c: char) -> Option<char> {
+    match c {
+        '\\' => Some('\\'),
+        '"' => Some('"'),
+        'n' => Some('\n'),
+        'r' => Some('\r'),
+        't' => Some('\t'),
+        '0' => Some('\0'),
+        _ => None,
     }
 }
 
@@ -420,6 +1094,9 @@ fn get_single_char_punctuation_kind(c: char) -> Option<SingleCharPunctuationKind
         '}' => Some(SingleCharPunctuationKind::RCurly),
         '<' => Some(SingleCharPunctuationKind::LAngle),
         '>' => Some(SingleCharPunctuationKind::RAngle),
+        '*' => Some(SingleCharPunctuationKind::Star),
+        '+' => Some(SingleCharPunctuationKind::Plus),
+        '?' => Some(SingleCharPunctuationKind::Question),
         _ => None,
     }
 }
@@ -434,6 +1111,9 @@ fn get_single_char_punctuation_token(kind: SingleCharPunctuationKind, index: Byt
         SingleCharPunctuationKind::RCurly => Token::RCurly(index),
         SingleCharPunctuationKind::LAngle => Token::LAngle(index),
         SingleCharPunctuationKind::RAngle => Token::RAngle(index),
+        SingleCharPunctuationKind::Star => Token::Star(index),
+        SingleCharPunctuationKind::Plus => Token::Plus(index),
+        SingleCharPunctuationKind::Question => Token::Question(index),
     }
 }
 
@@ -525,6 +1205,262 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn equals_without_right_angle() {
+        let src = "= {}";
+        let actual = tokenize(src).unwrap();
+        let expected = vec![
+            Token::Equals(ByteIndex(0)),
+            Token::LCurly(ByteIndex(2)),
+            Token::RCurly(ByteIndex(3)),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn finish_equals() {
+        let src = "$Plus: () =Plus";
+        let actual = tokenize(src).unwrap();
+        assert_eq!(Token::Equals(ByteIndex(10)), actual[4]);
+        assert!(matches!(&actual[5], Token::Ident(ident) if ident.name == "Plus"));
+    }
+
+    #[test]
+    fn extern_keyword() {
+        let src = "terminal extern crate::Token";
+        let actual = tokenize(src).unwrap();
+        assert_eq!(Token::ExternKw(ByteIndex(9)), actual[1]);
+    }
+
+    #[test]
+    fn doc_comment() {
+        let src = "/// Foo\r\nstruct";
+        let actual = tokenize(src).unwrap();
+        let expected = vec![
+            Token::DocComment(DocComment {
+                text: " Foo".to_string(),
+                position: ByteIndex(0),
+            }),
+            Token::StructKw(ByteIndex(9)),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn finish_doc_comment() {
+        let src = "///\nstruct";
+        let actual = tokenize(src).unwrap();
+        let expected = vec![
+            Token::DocComment(DocComment {
+                text: "".to_string(),
+                position: ByteIndex(0),
+            }),
+            Token::StructKw(ByteIndex(4)),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn doc_comment_before_start_is_ordinary_comment() {
+        let src = "/// Foo\nstart Foo";
+        let actual = tokenize(src).unwrap();
+        assert!(!has_doc_comment(&actual));
+    }
+
+    #[test]
+    fn doc_comment_at_end_of_file_is_ordinary_comment() {
+        let src = "struct Foo\n/// Foo";
+        let actual = tokenize(src).unwrap();
+        assert!(!has_doc_comment(&actual));
+    }
+
+    #[test]
+    fn doc_comment_after_field_on_same_line_is_ordinary_comment() {
+        let src = "struct Foo {\n    a: $A /// Foo\n    b: $B\n}";
+        let actual = tokenize(src).unwrap();
+        assert!(!has_doc_comment(&actual));
+    }
+
+    #[test]
+    fn doc_comment_in_tuple_fieldset_is_ordinary_comment() {
+        let src = "struct Foo(\n    /// Foo\n    $A\n)";
+        let actual = tokenize(src).unwrap();
+        assert!(!has_doc_comment(&actual));
+    }
+
+    #[test]
+    fn doc_comment_before_named_field_is_kept() {
+        let src = "struct Foo {\n    a: $A\n    /// Foo\n    b: $B\n}";
+        let actual = tokenize(src).unwrap();
+        assert!(has_doc_comment(&actual));
+    }
+
+    #[test]
+    fn quadruple_slash_is_not_doc_comment() {
+        let src = "//// Foo\n// Bar\n//";
+        let actual = tokenize(src).unwrap();
+        assert_eq!(Vec::<Token>::new(), actual);
+    }
+
+    #[test]
+    fn block_comment() {
+        let src = "start /* Foo\n * Bar */ Expr";
+        let actual = tokenize(src).unwrap();
+        let expected = vec![
+            Token::StartKw(ByteIndex(0)),
+            Token::Ident(Ident {
+                name: "Expr".to_string(),
+                position: ByteIndex(23),
+            }),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn nested_block_comment() {
+        let src = "/* /* */ $Foo */ /**/ /***/ /*/ */ //*";
+        let actual = tokenize(src).unwrap();
+        assert_eq!(Vec::<Token>::new(), actual);
+    }
+
+    #[test]
+    fn doc_comment_inside_block_comment() {
+        let src = "/*\n/// Foo\n*/";
+        let actual = tokenize(src).unwrap();
+        assert_eq!(Vec::<Token>::new(), actual);
+    }
+
+    #[test]
+    fn slash_star_inside_line_comment() {
+        let src = "// /*\n$Foo";
+        let actual = tokenize(src).unwrap();
+        assert_eq!(1, actual.len());
+    }
+
+    #[test]
+    fn semantic_action() {
+        let src = "=> { if x { \"}\" } else { '}' } } $Foo";
+        let actual = tokenize(src).unwrap();
+        let expected = vec![
+            Token::SemanticAction(SemanticAction {
+                src: "=> { if x { \"}\" } else { '}' } }".to_string(),
+                position: ByteIndex(0),
+            }),
+            Token::TerminalIdent(TerminalIdent {
+                name: DollarlessTerminalName::remove_dollars("Foo"),
+                dollarless_position: ByteIndex(34),
+            }),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn semantic_action_with_lifetime_and_comment() {
+        let src = "=> { let s: &'static str = x; // }\n s }";
+        let actual = tokenize(src).unwrap();
+        let expected = vec![Token::SemanticAction(SemanticAction {
+            src: src.to_string(),
+            position: ByteIndex(0),
+        })];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn semantic_action_with_escaped_quote_char_literal() {
+        let src = r"=> { let _ = ('\'','}'); 1 } $Foo";
+        let actual = tokenize(src).unwrap();
+        let action_end = src.find(" $Foo").unwrap();
+        let expected = vec![
+            Token::SemanticAction(SemanticAction {
+                src: src[..action_end].to_string(),
+                position: ByteIndex(0),
+            }),
+            Token::TerminalIdent(TerminalIdent {
+                name: DollarlessTerminalName::remove_dollars("Foo"),
+                dollarless_position: ByteIndex(action_end + 2),
+            }),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn semantic_action_with_block_comment() {
+        let src = "=> { /* } /* { */ } */ 1 } $Foo";
+        let actual = tokenize(src).unwrap();
+        let expected = vec![
+            Token::SemanticAction(SemanticAction {
+                src: "=> { /* } /* { */ } */ 1 }".to_string(),
+                position: ByteIndex(0),
+            }),
+            Token::TerminalIdent(TerminalIdent {
+                name: DollarlessTerminalName::remove_dollars("Foo"),
+                dollarless_position: ByteIndex(28),
+            }),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn semantic_action_with_raw_string() {
+        let src = r###"=> { (r"}", br#"}"{"#, r##"}"#"##, r#type, bar"") } $Foo"###;
+        let actual = tokenize(src).unwrap();
+        let action_end = src.find(" $Foo").unwrap();
+        let expected = vec![
+            Token::SemanticAction(SemanticAction {
+                src: src[..action_end].to_string(),
+                position: ByteIndex(0),
+            }),
+            Token::TerminalIdent(TerminalIdent {
+                name: DollarlessTerminalName::remove_dollars("Foo"),
+                dollarless_position: ByteIndex(action_end + 2),
+            }),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn string_literal() {
+        let src = r#"$Foo = "a\"b\\c\n" $Bar"#;
+        let actual = tokenize(src).unwrap();
+        let expected = Token::StringLiteral(StringLiteral {
+            src: r#""a\"b\\c\n""#.to_string(),
+            value: "a\"b\\c\n".to_string(),
+            position: ByteIndex(7),
+        });
+        assert_eq!(expected, actual[2]);
+        assert_eq!(4, actual.len());
+    }
+
+    #[test]
+    fn regex_literal() {
+        let src = r#"r"[0-9]+" rust"#;
+        let actual = tokenize(src).unwrap();
+        let expected = vec![
+            Token::RegexLiteral(RegexLiteral {
+                pattern: "[0-9]+".to_string(),
+                position: ByteIndex(0),
+                pattern_position: ByteIndex(2),
+            }),
+            Token::Ident(Ident {
+                name: "rust".to_string(),
+                position: ByteIndex(10),
+            }),
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn hash_delimited_regex_literal() {
+        let src = r###"r##""#a"##"###;
+        let actual = tokenize(src).unwrap();
+        let expected = vec![Token::RegexLiteral(RegexLiteral {
+            pattern: "\"#a".to_string(),
+            position: ByteIndex(0),
+            pattern_position: ByteIndex(4),
+        })];
+        assert_eq!(expected, actual);
+    }
+
     // ## Lexing should fail
 
     #[test]
@@ -639,6 +1575,72 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn fat_arrow_without_block() {
+        let src = "=> x";
+        let actual = tokenize(src).unwrap_err().lex_err().unwrap();
+        let expected = (ByteIndex(3), Some('x'));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn unterminated_semantic_action() {
+        let src = "=> { { }";
+        let actual = tokenize(src).unwrap_err();
+        assert!(matches!(
+            actual,
+            KikiErr::UnterminatedSemanticAction(ByteIndex(0))
+        ));
+    }
+
+    #[test]
+    fn unterminated_block_comment() {
+        let src = "$Foo /* /* */ */ /* /* */";
+        let actual = tokenize(src).unwrap_err();
+        assert!(matches!(
+            actual,
+            KikiErr::UnterminatedBlockComment(ByteIndex(17))
+        ));
+    }
+
+    #[test]
+    fn unterminated_nested_block_comment() {
+        let src = "/* /* */";
+        let actual = tokenize(src).unwrap_err();
+        assert!(matches!(
+            actual,
+            KikiErr::UnterminatedBlockComment(ByteIndex(0))
+        ));
+    }
+
+    #[test]
+    fn invalid_escape() {
+        let src = r#""a\q""#;
+        let actual = tokenize(src).unwrap_err().lex_err().unwrap();
+        let expected = (ByteIndex(3), Some('q'));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn unterminated_string_literal() {
+        let src = r#"$Foo "a\""#;
+        let actual = tokenize(src).unwrap_err();
+        assert!(matches!(
+            actual,
+            KikiErr::UnterminatedStringLiteral(ByteIndex(5))
+        ));
+    }
+
+    #[test]
+    fn unterminated_regex_literal() {
+        let src = r##"r#"a"b""##;
+        let actual = tokenize(src).unwrap_err();
+        assert!(matches!(
+            actual,
+            KikiErr::UnterminatedStringLiteral(ByteIndex(0))
+        ));
+    }
+
     impl KikiErr {
         fn lex_err(self) -> Option<(ByteIndex, Option<char>)> {
             match self {
@@ -647,4 +1649,10 @@ mod tests {
             }
         }
     }
+
+    fn has_doc_comment(tokens: &[Token]) -> bool {
+        tokens
+            .iter()
+            .any(|token| matches!(token, Token::DocComment(_)))
+    }
 }
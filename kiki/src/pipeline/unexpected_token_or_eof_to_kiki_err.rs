@@ -22,19 +22,30 @@ impl Token {
             Token::Ident(ident) => ident.position,
             Token::TerminalIdent(ident) => ByteIndex(ident.dollarless_position.0 - "$".len()),
             Token::OuterAttribute(attr) => attr.position,
+            Token::DocComment(doc) => doc.position,
+            Token::SemanticAction(action) => action.position,
+            Token::StringLiteral(literal) => literal.position,
+            Token::RegexLiteral(literal) => literal.position,
             Token::StartKw(start) => *start,
             Token::StructKw(start) => *start,
             Token::EnumKw(start) => *start,
             Token::TerminalKw(start) => *start,
+            Token::PrecedenceKw(start) => *start,
+            Token::PrecKw(start) => *start,
+            Token::ExternKw(start) => *start,
             Token::Colon(start) => *start,
             Token::DoubleColon(start) => *start,
             Token::Comma(start) => *start,
+            Token::Equals(start) => *start,
             Token::LParen(start) => *start,
             Token::RParen(start) => *start,
             Token::LCurly(start) => *start,
             Token::RCurly(start) => *start,
             Token::LAngle(start) => *start,
             Token::RAngle(start) => *start,
+            Token::Star(start) => *start,
+            Token::Plus(start) => *start,
+            Token::Question(start) => *start,
         }
     }
 
@@ -44,19 +55,34 @@ impl Token {
             Token::Ident(ident) => ident.name.len(),
             Token::TerminalIdent(ident) => "$".len() + ident.name.raw().len(),
             Token::OuterAttribute(attr) => attr.src.len(),
+            Token::DocComment(doc) => "///".len() + doc.text.len(),
+            Token::SemanticAction(action) => action.src.len(),
+            Token::StringLiteral(literal) => literal.src.len(),
+            Token::RegexLiteral(literal) => {
+                let opening_delimiter_len = literal.pattern_position.0 - literal.position.0;
+                let closing_delimiter_len = opening_delimiter_len - "r".len();
+                opening_delimiter_len + literal.pattern.len() + closing_delimiter_len
+            }
             Token::StartKw(_) => "start".len(),
             Token::StructKw(_) => "struct".len(),
             Token::EnumKw(_) => "enum".len(),
             Token::TerminalKw(_) => "terminal".len(),
+            Token::PrecedenceKw(_) => "precedence".len(),
+            Token::PrecKw(_) => "prec".len(),
+            Token::ExternKw(_) => "extern".len(),
             Token::Colon(_) => ":".len(),
             Token::DoubleColon(_) => "::".len(),
             Token::Comma(_) => ",".len(),
+            Token::Equals(_) => "=".len(),
             Token::LParen(_) => "(".len(),
             Token::RParen(_) => ")".len(),
             Token::LCurly(_) => "{".len(),
             Token::RCurly(_) => "}".len(),
             Token::LAngle(_) => "<".len(),
             Token::RAngle(_) => ">".len(),
+            Token::Star(_) => "*".len(),
+            Token::Plus(_) => "+".len(),
+            Token::Question(_) => "?".len(),
         }
     }
 }
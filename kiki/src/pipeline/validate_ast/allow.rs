@@ -0,0 +1,42 @@
+use super::*;
+
+/// Returns the names of the lints allowed by `allow(...)` declarations.
+///
+/// This function validates that every allowed lint exists.
+/// A lint may be allowed more than once.
+pub fn get_allowed_lints(file: &File) -> Result<HashSet<&'static str>, KikiErr> {
+    let mut allowed = HashSet::new();
+    for item in &file.items {
+        let FileItem::Allow(allow) = item else {
+            continue;
+        };
+        for lint in &allow.lints {
+            let Some(name) = KikiWarning::LINT_NAMES
+                .iter()
+                .find(|name| **name == lint.name)
+            else {
+                return Err(KikiErr::UnknownLint(lint.name.clone(), lint.position));
+            };
+            allowed.insert(*name);
+        }
+    }
+    Ok(allowed)
+}
+
+/// `allow` is a contextual keyword,
+/// so the parser accepts `foo(...)` as an `allow` declaration.
+/// This function rejects such items,
+/// reporting the identifier as an unexpected token.
+pub fn assert_allow_keywords_are_valid(file: &File) -> Result<(), KikiErr> {
+    for item in &file.items {
+        let FileItem::Allow(allow) = item else {
+            continue;
+        };
+        let Ident { name, position } = &allow.keyword;
+        if name != "allow" {
+            let end = ByteIndex(position.0 + name.len());
+            return Err(KikiErr::Parse(*position, name.clone(), end));
+        }
+    }
+    Ok(())
+}
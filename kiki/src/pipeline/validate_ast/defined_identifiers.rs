@@ -5,6 +5,8 @@ use super::*;
 /// 2. There are no duplicate terminal variant names.
 /// 3. The nonterminal names, terminal variant names,
 ///    _and the terminal enum name_ are pairwise disjoint.
+///    For an extern terminal enum, the first segment of its path
+///    is used as its name.
 ///
 /// Things this function does **not** validate:
 /// 1. This function does **not** check for name clashes with
@@ -39,9 +41,7 @@ pub struct DefinedSymbols(pub HashSet<String>);
 /// This function does **not** validate capitalization.
 pub fn get_defined_symbols(file: &File) -> Result<DefinedSymbols, KikiErr> {
     let seen = get_defined_symbol_positions(file)?;
-    Ok(DefinedSymbols(
-        seen.into_iter().map(|(name, _)| name).collect(),
-    ))
+    Ok(DefinedSymbols(seen.into_keys().collect()))
 }
 
 fn get_defined_symbol_positions(file: &File) -> Result<HashMap<String, ByteIndex>, KikiErr> {
@@ -71,6 +71,9 @@ fn define_nonterminal_if_possible(
         FileItem::Struct(struct_def) => define_nonterminal(seen, &struct_def.name),
         FileItem::Enum(enum_def) => define_nonterminal(seen, &enum_def.name),
         FileItem::Terminal(_) => Ok(()),
+        FileItem::Precedence(_) => Ok(()),
+        FileItem::Lexer(_) => Ok(()),
+        FileItem::Allow(_) => Ok(()),
     }
 }
 
@@ -123,19 +126,17 @@ fn define_terminal_enum_name(
     file: &File,
 ) -> Result<(), KikiErr> {
     let terminal_enum = get_unvalidated_terminal_enum(file)?;
+    let name = terminal_enum.name.scope_ident();
 
-    if let Some(conflicting_symbol_position) = seen.get(&terminal_enum.name.name) {
+    if let Some(conflicting_symbol_position) = seen.get(&name.name) {
         return Err(KikiErr::NameClash(
-            terminal_enum.name.name.to_owned(),
+            name.name.to_owned(),
             *conflicting_symbol_position,
-            terminal_enum.name.position,
+            name.position,
         ));
     }
 
-    seen.insert(
-        terminal_enum.name.name.to_owned(),
-        terminal_enum.name.position,
-    );
+    seen.insert(name.name.to_owned(), name.position);
 
     Ok(())
 }
@@ -0,0 +1,272 @@
+use super::*;
+use crate::data::Symbol;
+
+/// This struct monomorphizes generic nonterminals
+/// (e.g., `struct List<T> { ... }`).
+///
+/// Every distinct instantiation (e.g., `List<Expr>`)
+/// becomes an ordinary struct or enum with a synthesized name
+/// (e.g., `ListExpr`).
+/// Every occurrence of the same instantiation
+/// shares a single nonterminal.
+pub struct GenericNonterminals<'a> {
+    definitions: HashMap<&'a str, UnvalidatedNonterminal<'a>>,
+    names: HashMap<(String, Vec<Symbol>), String>,
+    instantiations: Vec<validated::Nonterminal>,
+}
+
+/// A generic nonterminal whose parameters have been
+/// replaced by the arguments of an instantiation.
+pub enum InstantiatedNonterminal {
+    Struct(Struct),
+    Enum(Enum),
+}
+
+impl<'a> GenericNonterminals<'a> {
+    /// This function validates that:
+    /// 1. Every generic nonterminal name is properly capitalized.
+    /// 2. Every generic parameter name is properly capitalized.
+    /// 3. Every generic nonterminal's parameters have unique names.
+    ///
+    /// The bodies of generic nonterminals are validated
+    /// when they are instantiated.
+    pub fn new(file: &'a File) -> Result<Self, KikiErr> {
+        let mut definitions = HashMap::new();
+        for item in &file.items {
+            let (name, params, definition) = match item {
+                FileItem::Struct(struct_def) if !struct_def.params.is_empty() => (
+                    &struct_def.name,
+                    &struct_def.params,
+                    UnvalidatedNonterminal::Struct(struct_def),
+                ),
+                FileItem::Enum(enum_def) if !enum_def.params.is_empty() => (
+                    &enum_def.name,
+                    &enum_def.params,
+                    UnvalidatedNonterminal::Enum(enum_def),
+                ),
+                _ => continue,
+            };
+            validate_ident_uppercase_start(name)?;
+            assert_params_are_valid(params)?;
+            definitions.insert(name.name.as_str(), definition);
+        }
+
+        Ok(Self {
+            definitions,
+            names: HashMap::new(),
+            instantiations: vec![],
+        })
+    }
+
+    pub fn into_nonterminals(self) -> impl Iterator<Item = validated::Nonterminal> {
+        self.instantiations.into_iter()
+    }
+}
+
+fn assert_params_are_valid(params: &[Ident]) -> Result<(), KikiErr> {
+    let mut seen: HashMap<&str, ByteIndex> = HashMap::new();
+    for param in params {
+        validate_ident_uppercase_start(param)?;
+        if let Some(existing_position) = seen.get(param.name.as_str()) {
+            return Err(KikiErr::GenericParamNameClash(
+                param.name.clone(),
+                *existing_position,
+                param.position,
+            ));
+        }
+        seen.insert(&param.name, param.position);
+    }
+    Ok(())
+}
+
+impl GenericNonterminals<'_> {
+    pub fn assert_symbol_is_not_generic(
+        &self,
+        symbol: &IdentOrTerminalIdent,
+    ) -> Result<(), KikiErr> {
+        match symbol {
+            IdentOrTerminalIdent::Ident(ident) if self.definitions.contains_key(&*ident.name) => {
+                Err(KikiErr::GenericNonterminalUsedWithoutArgs(
+                    ident.name.clone(),
+                    ident.position,
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the name of the nonterminal that implements
+    /// `instantiation`, if it was already created.
+    pub fn get_instantiation_name(&self, instantiation: &Instantiation) -> Option<&str> {
+        self.names
+            .get(&get_instantiation_key(instantiation))
+            .map(String::as_str)
+    }
+
+    /// Substitutes the arguments of `instantiation` into
+    /// the generic nonterminal it refers to,
+    /// and renames the result to `name`.
+    ///
+    /// Subsequent calls to `get_instantiation_name` with
+    /// an equivalent instantiation will return `name`,
+    /// so recursive generic nonterminals terminate.
+    ///
+    /// The caller is responsible for validating the returned
+    /// nonterminal and passing it to `add_instantiation`.
+    ///
+    /// This function validates that:
+    /// 1. The callee is a defined nonterminal.
+    /// 2. The callee is generic.
+    /// 3. The number of arguments matches the number of parameters.
+    ///
+    /// The caller is responsible for validating the arguments.
+    pub fn instantiate(
+        &mut self,
+        instantiation: &Instantiation,
+        name: String,
+        defined_symbols: &DefinedSymbols,
+    ) -> Result<InstantiatedNonterminal, KikiErr> {
+        let callee = &instantiation.callee;
+        let Some(definition) = self.definitions.get(&*callee.name).copied() else {
+            if defined_symbols.0.contains(&callee.name) {
+                return Err(KikiErr::NonterminalIsNotGeneric(
+                    callee.name.clone(),
+                    callee.position,
+                ));
+            }
+            return Err(KikiErr::UndefinedNonterminal(
+                callee.name.clone(),
+                callee.position,
+            ));
+        };
+
+        let params = match definition {
+            UnvalidatedNonterminal::Struct(struct_def) => &struct_def.params,
+            UnvalidatedNonterminal::Enum(enum_def) => &enum_def.params,
+        };
+        if params.len() != instantiation.args.len() {
+            return Err(KikiErr::GenericArgCountMismatch(
+                callee.name.clone(),
+                params.len(),
+                instantiation.args.len(),
+                callee.position,
+            ));
+        }
+
+        let substitutions: HashMap<&str, &IdentOrTerminalIdent> = params
+            .iter()
+            .map(|param| param.name.as_str())
+            .zip(instantiation.args.iter())
+            .collect();
+
+        self.names
+            .insert(get_instantiation_key(instantiation), name.clone());
+
+        Ok(match definition {
+            UnvalidatedNonterminal::Struct(struct_def) => {
+                let mut instance = struct_def.clone();
+                instance.name.name = name;
+                instance.params = vec![];
+                substitute_fieldset(&mut instance.fieldset, &substitutions);
+                InstantiatedNonterminal::Struct(instance)
+            }
+            UnvalidatedNonterminal::Enum(enum_def) => {
+                let mut instance = enum_def.clone();
+                instance.name.name = name;
+                instance.params = vec![];
+                for variant in &mut instance.variants {
+                    substitute_fieldset(&mut variant.fieldset, &substitutions);
+                }
+                InstantiatedNonterminal::Enum(instance)
+            }
+        })
+    }
+
+    pub fn add_instantiation(&mut self, nonterminal: validated::Nonterminal) {
+        self.instantiations.push(nonterminal);
+    }
+}
+
+fn get_instantiation_key(instantiation: &Instantiation) -> (String, Vec<Symbol>) {
+    (
+        instantiation.callee.name.clone(),
+        instantiation
+            .args
+            .iter()
+            .map(|arg| arg.clone().into())
+            .collect(),
+    )
+}
+
+fn substitute_fieldset(
+    fieldset: &mut Fieldset,
+    substitutions: &HashMap<&str, &IdentOrTerminalIdent>,
+) {
+    match fieldset {
+        Fieldset::Empty => {}
+        Fieldset::Named(named) => {
+            for field in &mut named.fields {
+                substitute_field_symbol(&mut field.symbol, substitutions);
+            }
+        }
+        Fieldset::Tuple(tuple) => {
+            for field in &mut tuple.fields {
+                match field {
+                    TupleField::Used(symbol) | TupleField::Skipped(symbol) => {
+                        substitute_field_symbol(symbol, substitutions)
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn substitute_field_symbol(
+    symbol: &mut FieldSymbol,
+    substitutions: &HashMap<&str, &IdentOrTerminalIdent>,
+) {
+    match symbol {
+        FieldSymbol::Plain(symbol) => substitute_symbol(symbol, substitutions),
+        FieldSymbol::Repetition(repetition) => {
+            substitute_symbol(&mut repetition.element, substitutions)
+        }
+        FieldSymbol::Optional(optional) => substitute_symbol(&mut optional.element, substitutions),
+        FieldSymbol::Call(call) => {
+            substitute_symbol(&mut call.element, substitutions);
+            substitute_symbol(&mut call.separator, substitutions);
+        }
+        FieldSymbol::Instantiation(instantiation) => {
+            for arg in &mut instantiation.args {
+                substitute_symbol(arg, substitutions);
+            }
+        }
+    }
+}
+
+/// If `symbol` is a parameter, it is replaced by the corresponding argument.
+/// The replacement keeps the position of the parameter occurrence,
+/// so errors point into the generic nonterminal's body.
+/// The caller wraps such errors in `KikiErr::InGenericInstantiation`,
+/// which also points at the use site.
+fn substitute_symbol(
+    symbol: &mut IdentOrTerminalIdent,
+    substitutions: &HashMap<&str, &IdentOrTerminalIdent>,
+) {
+    let IdentOrTerminalIdent::Ident(ident) = symbol else {
+        return;
+    };
+    let Some(arg) = substitutions.get(ident.name.as_str()) else {
+        return;
+    };
+    let position = ident.position;
+    *symbol = match arg {
+        IdentOrTerminalIdent::Ident(arg) => IdentOrTerminalIdent::Ident(Ident {
+            name: arg.name.clone(),
+            position,
+        }),
+        IdentOrTerminalIdent::Terminal(arg) => IdentOrTerminalIdent::Terminal(TerminalIdent {
+            name: arg.name.clone(),
+            dollarless_position: position,
+        }),
+    };
+}
@@ -0,0 +1,321 @@
+use super::*;
+use crate::{data::Symbol, pipeline::create_unique_identifier::create_unique_identifier};
+
+/// This struct synthesizes the helper nonterminals
+/// that implement sugared field symbols (e.g., `Foo*`).
+///
+/// Every occurrence of the same sugared symbol
+/// (e.g., every `$Comma+` in the file)
+/// shares a single helper.
+pub struct HelperNonterminals {
+    used_identifiers: HashSet<String>,
+    helpers: Vec<validated::Helper>,
+    names: HashMap<HelperKey, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum HelperKey {
+    Repetition(RepetitionKind, Symbol),
+    Optional(Symbol),
+    SeparatedList {
+        element: Symbol,
+        separator: Symbol,
+        options: SeparatedListOptions,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeparatedListOptions {
+    /// If `true`, the list must have at least one element.
+    pub nonempty: bool,
+    /// If `true`, the list may end with a separator
+    /// (as long as it has at least one element).
+    pub trailing: bool,
+}
+
+impl HelperNonterminals {
+    pub fn new(file: &File, defined_symbols: &DefinedSymbols) -> Result<Self, KikiErr> {
+        let terminal_enum = get_unvalidated_terminal_enum(file)?;
+        let mut used_identifiers = defined_symbols.0.clone();
+        used_identifiers.insert(terminal_enum.name.scope_ident().name.clone());
+        Ok(Self {
+            used_identifiers,
+            helpers: vec![],
+            names: HashMap::new(),
+        })
+    }
+
+    pub fn into_nonterminals(self) -> impl Iterator<Item = validated::Nonterminal> {
+        self.helpers.into_iter().map(validated::Nonterminal::Helper)
+    }
+
+    /// Returns an identifier based on `preferred_name`
+    /// that does not clash with any defined symbol,
+    /// the terminal enum name, or any previously reserved identifier
+    /// (including helper names).
+    pub fn reserve_identifier(&mut self, preferred_name: &str) -> String {
+        create_unique_identifier(preferred_name, &mut self.used_identifiers)
+    }
+}
+
+impl HelperNonterminals {
+    /// Returns an identifier referring to the helper for `repetition`.
+    /// The helper is created if it does not already exist.
+    ///
+    /// The returned identifier's position is the position of
+    /// the repetition's `*` or `+`.
+    pub fn get_repetition_helper(&mut self, repetition: &Repetition) -> IdentOrTerminalIdent {
+        let key = HelperKey::Repetition(repetition.kind, repetition.element.clone().into());
+        let name = match self.names.get(&key) {
+            Some(name) => name.clone(),
+            None => self.create_repetition_helper(key, repetition),
+        };
+        IdentOrTerminalIdent::Ident(Ident {
+            name,
+            position: repetition.operator_position,
+        })
+    }
+
+    /// Returns an identifier referring to the helper for `optional`.
+    /// The helper is created if it does not already exist.
+    ///
+    /// The returned identifier's position is the position of
+    /// the `?`.
+    pub fn get_optional_helper(&mut self, optional: &Optional) -> IdentOrTerminalIdent {
+        let key = HelperKey::Optional(optional.element.clone().into());
+        let name = match self.names.get(&key) {
+            Some(name) => name.clone(),
+            None => self.create_optional_helper(key, optional),
+        };
+        IdentOrTerminalIdent::Ident(Ident {
+            name,
+            position: optional.operator_position,
+        })
+    }
+
+    fn create_repetition_helper(&mut self, key: HelperKey, repetition: &Repetition) -> String {
+        let suffix = match repetition.kind {
+            RepetitionKind::ZeroOrMore => "Star",
+            RepetitionKind::OneOrMore => "Plus",
+        };
+        let preferred_name = format!("{}{suffix}", get_symbol_name(&repetition.element));
+        let name = create_unique_identifier(&preferred_name, &mut self.used_identifiers);
+
+        let helper_ident = IdentOrTerminalIdent::Ident(Ident {
+            name: name.clone(),
+            position: repetition.operator_position,
+        });
+        let element = repetition.element.clone();
+        let base_rule = match repetition.kind {
+            RepetitionKind::ZeroOrMore => validated::HelperRule {
+                fieldset: validated::Fieldset::Empty,
+                reduction: validated::HelperReduction::EmptyVec,
+            },
+            RepetitionKind::OneOrMore => validated::HelperRule {
+                fieldset: used_tuple_fieldset(vec![element.clone()]),
+                reduction: validated::HelperReduction::SingletonVec,
+            },
+        };
+        // We use left recursion so that the parser's stack
+        // does not grow with the number of elements.
+        let push_rule = validated::HelperRule {
+            fieldset: used_tuple_fieldset(vec![helper_ident, element.clone()]),
+            reduction: validated::HelperReduction::Push,
+        };
+
+        self.helpers.push(validated::Helper {
+            name: name.clone(),
+            kind: validated::HelperKind::Vec,
+            element,
+            sugar: validated::HelperSugar::Repetition(repetition.kind),
+            rules: vec![base_rule, push_rule],
+            operator_position: repetition.operator_position,
+        });
+        self.names.insert(key, name.clone());
+        name
+    }
+
+    /// Returns an identifier referring to the helper for `call`
+    /// (a `sep(...)` field symbol).
+    /// The helper is created if it does not already exist.
+    ///
+    /// The returned identifier's position is the position of
+    /// the callee (i.e., `sep`).
+    pub fn get_separated_list_helper(
+        &mut self,
+        call: &FieldSymbolCall,
+        options: SeparatedListOptions,
+    ) -> IdentOrTerminalIdent {
+        let name = self.get_or_create_separated_list_helper_name(call, options);
+        IdentOrTerminalIdent::Ident(Ident {
+            name,
+            position: call.callee.position,
+        })
+    }
+
+    fn get_or_create_separated_list_helper_name(
+        &mut self,
+        call: &FieldSymbolCall,
+        options: SeparatedListOptions,
+    ) -> String {
+        let key = HelperKey::SeparatedList {
+            element: call.element.clone().into(),
+            separator: call.separator.clone().into(),
+            options,
+        };
+        match self.names.get(&key) {
+            Some(name) => name.clone(),
+            None => self.create_separated_list_helper(key, call, options),
+        }
+    }
+
+    /// Every separated list helper is built on top of
+    /// a nonempty, non-trailing helper:
+    ///
+    /// ```text
+    /// Base -> Element
+    /// Base -> Base Separator Element
+    /// ```
+    ///
+    /// The other variants wrap `Base`:
+    /// - `trailing` adds `Helper -> Base Separator`.
+    /// - Omitting `nonempty` adds `Helper -> <empty>`.
+    fn create_separated_list_helper(
+        &mut self,
+        key: HelperKey,
+        call: &FieldSymbolCall,
+        options: SeparatedListOptions,
+    ) -> String {
+        let base_options = SeparatedListOptions {
+            nonempty: true,
+            trailing: false,
+        };
+        let is_base = options == base_options;
+        let base_name = if is_base {
+            None
+        } else {
+            Some(self.get_or_create_separated_list_helper_name(call, base_options))
+        };
+
+        let quantifier_suffix = if options.nonempty { "Plus" } else { "Star" };
+        let trailing_suffix = if options.trailing { "Trailing" } else { "" };
+        let preferred_name = format!(
+            "Sep{}{}{quantifier_suffix}{trailing_suffix}",
+            get_symbol_name(&call.element),
+            get_symbol_name(&call.separator)
+        );
+        let name = create_unique_identifier(&preferred_name, &mut self.used_identifiers);
+
+        let element = call.element.clone();
+        let separator = call.separator.clone();
+        let rules = match base_name {
+            None => {
+                let helper_ident = IdentOrTerminalIdent::Ident(Ident {
+                    name: name.clone(),
+                    position: call.callee.position,
+                });
+                vec![
+                    validated::HelperRule {
+                        fieldset: used_tuple_fieldset(vec![element.clone()]),
+                        reduction: validated::HelperReduction::SingletonVec,
+                    },
+                    validated::HelperRule {
+                        fieldset: validated::Fieldset::Tuple(validated::TupleFieldset {
+                            fields: vec![
+                                validated::TupleField::Used(helper_ident),
+                                validated::TupleField::Skipped(separator),
+                                validated::TupleField::Used(element.clone()),
+                            ],
+                        }),
+                        reduction: validated::HelperReduction::Push,
+                    },
+                ]
+            }
+            Some(base_name) => {
+                let base_ident = IdentOrTerminalIdent::Ident(Ident {
+                    name: base_name,
+                    position: call.callee.position,
+                });
+                let mut rules = vec![validated::HelperRule {
+                    fieldset: used_tuple_fieldset(vec![base_ident.clone()]),
+                    reduction: validated::HelperReduction::Identity,
+                }];
+                if options.trailing {
+                    rules.push(validated::HelperRule {
+                        fieldset: validated::Fieldset::Tuple(validated::TupleFieldset {
+                            fields: vec![
+                                validated::TupleField::Used(base_ident),
+                                validated::TupleField::Skipped(separator),
+                            ],
+                        }),
+                        reduction: validated::HelperReduction::Identity,
+                    });
+                }
+                if !options.nonempty {
+                    rules.push(validated::HelperRule {
+                        fieldset: validated::Fieldset::Empty,
+                        reduction: validated::HelperReduction::EmptyVec,
+                    });
+                }
+                rules
+            }
+        };
+
+        self.helpers.push(validated::Helper {
+            name: name.clone(),
+            kind: validated::HelperKind::Vec,
+            element,
+            sugar: validated::HelperSugar::SeparatedList {
+                separator: call.separator.clone(),
+                nonempty: options.nonempty,
+                trailing: options.trailing,
+            },
+            rules,
+            operator_position: call.callee.position,
+        });
+        self.names.insert(key, name.clone());
+        name
+    }
+
+    fn create_optional_helper(&mut self, key: HelperKey, optional: &Optional) -> String {
+        let preferred_name = format!("{}Opt", get_symbol_name(&optional.element));
+        let name = create_unique_identifier(&preferred_name, &mut self.used_identifiers);
+
+        let element = optional.element.clone();
+        let none_rule = validated::HelperRule {
+            fieldset: validated::Fieldset::Empty,
+            reduction: validated::HelperReduction::None,
+        };
+        let some_rule = validated::HelperRule {
+            fieldset: used_tuple_fieldset(vec![element.clone()]),
+            reduction: validated::HelperReduction::Some,
+        };
+
+        self.helpers.push(validated::Helper {
+            name: name.clone(),
+            kind: validated::HelperKind::Option,
+            element,
+            sugar: validated::HelperSugar::Optional,
+            rules: vec![none_rule, some_rule],
+            operator_position: optional.operator_position,
+        });
+        self.names.insert(key, name.clone());
+        name
+    }
+}
+
+pub fn get_symbol_name(symbol: &IdentOrTerminalIdent) -> &str {
+    match symbol {
+        IdentOrTerminalIdent::Ident(ident) => &ident.name,
+        IdentOrTerminalIdent::Terminal(terminal) => terminal.name.raw(),
+    }
+}
+
+fn used_tuple_fieldset(symbols: Vec<IdentOrTerminalIdent>) -> validated::Fieldset {
+    validated::Fieldset::Tuple(validated::TupleFieldset {
+        fields: symbols
+            .into_iter()
+            .map(validated::TupleField::Used)
+            .collect(),
+    })
+}
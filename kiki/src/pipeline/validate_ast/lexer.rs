@@ -0,0 +1,134 @@
+use super::*;
+use crate::data::regex::Regex;
+
+/// This function validates that:
+/// 1. There is at most one `lexer` statement.
+/// 2. If there is a `lexer` statement, the terminal enum is not extern.
+/// 3. If there is a `lexer` statement and the terminal enum is spanned,
+///    the span position type is `usize`.
+/// 4. Every terminal named in the lexer is defined.
+/// 5. No terminal is named by more than one lexer rule.
+///    (Skip rules may appear any number of times.)
+/// 6. Every regex is valid.
+/// 7. No pattern matches the empty string.
+pub fn get_lexer(
+    file: &File,
+    terminal_enum: &validated::TerminalEnum,
+) -> Result<Option<validated::Lexer>, KikiErr> {
+    let lexers: Vec<&Lexer> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            FileItem::Lexer(lexer) => Some(lexer),
+            _ => None,
+        })
+        .collect();
+
+    if lexers.len() > 1 {
+        let positions = lexers.iter().map(|lexer| lexer.keyword.position).collect();
+        return Err(KikiErr::MultipleLexers(positions));
+    }
+
+    let Some(lexer) = lexers.first() else {
+        return Ok(None);
+    };
+
+    if terminal_enum.is_extern {
+        return Err(KikiErr::LexerWithExternTerminalEnum(lexer.keyword.position));
+    }
+
+    if terminal_enum
+        .span_position_type
+        .as_ref()
+        .is_some_and(|position_type| position_type != "usize")
+    {
+        return Err(KikiErr::LexerSpanPositionTypeNotUsize(
+            lexer.keyword.position,
+        ));
+    }
+
+    let mut seen: HashMap<&DollarlessTerminalName, ByteIndex> = HashMap::new();
+    let rules = lexer
+        .rules
+        .iter()
+        .map(|rule| {
+            let (terminal, position) = match &rule.name {
+                TerminalIdentOrUnderscore::Terminal(terminal) => {
+                    assert_lexer_terminal_is_defined(terminal, terminal_enum)?;
+                    declare_lexer_terminal(&mut seen, terminal)?;
+                    (Some(terminal.name.clone()), terminal.dollarless_position)
+                }
+                TerminalIdentOrUnderscore::Underscore(position) => (None, *position),
+            };
+            Ok(validated::LexerRule {
+                terminal,
+                regex: get_regex(&rule.pattern)?,
+                position,
+            })
+        })
+        .collect::<Result<Vec<_>, KikiErr>>()?;
+
+    Ok(Some(validated::Lexer { rules }))
+}
+
+/// `lexer` is a contextual keyword,
+/// so the parser accepts `foo { ... }` as a lexer section.
+/// This function rejects such items,
+/// reporting the identifier as an unexpected token.
+pub fn assert_lexer_keywords_are_valid(file: &File) -> Result<(), KikiErr> {
+    for item in &file.items {
+        let FileItem::Lexer(lexer) = item else {
+            continue;
+        };
+        let Ident { name, position } = &lexer.keyword;
+        if name != "lexer" {
+            let end = ByteIndex(position.0 + name.len());
+            return Err(KikiErr::Parse(*position, name.clone(), end));
+        }
+    }
+    Ok(())
+}
+
+fn assert_lexer_terminal_is_defined(
+    terminal: &TerminalIdent,
+    terminal_enum: &validated::TerminalEnum,
+) -> Result<(), KikiErr> {
+    if terminal_enum.get_type(&terminal.name).is_none() {
+        return Err(KikiErr::UndefinedTerminal(
+            terminal.name.clone(),
+            terminal.dollarless_position,
+        ));
+    }
+    Ok(())
+}
+
+fn declare_lexer_terminal<'a>(
+    seen: &mut HashMap<&'a DollarlessTerminalName, ByteIndex>,
+    terminal: &'a TerminalIdent,
+) -> Result<(), KikiErr> {
+    if let Some(existing_position) = seen.get(&terminal.name) {
+        return Err(KikiErr::LexerTerminalRedeclared(
+            terminal.name.clone(),
+            *existing_position,
+            terminal.dollarless_position,
+        ));
+    }
+    seen.insert(&terminal.name, terminal.dollarless_position);
+    Ok(())
+}
+
+fn get_regex(pattern: &LexerPattern) -> Result<Regex, KikiErr> {
+    let (regex, position) = match pattern {
+        LexerPattern::Literal(literal) => (Regex::literal(&literal.value), literal.position),
+        LexerPattern::Regex(literal) => (
+            parse_regex(&literal.pattern, literal.pattern_position)?,
+            literal.position,
+        ),
+    };
+
+    if regex.matches_empty_string() {
+        return Err(KikiErr::LexerPatternMatchesEmptyString(position));
+    }
+
+    Ok(regex)
+}
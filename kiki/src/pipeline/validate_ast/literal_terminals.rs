@@ -0,0 +1,501 @@
+use super::*;
+use crate::pipeline::create_unique_identifier::create_unique_identifier;
+
+/// Replaces the provisional name of every literal terminal
+/// (e.g., `"("`) with a generated identifier (e.g., `LParen`).
+///
+/// Literals declared in the terminal enum (e.g., `"(": () = LParen`)
+/// keep their declared type and pattern.
+/// Every other literal is registered as a new terminal variant
+/// of type `()`, appended to the terminal enum.
+/// If the grammar has a `lexer` section,
+/// a rule for every literal is inserted before the user's rules,
+/// so literals (e.g., `"while"`) take priority over
+/// user-defined patterns that match the same text
+/// (e.g., `r"[a-z]+"`).
+///
+/// This function validates that:
+/// 1. No literal is empty.
+/// 2. No literal is declared in the terminal enum more than once.
+/// 3. If the terminal enum is extern,
+///    every literal used in the grammar is declared in it.
+///
+/// If there is not exactly one `terminal` statement,
+/// this function returns the file unchanged,
+/// leaving the error to `get_terminal_enum`.
+pub fn register_literal_terminals(mut file: File) -> Result<File, KikiErr> {
+    let mut terminal_enums = file.items.iter().filter_map(|item| match item {
+        FileItem::Terminal(terminal_enum) => Some(terminal_enum),
+        _ => None,
+    });
+    let (Some(terminal_enum), None) = (terminal_enums.next(), terminal_enums.next()) else {
+        return Ok(file);
+    };
+
+    let mut registry = LiteralRegistry {
+        is_extern: matches!(terminal_enum.name, TerminalEnumName::Extern(_)),
+        used_identifiers: get_used_identifiers(&file),
+        names: HashMap::new(),
+        literals: vec![],
+        new_variants: vec![],
+    };
+
+    for item in &mut file.items {
+        if let FileItem::Terminal(terminal_enum) = item {
+            registry.declare_terminal_enum_literals(terminal_enum)?;
+        }
+    }
+
+    for item in &mut file.items {
+        registry.rename_item_literals(item)?;
+    }
+
+    let LiteralRegistry {
+        literals,
+        new_variants,
+        ..
+    } = registry;
+    for item in &mut file.items {
+        match item {
+            FileItem::Terminal(terminal_enum) => {
+                terminal_enum.variants.extend(new_variants.iter().cloned());
+            }
+            FileItem::Lexer(lexer) => {
+                let literal_rules = literals.iter().map(|(name, literal)| LexerRule {
+                    name: TerminalIdentOrUnderscore::Terminal(name.clone()),
+                    pattern: LexerPattern::Literal(literal.clone()),
+                });
+                lexer.rules.splice(0..0, literal_rules);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(file)
+}
+
+/// Replaces the generated name of every literal terminal in the error
+/// (e.g., `Plus`) with its provisional name (e.g., `"+"`),
+/// so diagnostics show terminals the way they appear in the grammar.
+///
+/// `file` must be the file returned by `register_literal_terminals`.
+pub fn restore_literal_terminal_names(err: KikiErr, file: &File) -> KikiErr {
+    let provisional_names: HashMap<&DollarlessTerminalName, DollarlessTerminalName> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            FileItem::Terminal(terminal_enum) => Some(&terminal_enum.variants),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|variant| {
+            let literal = variant.literal.as_ref()?;
+            Some((&variant.name.name, DollarlessTerminalName::literal(literal)))
+        })
+        .collect();
+    let restore = |name: DollarlessTerminalName| match provisional_names.get(&name) {
+        Some(provisional_name) => provisional_name.clone(),
+        None => name,
+    };
+
+    let restore_symbols = |symbols: Vec<Symbol>| {
+        symbols
+            .into_iter()
+            .map(|symbol| match symbol {
+                Symbol::Terminal(name) => Symbol::Terminal(restore(name)),
+                Symbol::Nonterminal(name) => Symbol::Nonterminal(name),
+            })
+            .collect()
+    };
+
+    match err {
+        KikiErr::NonterminalEnumVariantSymbolSequenceClash(symbols, first, second) => {
+            KikiErr::NonterminalEnumVariantSymbolSequenceClash(
+                restore_symbols(symbols),
+                first,
+                second,
+            )
+        }
+        KikiErr::InGenericInstantiation(err, name, args, position) => {
+            KikiErr::InGenericInstantiation(
+                Box::new(restore_literal_terminal_names(*err, file)),
+                name,
+                restore_symbols(args),
+                position,
+            )
+        }
+        KikiErr::UndefinedTerminal(name, position) => {
+            KikiErr::UndefinedTerminal(restore(name), position)
+        }
+        KikiErr::TerminalPrecedenceRedeclared(name, first, second) => {
+            KikiErr::TerminalPrecedenceRedeclared(restore(name), first, second)
+        }
+        KikiErr::PrecedenceOverrideTerminalHasNoPrecedence(name, position) => {
+            KikiErr::PrecedenceOverrideTerminalHasNoPrecedence(restore(name), position)
+        }
+        KikiErr::ExternTerminalMissingPattern(name, position) => {
+            KikiErr::ExternTerminalMissingPattern(restore(name), position)
+        }
+        KikiErr::TerminalPatternOnLocalTerminalEnum(name, position) => {
+            KikiErr::TerminalPatternOnLocalTerminalEnum(restore(name), position)
+        }
+        KikiErr::TerminalPatternBindsMultipleFields(name, position) => {
+            KikiErr::TerminalPatternBindsMultipleFields(restore(name), position)
+        }
+        KikiErr::TerminalPatternBindsNoField(name, position) => {
+            KikiErr::TerminalPatternBindsNoField(restore(name), position)
+        }
+        KikiErr::LexerTerminalRedeclared(name, first, second) => {
+            KikiErr::LexerTerminalRedeclared(restore(name), first, second)
+        }
+        other => other,
+    }
+}
+
+struct LiteralRegistry {
+    is_extern: bool,
+    used_identifiers: HashSet<String>,
+    /// Maps each literal's value to its generated name.
+    names: HashMap<String, DollarlessTerminalName>,
+    /// Every literal, in the order it was first encountered.
+    /// The terminal ident is the literal's generated name,
+    /// positioned at the literal's first occurrence.
+    literals: Vec<(TerminalIdent, StringLiteral)>,
+    /// The variants registered for literals
+    /// that the terminal enum does not declare.
+    new_variants: Vec<TerminalEnumVariant>,
+}
+
+impl LiteralRegistry {
+    fn declare_terminal_enum_literals(
+        &mut self,
+        terminal_enum: &mut TerminalEnum,
+    ) -> Result<(), KikiErr> {
+        for variant in &mut terminal_enum.variants {
+            let Some(value) = variant.name.name.literal_value() else {
+                continue;
+            };
+            let value = value.to_owned();
+
+            if let Some(existing) = self.names.get(&value) {
+                let (existing_ident, _) = self
+                    .literals
+                    .iter()
+                    .find(|(name, _)| name.name == *existing)
+                    .unwrap();
+                return Err(KikiErr::NameClash(
+                    format!("{value:?}"),
+                    existing_ident.dollarless_position,
+                    variant.name.dollarless_position,
+                ));
+            }
+
+            variant.name = self.register(&value, variant.name.dollarless_position)?;
+            variant.literal = Some(value);
+        }
+        Ok(())
+    }
+
+    fn rename_item_literals(&mut self, item: &mut FileItem) -> Result<(), KikiErr> {
+        match item {
+            FileItem::Struct(struct_def) => self.rename_fieldset_literals(&mut struct_def.fieldset),
+            FileItem::Enum(enum_def) => {
+                for variant in &mut enum_def.variants {
+                    self.rename_fieldset_literals(&mut variant.fieldset)?;
+                    if let Some(precedence) = &mut variant.precedence {
+                        self.rename_terminal_ident(precedence)?;
+                    }
+                }
+                Ok(())
+            }
+            FileItem::Precedence(precedence) => {
+                for terminal in &mut precedence.terminals {
+                    self.rename_terminal_ident(terminal)?;
+                }
+                Ok(())
+            }
+            FileItem::Start(_)
+            | FileItem::Terminal(_)
+            | FileItem::Lexer(_)
+            | FileItem::Allow(_) => Ok(()),
+        }
+    }
+
+    fn rename_fieldset_literals(&mut self, fieldset: &mut Fieldset) -> Result<(), KikiErr> {
+        let symbols: Vec<&mut FieldSymbol> = match fieldset {
+            Fieldset::Empty => vec![],
+            Fieldset::Named(named) => named
+                .fields
+                .iter_mut()
+                .map(|field| &mut field.symbol)
+                .collect(),
+            Fieldset::Tuple(tuple) => tuple
+                .fields
+                .iter_mut()
+                .map(|field| match field {
+                    TupleField::Used(symbol) | TupleField::Skipped(symbol) => symbol,
+                })
+                .collect(),
+        };
+
+        for symbol in symbols {
+            self.rename_field_symbol_literals(symbol)?;
+        }
+        Ok(())
+    }
+
+    fn rename_field_symbol_literals(&mut self, symbol: &mut FieldSymbol) -> Result<(), KikiErr> {
+        let elements: Vec<&mut IdentOrTerminalIdent> = match symbol {
+            FieldSymbol::Plain(element) => vec![element],
+            FieldSymbol::Repetition(repetition) => vec![&mut repetition.element],
+            FieldSymbol::Optional(optional) => vec![&mut optional.element],
+            FieldSymbol::Call(call) => vec![&mut call.element, &mut call.separator],
+            FieldSymbol::Instantiation(instantiation) => instantiation.args.iter_mut().collect(),
+        };
+
+        for element in elements {
+            if let IdentOrTerminalIdent::Terminal(terminal) = element {
+                self.rename_terminal_ident(terminal)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// If `terminal` is a literal, this function replaces its
+    /// provisional name with the literal's generated name,
+    /// registering the literal if necessary.
+    fn rename_terminal_ident(&mut self, terminal: &mut TerminalIdent) -> Result<(), KikiErr> {
+        let Some(value) = terminal.name.literal_value() else {
+            return Ok(());
+        };
+
+        if let Some(name) = self.names.get(value) {
+            terminal.name = name.clone();
+            return Ok(());
+        }
+
+        let value = value.to_owned();
+        let position = terminal.dollarless_position;
+        if self.is_extern {
+            return Err(KikiErr::ExternLiteralTerminalNotDeclared(
+                format!("{value:?}"),
+                position,
+            ));
+        }
+
+        let registered = self.register(&value, position)?;
+        self.new_variants.push(TerminalEnumVariant {
+            doc: vec![],
+            name: registered.clone(),
+            type_: Type::Unit,
+            pattern: None,
+            literal: Some(value),
+        });
+        terminal.name = registered.name;
+        Ok(())
+    }
+
+    fn register(&mut self, value: &str, position: ByteIndex) -> Result<TerminalIdent, KikiErr> {
+        if value.is_empty() {
+            return Err(KikiErr::EmptyLiteralTerminal(position));
+        }
+
+        let name = create_unique_identifier(
+            &get_preferred_literal_terminal_name(value),
+            &mut self.used_identifiers,
+        );
+        let name = DollarlessTerminalName::remove_dollars(&name);
+        let ident = TerminalIdent {
+            name: name.clone(),
+            dollarless_position: position,
+        };
+        self.names.insert(value.to_owned(), name);
+        self.literals.push((
+            ident.clone(),
+            StringLiteral {
+                src: format!("{value:?}"),
+                value: value.to_owned(),
+                position,
+            },
+        ));
+        Ok(ident)
+    }
+}
+
+/// Returns every name that a generated terminal name must avoid:
+/// the nonterminal names, the terminal enum name,
+/// and every (non-literal) terminal name that appears anywhere,
+/// even if it is undefined.
+/// Avoiding undefined terminal names ensures that
+/// a generated name never accidentally defines one.
+fn get_used_identifiers(file: &File) -> HashSet<String> {
+    let mut used = HashSet::new();
+    let mut use_terminal = |terminal: &TerminalIdent| {
+        if terminal.name.literal_value().is_none() {
+            used.insert(terminal.name.raw().to_owned());
+        }
+    };
+
+    for item in &file.items {
+        match item {
+            FileItem::Start(_) | FileItem::Allow(_) => {}
+            FileItem::Struct(struct_def) => {
+                use_fieldset_terminals(&struct_def.fieldset, &mut use_terminal);
+            }
+            FileItem::Enum(enum_def) => {
+                for variant in &enum_def.variants {
+                    use_fieldset_terminals(&variant.fieldset, &mut use_terminal);
+                    if let Some(precedence) = &variant.precedence {
+                        use_terminal(precedence);
+                    }
+                }
+            }
+            FileItem::Terminal(terminal_enum) => {
+                for variant in &terminal_enum.variants {
+                    use_terminal(&variant.name);
+                }
+            }
+            FileItem::Precedence(precedence) => {
+                for terminal in &precedence.terminals {
+                    use_terminal(terminal);
+                }
+            }
+            FileItem::Lexer(lexer) => {
+                for rule in &lexer.rules {
+                    if let TerminalIdentOrUnderscore::Terminal(terminal) = &rule.name {
+                        use_terminal(terminal);
+                    }
+                }
+            }
+        }
+    }
+
+    for item in &file.items {
+        match item {
+            FileItem::Struct(struct_def) => {
+                used.insert(struct_def.name.name.clone());
+            }
+            FileItem::Enum(enum_def) => {
+                used.insert(enum_def.name.name.clone());
+            }
+            FileItem::Terminal(terminal_enum) => {
+                used.insert(terminal_enum.name.scope_ident().name.clone());
+            }
+            _ => {}
+        }
+    }
+
+    used
+}
+
+fn use_fieldset_terminals(fieldset: &Fieldset, use_terminal: &mut impl FnMut(&TerminalIdent)) {
+    let symbols: Vec<&FieldSymbol> = match fieldset {
+        Fieldset::Empty => vec![],
+        Fieldset::Named(named) => named.fields.iter().map(|field| &field.symbol).collect(),
+        Fieldset::Tuple(tuple) => tuple.fields.iter().map(TupleField::symbol).collect(),
+    };
+
+    for symbol in symbols {
+        let elements: Vec<&IdentOrTerminalIdent> = match symbol {
+            FieldSymbol::Plain(element) => vec![element],
+            FieldSymbol::Repetition(repetition) => vec![&repetition.element],
+            FieldSymbol::Optional(optional) => vec![&optional.element],
+            FieldSymbol::Call(call) => vec![&call.element, &call.separator],
+            FieldSymbol::Instantiation(instantiation) => instantiation.args.iter().collect(),
+        };
+        for element in elements {
+            if let IdentOrTerminalIdent::Terminal(terminal) = element {
+                use_terminal(terminal);
+            }
+        }
+    }
+}
+
+/// Words (e.g., `while`) become `WhileKw`.
+/// Other literals are spelled out one character at a time,
+/// so `->` becomes `MinusRAngle`,
+/// and `!=` becomes `BangEq`.
+/// Runs of letters and digits are capitalized,
+/// so `a+` becomes `APlus`.
+fn get_preferred_literal_terminal_name(value: &str) -> String {
+    let is_word = value.starts_with(|c: char| c.is_ascii_alphabetic())
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_word {
+        let words: String = value.split('_').map(capitalize).collect();
+        return format!("{words}Kw");
+    }
+
+    let mut name = String::new();
+    let mut rest = value;
+    while let Some(c) = rest.chars().next() {
+        if c.is_ascii_alphanumeric() {
+            let run_len = rest
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+            name.push_str(&capitalize(&rest[..run_len]));
+            rest = &rest[run_len..];
+            continue;
+        }
+
+        match get_punctuation_name(c) {
+            Some(punctuation_name) => name.push_str(punctuation_name),
+            None => name.push_str(&format!("U{:X}", c as u32)),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return format!("Lit{name}");
+    }
+    name
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn get_punctuation_name(c: char) -> Option<&'static str> {
+    Some(match c {
+        '(' => "LParen",
+        ')' => "RParen",
+        '[' => "LSquare",
+        ']' => "RSquare",
+        '{' => "LCurly",
+        '}' => "RCurly",
+        '<' => "LAngle",
+        '>' => "RAngle",
+        '+' => "Plus",
+        '-' => "Minus",
+        '*' => "Star",
+        '/' => "Slash",
+        '\\' => "Backslash",
+        '%' => "Percent",
+        '^' => "Caret",
+        '&' => "Amp",
+        '|' => "Pipe",
+        '!' => "Bang",
+        '~' => "Tilde",
+        '=' => "Eq",
+        '.' => "Dot",
+        ',' => "Comma",
+        ';' => "Semicolon",
+        ':' => "Colon",
+        '?' => "Question",
+        '@' => "At",
+        '#' => "Hash",
+        '$' => "Dollar",
+        '\'' => "Quote",
+        '"' => "DoubleQuote",
+        '`' => "Backtick",
+        '_' => "Underscore",
+        ' ' => "Space",
+        '\t' => "Tab",
+        '\n' => "Newline",
+        '\r' => "CarriageReturn",
+        _ => return None,
+    })
+}
@@ -1,21 +1,39 @@
 use crate::data::{
     ast::*,
     validated_file::{self as validated},
-    ByteIndex, DollarlessTerminalName, KikiErr,
+    ByteIndex, DollarlessTerminalName, KikiErr, KikiWarning, Symbol,
 };
 use std::collections::{HashMap, HashSet};
 
 pub fn validate_ast(file: File) -> Result<validated::File, KikiErr> {
-    let terminal_enum = get_terminal_enum(&file)?;
-    let nonterminals = get_nonterminals(&file)?;
-    let start = get_start_symbol_name(&file, &nonterminals)?;
-    assert_there_are_no_top_level_name_clashes(&file)?;
+    assert_lexer_keywords_are_valid(&file)?;
+    assert_allow_keywords_are_valid(&file)?;
+    let file = register_literal_terminals(file)?;
+    validate_registered_ast(&file).map_err(|err| restore_literal_terminal_names(err, &file))
+}
+
+fn validate_registered_ast(file: &File) -> Result<validated::File, KikiErr> {
+    let terminal_enum = get_terminal_enum(file)?;
+    let nonterminals = get_nonterminals(file)?;
+    assert_span_field_is_free(&terminal_enum, &nonterminals)?;
+    let starts = get_start_symbol_names(file)?;
+    assert_there_are_no_top_level_name_clashes(file)?;
+    let precedence_levels = get_precedence_levels(file, &terminal_enum, &nonterminals)?;
+    let lexer = get_lexer(file, &terminal_enum)?;
+    let allowed_lints = get_allowed_lints(file)?;
 
-    Ok(validated::File {
-        start,
+    let validated = validated::File {
+        starts,
         terminal_enum,
         nonterminals,
-    })
+        precedence_levels,
+        lexer,
+        allowed_lints,
+    };
+    assert_parse_fn_names_are_unique(file, validated.uses_error_terminal())?;
+    assert_grammar_is_productive(&validated)?;
+
+    Ok(validated)
 }
 
 mod terminal_enum;
@@ -30,6 +48,33 @@ use start_symbol::*;
 mod defined_identifiers;
 use defined_identifiers::*;
 
+mod precedence;
+use precedence::*;
+
+mod helpers;
+use helpers::*;
+
+mod generics;
+use generics::*;
+
+mod spans;
+use spans::*;
+
+mod lexer;
+use lexer::*;
+
+mod regex;
+use regex::*;
+
+mod allow;
+use allow::*;
+
+mod literal_terminals;
+use literal_terminals::*;
+
+mod productivity;
+use productivity::*;
+
 mod type_to_string;
 
 fn validate_ident_uppercase_start(ident: &Ident) -> Result<&str, KikiErr> {
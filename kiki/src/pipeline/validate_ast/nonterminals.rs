@@ -9,6 +9,23 @@ use crate::data::Symbol;
 ///    1. _Different_ nonterminal enums may have variants with the same name.
 /// 5. Every nonterminal enum's variants have a unique sequence of field symbols.
 ///    1. _Different_ nonterminal enums may have variants with the same sequence of field symbols.
+/// 6. Every symbol used in a field is defined.
+/// 7. Every rule of a nonterminal with a declared result type
+///    has a semantic action, and no other rule does.
+/// 8. Nonterminals with a declared result type have no outer attributes.
+/// 9. Generic nonterminals are only used with the right number of arguments,
+///    and every generic argument is a defined, non-generic symbol.
+///
+/// A plain `error` field symbol is replaced by the `error` pseudo-terminal.
+/// It cannot be used with sugar (e.g., `error*`) or as a generic argument.
+///
+/// Sugared field symbols (e.g., `Foo*`) are replaced by
+/// helper nonterminals, which are appended to the returned nonterminals.
+///
+/// Generic nonterminals (e.g., `List<T>`) are not returned.
+/// Instead, each distinct instantiation (e.g., `List<Expr>`)
+/// is validated and returned as an ordinary nonterminal,
+/// placed after the user-defined nonterminals and before the helpers.
 ///
 /// This function does **not** check for name clashes,
 /// except for those between nonterminal enum variants.
@@ -25,22 +42,38 @@ pub fn get_nonterminals(file: &File) -> Result<Vec<validated::Nonterminal>, Kiki
         .items
         .iter()
         .filter_map(get_unvalidated_nonterminal)
+        .filter(|nonterminal| !nonterminal.is_generic())
         .collect();
 
     let defined_symbols = get_defined_symbols(file)?;
-    let nonterminals = unvalidated
+    let mut helpers = HelperNonterminals::new(file, &defined_symbols)?;
+    let mut generics = GenericNonterminals::new(file)?;
+    let mut nonterminals = unvalidated
         .iter()
-        .map(|nonterminal| validate_nonterminal(*nonterminal, &defined_symbols))
+        .map(|nonterminal| {
+            validate_nonterminal(*nonterminal, &defined_symbols, &mut helpers, &mut generics)
+        })
         .collect::<Result<Vec<_>, _>>()?;
+    nonterminals.extend(generics.into_nonterminals());
+    nonterminals.extend(helpers.into_nonterminals());
     Ok(nonterminals)
 }
 
 #[derive(Debug, Clone, Copy)]
-enum UnvalidatedNonterminal<'a> {
+pub enum UnvalidatedNonterminal<'a> {
     Struct(&'a Struct),
     Enum(&'a Enum),
 }
 
+impl UnvalidatedNonterminal<'_> {
+    fn is_generic(&self) -> bool {
+        match self {
+            UnvalidatedNonterminal::Struct(struct_def) => !struct_def.params.is_empty(),
+            UnvalidatedNonterminal::Enum(enum_def) => !enum_def.params.is_empty(),
+        }
+    }
+}
+
 fn get_unvalidated_nonterminal(item: &FileItem) -> Option<UnvalidatedNonterminal<'_>> {
     match item {
         FileItem::Struct(struct_def) => Some(UnvalidatedNonterminal::Struct(struct_def)),
@@ -52,35 +85,69 @@ fn get_unvalidated_nonterminal(item: &FileItem) -> Option<UnvalidatedNonterminal
 fn validate_nonterminal(
     nonterminal: UnvalidatedNonterminal,
     defined_symbols: &DefinedSymbols,
+    helpers: &mut HelperNonterminals,
+    generics: &mut GenericNonterminals,
 ) -> Result<validated::Nonterminal, KikiErr> {
     match nonterminal {
-        UnvalidatedNonterminal::Enum(e) => validate_enum(e, defined_symbols),
-        UnvalidatedNonterminal::Struct(s) => validate_struct(s, defined_symbols),
+        UnvalidatedNonterminal::Enum(e) => validate_enum(e, defined_symbols, helpers, generics),
+        UnvalidatedNonterminal::Struct(s) => validate_struct(s, defined_symbols, helpers, generics),
     }
 }
 
 fn validate_enum(
     enum_def: &Enum,
     defined_symbols: &DefinedSymbols,
+    helpers: &mut HelperNonterminals,
+    generics: &mut GenericNonterminals,
 ) -> Result<validated::Nonterminal, KikiErr> {
     validate_ident_uppercase_start(&enum_def.name)?;
-    assert_variants_are_valid(&enum_def.variants, defined_symbols)?;
-    Ok(validated::Nonterminal::Enum(enum_def.clone()))
+    let result_type = validate_result_type(
+        &enum_def.name,
+        &enum_def.attributes,
+        enum_def.result_type.as_ref(),
+    )?;
+    let variants = validate_variants(&enum_def.variants, defined_symbols, helpers, generics)?;
+    for variant in &variants {
+        validate_action(
+            &format!("{}::{}", enum_def.name.name, variant.name.name),
+            variant.name.position,
+            result_type.is_some(),
+            variant.action.as_ref(),
+        )?;
+    }
+    Ok(validated::Nonterminal::Enum(validated::Enum {
+        doc: enum_def.doc.clone(),
+        attributes: enum_def.attributes.clone(),
+        name: enum_def.name.clone(),
+        result_type,
+        variants,
+    }))
 }
 
-fn assert_variants_are_valid(
+fn validate_variants(
     variants: &[EnumVariant],
     defined_symbols: &DefinedSymbols,
-) -> Result<(), KikiErr> {
+    helpers: &mut HelperNonterminals,
+    generics: &mut GenericNonterminals,
+) -> Result<Vec<validated::EnumVariant>, KikiErr> {
     assert_variants_have_unique_names(variants)?;
-    assert_variants_have_unique_field_symbol_sequences(variants)?;
 
+    let mut validated_variants = vec![];
     for variant in variants {
         validate_ident_uppercase_start(&variant.name)?;
-        assert_fieldset_is_valid(&variant.fieldset, defined_symbols)?;
+        let fieldset = validate_fieldset(&variant.fieldset, defined_symbols, helpers, generics)?;
+        validated_variants.push(validated::EnumVariant {
+            doc: variant.doc.clone(),
+            name: variant.name.clone(),
+            fieldset,
+            precedence: variant.precedence.clone(),
+            action: variant.action.clone(),
+        });
     }
 
-    Ok(())
+    assert_variants_have_unique_field_symbol_sequences(&validated_variants)?;
+
+    Ok(validated_variants)
 }
 
 fn assert_variants_have_unique_names(variants: &[EnumVariant]) -> Result<(), KikiErr> {
@@ -102,8 +169,13 @@ fn assert_variants_have_unique_names(variants: &[EnumVariant]) -> Result<(), Kik
     Ok(())
 }
 
+/// Since this function runs on _validated_ variants,
+/// sugared field symbols (e.g., `Foo*`) have already been
+/// replaced by their helper nonterminals.
+/// Two variants that use the same sugared symbol
+/// use the same helper, so they will still clash.
 fn assert_variants_have_unique_field_symbol_sequences(
-    variants: &[EnumVariant],
+    variants: &[validated::EnumVariant],
 ) -> Result<(), KikiErr> {
     let mut seen: HashMap<Vec<Symbol>, ByteIndex> = HashMap::new();
 
@@ -123,15 +195,15 @@ fn assert_variants_have_unique_field_symbol_sequences(
     Ok(())
 }
 
-fn get_field_symbol_sequence(variant: &EnumVariant) -> Vec<Symbol> {
+fn get_field_symbol_sequence(variant: &validated::EnumVariant) -> Vec<Symbol> {
     match &variant.fieldset {
-        Fieldset::Empty => vec![],
-        Fieldset::Named(named) => named
+        validated::Fieldset::Empty => vec![],
+        validated::Fieldset::Named(named) => named
             .fields
             .iter()
             .map(|field| field.symbol.clone().into())
             .collect::<Vec<_>>(),
-        Fieldset::Tuple(tuple) => tuple
+        validated::Fieldset::Tuple(tuple) => tuple
             .fields
             .iter()
             .map(|field| field.symbol().clone().into())
@@ -142,42 +214,257 @@ fn get_field_symbol_sequence(variant: &EnumVariant) -> Vec<Symbol> {
 fn validate_struct(
     struct_def: &Struct,
     defined_symbols: &DefinedSymbols,
+    helpers: &mut HelperNonterminals,
+    generics: &mut GenericNonterminals,
 ) -> Result<validated::Nonterminal, KikiErr> {
     validate_ident_uppercase_start(&struct_def.name)?;
-    assert_fieldset_is_valid(&struct_def.fieldset, defined_symbols)?;
-    Ok(validated::Nonterminal::Struct(struct_def.clone()))
+    let result_type = validate_result_type(
+        &struct_def.name,
+        &struct_def.attributes,
+        struct_def.result_type.as_ref(),
+    )?;
+    validate_action(
+        &struct_def.name.name,
+        struct_def.name.position,
+        result_type.is_some(),
+        struct_def.action.as_ref(),
+    )?;
+    let fieldset = validate_fieldset(&struct_def.fieldset, defined_symbols, helpers, generics)?;
+    Ok(validated::Nonterminal::Struct(validated::Struct {
+        doc: struct_def.doc.clone(),
+        attributes: struct_def.attributes.clone(),
+        name: struct_def.name.clone(),
+        result_type,
+        fieldset,
+        action: struct_def.action.clone(),
+    }))
+}
+
+fn validate_result_type(
+    nonterminal_name: &Ident,
+    attributes: &[Attribute],
+    result_type: Option<&Type>,
+) -> Result<Option<String>, KikiErr> {
+    let Some(result_type) = result_type else {
+        return Ok(None);
+    };
+
+    if let Some(attribute) = attributes.first() {
+        return Err(KikiErr::OuterAttributeOnNonterminalWithResultType(
+            nonterminal_name.name.clone(),
+            attribute.position,
+        ));
+    }
+
+    Ok(Some(type_to_string::type_to_string(result_type)))
 }
 
-fn assert_fieldset_is_valid(
+fn validate_action(
+    constructor_name: &str,
+    constructor_position: ByteIndex,
+    has_result_type: bool,
+    action: Option<&SemanticAction>,
+) -> Result<(), KikiErr> {
+    match (has_result_type, action) {
+        (true, Some(_)) | (false, None) => Ok(()),
+        (true, None) => Err(KikiErr::MissingSemanticAction(
+            constructor_name.to_owned(),
+            constructor_position,
+        )),
+        (false, Some(action)) => Err(KikiErr::SemanticActionWithoutResultType(
+            constructor_name.to_owned(),
+            action.position,
+        )),
+    }
+}
+
+fn validate_fieldset(
     fieldset: &Fieldset,
     defined_symbols: &DefinedSymbols,
-) -> Result<(), KikiErr> {
+    helpers: &mut HelperNonterminals,
+    generics: &mut GenericNonterminals,
+) -> Result<validated::Fieldset, KikiErr> {
     match fieldset {
-        Fieldset::Empty => Ok(()),
-        Fieldset::Named(named) => assert_named_fieldset_is_valid(named, defined_symbols),
-        Fieldset::Tuple(tuple) => assert_tuple_fieldset_is_valid(tuple, defined_symbols),
+        Fieldset::Empty => Ok(validated::Fieldset::Empty),
+        Fieldset::Named(named) => Ok(validated::Fieldset::Named(validate_named_fieldset(
+            named,
+            defined_symbols,
+            helpers,
+            generics,
+        )?)),
+        Fieldset::Tuple(tuple) => Ok(validated::Fieldset::Tuple(validate_tuple_fieldset(
+            tuple,
+            defined_symbols,
+            helpers,
+            generics,
+        )?)),
     }
 }
 
-fn assert_named_fieldset_is_valid(
+fn validate_named_fieldset(
     fieldset: &NamedFieldset,
     defined_symbols: &DefinedSymbols,
-) -> Result<(), KikiErr> {
+    helpers: &mut HelperNonterminals,
+    generics: &mut GenericNonterminals,
+) -> Result<validated::NamedFieldset, KikiErr> {
+    let mut fields = vec![];
     for field in &fieldset.fields {
         assert_field_ident_or_underscore_name_is_valid(&field.name)?;
-        assert_symbol_is_defined(&field.symbol, defined_symbols)?;
+        fields.push(validated::NamedField {
+            doc: field.doc.clone(),
+            name: field.name.clone(),
+            symbol: validate_field_symbol(&field.symbol, defined_symbols, helpers, generics)?,
+        });
     }
-    Ok(())
+    Ok(validated::NamedFieldset { fields })
 }
 
-fn assert_tuple_fieldset_is_valid(
+fn validate_tuple_fieldset(
     fieldset: &TupleFieldset,
     defined_symbols: &DefinedSymbols,
-) -> Result<(), KikiErr> {
+    helpers: &mut HelperNonterminals,
+    generics: &mut GenericNonterminals,
+) -> Result<validated::TupleFieldset, KikiErr> {
+    let mut fields = vec![];
     for field in &fieldset.fields {
-        assert_symbol_is_defined(field.symbol(), defined_symbols)?;
+        let symbol = validate_field_symbol(field.symbol(), defined_symbols, helpers, generics)?;
+        fields.push(match field {
+            TupleField::Used(_) => validated::TupleField::Used(symbol),
+            TupleField::Skipped(_) => validated::TupleField::Skipped(symbol),
+        });
     }
-    Ok(())
+    Ok(validated::TupleFieldset { fields })
+}
+
+/// If the symbol is sugared (e.g., `Foo*`),
+/// this function returns the helper nonterminal
+/// that implements it.
+/// If the symbol is an instantiation of a generic nonterminal
+/// (e.g., `List<Expr>`), this function returns the
+/// monomorphized nonterminal that implements it.
+/// If the symbol is `error`, this function returns
+/// the `error` pseudo-terminal.
+/// Otherwise, it returns the symbol as-is.
+fn validate_field_symbol(
+    symbol: &FieldSymbol,
+    defined_symbols: &DefinedSymbols,
+    helpers: &mut HelperNonterminals,
+    generics: &mut GenericNonterminals,
+) -> Result<IdentOrTerminalIdent, KikiErr> {
+    match symbol {
+        FieldSymbol::Plain(IdentOrTerminalIdent::Ident(ident)) if ident.name == "error" => {
+            Ok(IdentOrTerminalIdent::Terminal(TerminalIdent {
+                name: DollarlessTerminalName::error(),
+                dollarless_position: ident.position,
+            }))
+        }
+        FieldSymbol::Plain(symbol) => {
+            assert_symbol_is_usable(symbol, defined_symbols, generics)?;
+            Ok(symbol.clone())
+        }
+        FieldSymbol::Repetition(repetition) => {
+            assert_symbol_is_usable(&repetition.element, defined_symbols, generics)?;
+            Ok(helpers.get_repetition_helper(repetition))
+        }
+        FieldSymbol::Optional(optional) => {
+            assert_symbol_is_usable(&optional.element, defined_symbols, generics)?;
+            Ok(helpers.get_optional_helper(optional))
+        }
+        FieldSymbol::Call(call) => {
+            let options = get_separated_list_options(call)?;
+            assert_symbol_is_usable(&call.element, defined_symbols, generics)?;
+            assert_symbol_is_usable(&call.separator, defined_symbols, generics)?;
+            Ok(helpers.get_separated_list_helper(call, options))
+        }
+        FieldSymbol::Instantiation(instantiation) => {
+            for arg in &instantiation.args {
+                assert_symbol_is_usable(arg, defined_symbols, generics)?;
+            }
+            let name =
+                get_or_create_instantiation(instantiation, defined_symbols, helpers, generics)?;
+            Ok(IdentOrTerminalIdent::Ident(Ident {
+                name,
+                position: instantiation.callee.position,
+            }))
+        }
+    }
+}
+
+fn get_or_create_instantiation(
+    instantiation: &Instantiation,
+    defined_symbols: &DefinedSymbols,
+    helpers: &mut HelperNonterminals,
+    generics: &mut GenericNonterminals,
+) -> Result<String, KikiErr> {
+    if let Some(name) = generics.get_instantiation_name(instantiation) {
+        return Ok(name.to_owned());
+    }
+
+    let preferred_name: String = std::iter::once(instantiation.callee.name.as_str())
+        .chain(instantiation.args.iter().map(get_symbol_name))
+        .collect();
+    let name = helpers.reserve_identifier(&preferred_name);
+    let instance = generics.instantiate(instantiation, name.clone(), defined_symbols)?;
+    let nonterminal = match &instance {
+        InstantiatedNonterminal::Struct(s) => {
+            validate_struct(s, defined_symbols, helpers, generics)
+        }
+        InstantiatedNonterminal::Enum(e) => validate_enum(e, defined_symbols, helpers, generics),
+    }
+    .map_err(|err| {
+        let args = instantiation.args.iter().cloned().map(Symbol::from);
+        KikiErr::InGenericInstantiation(
+            Box::new(err),
+            instantiation.callee.name.clone(),
+            args.collect(),
+            instantiation.callee.position,
+        )
+    })?;
+    generics.add_instantiation(nonterminal);
+    Ok(name)
+}
+
+/// This function validates that:
+/// 1. The callee is `sep`.
+/// 2. Every flag is `nonempty` or `trailing`.
+/// 3. No flag is repeated.
+fn get_separated_list_options(call: &FieldSymbolCall) -> Result<SeparatedListOptions, KikiErr> {
+    if call.callee.name != "sep" {
+        return Err(KikiErr::UnknownFieldSymbolFunction(
+            call.callee.name.clone(),
+            call.callee.position,
+        ));
+    }
+
+    let mut seen: HashMap<&str, ByteIndex> = HashMap::new();
+    let mut options = SeparatedListOptions {
+        nonempty: false,
+        trailing: false,
+    };
+
+    for flag in &call.flags {
+        if let Some(existing_position) = seen.get(flag.name.as_str()) {
+            return Err(KikiErr::SeparatedListFlagRepeated(
+                flag.name.clone(),
+                *existing_position,
+                flag.position,
+            ));
+        }
+        seen.insert(&flag.name, flag.position);
+
+        match flag.name.as_str() {
+            "nonempty" => options.nonempty = true,
+            "trailing" => options.trailing = true,
+            _ => {
+                return Err(KikiErr::UnknownSeparatedListFlag(
+                    flag.name.clone(),
+                    flag.position,
+                ))
+            }
+        }
+    }
+
+    Ok(options)
 }
 
 fn assert_field_ident_or_underscore_name_is_valid(
@@ -207,6 +494,15 @@ fn assert_lowercase_start(name: &str, position: ByteIndex) -> Result<(), KikiErr
     }
 }
 
+fn assert_symbol_is_usable(
+    symbol: &IdentOrTerminalIdent,
+    defined_symbols: &DefinedSymbols,
+    generics: &GenericNonterminals,
+) -> Result<(), KikiErr> {
+    assert_symbol_is_defined(symbol, defined_symbols)?;
+    generics.assert_symbol_is_not_generic(symbol)
+}
+
 fn assert_symbol_is_defined(
     symbol: &IdentOrTerminalIdent,
     defined_symbols: &DefinedSymbols,
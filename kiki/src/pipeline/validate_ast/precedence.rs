@@ -0,0 +1,114 @@
+use super::*;
+
+/// This function validates that:
+/// 1. Every precedence declaration's associativity
+///    (if present) is `left`, `right`, or `nonassoc`.
+/// 2. Every terminal named in a precedence declaration is defined.
+/// 3. No terminal is named in more than one precedence declaration
+///    (or more than once in the same declaration).
+/// 4. Every terminal named in a `prec` override is defined
+///    and has a declared precedence.
+///
+/// Precedence declarations are ordered from lowest to highest precedence.
+/// That is, terminals declared later bind more tightly.
+pub fn get_precedence_levels(
+    file: &File,
+    terminal_enum: &validated::TerminalEnum,
+    nonterminals: &[validated::Nonterminal],
+) -> Result<Vec<validated::PrecedenceLevel>, KikiErr> {
+    let declarations = file.items.iter().filter_map(|item| match item {
+        FileItem::Precedence(declaration) => Some(declaration),
+        _ => None,
+    });
+
+    let mut seen: HashMap<&DollarlessTerminalName, ByteIndex> = HashMap::new();
+    let mut levels = vec![];
+
+    for declaration in declarations {
+        let associativity = get_associativity(declaration.associativity.as_ref())?;
+        let terminals = declaration
+            .terminals
+            .iter()
+            .map(|terminal| {
+                assert_precedence_terminal_is_defined(terminal, terminal_enum)?;
+                declare_terminal_precedence(&mut seen, terminal)?;
+                Ok(terminal.name.clone())
+            })
+            .collect::<Result<Vec<_>, KikiErr>>()?;
+        levels.push(validated::PrecedenceLevel {
+            associativity,
+            terminals,
+        });
+    }
+
+    for override_ in get_precedence_overrides(nonterminals) {
+        assert_precedence_terminal_is_defined(override_, terminal_enum)?;
+        if !seen.contains_key(&override_.name) {
+            return Err(KikiErr::PrecedenceOverrideTerminalHasNoPrecedence(
+                override_.name.clone(),
+                override_.dollarless_position,
+            ));
+        }
+    }
+
+    Ok(levels)
+}
+
+fn get_associativity(ident: Option<&Ident>) -> Result<validated::Associativity, KikiErr> {
+    let Some(ident) = ident else {
+        return Ok(validated::Associativity::None);
+    };
+
+    match ident.name.as_str() {
+        "left" => Ok(validated::Associativity::Left),
+        "right" => Ok(validated::Associativity::Right),
+        "nonassoc" => Ok(validated::Associativity::Nonassoc),
+        _ => Err(KikiErr::UnknownAssociativity(
+            ident.name.clone(),
+            ident.position,
+        )),
+    }
+}
+
+fn assert_precedence_terminal_is_defined(
+    terminal: &TerminalIdent,
+    terminal_enum: &validated::TerminalEnum,
+) -> Result<(), KikiErr> {
+    if terminal_enum.get_type(&terminal.name).is_some() {
+        Ok(())
+    } else {
+        Err(KikiErr::UndefinedTerminal(
+            terminal.name.clone(),
+            terminal.dollarless_position,
+        ))
+    }
+}
+
+fn declare_terminal_precedence<'a>(
+    seen: &mut HashMap<&'a DollarlessTerminalName, ByteIndex>,
+    terminal: &'a TerminalIdent,
+) -> Result<(), KikiErr> {
+    if let Some(existing_position) = seen.get(&terminal.name) {
+        return Err(KikiErr::TerminalPrecedenceRedeclared(
+            terminal.name.clone(),
+            *existing_position,
+            terminal.dollarless_position,
+        ));
+    }
+
+    seen.insert(&terminal.name, terminal.dollarless_position);
+    Ok(())
+}
+
+fn get_precedence_overrides(
+    nonterminals: &[validated::Nonterminal],
+) -> impl Iterator<Item = &TerminalIdent> {
+    nonterminals
+        .iter()
+        .filter_map(|nonterminal| match nonterminal {
+            validated::Nonterminal::Enum(e) => Some(e),
+            validated::Nonterminal::Struct(_) | validated::Nonterminal::Helper(_) => None,
+        })
+        .flat_map(|e| e.variants.iter())
+        .filter_map(|variant| variant.precedence.as_ref())
+}
@@ -0,0 +1,138 @@
+use super::*;
+
+/// This function validates that:
+/// 1. Every start symbol derives at least one finite string of terminals.
+/// 2. Every other nonterminal derives at least one finite string of terminals.
+/// 3. There is no cycle of unit rules (e.g., `A -> B`, `B -> A`).
+///
+/// Since helper nonterminals are only nonproductive
+/// if the nonterminals they are built from are,
+/// this function only reports structs and enums
+/// as nonproductive.
+/// However, unit cycles may pass through helpers
+/// (e.g., `enum A { B(A?) ... }`).
+pub fn assert_grammar_is_productive(file: &validated::File) -> Result<(), KikiErr> {
+    let productive = get_productive_nonterminals(file);
+    let mut nonproductive = file
+        .nonterminals
+        .iter()
+        .filter_map(|nonterminal| match nonterminal {
+            validated::Nonterminal::Struct(s) => Some(&s.name),
+            validated::Nonterminal::Enum(e) => Some(&e.name),
+            validated::Nonterminal::Helper(_) => None,
+        })
+        .filter(|name| !productive.contains(name.name.as_str()));
+
+    let nonproductive_start = nonproductive
+        .clone()
+        .find(|name| file.starts.contains(&name.name));
+    if let Some(name) = nonproductive_start {
+        return Err(KikiErr::EmptyStartLanguage(
+            name.name.clone(),
+            name.position,
+        ));
+    }
+
+    if let Some(name) = nonproductive.next() {
+        return Err(KikiErr::NonproductiveNonterminal(
+            name.name.clone(),
+            name.position,
+        ));
+    }
+
+    assert_there_are_no_unit_cycles(file)
+}
+
+/// A nonterminal is productive if it has a rule
+/// whose symbols are all terminals or productive nonterminals.
+fn get_productive_nonterminals(file: &validated::File) -> HashSet<&str> {
+    let mut productive: HashSet<&str> = HashSet::new();
+    loop {
+        let mut changed = false;
+        for rule in file.get_rules() {
+            let name = rule.constructor_name.type_name();
+            if productive.contains(name) {
+                continue;
+            }
+
+            let is_rule_productive =
+                (0..rule.fieldset.len()).all(|i| match rule.fieldset.get_symbol_ident(i) {
+                    IdentOrTerminalIdent::Terminal(_) => true,
+                    IdentOrTerminalIdent::Ident(ident) => productive.contains(ident.name.as_str()),
+                });
+            if is_rule_productive {
+                productive.insert(name);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            return productive;
+        }
+    }
+}
+
+fn assert_there_are_no_unit_cycles(file: &validated::File) -> Result<(), KikiErr> {
+    // Each nonterminal maps to the targets of its unit rules,
+    // along with the positions of those rules.
+    let mut unit_rules: HashMap<&str, Vec<(&str, ByteIndex)>> = HashMap::new();
+    for rule in file.get_rules() {
+        if rule.fieldset.len() != 1 {
+            continue;
+        }
+        if let IdentOrTerminalIdent::Ident(target) = rule.fieldset.get_symbol_ident(0) {
+            unit_rules
+                .entry(rule.constructor_name.type_name())
+                .or_default()
+                .push((&target.name, rule.position));
+        }
+    }
+
+    let mut finished: HashSet<&str> = HashSet::new();
+    for nonterminal in &file.nonterminals {
+        let mut path = vec![];
+        if let Some(cycle) =
+            find_unit_cycle(nonterminal.name(), &unit_rules, &mut path, &mut finished)
+        {
+            return Err(KikiErr::UnitCycle(cycle));
+        }
+    }
+
+    Ok(())
+}
+
+/// Performs a depth-first search over unit rules.
+/// `path` holds the nonterminals currently being visited,
+/// along with the positions of the unit rules taken from them.
+/// `finished` holds the nonterminals from which no cycle is reachable.
+fn find_unit_cycle<'a>(
+    name: &'a str,
+    unit_rules: &HashMap<&'a str, Vec<(&'a str, ByteIndex)>>,
+    path: &mut Vec<(&'a str, ByteIndex)>,
+    finished: &mut HashSet<&'a str>,
+) -> Option<Vec<(String, ByteIndex)>> {
+    if finished.contains(name) {
+        return None;
+    }
+
+    if let Some(cycle_start) = path.iter().position(|(visited, _)| *visited == name) {
+        return Some(
+            path[cycle_start..]
+                .iter()
+                .map(|(name, position)| (name.to_string(), *position))
+                .collect(),
+        );
+    }
+
+    for &(target, position) in unit_rules.get(name).into_iter().flatten() {
+        path.push((name, position));
+        let cycle = find_unit_cycle(target, unit_rules, path, finished);
+        path.pop();
+        if cycle.is_some() {
+            return cycle;
+        }
+    }
+
+    finished.insert(name);
+    None
+}
@@ -0,0 +1,320 @@
+use super::*;
+use crate::data::regex::{CharClass, Regex};
+
+/// Each repetition of a counted repetition (e.g., `a{3}`)
+/// becomes its own copy of the repeated regex in the lexer's automaton,
+/// so we cap the counts to keep the automaton reasonably small.
+const MAX_REPETITION_COUNT: u32 = 1000;
+
+/// Parses the pattern of a regex literal (e.g., the `[0-9]+` in `r"[0-9]+"`).
+/// `pattern_position` is the position of the pattern's first byte.
+///
+/// The supported syntax is a subset of the `regex` crate's syntax:
+/// 1. Literal characters, and escaped punctuation (e.g., `\.`).
+/// 2. `\n`, `\r`, and `\t`.
+/// 3. `.`, which matches any character except `\n`.
+/// 4. Character classes (e.g., `[a-z_]` or `[^"\\]`).
+/// 5. `\d`, `\w`, and `\s`, and their negations
+///    `\D`, `\W`, and `\S`.
+///    These are ASCII-only.
+/// 6. Groups (e.g., `(ab)` or `(?:ab)`).
+///    Groups do not capture anything, so the two forms are equivalent.
+/// 7. Alternation (e.g., `a|b`).
+/// 8. Repetition (e.g., `a*`, `a+`, `a?`, `a{3}`, `a{3,}`, and `a{3,5}`).
+pub fn parse_regex(pattern: &str, pattern_position: ByteIndex) -> Result<Regex, KikiErr> {
+    let mut parser = RegexParser {
+        pattern,
+        pattern_position,
+        offset: 0,
+    };
+    let regex = parser.parse_alternation()?;
+
+    // `parse_alternation` only stops early
+    // if it encounters an unmatched `)`.
+    if parser.peek().is_some() {
+        return Err(parser.err_at(parser.offset, "unmatched `)`"));
+    }
+
+    Ok(regex)
+}
+
+struct RegexParser<'a> {
+    pattern: &'a str,
+    pattern_position: ByteIndex,
+    /// The byte offset of the next character, relative to the pattern.
+    offset: usize,
+}
+
+impl RegexParser<'_> {
+    fn parse_alternation(&mut self) -> Result<Regex, KikiErr> {
+        let mut alternatives = vec![self.parse_concat()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_concat()?);
+        }
+
+        if alternatives.len() == 1 {
+            return Ok(alternatives.pop().unwrap());
+        }
+        Ok(Regex::Alternation(alternatives))
+    }
+
+    fn parse_concat(&mut self) -> Result<Regex, KikiErr> {
+        let mut items = vec![];
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            items.push(self.parse_repetition()?);
+        }
+
+        match items.len() {
+            0 => Ok(Regex::Empty),
+            1 => Ok(items.pop().unwrap()),
+            _ => Ok(Regex::Concat(items)),
+        }
+    }
+
+    fn parse_repetition(&mut self) -> Result<Regex, KikiErr> {
+        let mut regex = self.parse_atom()?;
+        loop {
+            let (min, max) = match self.peek() {
+                Some('*') => {
+                    self.next();
+                    (0, None)
+                }
+                Some('+') => {
+                    self.next();
+                    (1, None)
+                }
+                Some('?') => {
+                    self.next();
+                    (0, Some(1))
+                }
+                Some('{') => self.parse_counted_repetition()?,
+                _ => return Ok(regex),
+            };
+            regex = Regex::Repeat {
+                regex: Box::new(regex),
+                min,
+                max,
+            };
+        }
+    }
+
+    fn parse_counted_repetition(&mut self) -> Result<(u32, Option<u32>), KikiErr> {
+        let open_offset = self.offset;
+        self.next();
+
+        let min = self.parse_repetition_count()?;
+        let max = if !self.eat(',') {
+            Some(min)
+        } else if self.peek() == Some('}') {
+            None
+        } else {
+            Some(self.parse_repetition_count()?)
+        };
+
+        if !self.eat('}') {
+            return Err(self.err_at(self.offset, "expected `}`"));
+        }
+
+        if max.is_some_and(|max| min > max) {
+            return Err(self.err_at(
+                open_offset,
+                "the minimum repetition count is greater than the maximum",
+            ));
+        }
+
+        Ok((min, max))
+    }
+
+    fn parse_repetition_count(&mut self) -> Result<u32, KikiErr> {
+        let start = self.offset;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.next();
+        }
+
+        let digits = &self.pattern[start..self.offset];
+        if digits.is_empty() {
+            return Err(self.err_at(start, "expected a repetition count"));
+        }
+
+        match digits.parse::<u32>() {
+            Ok(count) if count <= MAX_REPETITION_COUNT => Ok(count),
+            _ => Err(self.err_at(
+                start,
+                format!("repetition counts cannot exceed {MAX_REPETITION_COUNT}"),
+            )),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Regex, KikiErr> {
+        let start = self.offset;
+        let Some(c) = self.next() else {
+            return Err(self.err_at(start, "unexpected end of regex"));
+        };
+
+        match c {
+            '(' => {
+                if self.pattern[self.offset..].starts_with("?:") {
+                    self.offset += "?:".len();
+                } else if self.peek() == Some('?') {
+                    return Err(self.err_at(self.offset, "only `(?:` groups may start with `?`"));
+                }
+
+                let regex = self.parse_alternation()?;
+                if !self.eat(')') {
+                    return Err(self.err_at(start, "unclosed group"));
+                }
+                Ok(regex)
+            }
+            '[' => Ok(Regex::Class(self.parse_class(start)?)),
+            '.' => Ok(Regex::Class(CharClass::single('\n').negate())),
+            '\\' => Ok(Regex::Class(self.parse_escape(start)?)),
+            '*' | '+' | '?' | '{' => {
+                Err(self.err_at(start, format!("`{c}` must follow something to repeat")))
+            }
+            '^' | '$' => Err(self.err_at(start, "anchors are not supported")),
+            _ => Ok(Regex::Class(CharClass::single(c))),
+        }
+    }
+
+    /// `open_offset` is the offset of the `[`.
+    fn parse_class(&mut self, open_offset: usize) -> Result<CharClass, KikiErr> {
+        let is_negated = self.eat('^');
+        let mut class = CharClass::new(vec![]);
+        let mut is_first = true;
+
+        loop {
+            let item_offset = self.offset;
+            let Some(c) = self.next() else {
+                return Err(self.err_at(open_offset, "unclosed character class"));
+            };
+
+            // Like in the `regex` crate,
+            // a `]` at the start of a class is a literal `]`.
+            if c == ']' && !is_first {
+                break;
+            }
+            is_first = false;
+
+            let item = self.parse_class_item(c, item_offset)?;
+            let item = if self.is_at_range_hyphen() {
+                self.next();
+                self.parse_class_range_end(item, item_offset)?
+            } else {
+                item
+            };
+            class = class.union(&item);
+        }
+
+        if is_negated {
+            return Ok(class.negate());
+        }
+        Ok(class)
+    }
+
+    /// A `-` denotes a range unless it is the last character in the class.
+    fn is_at_range_hyphen(&self) -> bool {
+        let mut rest = self.pattern[self.offset..].chars();
+        rest.next() == Some('-') && !matches!(rest.next(), None | Some(']'))
+    }
+
+    fn parse_class_range_end(
+        &mut self,
+        range_start: CharClass,
+        range_start_offset: usize,
+    ) -> Result<CharClass, KikiErr> {
+        let end_offset = self.offset;
+        let end_c = self.next().unwrap();
+        let range_end = self.parse_class_item(end_c, end_offset)?;
+
+        let (Some(start), Some(end)) = (get_single_char(&range_start), get_single_char(&range_end))
+        else {
+            return Err(self.err_at(
+                range_start_offset,
+                "the endpoints of a range must be single characters",
+            ));
+        };
+
+        if start > end {
+            return Err(self.err_at(
+                range_start_offset,
+                "the start of a range must not be greater than its end",
+            ));
+        }
+
+        Ok(CharClass::new(vec![(start, end)]))
+    }
+
+    fn parse_class_item(&mut self, c: char, offset: usize) -> Result<CharClass, KikiErr> {
+        match c {
+            '\\' => self.parse_escape(offset),
+            '[' => Err(self.err_at(offset, "nested character classes are not supported")),
+            _ => Ok(CharClass::single(c)),
+        }
+    }
+
+    /// `backslash_offset` is the offset of the `\`.
+    fn parse_escape(&mut self, backslash_offset: usize) -> Result<CharClass, KikiErr> {
+        let Some(c) = self.next() else {
+            return Err(self.err_at(backslash_offset, "incomplete escape sequence"));
+        };
+
+        match c {
+            'n' => Ok(CharClass::single('\n')),
+            'r' => Ok(CharClass::single('\r')),
+            't' => Ok(CharClass::single('\t')),
+            'd' => Ok(get_digit_class()),
+            'w' => Ok(get_word_class()),
+            's' => Ok(get_space_class()),
+            'D' => Ok(get_digit_class().negate()),
+            'W' => Ok(get_word_class().negate()),
+            'S' => Ok(get_space_class().negate()),
+            _ if c.is_ascii_punctuation() => Ok(CharClass::single(c)),
+            _ => Err(self.err_at(backslash_offset, format!("unknown escape sequence `\\{c}`"))),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.pattern[self.offset..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.offset += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn err_at(&self, offset: usize, message: impl Into<String>) -> KikiErr {
+        KikiErr::InvalidRegex(ByteIndex(self.pattern_position.0 + offset), message.into())
+    }
+}
+
+fn get_single_char(class: &CharClass) -> Option<char> {
+    match class.ranges() {
+        [(start, end)] if start == end => Some(*start),
+        _ => None,
+    }
+}
+
+fn get_digit_class() -> CharClass {
+    CharClass::new(vec![('0', '9')])
+}
+
+fn get_word_class() -> CharClass {
+    CharClass::new(vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')])
+}
+
+fn get_space_class() -> CharClass {
+    CharClass::new(vec![('\t', '\r'), (' ', ' ')])
+}
@@ -0,0 +1,53 @@
+use super::*;
+
+/// If the terminal enum is spanned,
+/// every generated struct and enum variant gets a `span` field.
+/// This function validates that no named field
+/// of such a struct or variant is also called `span`.
+///
+/// Nonterminals with declared result types are not checked,
+/// since Kiki does not generate types for them.
+pub fn assert_span_field_is_free(
+    terminal_enum: &validated::TerminalEnum,
+    nonterminals: &[validated::Nonterminal],
+) -> Result<(), KikiErr> {
+    if terminal_enum.span_position_type.is_none() {
+        return Ok(());
+    }
+
+    for nonterminal in nonterminals {
+        if nonterminal.result_type().is_some() {
+            continue;
+        }
+
+        match nonterminal {
+            validated::Nonterminal::Struct(struct_def) => {
+                assert_fieldset_has_no_span_field(&struct_def.fieldset)?;
+            }
+            validated::Nonterminal::Enum(enum_def) => {
+                for variant in &enum_def.variants {
+                    assert_fieldset_has_no_span_field(&variant.fieldset)?;
+                }
+            }
+            validated::Nonterminal::Helper(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn assert_fieldset_has_no_span_field(fieldset: &validated::Fieldset) -> Result<(), KikiErr> {
+    let validated::Fieldset::Named(named) = fieldset else {
+        return Ok(());
+    };
+
+    for field in &named.fields {
+        if let IdentOrUnderscore::Ident(ident) = &field.name {
+            if ident.name == "span" {
+                return Err(KikiErr::SpanFieldNameClash(ident.position));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,12 +1,15 @@
 use super::*;
 
+use crate::pipeline::pascal_to_snake_case::pascal_to_snake_case;
+
 /// This function validates that:
-/// 1. There is exactly one `start` statement.
-/// 2. The start symbol refers to a valid nonterminal.
-pub fn get_start_symbol_name(
-    file: &File,
-    nonterminals: &[validated::Nonterminal],
-) -> Result<String, KikiErr> {
+/// 1. There is at least one `start` statement.
+/// 2. Every start symbol refers to a valid user-defined nonterminal.
+///    Generic nonterminals cannot be start symbols.
+/// 3. No start symbol is declared more than once.
+///
+/// The start symbol names are returned in declaration order.
+pub fn get_start_symbol_names(file: &File) -> Result<Vec<String>, KikiErr> {
     let starts: Vec<&Ident> = file
         .items
         .iter()
@@ -20,28 +23,98 @@ pub fn get_start_symbol_name(
         return Err(KikiErr::NoStartSymbol);
     }
 
-    if starts.len() > 1 {
-        let positions = starts.iter().map(|start| start.position).collect();
-        return Err(KikiErr::MultipleStartSymbols(positions));
+    let mut positions: HashMap<&str, ByteIndex> = HashMap::new();
+    let mut names = Vec::with_capacity(starts.len());
+    for start in starts {
+        if let Some(&first_position) = positions.get(start.name.as_str()) {
+            return Err(KikiErr::StartSymbolRedeclared(
+                start.name.clone(),
+                first_position,
+                start.position,
+            ));
+        }
+        positions.insert(&start.name, start.position);
+        names.push(validate_start_symbol_name_is_defined(start, file)?);
     }
 
-    validate_start_symbol_name_is_defined(starts[0], nonterminals)
+    Ok(names)
 }
 
 fn validate_start_symbol_name_is_defined(
     start_symbol: &Ident,
-    nonterminals: &[validated::Nonterminal],
+    file: &File,
 ) -> Result<String, KikiErr> {
-    let is_defined = nonterminals
-        .iter()
-        .any(|nonterminal| nonterminal.name() == start_symbol.name);
+    let params = file.items.iter().find_map(|item| match item {
+        FileItem::Struct(struct_def) if struct_def.name.name == start_symbol.name => {
+            Some(&struct_def.params)
+        }
+        FileItem::Enum(enum_def) if enum_def.name.name == start_symbol.name => {
+            Some(&enum_def.params)
+        }
+        _ => None,
+    });
 
-    if !is_defined {
-        return Err(KikiErr::UndefinedNonterminal(
+    match params {
+        None => Err(KikiErr::UndefinedNonterminal(
+            start_symbol.name.to_owned(),
+            start_symbol.position,
+        )),
+        Some(params) if !params.is_empty() => Err(KikiErr::GenericNonterminalUsedWithoutArgs(
             start_symbol.name.to_owned(),
             start_symbol.position,
-        ));
+        )),
+        Some(_) => Ok(start_symbol.name.to_owned()),
+    }
+}
+
+/// If there are multiple start symbols,
+/// each start symbol `Foo` gets the public functions
+/// `parse_foo`, `parse_foo_verbose`, and,
+/// if the grammar uses the `error` pseudo-terminal,
+/// `parse_foo_recovering`.
+/// This function validates that no two start symbols
+/// would get a function with the same name
+/// (e.g., `Foo` and `FooVerbose` would both get `parse_foo_verbose`).
+pub fn assert_parse_fn_names_are_unique(
+    file: &File,
+    uses_error_terminal: bool,
+) -> Result<(), KikiErr> {
+    let starts: Vec<&Ident> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            FileItem::Start(start) => Some(start),
+            _ => None,
+        })
+        .collect();
+
+    if starts.len() < 2 {
+        return Ok(());
+    }
+
+    let suffixes: &[&str] = if uses_error_terminal {
+        &["", "_verbose", "_recovering"]
+    } else {
+        &["", "_verbose"]
+    };
+
+    let mut owners: HashMap<String, &Ident> = HashMap::new();
+    for start in starts {
+        let parse_fn_name = format!("parse_{}", pascal_to_snake_case(&start.name));
+        for suffix in suffixes {
+            let fn_name = format!("{parse_fn_name}{suffix}");
+            if let Some(owner) = owners.get(&fn_name) {
+                return Err(KikiErr::ParseFnNameClash(
+                    fn_name,
+                    owner.name.clone(),
+                    owner.position,
+                    start.name.clone(),
+                    start.position,
+                ));
+            }
+            owners.insert(fn_name, start);
+        }
     }
 
-    Ok(start_symbol.name.to_owned())
+    Ok(())
 }
@@ -4,12 +4,20 @@ use super::*;
 /// 1. There is exactly one `terminal` statement.
 /// 2. The terminal enum name has proper capitalization.
 /// 3. Each variant name has proper capitalization.
+/// 4. If the enum is extern:
+///    1. It has no outer attributes.
+///    2. Every variant has a pattern.
+///    3. Every pattern binds at most one field,
+///       and binds exactly one field unless the variant's type is `()`.
+///    4. No two patterns match the same extern variant.
+/// 5. If the enum is not extern, no variant has a pattern.
 ///
 /// This function does **not** check for name clashes.
 ///
 /// ## Capitalization rules:
 /// 1. If a terminal enum name contains one or more letters,
 ///    the first letter must be uppercase.
+///    This does not apply to extern enum paths.
 /// 2. If a terminal variant name contains one or more letters,
 ///    the first letter must be uppercase.
 pub fn get_terminal_enum(file: &File) -> Result<validated::TerminalEnum, KikiErr> {
@@ -37,7 +45,10 @@ pub fn get_unvalidated_terminal_enum(file: &File) -> Result<&TerminalEnum, KikiE
     }
 
     if terminals.len() > 1 {
-        let positions = terminals.iter().map(|t| t.name.position).collect();
+        let positions = terminals
+            .iter()
+            .map(|t| t.name.scope_ident().position)
+            .collect();
         return Err(KikiErr::MultipleTerminalEnums(positions));
     }
 
@@ -46,34 +57,133 @@ pub fn get_unvalidated_terminal_enum(file: &File) -> Result<&TerminalEnum, KikiE
 
 fn validate_terminal_def(def: &TerminalEnum) -> Result<validated::TerminalEnum, KikiErr> {
     let attributes = def.attributes.clone();
-    let name = validate_ident_uppercase_start(&def.name)?.to_string();
-    let variants = validate_terminal_variants(def)?;
+    let (name, is_extern) = match &def.name {
+        TerminalEnumName::Local(ident) => {
+            (validate_ident_uppercase_start(ident)?.to_string(), false)
+        }
+        TerminalEnumName::Extern(path) => {
+            if let Some(attribute) = attributes.first() {
+                return Err(KikiErr::OuterAttributeOnExternTerminalEnum(
+                    attribute.position,
+                ));
+            }
+            let segments: Vec<&str> = path.iter().map(|ident| ident.name.as_str()).collect();
+            (segments.join("::"), true)
+        }
+    };
+    let variants = validate_terminal_variants(def, is_extern)?;
     Ok(validated::TerminalEnum {
+        doc: def.doc.clone(),
         attributes,
         name,
+        is_extern,
+        span_position_type: def
+            .span_position_type
+            .as_ref()
+            .map(type_to_string::type_to_string),
         variants,
     })
 }
 
 fn validate_terminal_variants(
     def: &TerminalEnum,
+    is_extern: bool,
 ) -> Result<Vec<validated::TerminalVariant>, KikiErr> {
     let variants = def
         .variants
         .iter()
-        .map(validate_variant_capitalization)
+        .map(|variant| validate_variant(variant, is_extern))
         .collect::<Result<Vec<_>, _>>()?;
+
+    if is_extern {
+        assert_extern_variants_are_unique(def)?;
+    }
+
     Ok(variants)
 }
 
-fn validate_variant_capitalization(
+fn validate_variant(
     variant: &TerminalEnumVariant,
+    is_extern: bool,
 ) -> Result<validated::TerminalVariant, KikiErr> {
     let validated_name = validate_terminal_ident_uppercase_start(&variant.name)?;
     let dollarless_name = DollarlessTerminalName::remove_dollars(validated_name);
     let type_ = type_to_string::type_to_string(&variant.type_);
+    let pattern = validate_pattern(variant, &dollarless_name, is_extern)?;
     Ok(validated::TerminalVariant {
+        doc: variant.doc.clone(),
         dollarless_name,
+        position: variant.name.dollarless_position,
         type_,
+        pattern,
+        literal: variant.literal.clone(),
     })
 }
+
+fn validate_pattern(
+    variant: &TerminalEnumVariant,
+    dollarless_name: &DollarlessTerminalName,
+    is_extern: bool,
+) -> Result<Option<TerminalPattern>, KikiErr> {
+    let pattern = match (&variant.pattern, is_extern) {
+        (None, false) => return Ok(None),
+        (None, true) => {
+            return Err(KikiErr::ExternTerminalMissingPattern(
+                dollarless_name.clone(),
+                variant.name.dollarless_position,
+            ))
+        }
+        (Some(pattern), false) => {
+            return Err(KikiErr::TerminalPatternOnLocalTerminalEnum(
+                dollarless_name.clone(),
+                pattern.variant.position,
+            ))
+        }
+        (Some(pattern), true) => pattern,
+    };
+
+    let bindings: Vec<&Ident> = pattern
+        .fields
+        .iter()
+        .flatten()
+        .filter_map(|field| match field {
+            IdentOrUnderscore::Ident(ident) => Some(ident),
+            IdentOrUnderscore::Underscore(_) => None,
+        })
+        .collect();
+
+    if let Some(second_binding) = bindings.get(1) {
+        return Err(KikiErr::TerminalPatternBindsMultipleFields(
+            dollarless_name.clone(),
+            second_binding.position,
+        ));
+    }
+
+    if bindings.is_empty() && !matches!(variant.type_, Type::Unit) {
+        return Err(KikiErr::TerminalPatternBindsNoField(
+            dollarless_name.clone(),
+            pattern.variant.position,
+        ));
+    }
+
+    Ok(Some(pattern.clone()))
+}
+
+/// Since every pattern matches _every_ value of its extern variant,
+/// two patterns on the same extern variant would make
+/// the second one unreachable.
+fn assert_extern_variants_are_unique(def: &TerminalEnum) -> Result<(), KikiErr> {
+    let mut seen: HashMap<&str, ByteIndex> = HashMap::new();
+    for pattern in def.variants.iter().filter_map(|v| v.pattern.as_ref()) {
+        let name = pattern.variant.name.as_str();
+        if let Some(existing_position) = seen.get(name) {
+            return Err(KikiErr::ExternTerminalVariantRepeated(
+                name.to_owned(),
+                *existing_position,
+                pattern.variant.position,
+            ));
+        }
+        seen.insert(name, pattern.variant.position);
+    }
+    Ok(())
+}
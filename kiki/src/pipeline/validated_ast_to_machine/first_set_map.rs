@@ -1,6 +1,6 @@
 use super::*;
 
-pub(super) fn get_first_sets(rules: &[Rule]) -> HashMap<String, FirstSet> {
+pub(crate) fn get_first_sets(rules: &[Rule]) -> HashMap<String, FirstSet> {
     let builder = FirstSetMapBuilder { rules };
     builder.get_first_sets()
 }
@@ -0,0 +1,716 @@
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// Builds the LALR(1) machine.
+///
+/// We first build the LR(0) machine,
+/// looking up each state by its kernel in a hash map.
+/// Then we compute the lookaheads of each kernel item
+/// by propagation (see "Efficient Construction of LALR Parsing Tables"
+/// in _Compilers: Principles, Techniques, and Tools_),
+/// storing each lookahead set as a bitset.
+/// Finally, we expand each state into LR(1) items.
+///
+/// The result is identical to the machine we would get
+/// by merging the states of the canonical LR(1) machine
+/// that have equal cores.
+///
+/// If the machine has a reduce/reduce conflict,
+/// we also return the canonical LR(1) states that were merged,
+/// so the conflict can be explained.
+/// Merging never causes any other kind of conflict,
+/// so otherwise, we don't spend time finding those states.
+pub(super) fn get_lalr1_machine(
+    context: &ImmutContext,
+) -> (UnnormalizedMachine, Option<LalrMergeRecord>) {
+    let grammar = Grammar::new(context);
+    let states = grammar.get_lr0_states();
+    let closures: Vec<ClosureLookaheads> = states
+        .iter()
+        .map(|state| grammar.get_closure_lookaheads(state))
+        .collect();
+    let kernel_lookaheads = grammar.get_kernel_lookaheads(&states, &closures);
+    let machine = grammar.expand(&states, &closures, &kernel_lookaheads);
+    let lalr_merges = !grammar
+        .get_reduce_reduce_conflict_lookaheads(&states, &closures, &kernel_lookaheads)
+        .is_empty();
+    let lalr_merges = lalr_merges.then(|| grammar.get_lalr_merge_record(&states, &closures));
+    (machine, lalr_merges)
+}
+
+/// An item without a lookahead.
+pub(super) type CoreItem = (RuleIndex, usize);
+
+pub(super) struct Grammar<'a> {
+    start_count: usize,
+    rule_symbols: Vec<Vec<Symbol>>,
+    /// The right-hand side of the augmented rule `S' -> S`
+    /// for each start symbol `S`.
+    augmented_rule_symbols: Vec<Vec<Symbol>>,
+    rules_by_nonterminal: HashMap<&'a str, Vec<usize>>,
+    /// The nonterminal of each original rule.
+    rule_nonterminals: Vec<&'a str>,
+    /// The lookahead with index `i` is `lookaheads[i]`.
+    /// The end of input has index 0.
+    lookaheads: Vec<Lookahead>,
+    lookahead_indices: HashMap<DollarlessTerminalName, usize>,
+    /// The first set of each nonterminal,
+    /// and whether the nonterminal derives the empty string.
+    first_sets: HashMap<&'a str, (Bitset, bool)>,
+}
+
+#[derive(Clone)]
+pub(super) struct Lr0State {
+    /// The kernel items, sorted.
+    pub(super) kernel: Vec<CoreItem>,
+    /// The kernel items, followed by the items added by closure.
+    /// The closure items of each nonterminal are contiguous.
+    pub(super) items: Vec<CoreItem>,
+    /// The state reached by each symbol, sorted by symbol.
+    pub(super) transitions: Vec<(Symbol, usize)>,
+}
+
+/// Describes where the lookaheads of a state's closure items come from.
+/// Every closure item of a given nonterminal
+/// has the same lookaheads,
+/// so we only store one entry per nonterminal.
+#[derive(Clone)]
+pub(super) struct ClosureLookaheads<'a> {
+    /// The lookaheads that the state generates itself
+    /// (i.e., the first sets of the symbols after the nonterminal).
+    spontaneous: HashMap<&'a str, Bitset>,
+    /// The kernel items (by index in `Lr0State::kernel`)
+    /// whose lookaheads propagate to the nonterminal's items.
+    propagated_from: HashMap<&'a str, Bitset>,
+}
+
+impl<'a> Grammar<'a> {
+    pub(super) fn new(context: &'a ImmutContext<'a>) -> Grammar<'a> {
+        let rule_symbols: Vec<Vec<Symbol>> = context
+            .rules
+            .iter()
+            .map(|rule| get_field_symbols_from_n_onwards(rule.fieldset, 0))
+            .collect();
+        let augmented_rule_symbols = context
+            .start_nonterminal_names
+            .iter()
+            .map(|name| vec![Symbol::Nonterminal(name.clone())])
+            .collect();
+
+        let rule_nonterminals: Vec<&str> = context
+            .rules
+            .iter()
+            .map(|rule| rule.constructor_name.type_name())
+            .collect();
+        let mut rules_by_nonterminal: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (rule_index, &name) in rule_nonterminals.iter().enumerate() {
+            rules_by_nonterminal
+                .entry(name)
+                .or_default()
+                .push(rule_index);
+        }
+
+        // Every terminal that can be a lookahead appears in some rule.
+        let mut lookaheads = vec![Lookahead::Eof];
+        let mut lookahead_indices = HashMap::new();
+        for symbol in rule_symbols.iter().flatten() {
+            if let Symbol::Terminal(terminal) = symbol {
+                lookahead_indices
+                    .entry(terminal.clone())
+                    .or_insert_with(|| {
+                        lookaheads.push(Lookahead::Terminal(terminal.clone()));
+                        lookaheads.len() - 1
+                    });
+            }
+        }
+
+        let len = lookaheads.len();
+        let first_sets = context
+            .first_sets
+            .iter()
+            .map(|(name, first_set)| {
+                let mut terminals = Bitset::new(len);
+                for terminal in first_set.terminals.iter() {
+                    terminals.insert(lookahead_indices[terminal]);
+                }
+                (name.as_str(), (terminals, first_set.contains_epsilon))
+            })
+            .collect();
+
+        Grammar {
+            start_count: context.start_nonterminal_names.len(),
+            rule_symbols,
+            augmented_rule_symbols,
+            rules_by_nonterminal,
+            rule_nonterminals,
+            lookaheads,
+            lookahead_indices,
+            first_sets,
+        }
+    }
+
+    fn get_symbols(&self, rule_index: RuleIndex) -> &[Symbol] {
+        match rule_index {
+            RuleIndex::Original(rule_index) => &self.rule_symbols[rule_index],
+            RuleIndex::Augmented(start_index) => &self.augmented_rule_symbols[start_index],
+        }
+    }
+
+    fn get_symbol_right_of_dot(&self, (rule_index, dot): CoreItem) -> Option<&Symbol> {
+        self.get_symbols(rule_index).get(dot)
+    }
+
+    /// Returns the first set of `symbols`,
+    /// and whether `symbols` derives the empty string.
+    fn get_first_of_symbol_sequence(&self, symbols: &[Symbol]) -> (Bitset, bool) {
+        let mut first = Bitset::new(self.lookaheads.len());
+        for symbol in symbols {
+            match symbol {
+                Symbol::Terminal(name) => {
+                    first.insert(self.lookahead_indices[name]);
+                    return (first, false);
+                }
+                Symbol::Nonterminal(name) => {
+                    let (nonterminal_first, contains_epsilon) = &self.first_sets[name.as_str()];
+                    first.union_with(nonterminal_first);
+                    if !contains_epsilon {
+                        return (first, false);
+                    }
+                }
+            }
+        }
+        (first, true)
+    }
+}
+
+impl Grammar<'_> {
+    pub(super) fn get_lr0_states(&self) -> Vec<Lr0State> {
+        let mut kernels: Vec<Vec<CoreItem>> = (0..self.start_count)
+            .map(|start_index| vec![(RuleIndex::Augmented(start_index), 0)])
+            .collect();
+        let mut kernel_indices: HashMap<Vec<CoreItem>, usize> = kernels
+            .iter()
+            .enumerate()
+            .map(|(i, kernel)| (kernel.clone(), i))
+            .collect();
+
+        let mut states = vec![];
+        while states.len() < kernels.len() {
+            let kernel = kernels[states.len()].clone();
+            let items = self.get_closure(&kernel);
+
+            let mut targets: BTreeMap<&Symbol, Vec<CoreItem>> = BTreeMap::new();
+            for &(rule_index, dot) in &items {
+                if let Some(symbol) = self.get_symbol_right_of_dot((rule_index, dot)) {
+                    targets
+                        .entry(symbol)
+                        .or_default()
+                        .push((rule_index, dot + 1));
+                }
+            }
+
+            let transitions = targets
+                .into_iter()
+                .map(|(symbol, mut target)| {
+                    target.sort_unstable();
+                    let index = *kernel_indices.entry(target).or_insert_with_key(|target| {
+                        kernels.push(target.clone());
+                        kernels.len() - 1
+                    });
+                    (symbol.clone(), index)
+                })
+                .collect();
+
+            states.push(Lr0State {
+                kernel,
+                items,
+                transitions,
+            });
+        }
+        states
+    }
+
+    fn get_closure(&self, kernel: &[CoreItem]) -> Vec<CoreItem> {
+        let mut items = kernel.to_vec();
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut i = 0;
+        while i < items.len() {
+            if let Some(Symbol::Nonterminal(name)) = self.get_symbol_right_of_dot(items[i]) {
+                if visited.insert(name) {
+                    items.extend(
+                        self.rules_by_nonterminal[name.as_str()]
+                            .iter()
+                            .map(|&rule_index| (RuleIndex::Original(rule_index), 0)),
+                    );
+                }
+            }
+            i += 1;
+        }
+        items
+    }
+
+    /// Returns the nonterminal of the rule,
+    /// or `None` if the rule is an augmented rule.
+    fn get_nonterminal(&self, rule_index: RuleIndex) -> Option<&str> {
+        match rule_index {
+            RuleIndex::Original(rule_index) => Some(self.rule_nonterminals[rule_index]),
+            RuleIndex::Augmented(_) => None,
+        }
+    }
+}
+
+impl Grammar<'_> {
+    pub(super) fn get_closure_lookaheads(&self, state: &Lr0State) -> ClosureLookaheads<'_> {
+        let empty_lookaheads = Bitset::new(self.lookaheads.len());
+        let empty_kernel = Bitset::new(state.kernel.len());
+        let mut closure = ClosureLookaheads {
+            spontaneous: HashMap::new(),
+            propagated_from: HashMap::new(),
+        };
+
+        // For each item `A -> alpha . B beta`,
+        // the items of `B` get the first set of `beta`,
+        // and if `beta` derives the empty string,
+        // they also get the lookaheads of the item itself.
+        let mut parents: Vec<(usize, &str, Bitset, bool)> = vec![];
+        for (i, &item) in state.items.iter().enumerate() {
+            if let Some(Symbol::Nonterminal(name)) = self.get_symbol_right_of_dot(item) {
+                let (rule_index, dot) = item;
+                let after = &self.get_symbols(rule_index)[dot + 1..];
+                let (first, contains_epsilon) = self.get_first_of_symbol_sequence(after);
+                parents.push((i, name.as_str(), first, contains_epsilon));
+            }
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (i, name, first, contains_epsilon) in &parents {
+                let (parent_spontaneous, parent_propagated_from) = if *i < state.kernel.len() {
+                    let mut propagated_from = empty_kernel.clone();
+                    propagated_from.insert(*i);
+                    (empty_lookaheads.clone(), propagated_from)
+                } else {
+                    let parent = self.get_nonterminal(state.items[*i].0).unwrap();
+                    (
+                        closure.spontaneous[parent].clone(),
+                        closure.propagated_from[parent].clone(),
+                    )
+                };
+
+                let spontaneous = closure
+                    .spontaneous
+                    .entry(name)
+                    .or_insert_with(|| empty_lookaheads.clone());
+                changed |= spontaneous.union_with(first);
+                if *contains_epsilon {
+                    changed |= spontaneous.union_with(&parent_spontaneous);
+                }
+
+                let propagated_from = closure
+                    .propagated_from
+                    .entry(name)
+                    .or_insert_with(|| empty_kernel.clone());
+                if *contains_epsilon {
+                    changed |= propagated_from.union_with(&parent_propagated_from);
+                }
+            }
+        }
+
+        closure
+    }
+
+    /// Returns the lookaheads of each kernel item of each state.
+    pub(super) fn get_kernel_lookaheads(
+        &self,
+        states: &[Lr0State],
+        closures: &[ClosureLookaheads],
+    ) -> Vec<Vec<Bitset>> {
+        let mut lookaheads: Vec<Vec<Bitset>> = states
+            .iter()
+            .map(|state| vec![Bitset::new(self.lookaheads.len()); state.kernel.len()])
+            .collect();
+        for start_lookaheads in lookaheads.iter_mut().take(self.start_count) {
+            // The augmented start item has the lookahead `Eof`.
+            start_lookaheads[0].insert(0);
+        }
+
+        // Each kernel item (identified by state index and kernel index)
+        // propagates its lookaheads to these kernel items.
+        let mut propagates_to: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+        for (state_index, state) in states.iter().enumerate() {
+            let closure = &closures[state_index];
+            for (i, &(rule_index, dot)) in state.items.iter().enumerate() {
+                let Some(symbol) = self.get_symbol_right_of_dot((rule_index, dot)) else {
+                    continue;
+                };
+                let target = state
+                    .transitions
+                    .binary_search_by(|(other, _)| other.cmp(symbol))
+                    .map(|j| state.transitions[j].1)
+                    .unwrap();
+                let target_kernel_index = states[target]
+                    .kernel
+                    .binary_search(&(rule_index, dot + 1))
+                    .unwrap();
+                let target_item = (target, target_kernel_index);
+
+                if i < state.kernel.len() {
+                    propagates_to
+                        .entry((state_index, i))
+                        .or_default()
+                        .push(target_item);
+                } else {
+                    let name = self.get_nonterminal(rule_index).unwrap();
+                    lookaheads[target][target_kernel_index].union_with(&closure.spontaneous[name]);
+                    for kernel_index in closure.propagated_from[name].iter() {
+                        propagates_to
+                            .entry((state_index, kernel_index))
+                            .or_default()
+                            .push(target_item);
+                    }
+                }
+            }
+        }
+
+        let mut queue: VecDeque<(usize, usize)> = states
+            .iter()
+            .enumerate()
+            .flat_map(|(state_index, state)| {
+                (0..state.kernel.len()).map(move |kernel_index| (state_index, kernel_index))
+            })
+            .collect();
+        while let Some((state_index, kernel_index)) = queue.pop_front() {
+            let source = lookaheads[state_index][kernel_index].clone();
+            for &(target, target_kernel_index) in propagates_to
+                .get(&(state_index, kernel_index))
+                .into_iter()
+                .flatten()
+            {
+                if lookaheads[target][target_kernel_index].union_with(&source) {
+                    queue.push_back((target, target_kernel_index));
+                }
+            }
+        }
+
+        lookaheads
+    }
+
+    pub(super) fn expand(
+        &self,
+        states: &[Lr0State],
+        closures: &[ClosureLookaheads],
+        kernel_lookaheads: &[Vec<Bitset>],
+    ) -> UnnormalizedMachine {
+        let mut transitions = HashSet::new();
+        let states = states
+            .iter()
+            .enumerate()
+            .map(|(state_index, state)| {
+                for (symbol, target) in &state.transitions {
+                    transitions.insert(Transition {
+                        from: StateIndex(state_index),
+                        to: StateIndex(*target),
+                        symbol: symbol.clone(),
+                    });
+                }
+
+                let lookaheads = self.get_item_lookaheads(
+                    state,
+                    &closures[state_index],
+                    &kernel_lookaheads[state_index],
+                );
+                State {
+                    items: self.get_items(state, &lookaheads).collect(),
+                }
+            })
+            .collect();
+
+        UnnormalizedMachine {
+            states,
+            starts: (0..self.start_count).map(StateIndex).collect(),
+            transitions,
+        }
+    }
+
+    /// Returns the lookaheads of each item of the state,
+    /// given the lookaheads of its kernel items.
+    pub(super) fn get_item_lookaheads(
+        &self,
+        state: &Lr0State,
+        closure: &ClosureLookaheads,
+        kernel_lookaheads: &[Bitset],
+    ) -> Vec<Bitset> {
+        let closure_lookaheads: HashMap<&str, Bitset> = closure
+            .spontaneous
+            .iter()
+            .map(|(&name, spontaneous)| {
+                let mut lookaheads = spontaneous.clone();
+                for kernel_index in closure.propagated_from[name].iter() {
+                    lookaheads.union_with(&kernel_lookaheads[kernel_index]);
+                }
+                (name, lookaheads)
+            })
+            .collect();
+
+        state
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, &(rule_index, _))| {
+                if i < state.kernel.len() {
+                    kernel_lookaheads[i].clone()
+                } else {
+                    closure_lookaheads[self.get_nonterminal(rule_index).unwrap()].clone()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the LR(1) items of the state,
+    /// given the lookaheads of each of its items.
+    fn get_items<'b>(
+        &'b self,
+        state: &'b Lr0State,
+        lookaheads: &'b [Bitset],
+    ) -> impl Iterator<Item = StateItem> + 'b {
+        state
+            .items
+            .iter()
+            .zip(lookaheads)
+            .flat_map(move |(&(rule_index, dot), lookaheads)| {
+                lookaheads.iter().map(move |lookahead| StateItem {
+                    rule_index,
+                    lookahead: self.lookaheads[lookahead].clone(),
+                    dot,
+                })
+            })
+    }
+
+    /// Returns the lookaheads on which some state
+    /// would reduce more than one rule.
+    pub(super) fn get_reduce_reduce_conflict_lookaheads(
+        &self,
+        states: &[Lr0State],
+        closures: &[ClosureLookaheads],
+        kernel_lookaheads: &[Vec<Bitset>],
+    ) -> Bitset {
+        let mut conflicting = Bitset::new(self.lookaheads.len());
+        for (state_index, state) in states.iter().enumerate() {
+            let lookaheads = self.get_item_lookaheads(
+                state,
+                &closures[state_index],
+                &kernel_lookaheads[state_index],
+            );
+            // No two items of a state have the same core,
+            // so each reduction has a different rule.
+            let mut reduced = Bitset::new(self.lookaheads.len());
+            for reduction in self.get_reduction_lookaheads(state, &lookaheads) {
+                conflicting.union_with(&reduced.intersection(reduction));
+                reduced.union_with(reduction);
+            }
+        }
+        conflicting
+    }
+
+    /// Returns the lookaheads of the items whose dot is at the end of their rule,
+    /// given the lookaheads of each item of the state.
+    pub(super) fn get_reduction_lookaheads<'b>(
+        &self,
+        state: &Lr0State,
+        lookaheads: &'b [Bitset],
+    ) -> Vec<&'b Bitset> {
+        state
+            .items
+            .iter()
+            .zip(lookaheads)
+            .filter(|(&item, _)| self.get_symbol_right_of_dot(item).is_none())
+            .map(|(_, lookaheads)| lookaheads)
+            .collect()
+    }
+}
+
+impl Grammar<'_> {
+    /// Finds the states of the canonical LR(1) machine,
+    /// and records which LALR(1) state each of them was merged into.
+    ///
+    /// A canonical LR(1) state is determined by its LR(0) state
+    /// and the lookaheads of its kernel items,
+    /// so instead of computing closures,
+    /// we reuse the closure lookaheads of the LR(0) states.
+    fn get_lalr_merge_record(
+        &self,
+        states: &[Lr0State],
+        closures: &[ClosureLookaheads],
+    ) -> LalrMergeRecord {
+        let (kernels, transitions) = self.get_lr1_kernels(states, closures, None);
+        let lr1_states = kernels
+            .iter()
+            .map(|(state_index, kernel_lookaheads)| {
+                let state = &states[*state_index];
+                let lookaheads =
+                    self.get_item_lookaheads(state, &closures[*state_index], kernel_lookaheads);
+                MergedState {
+                    // The index of each LALR(1) state
+                    // equals the index of its LR(0) state.
+                    merged_into: StateIndex(*state_index),
+                    reductions: self
+                        .get_items(state, &lookaheads)
+                        .filter(|item| {
+                            self.get_symbol_right_of_dot((item.rule_index, item.dot))
+                                .is_none()
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+        LalrMergeRecord {
+            lr1_starts: (0..self.start_count).map(StateIndex).collect(),
+            lr1_states,
+            lr1_transitions: transitions.into_iter().collect(),
+        }
+    }
+
+    /// Returns the index of the LR(0) state of each canonical LR(1) state
+    /// and the lookaheads of its kernel items,
+    /// along with the transitions between the canonical LR(1) states.
+    ///
+    /// The first `n` states are the start states,
+    /// where `n` is the number of start symbols.
+    ///
+    /// If `restrict_to` is `Some`, we remove every other lookahead
+    /// from every item,
+    /// so canonical LR(1) states that only differ
+    /// in the removed lookaheads become the same state.
+    pub(super) fn get_lr1_kernels(
+        &self,
+        states: &[Lr0State],
+        closures: &[ClosureLookaheads],
+        restrict_to: Option<&Bitset>,
+    ) -> (Vec<(usize, Vec<Bitset>)>, HashSet<Transition>) {
+        let restrict = |lookaheads: &mut Bitset| {
+            if let Some(restrict_to) = restrict_to {
+                lookaheads.intersect_with(restrict_to);
+            }
+        };
+
+        let mut kernels: Vec<(usize, Vec<Bitset>)> = (0..self.start_count)
+            .map(|start_index| {
+                // The augmented start item has the lookahead `Eof`.
+                let mut lookaheads = Bitset::new(self.lookaheads.len());
+                lookaheads.insert(0);
+                restrict(&mut lookaheads);
+                (start_index, vec![lookaheads])
+            })
+            .collect();
+        let mut kernel_indices: HashMap<(usize, Vec<Bitset>), usize> = kernels
+            .iter()
+            .enumerate()
+            .map(|(i, kernel)| (kernel.clone(), i))
+            .collect();
+        let mut transitions = HashSet::new();
+
+        let mut i = 0;
+        while i < kernels.len() {
+            let (state_index, kernel_lookaheads) = kernels[i].clone();
+            let state = &states[state_index];
+            let mut lookaheads =
+                self.get_item_lookaheads(state, &closures[state_index], &kernel_lookaheads);
+            lookaheads.iter_mut().for_each(restrict);
+
+            let mut targets: Vec<(usize, Vec<Bitset>)> = state
+                .transitions
+                .iter()
+                .map(|&(_, target)| {
+                    let empty = Bitset::new(self.lookaheads.len());
+                    (target, vec![empty; states[target].kernel.len()])
+                })
+                .collect();
+            for (&(rule_index, dot), lookaheads) in state.items.iter().zip(&lookaheads) {
+                let Some(symbol) = self.get_symbol_right_of_dot((rule_index, dot)) else {
+                    continue;
+                };
+                let j = state
+                    .transitions
+                    .binary_search_by(|(other, _)| other.cmp(symbol))
+                    .unwrap();
+                let (target, target_lookaheads) = &mut targets[j];
+                let target_kernel_index = states[*target]
+                    .kernel
+                    .binary_search(&(rule_index, dot + 1))
+                    .unwrap();
+                target_lookaheads[target_kernel_index].union_with(lookaheads);
+            }
+
+            for ((symbol, _), target) in state.transitions.iter().zip(targets) {
+                let index = *kernel_indices.entry(target).or_insert_with_key(|target| {
+                    kernels.push(target.clone());
+                    kernels.len() - 1
+                });
+                transitions.insert(Transition {
+                    from: StateIndex(i),
+                    to: StateIndex(index),
+                    symbol: symbol.clone(),
+                });
+            }
+            i += 1;
+        }
+
+        (kernels, transitions)
+    }
+}
+
+/// A set of small integers.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(super) struct Bitset {
+    words: Vec<u64>,
+}
+
+impl Bitset {
+    /// Returns an empty set that can hold the integers `0..len`.
+    fn new(len: usize) -> Bitset {
+        Bitset {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, n: usize) {
+        self.words[n / 64] |= 1 << (n % 64);
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Returns true if any integers were added.
+    pub(super) fn union_with(&mut self, other: &Bitset) -> bool {
+        let mut changed = false;
+        for (word, other_word) in self.words.iter_mut().zip(&other.words) {
+            let union = *word | other_word;
+            changed |= union != *word;
+            *word = union;
+        }
+        changed
+    }
+
+    fn intersect_with(&mut self, other: &Bitset) {
+        for (word, other_word) in self.words.iter_mut().zip(&other.words) {
+            *word &= other_word;
+        }
+    }
+
+    pub(super) fn intersection(&self, other: &Bitset) -> Bitset {
+        let mut intersection = self.clone();
+        intersection.intersect_with(other);
+        intersection
+    }
+
+    pub(super) fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| i * 64 + bit)
+        })
+    }
+}
@@ -0,0 +1,274 @@
+use super::lalr1::{Bitset, ClosureLookaheads, Grammar, Lr0State};
+use super::*;
+
+use std::hash::Hash;
+
+/// Builds the minimal LR(1) machine
+/// by splitting the states of the LALR(1) machine
+/// whose merge causes a reduce/reduce conflict.
+///
+/// If the LALR(1) machine has no reduce/reduce conflict,
+/// we return it as is.
+/// Otherwise, we call the lookaheads on which some LALR(1) state
+/// has a reduce/reduce conflict the _conflicting lookaheads_.
+/// Splitting a state only removes lookaheads from it,
+/// so a split state can only have conflicts on conflicting lookaheads.
+/// So, we find the states of the canonical LR(1) machine,
+/// with every other lookahead removed
+/// (which merges the states that only differed in those lookaheads).
+///
+/// We start by grouping these states by core,
+/// which corresponds to the LALR(1) machine.
+/// Then, until nothing changes, we:
+/// 1. Split each group whose members have transitions
+///    on the same symbol to different groups,
+///    since a merged state can only have one transition per symbol.
+/// 2. Split each group whose merged state would have
+///    a reduce/reduce conflict that none of its members have.
+///
+/// Finally, we compute the lookaheads of each group's state
+/// by propagation, just like for LALR(1).
+pub(super) fn get_minimal_lr1_machine(context: &ImmutContext) -> UnnormalizedMachine {
+    let grammar = Grammar::new(context);
+    let states = grammar.get_lr0_states();
+    let closures: Vec<ClosureLookaheads> = states
+        .iter()
+        .map(|state| grammar.get_closure_lookaheads(state))
+        .collect();
+    let kernel_lookaheads = grammar.get_kernel_lookaheads(&states, &closures);
+    let conflicting =
+        grammar.get_reduce_reduce_conflict_lookaheads(&states, &closures, &kernel_lookaheads);
+    if conflicting.is_empty() {
+        return grammar.expand(&states, &closures, &kernel_lookaheads);
+    }
+
+    let (kernels, transitions) = grammar.get_lr1_kernels(&states, &closures, Some(&conflicting));
+    let successors = get_successors(kernels.len(), &transitions);
+    let reductions: Vec<Vec<Bitset>> = kernels
+        .iter()
+        .map(|(state_index, kernel_lookaheads)| {
+            let state = &states[*state_index];
+            let lookaheads =
+                grammar.get_item_lookaheads(state, &closures[*state_index], kernel_lookaheads);
+            grammar
+                .get_reduction_lookaheads(state, &lookaheads)
+                .into_iter()
+                .map(|reduction| reduction.intersection(&conflicting))
+                .collect()
+        })
+        .collect();
+    let conflicts: Vec<HashSet<Conflict>> = reductions
+        .iter()
+        .map(|reductions| get_conflicts(reductions))
+        .collect();
+
+    let mut groups = Groups::new(
+        kernels
+            .iter()
+            .map(|(state_index, _)| *state_index)
+            .collect(),
+    );
+    loop {
+        groups = split_until_transitions_agree(groups, &successors);
+        let split = split_conflicting_groups(&kernels, &reductions, &conflicts, &groups);
+        if split.len == groups.len {
+            break;
+        }
+        groups = split;
+    }
+
+    let (split_states, split_closures) =
+        get_group_states(&states, &closures, &kernels, &successors, &groups);
+    let split_kernel_lookaheads = grammar.get_kernel_lookaheads(&split_states, &split_closures);
+    grammar.expand(&split_states, &split_closures, &split_kernel_lookaheads)
+}
+
+/// A lookahead (by index), followed by two distinct reductions
+/// (by index in the state's list of reductions)
+/// that can both be reduced on that lookahead.
+/// The first reduction index is less than the second.
+type Conflict = (usize, usize, usize);
+
+fn get_conflicts(reductions: &[Bitset]) -> HashSet<Conflict> {
+    let mut conflicts = HashSet::new();
+    for (i, first) in reductions.iter().enumerate() {
+        for (j, second) in reductions.iter().enumerate().skip(i + 1) {
+            conflicts.extend(
+                first
+                    .intersection(second)
+                    .iter()
+                    .map(|lookahead| (lookahead, i, j)),
+            );
+        }
+    }
+    conflicts
+}
+
+/// A partition of the states of a machine.
+#[derive(Debug, Clone)]
+struct Groups {
+    /// The group index of each state.
+    /// Group indices are assigned in order of first appearance,
+    /// so the lowest-indexed state in each group
+    /// determines the group's index.
+    of: Vec<usize>,
+    len: usize,
+}
+
+impl Groups {
+    /// Puts the states with equal keys in the same group.
+    fn new<K: Hash + Eq>(keys: Vec<K>) -> Groups {
+        let mut indices: HashMap<K, usize> = HashMap::new();
+        let of = keys
+            .into_iter()
+            .map(|key| {
+                let len = indices.len();
+                *indices.entry(key).or_insert(len)
+            })
+            .collect();
+        Groups {
+            of,
+            len: indices.len(),
+        }
+    }
+
+    fn get_members(&self) -> Vec<Vec<usize>> {
+        let mut members = vec![vec![]; self.len];
+        for (state_index, &group) in self.of.iter().enumerate() {
+            members[group].push(state_index);
+        }
+        members
+    }
+}
+
+/// Returns the transitions of each state,
+/// sorted by symbol.
+fn get_successors(
+    state_count: usize,
+    transitions: &HashSet<Transition>,
+) -> Vec<Vec<(&Symbol, usize)>> {
+    let mut successors = vec![vec![]; state_count];
+    for transition in transitions {
+        successors[transition.from.0].push((&transition.symbol, transition.to.0));
+    }
+    for state_successors in &mut successors {
+        state_successors.sort_unstable();
+    }
+    successors
+}
+
+fn split_until_transitions_agree(
+    mut groups: Groups,
+    successors: &[Vec<(&Symbol, usize)>],
+) -> Groups {
+    loop {
+        let keys = successors
+            .iter()
+            .enumerate()
+            .map(|(state_index, state_successors)| {
+                let successor_groups: Vec<(&Symbol, usize)> = state_successors
+                    .iter()
+                    .map(|&(symbol, to)| (symbol, groups.of[to]))
+                    .collect();
+                (groups.of[state_index], successor_groups)
+            })
+            .collect();
+        let split = Groups::new(keys);
+        if split.len == groups.len {
+            return split;
+        }
+        groups = split;
+    }
+}
+
+/// Splits each group whose merged state would have a new conflict.
+///
+/// Each member joins the first subgroup it can join
+/// without introducing a new conflict.
+/// We visit the members in order of their kernel lookaheads,
+/// so the result does not depend on the order
+/// in which the states were found.
+/// However, this greedy approach does not guarantee
+/// the fewest possible subgroups
+/// (finding those is as hard as graph coloring),
+/// so the machine may have more states than necessary.
+fn split_conflicting_groups(
+    kernels: &[(usize, Vec<Bitset>)],
+    reductions: &[Vec<Bitset>],
+    conflicts: &[HashSet<Conflict>],
+    groups: &Groups,
+) -> Groups {
+    let mut keys = vec![(0, 0); kernels.len()];
+    for (group, mut members) in groups.get_members().into_iter().enumerate() {
+        members.sort_unstable_by(|&a, &b| kernels[a].1.cmp(&kernels[b].1));
+        let mut subgroups: Vec<(Vec<Bitset>, HashSet<&Conflict>)> = vec![];
+        for state_index in members {
+            let state_reductions = &reductions[state_index];
+            let subgroup = subgroups.iter().position(|(merged, allowed)| {
+                let merged: Vec<Bitset> = merge_reductions(merged.clone(), state_reductions);
+                get_conflicts(&merged).iter().all(|conflict| {
+                    allowed.contains(conflict) || conflicts[state_index].contains(conflict)
+                })
+            });
+            let subgroup = match subgroup {
+                Some(subgroup) => {
+                    let (merged, allowed) = &mut subgroups[subgroup];
+                    *merged = merge_reductions(std::mem::take(merged), state_reductions);
+                    allowed.extend(&conflicts[state_index]);
+                    subgroup
+                }
+                None => {
+                    subgroups.push((
+                        state_reductions.clone(),
+                        conflicts[state_index].iter().collect(),
+                    ));
+                    subgroups.len() - 1
+                }
+            };
+            keys[state_index] = (group, subgroup);
+        }
+    }
+    Groups::new(keys)
+}
+
+/// Returns the lookaheads of each reduction of the merge of two states
+/// with the same core.
+fn merge_reductions(mut merged: Vec<Bitset>, reductions: &[Bitset]) -> Vec<Bitset> {
+    for (merged, reduction) in merged.iter_mut().zip(reductions) {
+        merged.union_with(reduction);
+    }
+    merged
+}
+
+/// Returns the LR(0) state of each group,
+/// except that each transition goes to the target group,
+/// along with the closure lookaheads of each group's LR(0) state.
+///
+/// The members of a group have the same LR(0) state,
+/// and their transitions agree,
+/// so it does not matter which member we use.
+fn get_group_states<'a>(
+    states: &[Lr0State],
+    closures: &[ClosureLookaheads<'a>],
+    kernels: &[(usize, Vec<Bitset>)],
+    successors: &[Vec<(&Symbol, usize)>],
+    groups: &Groups,
+) -> (Vec<Lr0State>, Vec<ClosureLookaheads<'a>>) {
+    groups
+        .get_members()
+        .into_iter()
+        .map(|members| {
+            let member = members[0];
+            let state_index = kernels[member].0;
+            let transitions = successors[member]
+                .iter()
+                .map(|&(symbol, to)| (symbol.clone(), groups.of[to]))
+                .collect();
+            let state = Lr0State {
+                transitions,
+                ..states[state_index].clone()
+            };
+            (state, closures[state_index].clone())
+        })
+        .unzip()
+}
@@ -1,64 +1,97 @@
 use crate::data::{
     machine::*, unnormalized_machine::UnnormalizedMachine, validated_file::*,
-    DollarlessTerminalName, Oset, Symbol,
+    DollarlessTerminalName, Oset, ParserAlgorithm, Symbol,
 };
 
 use std::collections::VecDeque;
 use std::collections::{HashMap, HashSet};
 
-use crate::pipeline::normalize_machine::normalize_machine;
+use crate::pipeline::normalize_machine::{
+    normalize_machine, normalize_machine_and_get_index_updater, update_lalr_merge_record,
+};
+
+/// Converts the AST to a finite state machine (FSM),
+/// using the given algorithm.
+///
+/// If the algorithm is LALR(1) and the machine has a reduce/reduce conflict,
+/// this also returns the canonical LR(1) states that were merged
+/// into each state of the machine.
+pub fn validated_ast_to_machine(
+    file: &File,
+    algorithm: ParserAlgorithm,
+) -> (Machine, Option<LalrMergeRecord>) {
+    let context = ImmutContext::new(file);
+    match algorithm {
+        ParserAlgorithm::CanonicalLr1 => {
+            (normalize_machine(get_canonical_lr1_machine(&context)), None)
+        }
+        ParserAlgorithm::Lalr1 => {
+            let (unnormalized, lalr_merges) = get_lalr1_machine(&context);
+            let (machine, updater) = normalize_machine_and_get_index_updater(unnormalized);
+            let lalr_merges =
+                lalr_merges.map(|lalr_merges| update_lalr_merge_record(lalr_merges, &updater));
+            (machine, lalr_merges)
+        }
+        ParserAlgorithm::MinimalLr1 => (normalize_machine(get_minimal_lr1_machine(&context)), None),
+    }
+}
 
-/// Converts the AST to a finite state machine (FSM).
-pub fn validated_ast_to_machine(file: &File) -> Machine {
-    let builder = UnnormalizedMachineBuilder::new(file);
-    let unnormalized = builder.build();
-    normalize_machine(unnormalized)
+fn get_canonical_lr1_machine(context: &ImmutContext) -> UnnormalizedMachine {
+    UnnormalizedMachineBuilder::new(context).build()
 }
 
+/// Builds the canonical LR(1) machine.
 #[derive(Debug, Clone)]
 struct UnnormalizedMachineBuilder<'a> {
-    context: ImmutContext<'a>,
-    /// The first state is the start state.
+    context: &'a ImmutContext<'a>,
+    /// The first `n` states are the start states,
+    /// where `n` is the number of start symbols.
     states: Vec<State>,
+    state_indices: HashMap<State, StateIndex>,
     transitions: HashSet<Transition>,
     queue: VecDeque<StateIndex>,
 }
 
 #[derive(Debug, Clone)]
 struct ImmutContext<'a> {
-    start_nonterminal_name: String,
+    /// The augmented rule `S' -> S` for `start_nonterminal_names[i]`
+    /// has the rule index `RuleIndex::Augmented(i)`.
+    start_nonterminal_names: Vec<String>,
     rules: Vec<Rule<'a>>,
     first_sets: HashMap<String, FirstSet>,
 }
 
 #[derive(Debug, Clone)]
-struct FirstSet {
-    terminals: Oset<DollarlessTerminalName>,
-    contains_epsilon: bool,
+pub(crate) struct FirstSet {
+    pub(crate) terminals: Oset<DollarlessTerminalName>,
+    pub(crate) contains_epsilon: bool,
 }
 
 #[derive(Debug, Clone)]
 struct AugmentedFirstSet(Oset<Lookahead>);
 
-impl UnnormalizedMachineBuilder<'_> {
-    fn new(file: &File) -> UnnormalizedMachineBuilder {
-        let context = ImmutContext::new(file);
-        let start_state = context.get_start_state();
-        UnnormalizedMachineBuilder {
+impl<'a> UnnormalizedMachineBuilder<'a> {
+    fn new(context: &'a ImmutContext<'a>) -> UnnormalizedMachineBuilder<'a> {
+        let mut builder = UnnormalizedMachineBuilder {
             context,
-            states: vec![start_state],
+            states: vec![],
+            state_indices: HashMap::new(),
             transitions: HashSet::new(),
-            queue: VecDeque::from([StateIndex(0)]),
+            queue: VecDeque::new(),
+        };
+        for start_index in 0..context.start_nonterminal_names.len() {
+            builder.enqueue_state_if_needed(context.get_start_state(start_index));
         }
+        builder
     }
 }
 
 impl ImmutContext<'_> {
-    fn new(file: &File) -> ImmutContext {
+    fn new(file: &File) -> ImmutContext<'_> {
         let rules: Vec<Rule> = file.get_rules().collect();
         let first_sets = get_first_sets(&rules);
         ImmutContext {
-            start_nonterminal_name: file.start.clone(),
+            start_nonterminal_names: file.starts.clone(),
             rules,
             first_sets,
         }
@@ -70,60 +103,27 @@ impl UnnormalizedMachineBuilder<'_> {
         while let Some(state_index) = self.queue.pop_front() {
             self.enqueue_transition_targets(state_index);
         }
+        let starts = (0..self.context.start_nonterminal_names.len())
+            .map(StateIndex)
+            .collect();
         UnnormalizedMachine {
             states: self.states,
+            starts,
             transitions: self.transitions,
         }
     }
 
     fn enqueue_state_if_needed(&mut self, state: State) -> StateIndex {
-        if let Some(index) = self.get_index_of_mergable(&state) {
-            self.merge(index, state.items)
+        if let Some(&index) = self.state_indices.get(&state) {
+            index
         } else {
             self.enqueue_new_state(state)
         }
     }
 
-    fn get_index_of_mergable(&self, state: &State) -> Option<StateIndex> {
-        self.states
-            .iter()
-            .enumerate()
-            .find_map(|(i, existing_state)| {
-                if are_cores_equal(state, existing_state) {
-                    Some(StateIndex(i))
-                } else {
-                    None
-                }
-            })
-    }
-
-    fn merge(&mut self, index: StateIndex, items: Oset<StateItem>) -> StateIndex {
-        let were_items_added = self.add_items_if_needed(index, items);
-
-        if were_items_added {
-            self.queue.push_back(index);
-        }
-
-        index
-    }
-
-    /// Returns true if items were added.
-    fn add_items_if_needed(&mut self, index: StateIndex, items: Oset<StateItem>) -> bool {
-        let state = self.state_mut(index);
-        let mut was_item_added = false;
-
-        for item in items {
-            if !state.items.contains(&item) {
-                state.items.insert(item);
-                was_item_added = true;
-            }
-        }
-
-        was_item_added
-    }
-
     fn enqueue_new_state(&mut self, state: State) -> StateIndex {
         let index = StateIndex(self.states.len());
+        self.state_indices.insert(state.clone(), index);
         self.states.push(state);
         self.queue.push_back(index);
         index
@@ -198,16 +198,12 @@ impl UnnormalizedMachineBuilder<'_> {
     fn state(&self, index: StateIndex) -> &State {
         &self.states[index.0]
     }
-
-    fn state_mut(&mut self, index: StateIndex) -> &mut State {
-        &mut self.states[index.0]
-    }
 }
 
 impl ImmutContext<'_> {
-    fn get_start_state(&self) -> State {
+    fn get_start_state(&self, start_index: usize) -> State {
         self.get_closure(&[StateItem {
-            rule_index: RuleIndex::Augmented,
+            rule_index: RuleIndex::Augmented(start_index),
             lookahead: Lookahead::Eof,
             dot: 0,
         }])
@@ -267,7 +263,9 @@ impl ImmutContext<'_> {
             RuleIndex::Original(rule_index) => {
                 self.get_symbol_sequence_after_dot_for_original_rule(rule_index, item.dot)
             }
-            RuleIndex::Augmented => self.get_symbol_sequence_after_dot_for_augmented_rule(item.dot),
+            RuleIndex::Augmented(start_index) => {
+                self.get_symbol_sequence_after_dot_for_augmented_rule(start_index, item.dot)
+            }
         }
     }
 
@@ -280,9 +278,15 @@ impl ImmutContext<'_> {
         get_field_symbols_from_n_onwards(rule.fieldset, dot)
     }
 
-    fn get_symbol_sequence_after_dot_for_augmented_rule(&self, dot: usize) -> Vec<Symbol> {
+    fn get_symbol_sequence_after_dot_for_augmented_rule(
+        &self,
+        start_index: usize,
+        dot: usize,
+    ) -> Vec<Symbol> {
         if dot == 0 {
-            vec![Symbol::Nonterminal(self.start_nonterminal_name.clone())]
+            vec![Symbol::Nonterminal(
+                self.start_nonterminal_names[start_index].clone(),
+            )]
         } else {
             vec![]
         }
@@ -340,7 +344,6 @@ impl ImmutContext<'_> {
         lookahead: Lookahead,
     ) -> Vec<StateItem> {
         self.get_rule_indices_for_nonterminal(&nonterminal_name)
-            .into_iter()
             .map(|rule_index| StateItem {
                 rule_index: RuleIndex::Original(rule_index),
                 lookahead: lookahead.clone(),
@@ -370,13 +373,21 @@ impl ImmutContext<'_> {
             RuleIndex::Original(rule_index) => {
                 self.get_symbol_right_of_dot_for_original_rule(item.dot, rule_index)
             }
-            RuleIndex::Augmented => self.get_symbol_right_of_dot_for_augmented_rule(item.dot),
+            RuleIndex::Augmented(start_index) => {
+                self.get_symbol_right_of_dot_for_augmented_rule(start_index, item.dot)
+            }
         }
     }
 
-    fn get_symbol_right_of_dot_for_augmented_rule(&self, dot: usize) -> Option<Symbol> {
+    fn get_symbol_right_of_dot_for_augmented_rule(
+        &self,
+        start_index: usize,
+        dot: usize,
+    ) -> Option<Symbol> {
         if dot == 0 {
-            Some(Symbol::Nonterminal(self.start_nonterminal_name.clone()))
+            Some(Symbol::Nonterminal(
+                self.start_nonterminal_names[start_index].clone(),
+            ))
         } else {
             None
         }
@@ -421,19 +432,6 @@ fn convert_first_set_to_augmented_as_is(first: FirstSet) -> AugmentedFirstSet {
     )
 }
 
-fn are_cores_equal(a: &State, b: &State) -> bool {
-    is_core_subset(a, b) && is_core_subset(b, a)
-}
-
-fn is_core_subset(substate: &State, superstate: &State) -> bool {
-    substate.items.iter().all(|sub| {
-        superstate
-            .items
-            .iter()
-            .any(|super_| sub.rule_index == super_.rule_index && sub.dot == super_.dot)
-    })
-}
-
 fn get_nth_field_symbol(n: usize, fieldset: &Fieldset) -> Option<Symbol> {
     match fieldset {
         Fieldset::Empty => None,
@@ -479,8 +477,14 @@ fn get_field_symbols_from_n_onwards_for_tuple(tuple: &TupleFieldset, n: usize) -
         .collect()
 }
 
-use first_set_map::get_first_sets;
+pub(crate) use first_set_map::get_first_sets;
 mod first_set_map;
 
+use lalr1::get_lalr1_machine;
+mod lalr1;
+
+use minimal_lr1::get_minimal_lr1_machine;
+mod minimal_lr1;
+
 #[cfg(test)]
 pub(crate) mod tests;
@@ -6,37 +6,54 @@ use pretty_assertions::assert_eq;
 #[test]
 fn balanced_parens() {
     let file = balanced_parens_input();
-    let actual = validated_ast_to_machine(&file);
+    let (actual, _) = validated_ast_to_machine(&file, ParserAlgorithm::Lalr1);
     let expected = balanced_parens_expected_output();
     assert_eq!(actual, expected);
 }
 
 pub fn balanced_parens_input() -> File {
     File {
-        start: "Expr".to_owned(),
+        starts: vec!["Expr".to_owned()],
         terminal_enum: TerminalEnum {
+            doc: vec![],
             attributes: vec![positionless_attribute("#[derive(Clone, Debug)]")],
             name: "Token".to_string(),
+            is_extern: false,
+            span_position_type: None,
             variants: vec![
                 TerminalVariant {
+                    doc: vec![],
                     dollarless_name: remove_dollars("LParen"),
+                    position: ByteIndex(0),
                     type_: "()".to_string(),
+                    pattern: None,
+                    literal: None,
                 },
                 TerminalVariant {
+                    doc: vec![],
                     dollarless_name: remove_dollars("RParen"),
+                    position: ByteIndex(0),
                     type_: "()".to_string(),
+                    pattern: None,
+                    literal: None,
                 },
             ],
         },
         nonterminals: vec![Nonterminal::Enum(Enum {
+            doc: vec![],
             attributes: vec![positionless_attribute("#[derive(Clone, Debug)]")],
             name: positionless_ident("Expr"),
+            result_type: None,
             variants: vec![
                 EnumVariant {
+                    doc: vec![],
                     name: positionless_ident("Empty"),
                     fieldset: Fieldset::Empty,
+                    precedence: None,
+                    action: None,
                 },
                 EnumVariant {
+                    doc: vec![],
                     name: positionless_ident("Wrap"),
                     fieldset: Fieldset::Tuple(TupleFieldset {
                         fields: vec![
@@ -51,9 +68,14 @@ pub fn balanced_parens_input() -> File {
                             )),
                         ],
                     }),
+                    precedence: None,
+                    action: None,
                 },
             ],
         })],
+        precedence_levels: vec![],
+        lexer: None,
+        allowed_lints: HashSet::new(),
     }
 }
 
@@ -65,7 +87,7 @@ pub fn balanced_parens_expected_output() -> Machine {
             State {
                 items: [
                     StateItem {
-                        rule_index: Augmented,
+                        rule_index: Augmented(0),
                         lookahead: Eof,
                         dot: 0,
                     },
@@ -85,7 +107,7 @@ pub fn balanced_parens_expected_output() -> Machine {
             },
             State {
                 items: [StateItem {
-                    rule_index: Augmented,
+                    rule_index: Augmented(0),
                     lookahead: Eof,
                     dot: 1,
                 }]
@@ -151,6 +173,7 @@ pub fn balanced_parens_expected_output() -> Machine {
                 .collect(),
             },
         ],
+        starts: vec![StateIndex(0)],
         transitions: [
             Transition {
                 from: StateIndex(0),
@@ -186,41 +209,59 @@ pub fn balanced_parens_expected_output() -> Machine {
 #[test]
 fn balanced_parens_esoteric() {
     let file = balanced_parens_esoteric_input();
-    let actual = validated_ast_to_machine(&file);
+    let (actual, _) = validated_ast_to_machine(&file, ParserAlgorithm::Lalr1);
     let expected = balanced_parens_esoteric_expected_output();
     assert_eq!(actual, expected);
 }
 
 fn balanced_parens_esoteric_input() -> File {
     File {
-        start: "Expr".to_owned(),
+        starts: vec!["Expr".to_owned()],
         terminal_enum: TerminalEnum {
+            doc: vec![],
             attributes: vec![positionless_attribute("#[derive(Clone, Debug)]")],
             name: "Token".to_string(),
+            is_extern: false,
+            span_position_type: None,
             variants: vec![
                 TerminalVariant {
+                    doc: vec![],
                     dollarless_name: DollarlessTerminalName::remove_dollars("LParen"),
+                    position: ByteIndex(0),
                     type_: "()".to_string(),
+                    pattern: None,
+                    literal: None,
                 },
                 TerminalVariant {
+                    doc: vec![],
                     dollarless_name: DollarlessTerminalName::remove_dollars("RParen"),
+                    position: ByteIndex(0),
                     type_: "()".to_string(),
+                    pattern: None,
+                    literal: None,
                 },
             ],
         },
         nonterminals: vec![Nonterminal::Enum(Enum {
+            doc: vec![],
             attributes: vec![positionless_attribute("#[derive(Clone, Debug)]")],
             name: positionless_ident("Expr"),
+            result_type: None,
             variants: vec![
                 EnumVariant {
+                    doc: vec![],
                     name: positionless_ident("Empty"),
                     fieldset: Fieldset::Empty,
+                    precedence: None,
+                    action: None,
                 },
                 EnumVariant {
+                    doc: vec![],
                     name: positionless_ident("Wrap"),
                     fieldset: Fieldset::Named(NamedFieldset {
                         fields: vec![
                             NamedField {
+                                doc: vec![],
                                 name: IdentOrUnderscore::Underscore(ByteIndex(0)),
                                 symbol: IdentOrTerminalIdent::Terminal(
                                     positionless_terminal_ident(
@@ -229,10 +270,12 @@ fn balanced_parens_esoteric_input() -> File {
                                 ),
                             },
                             NamedField {
+                                doc: vec![],
                                 name: IdentOrUnderscore::Ident(positionless_ident("inner")),
                                 symbol: IdentOrTerminalIdent::Ident(positionless_ident("Expr")),
                             },
                             NamedField {
+                                doc: vec![],
                                 name: IdentOrUnderscore::Ident(positionless_ident("right")),
                                 symbol: IdentOrTerminalIdent::Terminal(
                                     positionless_terminal_ident(
@@ -242,9 +285,14 @@ fn balanced_parens_esoteric_input() -> File {
                             },
                         ],
                     }),
+                    precedence: None,
+                    action: None,
                 },
             ],
         })],
+        precedence_levels: vec![],
+        lexer: None,
+        allowed_lints: HashSet::new(),
     }
 }
 
@@ -0,0 +1,237 @@
+use super::*;
+use std::path::Path;
+
+fn render(src: &str, err: KikiErr) -> String {
+    err.display(src, Path::new("grammar.kiki")).to_string()
+}
+
+#[test]
+fn line_and_column() {
+    let src = "ab\ncd\n\u{e9}f";
+    let expected = [
+        (0, 1, 1),
+        (1, 1, 2),
+        (2, 1, 3),
+        (3, 2, 1),
+        (6, 3, 1),
+        (8, 3, 2),
+        (9, 3, 3),
+        (100, 3, 3),
+    ];
+    for (index, line, column) in expected {
+        assert_eq!(
+            LineAndColumn { line, column },
+            ByteIndex(index).to_line_and_column(src),
+            "index {index}"
+        );
+    }
+}
+
+#[test]
+fn undefined_nonterminal() {
+    let src = "start Foo\nstruct Foo {\n    bar: Bar\n}\n";
+    let err = KikiErr::UndefinedNonterminal("Bar".to_owned(), ByteIndex(src.find("Bar").unwrap()));
+    let expected = "\
+error: undefined nonterminal `Bar`
+ --> grammar.kiki:3:10
+  |
+3 |     bar: Bar
+  |          ^^^ not defined
+";
+    assert_eq!(expected, render(src, err));
+}
+
+#[test]
+fn terminal_underline_includes_dollar() {
+    let src = "struct Foo(_: $Plus)";
+    let err = KikiErr::UndefinedTerminal(
+        DollarlessTerminalName::remove_dollars("Plus"),
+        ByteIndex(src.find("Plus").unwrap()),
+    );
+    let expected = "\
+error: undefined terminal `$Plus`
+ --> grammar.kiki:1:15
+  |
+1 | struct Foo(_: $Plus)
+  |               ^^^^^ not a variant of the terminal enum
+";
+    assert_eq!(expected, render(src, err));
+}
+
+#[test]
+fn secondary_label() {
+    let src = "start Foo\n\n\n\n\n\n\n\n\nstart Foo\n";
+    let err = KikiErr::StartSymbolRedeclared("Foo".to_owned(), ByteIndex(0), ByteIndex(18));
+    let expected = "\
+error: start symbol `Foo` is declared more than once
+  --> grammar.kiki:10:1
+   |
+10 | start Foo
+   | ^^^^^ redeclared here
+  ::: grammar.kiki:1:1
+   |
+ 1 | start Foo
+   | ----- first declared here
+";
+    assert_eq!(expected, render(src, err));
+}
+
+#[test]
+fn parse_error_underlines_whole_token() {
+    let src = "start Foo\n\"abc\"";
+    let err = KikiErr::Parse(ByteIndex(10), "\"abc\"".to_owned(), ByteIndex(15));
+    let expected = "\
+error: unexpected token `\"abc\"`
+ --> grammar.kiki:2:1
+  |
+2 | \"abc\"
+  | ^^^^^ unexpected token
+";
+    assert_eq!(expected, render(src, err));
+}
+
+#[test]
+fn unexpected_end_of_input() {
+    let src = "start";
+    let err = KikiErr::Parse(ByteIndex(5), "".to_owned(), ByteIndex(5));
+    let expected = "\
+error: unexpected end of input
+ --> grammar.kiki:1:6
+  |
+1 | start
+  |      ^ input ends here
+";
+    assert_eq!(expected, render(src, err));
+}
+
+#[test]
+fn no_position() {
+    let expected = "\
+error: no start symbol was declared
+--> grammar.kiki
+ = note: declare one with `start Name`
+";
+    assert_eq!(expected, render("", KikiErr::NoStartSymbol));
+}
+
+#[test]
+fn message_without_source() {
+    let err = KikiErr::UndefinedNonterminal("Bar".to_owned(), ByteIndex(0));
+    assert_eq!("undefined nonterminal `Bar`", err.to_string());
+}
+
+#[test]
+fn generated_error() {
+    let src = include_str!("../examples/should_fail/undefined_child_nonterminal.kiki");
+    let err = generate(src).expect_err("should fail");
+    let expected = "\
+error: undefined nonterminal `Bar`
+ --> grammar.kiki:3:12
+  |
+3 | struct Foo(Bar)
+  |            ^^^ not defined
+";
+    assert_eq!(expected, render(src, err));
+}
+
+#[test]
+fn generic_instantiation_error() {
+    let src = include_str!("../examples/should_fail/undefined_symbol_in_generic_body.kiki");
+    let err = generate(src).expect_err("should fail");
+    let expected = "\
+error: undefined nonterminal `Bar`
+ --> grammar.kiki:9:12
+  |
+9 |     extra: Bar
+  |            ^^^ not defined
+ ::: grammar.kiki:4:14
+  |
+4 |     wrapped: Wrapper<$A>
+  |              ------- in `Wrapper<$A>`, instantiated here
+";
+    assert_eq!(expected, render(src, err));
+}
+
+#[test]
+fn shift_reduce_conflict() {
+    let src = include_str!("../examples/should_fail/shift_reduce_conflict.kiki");
+    let err = generate(src).expect_err("should fail");
+    let expected = "\
+error: shift/reduce conflict on `$Plus` in parser state 3
+ --> grammar.kiki:7:5
+  |
+7 |     Add(Expr _: $Plus Expr)
+  |     ^^^ reduce `Expr -> Expr $Plus Expr .`
+ ::: grammar.kiki:7:5
+  |
+7 |     Add(Expr _: $Plus Expr)
+  |     --- shift `Expr -> Expr . $Plus Expr`
+  = note: the conflict occurs after the input `$Num $Plus $Num`, when the next token is `$Plus`
+  = note: the grammar is ambiguous: `$Num $Plus $Num • $Plus $Num` has two derivations
+  = note: first derivation: `Expr::Add(Expr::Num($Num) $Plus Expr::Add(Expr::Num($Num) $Plus Expr::Num($Num)))`
+  = note: second derivation: `Expr::Add(Expr::Add(Expr::Num($Num) $Plus Expr::Num($Num)) $Plus Expr::Num($Num))`
+  = note: a `precedence` declaration or a `prec` override may resolve this conflict
+";
+    assert_eq!(expected, render(src, err));
+}
+
+#[test]
+fn lalr_merge_conflict() {
+    let src = include_str!("../examples/lr1_not_lalr1.kiki");
+    let err = generate(src).expect_err("should fail");
+    let expected = "\
+error: reduce/reduce conflict on `$D` in parser state 11
+  --> grammar.kiki:17:8
+   |
+17 | struct Y(_: $C)
+   |        ^ reduce `Y -> $C .`
+  ::: grammar.kiki:15:8
+   |
+15 | struct X(_: $C)
+   |        - reduce `X -> $C .`
+   = note: the conflict occurs after the input `$A $C`, when the next token is `$D`
+   = note: example of the reduce: `$A $C • $D`, derived as `Foo::Axd($A X($C) $D)`
+   = note: example of the reduce: `$B $C • $D`, derived as `Foo::Byd($B Y($C) $D)`
+   = note: this conflict exists only because of LALR state merging
+   = note: the LR(1) state after `$A $C` reduces `X -> $C .` on `$D`
+   = note: the LR(1) state after `$B $C` reduces `Y -> $C .` on `$D`
+   = note: LALR(1) merges these states, since they have the same items apart from lookaheads
+   = note: `ParserAlgorithm::MinimalLr1` only merges states when the merge causes no conflict
+";
+    assert_eq!(expected, render(src, err));
+}
+
+#[test]
+fn literal_terminal_conflict() {
+    let src = r#"start Expr
+
+enum Expr {
+    Num($Num)
+    Add(Expr _: "+" Expr)
+}
+
+terminal Token {
+    $Num: i32
+}
+"#;
+    let err = generate(src).expect_err("should fail");
+    insta::assert_snapshot!(render(src, err));
+}
+
+#[test]
+fn literal_terminal_precedence_redeclared() {
+    let src = "start Expr\nprecedence left \"+\"\nprecedence right \"+\"\nenum Expr {\n    Num($Num)\n    Add(Expr _: \"+\" Expr)\n}\nterminal Token { $Num: i32 }\n";
+    let err = generate(src).expect_err("should fail");
+    let expected = "\
+error: the precedence of `\"+\"` is declared more than once
+ --> grammar.kiki:3:18
+  |
+3 | precedence right \"+\"
+  |                  ^^^ redeclared here
+ ::: grammar.kiki:2:17
+  |
+2 | precedence left \"+\"
+  |                 --- first declared here
+";
+    assert_eq!(expected, render(src, err));
+}
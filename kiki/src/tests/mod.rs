@@ -1,7 +1,11 @@
 use super::*;
 
+mod diagnostic_tests;
+
 mod get_grammar_hash_tests;
 
+mod parser_algorithm_tests;
+
 /// This module conducts end-to-end "dry run" tests.
 /// A dry run is when we generate the parser,
 /// but we don't actually test said generated parser.
@@ -0,0 +1,217 @@
+use super::*;
+
+use crate::data::{
+    machine::{Machine, State, StateIndex, Transition},
+    unnormalized_machine::UnnormalizedMachine,
+};
+use crate::pipeline::normalize_machine::normalize_machine;
+
+#[test]
+fn lalr1_has_merge_conflict() {
+    let src = include_str!("../examples/lr1_not_lalr1.kiki");
+    let err = generate_with_algorithm(src, ParserAlgorithm::Lalr1).expect_err("should fail");
+    let KikiErr::TableConflict(conflict) = err else {
+        panic!("expected a table conflict, but got {err:?}");
+    };
+    assert_eq!(ConflictKind::ReduceReduce, conflict.kind);
+
+    let lalr_merge = conflict
+        .lalr_merge
+        .as_ref()
+        .expect("should be caused by merging");
+    let mut prefixes = [
+        (
+            conflict.format_item(&conflict.items.0),
+            lalr_merge
+                .first_prefixes
+                .iter()
+                .map(|prefix| conflict.format_terminals(prefix))
+                .collect::<Vec<_>>(),
+        ),
+        (
+            conflict.format_item(&conflict.items.1),
+            lalr_merge
+                .second_prefixes
+                .iter()
+                .map(|prefix| conflict.format_terminals(prefix))
+                .collect::<Vec<_>>(),
+        ),
+    ];
+    prefixes.sort();
+    assert_eq!(
+        [
+            ("X -> $C .".to_owned(), vec!["$A $C".to_owned()]),
+            ("Y -> $C .".to_owned(), vec!["$B $C".to_owned()]),
+        ],
+        prefixes
+    );
+}
+
+#[test]
+fn lalr1_records_merged_states_only_if_there_is_a_reduce_reduce_conflict() {
+    let src = include_str!("../examples/lr1_not_lalr1.kiki");
+    let validated = get_validated(src);
+    let (lalr1, lalr_merges) = validated_ast_to_machine(&validated, ParserAlgorithm::Lalr1);
+    let lalr_merges = lalr_merges.expect("should record merges");
+    let canonical = get_machine(src, ParserAlgorithm::CanonicalLr1);
+    assert_eq!(canonical.states.len(), lalr_merges.lr1_states.len());
+    assert_eq!(
+        canonical.transitions.len(),
+        lalr_merges.lr1_transitions.len()
+    );
+    assert!(lalr_merges
+        .lr1_states
+        .iter()
+        .all(|lr1_state| lr1_state.merged_into.0 < lalr1.states.len()));
+
+    for algorithm in [ParserAlgorithm::CanonicalLr1, ParserAlgorithm::MinimalLr1] {
+        let (_, lalr_merges) = validated_ast_to_machine(&validated, algorithm);
+        assert_eq!(None, lalr_merges);
+    }
+
+    let src = include_str!("../examples/should_fail/shift_reduce_conflict.kiki");
+    let (_, lalr_merges) = validated_ast_to_machine(&get_validated(src), ParserAlgorithm::Lalr1);
+    assert_eq!(None, lalr_merges);
+}
+
+#[test]
+fn canonical_lr1_has_no_merge_conflict() {
+    let src = include_str!("../examples/lr1_not_lalr1.kiki");
+    generate_with_algorithm(src, ParserAlgorithm::CanonicalLr1).expect("should generate");
+}
+
+#[test]
+fn minimal_lr1_has_no_merge_conflict() {
+    let src = include_str!("../examples/lr1_not_lalr1.kiki");
+    generate_with_algorithm(src, ParserAlgorithm::MinimalLr1).expect("should generate");
+}
+
+#[test]
+fn minimal_lr1_only_splits_conflicting_states() {
+    let src = include_str!("../examples/lr1_not_lalr1.kiki");
+    let lalr1 = get_machine(src, ParserAlgorithm::Lalr1);
+    let minimal = get_machine(src, ParserAlgorithm::MinimalLr1);
+    // Only the state after `$C` is split.
+    assert_eq!(lalr1.states.len() + 1, minimal.states.len());
+}
+
+#[test]
+fn minimal_lr1_has_fewer_states_than_canonical_lr1() {
+    let src = include_str!("../examples/lr1_not_lalr1_with_list.kiki");
+    generate_with_algorithm(src, ParserAlgorithm::Lalr1).expect_err("should fail");
+    generate_with_algorithm(src, ParserAlgorithm::MinimalLr1).expect("should generate");
+    let lalr1 = get_machine(src, ParserAlgorithm::Lalr1);
+    let canonical = get_machine(src, ParserAlgorithm::CanonicalLr1);
+    let minimal = get_machine(src, ParserAlgorithm::MinimalLr1);
+    // Only the state after `$C` is split,
+    // while canonical LR(1) also splits the states of the list.
+    assert_eq!(lalr1.states.len() + 1, minimal.states.len());
+    assert!(minimal.states.len() < canonical.states.len());
+}
+
+#[test]
+fn minimal_lr1_is_lalr1_for_lalr1_grammars() {
+    for src in [
+        include_str!("../examples/balanced_parens.kiki"),
+        include_str!("../examples/json.kiki"),
+        include_str!("../examples/kiki.kiki"),
+    ] {
+        let lalr1 = get_machine(src, ParserAlgorithm::Lalr1);
+        let canonical = get_machine(src, ParserAlgorithm::CanonicalLr1);
+        let minimal = get_machine(src, ParserAlgorithm::MinimalLr1);
+        assert!(lalr1.states.len() < canonical.states.len());
+        assert_eq!(lalr1, minimal);
+    }
+}
+
+#[test]
+fn lalr1_is_canonical_lr1_with_merged_cores() {
+    for src in [
+        include_str!("../examples/balanced_parens.kiki"),
+        include_str!("../examples/json.kiki"),
+        include_str!("../examples/kiki.kiki"),
+        include_str!("../examples/lr1_not_lalr1.kiki"),
+        include_str!("../examples/should_fail/lookahead_conflict.kiki"),
+        include_str!("../examples/should_fail/optional_conflict.kiki"),
+        include_str!("../examples/should_fail/reduce_reduce_conflict.kiki"),
+        include_str!("../examples/should_fail/shift_reduce_conflict.kiki"),
+    ] {
+        let lalr1 = get_machine(src, ParserAlgorithm::Lalr1);
+        let canonical = get_machine(src, ParserAlgorithm::CanonicalLr1);
+        assert_eq!(merge_states_with_equal_cores(canonical), lalr1);
+    }
+}
+
+fn generate_with_algorithm(
+    src: &str,
+    parser_algorithm: ParserAlgorithm,
+) -> Result<(RustSrc, Vec<KikiWarning>), KikiErr> {
+    generate_with_options(
+        src,
+        &GenerateOptions {
+            parser_algorithm,
+            ..GenerateOptions::default()
+        },
+    )
+}
+
+fn get_machine(src: &str, algorithm: ParserAlgorithm) -> Machine {
+    validated_ast_to_machine(&get_validated(src), algorithm).0
+}
+
+fn get_validated(src: &str) -> crate::data::validated_file::File {
+    let tokens = tokenize(src).expect("should tokenize correctly");
+    let cst = parse(tokens).expect("should parse correctly");
+    let ast: crate::data::ast::File = cst.into();
+    validate_ast(ast).expect("should be valid")
+}
+
+/// Merging the states of the canonical LR(1) machine
+/// that have equal cores is the textbook definition of LALR(1).
+fn merge_states_with_equal_cores(machine: Machine) -> Machine {
+    let mut states: Vec<State> = vec![];
+    let mut merged_indices: Vec<usize> = vec![];
+    for state in machine.states {
+        match states
+            .iter()
+            .position(|merged| are_cores_equal(merged, &state))
+        {
+            Some(i) => {
+                states[i].items.extend(state.items);
+                merged_indices.push(i);
+            }
+            None => {
+                merged_indices.push(states.len());
+                states.push(state);
+            }
+        }
+    }
+
+    let update = |StateIndex(i)| StateIndex(merged_indices[i]);
+    normalize_machine(UnnormalizedMachine {
+        states,
+        starts: machine.starts.into_iter().map(update).collect(),
+        transitions: machine
+            .transitions
+            .into_iter()
+            .map(|transition| Transition {
+                from: update(transition.from),
+                to: update(transition.to),
+                symbol: transition.symbol,
+            })
+            .collect(),
+    })
+}
+
+fn are_cores_equal(a: &State, b: &State) -> bool {
+    is_core_subset(a, b) && is_core_subset(b, a)
+}
+
+fn is_core_subset(substate: &State, superstate: &State) -> bool {
+    substate.items.iter().all(|sub| {
+        superstate
+            .items
+            .iter()
+            .any(|super_| sub.rule_index == super_.rule_index && sub.dot == super_.dot)
+    })
+}
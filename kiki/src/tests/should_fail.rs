@@ -1,6 +1,8 @@
 use super::*;
 
-use crate::data::KikiErr;
+use crate::data::{
+    table::Quasiterminal, ConflictKind, CounterexampleKind, KikiErr, Symbol, TableConflictErr,
+};
 
 #[test]
 fn lowercase_nonterminal() {
@@ -63,6 +65,13 @@ fn undefined_child_nonterminal() {
     assert!(matches!(err, KikiErr::UndefinedNonterminal(name, _) if name == "Bar"));
 }
 
+#[test]
+fn undefined_repeated_nonterminal() {
+    let src = include_str!("../examples/should_fail/undefined_repeated_nonterminal.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::UndefinedNonterminal(name, _) if name == "Bar"));
+}
+
 #[test]
 fn undefined_child_terminal() {
     let src = include_str!("../examples/should_fail/undefined_child_terminal.kiki");
@@ -141,10 +150,23 @@ fn no_terminal_enum() {
 }
 
 #[test]
-fn multiple_starts() {
-    let src = include_str!("../examples/should_fail/multiple_starts.kiki");
+fn start_redeclared() {
+    let src = include_str!("../examples/should_fail/start_redeclared.kiki");
     let err = assert_src_fails_pre_machine_validation(src);
-    assert!(matches!(err, KikiErr::MultipleStartSymbols(starts) if starts.len() == 2));
+    assert!(
+        matches!(err, KikiErr::StartSymbolRedeclared(name, first, second) if name == "Foo" && first.0 < second.0)
+    );
+}
+
+#[test]
+fn parse_fn_name_clash() {
+    let src = include_str!("../examples/should_fail/parse_fn_name_clash.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(
+        err,
+        KikiErr::ParseFnNameClash(fn_name, first, _, second, _)
+            if fn_name == "parse_foo_verbose" && first == "Foo" && second == "FooVerbose"
+    ));
 }
 
 #[test]
@@ -156,6 +178,527 @@ fn multiple_terminal_enums() {
     );
 }
 
+#[test]
+fn unknown_associativity() {
+    let src = include_str!("../examples/should_fail/unknown_associativity.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::UnknownAssociativity(name, _) if name == "leftish"));
+}
+
+#[test]
+fn precedence_redeclared() {
+    let src = include_str!("../examples/should_fail/precedence_redeclared.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(
+        matches!(err, KikiErr::TerminalPrecedenceRedeclared(name, _, _) if name.raw() == "Plus")
+    );
+}
+
+#[test]
+fn undefined_precedence_terminal() {
+    let src = include_str!("../examples/should_fail/undefined_precedence_terminal.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::UndefinedTerminal(name, _) if name.raw() == "Minus"));
+}
+
+#[test]
+fn prec_override_without_precedence() {
+    let src = include_str!("../examples/should_fail/prec_override_without_precedence.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(
+        err,
+        KikiErr::PrecedenceOverrideTerminalHasNoPrecedence(name, _) if name.raw() == "UnaryMinus"
+    ));
+}
+
+#[test]
+fn unknown_lint() {
+    let src = include_str!("../examples/should_fail/unknown_lint.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let lint_position = ByteIndex(src.find("unused_terminals").unwrap());
+    assert!(matches!(
+        err,
+        KikiErr::UnknownLint(name, position) if name == "unused_terminals" && position == lint_position
+    ));
+}
+
+#[test]
+fn misspelled_allow_keyword() {
+    let src = include_str!("../examples/should_fail/misspelled_allow_keyword.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let keyword_position = ByteIndex(src.find("alow").unwrap());
+    assert!(
+        matches!(err, KikiErr::Parse(position, content, _) if position == keyword_position && content == "alow")
+    );
+}
+
+#[test]
+fn precedence_as_field_name() {
+    let src = include_str!("../examples/should_fail/precedence_as_field_name.kiki");
+    let err = generate(src).expect_err("`precedence` should be reserved");
+    let keyword_position = ByteIndex(src.find("precedence").unwrap());
+    assert!(matches!(err, KikiErr::Parse(position, _, _) if position == keyword_position));
+}
+
+#[test]
+fn unknown_field_symbol_function() {
+    let src = include_str!("../examples/should_fail/unknown_field_symbol_function.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::UnknownFieldSymbolFunction(name, _) if name == "seq"));
+}
+
+#[test]
+fn unknown_separated_list_flag() {
+    let src = include_str!("../examples/should_fail/unknown_separated_list_flag.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::UnknownSeparatedListFlag(name, _) if name == "trailng"));
+}
+
+#[test]
+fn separated_list_flag_repeated() {
+    let src = include_str!("../examples/should_fail/separated_list_flag_repeated.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::SeparatedListFlagRepeated(name, _, _) if name == "trailing"));
+}
+
+#[test]
+fn missing_semantic_action() {
+    let src = include_str!("../examples/should_fail/missing_semantic_action.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::MissingSemanticAction(name, _) if name == "Expr::Neg"));
+}
+
+#[test]
+fn semantic_action_without_result_type() {
+    let src = include_str!("../examples/should_fail/semantic_action_without_result_type.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let fat_arrow = ByteIndex(src.find("=>").unwrap());
+    assert!(
+        matches!(err, KikiErr::SemanticActionWithoutResultType(name, position) if name == "Expr::Neg" && position == fat_arrow)
+    );
+}
+
+#[test]
+fn outer_attribute_on_nonterminal_with_result_type() {
+    let src = include_str!(
+        "../examples/should_fail/outer_attribute_on_nonterminal_with_result_type.kiki"
+    );
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(
+        matches!(err, KikiErr::OuterAttributeOnNonterminalWithResultType(name, _) if name == "Num")
+    );
+}
+
+#[test]
+fn optional_conflict_points_at_question_mark() {
+    let src = include_str!("../examples/should_fail/optional_conflict.kiki");
+    let err = generate(src).expect_err("should have a table conflict");
+    let KikiErr::TableConflict(conflict) = err else {
+        panic!("expected a table conflict, but got {err:?}");
+    };
+    let first_question_mark = ByteIndex(src.find('?').unwrap());
+    let (left, right) = conflict.rule_positions();
+    assert!(left == Some(first_question_mark) || right == Some(first_question_mark));
+}
+
+#[test]
+fn sugar_conflict_is_written_as_sugar() {
+    let src = include_str!("../examples/should_fail/sugar_conflict.kiki");
+    let conflict = assert_src_has_table_conflict(src);
+    assert_eq!("Bar? -> .", conflict.format_item(&conflict.items.1));
+    let counterexample = conflict.counterexample.as_ref().unwrap();
+    let (first, second) = &counterexample.examples;
+    assert_eq!(
+        "Foo(Bar?(Bar($A)) Bar*())",
+        conflict.format_derivation(&first.derivation)
+    );
+    assert_eq!(
+        "Foo(Bar?() Bar*(Bar*() Bar($A)))",
+        conflict.format_derivation(&second.derivation)
+    );
+}
+
+#[test]
+fn shift_reduce_conflict() {
+    let src = include_str!("../examples/should_fail/shift_reduce_conflict.kiki");
+    let conflict = assert_src_has_table_conflict(src);
+    assert_eq!(ConflictKind::ShiftReduce, conflict.kind);
+    assert_eq!(
+        Quasiterminal::Terminal(&DollarlessTerminalName::remove_dollars("Plus")),
+        conflict.quasiterminal()
+    );
+    assert_eq!(
+        "Expr -> Expr . $Plus Expr",
+        conflict.format_item(&conflict.items.0)
+    );
+    assert_eq!(
+        "Expr -> Expr $Plus Expr .",
+        conflict.format_item(&conflict.items.1)
+    );
+    assert_eq!("$Num $Plus $Num", conflict.format_prefix());
+}
+
+#[test]
+fn reduce_reduce_conflict() {
+    let src = include_str!("../examples/should_fail/reduce_reduce_conflict.kiki");
+    let conflict = assert_src_has_table_conflict(src);
+    assert_eq!(ConflictKind::ReduceReduce, conflict.kind);
+    assert_eq!(Quasiterminal::Eof, conflict.quasiterminal());
+    let mut items = [
+        conflict.format_item(&conflict.items.0),
+        conflict.format_item(&conflict.items.1),
+    ];
+    items.sort();
+    assert_eq!(["A -> $X .", "B -> $X ."], items);
+    assert_eq!("$X", conflict.format_prefix());
+    assert_eq!(None, conflict.lalr_merge);
+}
+
+#[test]
+fn literal_conflict_uses_literals() {
+    let src = include_str!("../examples/should_fail/literal_conflict.kiki");
+    let conflict = assert_src_has_table_conflict(src);
+    assert_eq!("\"+\"", conflict.format_lookahead());
+    assert_eq!(
+        "Expr -> Expr . \"+\" Expr",
+        conflict.format_item(&conflict.items.0)
+    );
+}
+
+#[test]
+fn ambiguous_conflict_has_unifying_counterexample() {
+    let src = include_str!("../examples/should_fail/shift_reduce_conflict.kiki");
+    let conflict = assert_src_has_table_conflict(src);
+    let counterexample = conflict.counterexample.as_ref().unwrap();
+    assert_eq!(CounterexampleKind::Unifying, counterexample.kind);
+    let (first, second) = &counterexample.examples;
+    assert_eq!(
+        "$Num $Plus $Num • $Plus $Num",
+        conflict.format_sentence(first)
+    );
+    assert_eq!(first.sentence, second.sentence);
+    let mut derivations = [
+        conflict.format_derivation(&first.derivation),
+        conflict.format_derivation(&second.derivation),
+    ];
+    derivations.sort();
+    assert_eq!(
+        [
+            "Expr::Add(Expr::Add(Expr::Num($Num) $Plus Expr::Num($Num)) $Plus Expr::Num($Num))",
+            "Expr::Add(Expr::Num($Num) $Plus Expr::Add(Expr::Num($Num) $Plus Expr::Num($Num)))",
+        ],
+        derivations
+    );
+}
+
+#[test]
+fn unambiguous_conflict_has_non_unifying_counterexample() {
+    let src = include_str!("../examples/should_fail/lookahead_conflict.kiki");
+    let conflict = assert_src_has_table_conflict(src);
+    let counterexample = conflict.counterexample.as_ref().unwrap();
+    assert_eq!(CounterexampleKind::NonUnifying, counterexample.kind);
+    let (first, second) = &counterexample.examples;
+    let mut examples = [
+        (
+            conflict.format_sentence(first),
+            conflict.format_derivation(&first.derivation),
+        ),
+        (
+            conflict.format_sentence(second),
+            conflict.format_derivation(&second.derivation),
+        ),
+    ];
+    examples.sort();
+    assert_eq!(
+        [
+            ("$W • $X $Y".to_owned(), "Foo::A(A($W) $X $Y)".to_owned()),
+            ("$W • $X $Z".to_owned(), "Foo::B(B($W) $X $Z)".to_owned()),
+        ],
+        examples
+    );
+}
+
+#[test]
+fn generic_param_name_clash() {
+    let src = include_str!("../examples/should_fail/generic_param_name_clash.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::GenericParamNameClash(name, _, _) if name == "T"));
+}
+
+#[test]
+fn generic_nonterminal_used_without_args() {
+    let src = include_str!("../examples/should_fail/generic_nonterminal_used_without_args.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::GenericNonterminalUsedWithoutArgs(name, _) if name == "List"));
+}
+
+#[test]
+fn generic_start_symbol() {
+    let src = include_str!("../examples/should_fail/generic_start_symbol.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::GenericNonterminalUsedWithoutArgs(name, _) if name == "List"));
+}
+
+#[test]
+fn nonterminal_is_not_generic() {
+    let src = include_str!("../examples/should_fail/nonterminal_is_not_generic.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::NonterminalIsNotGeneric(name, _) if name == "Bar"));
+}
+
+#[test]
+fn generic_arg_count_mismatch() {
+    let src = include_str!("../examples/should_fail/generic_arg_count_mismatch.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::GenericArgCountMismatch(name, 2, 1, _) if name == "Pair"));
+}
+
+#[test]
+fn undefined_symbol_in_generic_body() {
+    let src = include_str!("../examples/should_fail/undefined_symbol_in_generic_body.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let use_site = ByteIndex(src.find("Wrapper<$A>").unwrap());
+    let KikiErr::InGenericInstantiation(inner, name, args, position) = err else {
+        panic!("expected InGenericInstantiation, got {err:?}");
+    };
+    assert_eq!(name, "Wrapper");
+    assert!(matches!(&args[..], [Symbol::Terminal(arg)] if arg.raw() == "A"));
+    assert_eq!(position, use_site);
+    assert!(matches!(*inner, KikiErr::UndefinedNonterminal(name, _) if name == "Bar"));
+}
+
+#[test]
+fn extern_terminal_missing_pattern() {
+    let src = include_str!("../examples/should_fail/extern_terminal_missing_pattern.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::ExternTerminalMissingPattern(name, _) if name.raw() == "B"));
+}
+
+#[test]
+fn terminal_pattern_on_local_terminal_enum() {
+    let src = include_str!("../examples/should_fail/terminal_pattern_on_local_terminal_enum.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(
+        matches!(err, KikiErr::TerminalPatternOnLocalTerminalEnum(name, _) if name.raw() == "A")
+    );
+}
+
+#[test]
+fn terminal_pattern_binds_multiple_fields() {
+    let src = include_str!("../examples/should_fail/terminal_pattern_binds_multiple_fields.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let position = ByteIndex(src.find("position").unwrap());
+    assert!(
+        matches!(err, KikiErr::TerminalPatternBindsMultipleFields(name, p) if name.raw() == "Num" && p == position)
+    );
+}
+
+#[test]
+fn terminal_pattern_binds_no_field() {
+    let src = include_str!("../examples/should_fail/terminal_pattern_binds_no_field.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::TerminalPatternBindsNoField(name, _) if name.raw() == "Num"));
+}
+
+#[test]
+fn extern_terminal_variant_repeated() {
+    let src = include_str!("../examples/should_fail/extern_terminal_variant_repeated.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::ExternTerminalVariantRepeated(name, _, _) if name == "Punct"));
+}
+
+#[test]
+fn outer_attribute_on_extern_terminal_enum() {
+    let src = include_str!("../examples/should_fail/outer_attribute_on_extern_terminal_enum.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(
+        err,
+        KikiErr::OuterAttributeOnExternTerminalEnum(_)
+    ));
+}
+
+#[test]
+fn extern_terminal_enum_name_clash() {
+    let src = include_str!("../examples/should_fail/extern_terminal_enum_name_clash.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::NameClash(name, _, _) if name == "Token"));
+}
+
+#[test]
+fn unterminated_block_comment_points_at_outermost_opening() {
+    let src = include_str!("../examples/should_fail/unterminated_block_comment.kiki");
+    let err = generate(src).expect_err("should fail to tokenize");
+    let first_opening = ByteIndex(src.find("/*").unwrap());
+    assert!(
+        matches!(err, KikiErr::UnterminatedBlockComment(position) if position == first_opening)
+    );
+}
+
+#[test]
+fn span_field_name_clash() {
+    let src = include_str!("../examples/should_fail/span_field_name_clash.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::SpanFieldNameClash(_)));
+}
+
+#[test]
+fn multiple_lexers() {
+    let src = include_str!("../examples/should_fail/multiple_lexers.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::MultipleLexers(positions) if positions.len() == 2));
+}
+
+#[test]
+fn misspelled_lexer_keyword() {
+    let src = include_str!("../examples/should_fail/misspelled_lexer_keyword.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let keyword_position = ByteIndex(src.find("lexr").unwrap());
+    assert!(
+        matches!(err, KikiErr::Parse(position, content, _) if position == keyword_position && content == "lexr")
+    );
+}
+
+#[test]
+fn lexer_with_extern_terminal_enum() {
+    let src = include_str!("../examples/should_fail/lexer_with_extern_terminal_enum.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::LexerWithExternTerminalEnum(_)));
+}
+
+#[test]
+fn lexer_span_position_type_not_usize() {
+    let src = include_str!("../examples/should_fail/lexer_span_position_type_not_usize.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::LexerSpanPositionTypeNotUsize(_)));
+}
+
+#[test]
+fn undefined_lexer_terminal() {
+    let src = include_str!("../examples/should_fail/undefined_lexer_terminal.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::UndefinedTerminal(name, _) if name.raw() == "C"));
+}
+
+#[test]
+fn lexer_terminal_redeclared() {
+    let src = include_str!("../examples/should_fail/lexer_terminal_redeclared.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::LexerTerminalRedeclared(name, _, _) if name.raw() == "A"));
+}
+
+#[test]
+fn invalid_regex_points_into_pattern() {
+    let src = include_str!("../examples/should_fail/invalid_regex.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let class_position = ByteIndex(src.find("[bc").unwrap());
+    assert!(matches!(err, KikiErr::InvalidRegex(position, _) if position == class_position));
+}
+
+#[test]
+fn lexer_pattern_matches_empty_string() {
+    let src = include_str!("../examples/should_fail/lexer_pattern_matches_empty_string.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let pattern_position = ByteIndex(src.find(r#"r"\s*""#).unwrap());
+    assert!(
+        matches!(err, KikiErr::LexerPatternMatchesEmptyString(position) if position == pattern_position)
+    );
+}
+
+#[test]
+fn unterminated_string_literal() {
+    let src = include_str!("../examples/should_fail/unterminated_string_literal.kiki");
+    let err = generate(src).expect_err("should fail to tokenize");
+    let quote_position = ByteIndex(src.find('"').unwrap());
+    assert!(
+        matches!(err, KikiErr::UnterminatedStringLiteral(position) if position == quote_position)
+    );
+}
+
+#[test]
+fn empty_literal_terminal() {
+    let src = include_str!("../examples/should_fail/empty_literal_terminal.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let literal_position = ByteIndex(src.find(r#""""#).unwrap());
+    assert!(matches!(err, KikiErr::EmptyLiteralTerminal(position) if position == literal_position));
+}
+
+#[test]
+fn extern_literal_terminal_not_declared() {
+    let src = include_str!("../examples/should_fail/extern_literal_terminal_not_declared.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let literal_position = ByteIndex(src.find(r#"")""#).unwrap());
+    assert!(matches!(
+        err,
+        KikiErr::ExternLiteralTerminalNotDeclared(literal, position)
+            if literal == r#"")""# && position == literal_position
+    ));
+}
+
+#[test]
+fn literal_terminal_redeclared() {
+    let src = include_str!("../examples/should_fail/literal_terminal_redeclared.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    assert!(matches!(err, KikiErr::NameClash(name, _, _) if name == r#""+""#));
+}
+
+#[test]
+fn nonproductive_nonterminal() {
+    let src = include_str!("../examples/should_fail/nonproductive_nonterminal.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let bar_position = ByteIndex(src.find("Bar {").unwrap());
+    assert!(matches!(
+        err,
+        KikiErr::NonproductiveNonterminal(name, position)
+            if name == "Bar" && position == bar_position
+    ));
+}
+
+#[test]
+fn empty_start_language() {
+    let src = include_str!("../examples/should_fail/empty_start_language.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let foo_position = ByteIndex(src.find("Foo {").unwrap());
+    assert!(matches!(
+        err,
+        KikiErr::EmptyStartLanguage(name, position) if name == "Foo" && position == foo_position
+    ));
+}
+
+#[test]
+fn unit_cycle() {
+    let src = include_str!("../examples/should_fail/unit_cycle.kiki");
+    let err = assert_src_fails_pre_machine_validation(src);
+    let foo_to_bar_position = ByteIndex(src.find("Bar(Bar)").unwrap());
+    let bar_to_foo_position = ByteIndex(src.find("Foo(Foo)").unwrap());
+    let expected = vec![
+        ("Foo".to_owned(), foo_to_bar_position),
+        ("Bar".to_owned(), bar_to_foo_position),
+    ];
+    assert!(matches!(err, KikiErr::UnitCycle(cycle) if cycle == expected));
+}
+
+#[test]
+fn nullable_cycle_conflict_has_unifying_counterexample() {
+    let src = include_str!("../examples/should_fail/nullable_cycle_conflict.kiki");
+    let conflict = assert_src_has_table_conflict(src);
+    let counterexample = conflict.counterexample.as_ref().unwrap();
+    assert_eq!(CounterexampleKind::Unifying, counterexample.kind);
+    let (first, second) = &counterexample.examples;
+    assert_eq!(first.sentence, second.sentence);
+    assert_ne!(
+        conflict.format_derivation(&first.derivation),
+        conflict.format_derivation(&second.derivation)
+    );
+}
+
+fn assert_src_has_table_conflict(src: &str) -> Box<TableConflictErr> {
+    match generate(src) {
+        Err(KikiErr::TableConflict(conflict)) => conflict,
+        Err(err) => panic!("expected a table conflict, but got {err:?}"),
+        Ok(_) => panic!("expected a table conflict, but generation succeeded"),
+    }
+}
+
 fn assert_src_fails_pre_machine_validation(src: &str) -> KikiErr {
     let tokens = tokenize(src).expect("Should be able to tokenize correctly");
     let cst = parse(tokens).expect("should parse correctly");
@@ -1,6 +1,7 @@
 extern crate kiki;
 extern crate walkdir;
 
+use kiki::{DefaultReductions, GenerateOptions, ParserAlgorithm, TableEncoding};
 use walkdir::WalkDir;
 
 use std::ffi::OsStr;
@@ -16,32 +17,38 @@ fn main() {
         }
 
         if entry.path().extension() == Some(OsStr::new("kiki")) {
-            let file_contents = fs::read_to_string(entry.path()).unwrap();
-
             let rs_path = entry
                 .path()
                 .parent()
                 .unwrap()
                 .join(Path::new(entry.path().file_stem().unwrap()).with_extension("rs"));
-
-            // We unconditionally regenerate the parser each build.
-            // The reason we do not compare hashes is because
-            // even if the `.kiki` file did not change,
-            // the local `kiki` crate might have changed.
-
-            let rust_src = match kiki::generate(&file_contents) {
-                Ok(s) => s,
-                Err(err) => {
-                    let file_path = entry.path().display();
-                    panic!("Invalid Kiki file {file_path}. Error: {err:#?}");
-                }
-            };
-            if let Err(err) = fs::write(&rs_path, &rust_src.0) {
-                let rs_path = rs_path.display();
-                panic!("Cannot write to \"{rs_path}\". Error: {err:#?}")
-            };
+            generate(entry.path(), &rs_path, get_options(entry.path()));
         }
     }
+
+    for (kiki_path, rs_path, options) in VARIANT_LIST {
+        generate(Path::new(kiki_path), Path::new(rs_path), options);
+    }
+}
+
+fn generate(kiki_path: &Path, rs_path: &Path, options: GenerateOptions) {
+    // We unconditionally regenerate the parser each build.
+    // The reason we do not compare hashes is because
+    // even if the `.kiki` file did not change,
+    // the local `kiki` crate might have changed.
+
+    let file_contents = fs::read_to_string(kiki_path).unwrap();
+    let rust_src = match kiki::generate_with_options(&file_contents, &options) {
+        Ok((s, _)) => s,
+        Err(err) => {
+            let diagnostic = err.display(&file_contents, kiki_path);
+            panic!("Invalid Kiki file.\n{diagnostic}");
+        }
+    };
+    if let Err(err) = fs::write(rs_path, &rust_src.0) {
+        let rs_path = rs_path.display();
+        panic!("Cannot write to \"{rs_path}\". Error: {err:#?}")
+    };
 }
 
 const IGNORE_LIST: [&str; 0] = [];
@@ -49,3 +56,65 @@ const IGNORE_LIST: [&str; 0] = [];
 fn is_ignored(path: &Path) -> bool {
     IGNORE_LIST.iter().any(|ignored| path.starts_with(ignored))
 }
+
+/// Examples that are generated with non-default options.
+const OPTIONS_LIST: [(&str, GenerateOptions); 2] = [
+    (
+        "./src/examples/canonical_lr1.kiki",
+        GenerateOptions {
+            parser_algorithm: ParserAlgorithm::CanonicalLr1,
+            table_encoding: TableEncoding::Dense,
+            default_reductions: DefaultReductions::Off,
+        },
+    ),
+    (
+        "./src/examples/lazy_default_reductions.kiki",
+        GenerateOptions {
+            parser_algorithm: ParserAlgorithm::Lalr1,
+            table_encoding: TableEncoding::Dense,
+            default_reductions: DefaultReductions::SingleReductionStates,
+        },
+    ),
+];
+
+/// Additional parsers that are generated from an existing example's grammar
+/// with non-default options,
+/// so that we can compare them to the example's parser
+/// without maintaining a copy of the grammar.
+const VARIANT_LIST: [(&str, &str, GenerateOptions); 3] = [
+    (
+        "./src/examples/arithmetic.kiki",
+        "./src/examples/compressed_tables.rs",
+        GenerateOptions {
+            parser_algorithm: ParserAlgorithm::Lalr1,
+            table_encoding: TableEncoding::Compressed,
+            default_reductions: DefaultReductions::Off,
+        },
+    ),
+    (
+        "./src/examples/arithmetic.kiki",
+        "./src/examples/default_reductions.rs",
+        GenerateOptions {
+            parser_algorithm: ParserAlgorithm::Lalr1,
+            table_encoding: TableEncoding::Dense,
+            default_reductions: DefaultReductions::SingleReductionStates,
+        },
+    ),
+    (
+        "./src/examples/canonical_lr1.kiki",
+        "./src/examples/minimal_lr1.rs",
+        GenerateOptions {
+            parser_algorithm: ParserAlgorithm::MinimalLr1,
+            table_encoding: TableEncoding::Dense,
+            default_reductions: DefaultReductions::Off,
+        },
+    ),
+];
+
+fn get_options(path: &Path) -> GenerateOptions {
+    OPTIONS_LIST
+        .iter()
+        .find(|(listed, _)| path == Path::new(listed))
+        .map(|(_, options)| *options)
+        .unwrap_or_default()
+}
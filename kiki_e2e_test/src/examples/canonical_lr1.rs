@@ -0,0 +1,620 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 b46125e0954f1c1ce134899f8ec9d2de68a1311be1fb7bb9b5d78f1d1a24abab
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    A(()),
+    B(()),
+    C(()),
+    D(()),
+    E(()),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Foo {
+    Axd(
+        Box<X>,
+    ),
+    Aye(
+        Box<Y>,
+    ),
+    Byd(
+        Box<Y>,
+    ),
+    Bxe(
+        Box<X>,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Y;
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<Foo, Option<Token>>
+where S: IntoIterator<Item = Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<Foo, ParseError>
+where S: IntoIterator<Item = Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S0];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(Foo::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    A = 0,
+    B = 1,
+    C = 2,
+    D = 3,
+    E = 4,
+    /// The end of input.
+    Eof = 5,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    Foo = 0,
+    X = 1,
+    Y = 2,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+}
+
+enum Node {
+    Foo(Foo),
+    X(X),
+    Y(Y),
+    A(()),
+    B(()),
+    C(()),
+    D(()),
+    E(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+    R5 = 5,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(X::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Foo(Foo::Axd(
+            t1,
+        )),
+        NonterminalKind::Foo,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(Y::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Foo(Foo::Aye(
+            t1,
+        )),
+        NonterminalKind::Foo,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(Y::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Foo(Foo::Byd(
+            t1,
+        )),
+        NonterminalKind::Foo,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(X::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Foo(Foo::Bxe(
+            t1,
+        )),
+        NonterminalKind::Foo,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::X(X),
+        NonterminalKind::X,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Y(Y),
+        NonterminalKind::Y,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::A(_) => Self::A,
+            Token::B(_) => Self::B,
+            Token::C(_) => Self::C,
+            Token::D(_) => Self::D,
+            Token::E(_) => Self::E,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::A => "$A",
+            Self::B => "$B",
+            Self::C => "$C",
+            Self::D => "$D",
+            Self::E => "$E",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::A(t) => Self::A(t),
+            Token::B(t) => Self::B(t),
+            Token::C(t) => Self::C(t),
+            Token::D(t) => Self::D(t),
+            Token::E(t) => Self::E(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 6]; 14] = [
+    [
+        Action::Shift(State::S1),
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S3),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S5),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S12),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S8),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S10),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 6] = [
+    QuasiterminalKind::A,
+    QuasiterminalKind::B,
+    QuasiterminalKind::C,
+    QuasiterminalKind::D,
+    QuasiterminalKind::E,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 3]; 14] = [
+    [
+        Some(State::S13),
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S2),
+        Some(State::S4),
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S9),
+        Some(State::S7),
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for Foo {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Foo(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for X {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::X(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Y {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Y(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_a_0(self) -> Result<(), Self> {
+        match self {
+            Self::A(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_b_1(self) -> Result<(), Self> {
+        match self {
+            Self::B(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_c_2(self) -> Result<(), Self> {
+        match self {
+            Self::C(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_d_3(self) -> Result<(), Self> {
+        match self {
+            Self::D(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_e_4(self) -> Result<(), Self> {
+        match self {
+            Self::E(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
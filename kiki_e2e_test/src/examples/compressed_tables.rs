@@ -0,0 +1,668 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 32443930939427b5dd56c5d056eebce23e4e3b6d1cc7c5002c759f93e556996a
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Num(
+        i32,
+    ),
+    Lt(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Add(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Sub(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Mul(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Div(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Pow(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Neg(
+        Box<Expr>,
+    ),
+    Paren(
+        Box<Expr>,
+    ),
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<Expr, Option<crate::shared_lexer::Token>>
+where S: IntoIterator<Item = crate::shared_lexer::Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<Expr, ParseError>
+where S: IntoIterator<Item = crate::shared_lexer::Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S8];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let Some(next_quasiterminal_kind) = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap()) else {
+            return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+        };
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<crate::shared_lexer::Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(crate::shared_lexer::Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    Num = 0,
+    Lt = 1,
+    Plus = 2,
+    Minus = 3,
+    Star = 4,
+    Slash = 5,
+    Caret = 6,
+    LParen = 7,
+    RParen = 8,
+    UnaryMinus = 9,
+    /// The end of input.
+    Eof = 10,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    Expr = 0,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+    S14 = 14,
+    S15 = 15,
+    S16 = 16,
+    S17 = 17,
+    S18 = 18,
+    S19 = 19,
+}
+
+enum Node {
+    Expr(Expr),
+    Num(i32),
+    Lt(()),
+    Plus(()),
+    Minus(()),
+    Star(()),
+    Slash(()),
+    Caret(()),
+    LParen(()),
+    RParen(()),
+    UnaryMinus(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+    R5 = 5,
+    R6 = 6,
+    R7 = 7,
+    R8 = 8,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_num_0().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Num(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Lt(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Add(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Sub(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Mul(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Div(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Pow(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::Expr(Expr::Neg(
+            t1,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r8(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Paren(
+            t1,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Option<Self> {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Some(Self::Eof),
+        }
+    }
+
+    fn from_terminal(terminal: &crate::shared_lexer::Token) -> Option<Self> {
+        match terminal {
+            crate::shared_lexer::Token::Num(_, _) => Some(Self::Num),
+            crate::shared_lexer::Token::Lt => Some(Self::Lt),
+            crate::shared_lexer::Token::Plus => Some(Self::Plus),
+            crate::shared_lexer::Token::Minus => Some(Self::Minus),
+            crate::shared_lexer::Token::Star => Some(Self::Star),
+            crate::shared_lexer::Token::Slash => Some(Self::Slash),
+            crate::shared_lexer::Token::Caret => Some(Self::Caret),
+            crate::shared_lexer::Token::LParen => Some(Self::LParen),
+            crate::shared_lexer::Token::RParen => Some(Self::RParen),
+            crate::shared_lexer::Token::UnaryMinus => Some(Self::UnaryMinus),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Num => "$Num",
+            Self::Lt => "$Lt",
+            Self::Plus => "$Plus",
+            Self::Minus => "$Minus",
+            Self::Star => "$Star",
+            Self::Slash => "$Slash",
+            Self::Caret => "$Caret",
+            Self::LParen => "$LParen",
+            Self::RParen => "$RParen",
+            Self::UnaryMinus => "$UnaryMinus",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: crate::shared_lexer::Token) -> Self {
+        match terminal {
+            crate::shared_lexer::Token::Num(value, _) => Self::Num(value),
+            crate::shared_lexer::Token::Lt => Self::Lt(()),
+            crate::shared_lexer::Token::Plus => Self::Plus(()),
+            crate::shared_lexer::Token::Minus => Self::Minus(()),
+            crate::shared_lexer::Token::Star => Self::Star(()),
+            crate::shared_lexer::Token::Slash => Self::Slash(()),
+            crate::shared_lexer::Token::Caret => Self::Caret(()),
+            crate::shared_lexer::Token::LParen => Self::LParen(()),
+            crate::shared_lexer::Token::RParen => Self::RParen(()),
+            crate::shared_lexer::Token::UnaryMinus => Self::UnaryMinus(()),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<crate::shared_lexer::Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_DEFAULTS: [u32; 20] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 23, 24, 25, 26, 27, 28,
+    29, 0, 0, 30,
+];
+
+static ACTION_BASES: [u32; 20] = [
+    60, 62, 68, 70, 76, 78, 84, 86, 92, 94, 0, 24, 32, 40, 44, 48,
+    52, 9, 17, 97,
+];
+
+static ACTION_CHECKS: [u32; 108] = [
+    10, 10, 10, 10, 10, 10, 10, 10, 4294967295, 10, 17, 17, 17, 17, 17, 17,
+    4294967295, 17, 18, 18, 18, 18, 18, 18, 11, 4294967295, 4294967295, 18, 11, 11, 11, 11,
+    12, 11, 4294967295, 4294967295, 12, 12, 12, 12, 13, 12, 4294967295, 4294967295, 14, 4294967295, 13, 13,
+    15, 13, 14, 14, 16, 14, 15, 15, 4294967295, 15, 16, 16, 0, 16, 1, 0,
+    4294967295, 1, 4294967295, 0, 2, 1, 3, 2, 4294967295, 3, 4294967295, 2, 4, 3, 5, 4,
+    4294967295, 5, 4294967295, 4, 6, 5, 7, 6, 4294967295, 7, 4294967295, 6, 8, 7, 9, 8,
+    4294967295, 19, 4294967295, 8, 4294967295, 9, 4294967295, 9, 19, 4294967295, 19, 4294967295,
+];
+
+static ACTION_VALUES: [u32; 108] = [
+    0, 0, 3, 4, 5, 6, 7, 0, 0, 0, 2, 3, 4, 5, 6, 7,
+    0, 21, 2, 3, 4, 5, 6, 7, 0, 0, 0, 1, 5, 6, 7, 0,
+    0, 0, 0, 0, 5, 6, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0,
+    0, 0, 7, 0, 0, 0, 7, 0, 0, 0, 7, 0, 11, 0, 11, 8,
+    0, 8, 0, 9, 11, 9, 11, 8, 0, 8, 0, 9, 11, 9, 11, 8,
+    0, 8, 0, 9, 11, 9, 11, 8, 0, 8, 0, 9, 11, 9, 0, 8,
+    0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    let row = top_state as usize;
+    let i = ACTION_BASES[row] as usize + next_quasiterminal_kind as usize;
+    let code = if ACTION_CHECKS[i] as usize == row {
+        ACTION_VALUES[i]
+    } else {
+        ACTION_DEFAULTS[row]
+    };
+    decode_action(code)
+}
+
+/// `0` is `Err`, `1` is `Accept`,
+/// `2 + s` is `Shift` to the `s`th state,
+/// and `22 + r` is `Reduce` by the `r`th rule.
+fn decode_action(code: u32) -> Action {
+    match code as usize {
+        0 => Action::Err,
+        1 => Action::Accept,
+        code if code < 22 => Action::Shift(STATES[code - 2]),
+        code => Action::Reduce(RULE_KINDS[code - 22]),
+    }
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 11] = [
+    QuasiterminalKind::Num,
+    QuasiterminalKind::Lt,
+    QuasiterminalKind::Plus,
+    QuasiterminalKind::Minus,
+    QuasiterminalKind::Star,
+    QuasiterminalKind::Slash,
+    QuasiterminalKind::Caret,
+    QuasiterminalKind::LParen,
+    QuasiterminalKind::RParen,
+    QuasiterminalKind::UnaryMinus,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_DEFAULTS: [u32; 20] = [
+    11, 12, 13, 14, 15, 16, 17, 18, 19, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0,
+];
+
+static GOTO_BASES: [u32; 20] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0,
+];
+
+static GOTO_CHECKS: [u32; 1] = [
+    4294967295,
+];
+
+static GOTO_VALUES: [u32; 1] = [
+    0,
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    let row = top_state as usize;
+    let i = GOTO_BASES[row] as usize + new_node_kind as usize;
+    let code = if GOTO_CHECKS[i] as usize == row {
+        GOTO_VALUES[i]
+    } else {
+        GOTO_DEFAULTS[row]
+    };
+    decode_goto(code)
+}
+
+/// `0` is `None`, and `1 + s` is the `s`th state.
+fn decode_goto(code: u32) -> Option<State> {
+    match code as usize {
+        0 => None,
+        code => Some(STATES[code - 1]),
+    }
+}
+
+static STATES: [State; 20] = [
+    State::S0,
+    State::S1,
+    State::S2,
+    State::S3,
+    State::S4,
+    State::S5,
+    State::S6,
+    State::S7,
+    State::S8,
+    State::S9,
+    State::S10,
+    State::S11,
+    State::S12,
+    State::S13,
+    State::S14,
+    State::S15,
+    State::S16,
+    State::S17,
+    State::S18,
+    State::S19,
+];
+
+static RULE_KINDS: [RuleKind; 9] = [
+    RuleKind::R0,
+    RuleKind::R1,
+    RuleKind::R2,
+    RuleKind::R3,
+    RuleKind::R4,
+    RuleKind::R5,
+    RuleKind::R6,
+    RuleKind::R7,
+    RuleKind::R8,
+];
+
+impl TryFrom<Node> for Expr {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Expr(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_num_0(self) -> Result<i32, Self> {
+        match self {
+            Self::Num(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_lt_1(self) -> Result<(), Self> {
+        match self {
+            Self::Lt(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_plus_2(self) -> Result<(), Self> {
+        match self {
+            Self::Plus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_minus_3(self) -> Result<(), Self> {
+        match self {
+            Self::Minus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_star_4(self) -> Result<(), Self> {
+        match self {
+            Self::Star(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_slash_5(self) -> Result<(), Self> {
+        match self {
+            Self::Slash(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_caret_6(self) -> Result<(), Self> {
+        match self {
+            Self::Caret(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_l_paren_7(self) -> Result<(), Self> {
+        match self {
+            Self::LParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_paren_8(self) -> Result<(), Self> {
+        match self {
+            Self::RParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_unary_minus_9(self) -> Result<(), Self> {
+        match self {
+            Self::UnaryMinus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
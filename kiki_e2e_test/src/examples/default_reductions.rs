@@ -0,0 +1,912 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 32443930939427b5dd56c5d056eebce23e4e3b6d1cc7c5002c759f93e556996a
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Num(
+        i32,
+    ),
+    Lt(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Add(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Sub(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Mul(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Div(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Pow(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Neg(
+        Box<Expr>,
+    ),
+    Paren(
+        Box<Expr>,
+    ),
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<Expr, Option<crate::shared_lexer::Token>>
+where S: IntoIterator<Item = crate::shared_lexer::Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<Expr, ParseError>
+where S: IntoIterator<Item = crate::shared_lexer::Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S8];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let action = match get_default_reduction(top_state) {
+            Some(rule_kind) => Action::Reduce(rule_kind),
+            None => {
+                let Some(next_quasiterminal_kind) = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap()) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                get_action(top_state, next_quasiterminal_kind)
+            }
+        };
+        match action {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<crate::shared_lexer::Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(crate::shared_lexer::Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    Num = 0,
+    Lt = 1,
+    Plus = 2,
+    Minus = 3,
+    Star = 4,
+    Slash = 5,
+    Caret = 6,
+    LParen = 7,
+    RParen = 8,
+    UnaryMinus = 9,
+    /// The end of input.
+    Eof = 10,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    Expr = 0,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+    S14 = 14,
+    S15 = 15,
+    S16 = 16,
+    S17 = 17,
+    S18 = 18,
+    S19 = 19,
+}
+
+enum Node {
+    Expr(Expr),
+    Num(i32),
+    Lt(()),
+    Plus(()),
+    Minus(()),
+    Star(()),
+    Slash(()),
+    Caret(()),
+    LParen(()),
+    RParen(()),
+    UnaryMinus(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+    R5 = 5,
+    R6 = 6,
+    R7 = 7,
+    R8 = 8,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_num_0().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Num(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Lt(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Add(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Sub(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Mul(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Div(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Pow(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::Expr(Expr::Neg(
+            t1,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r8(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Paren(
+            t1,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Option<Self> {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Some(Self::Eof),
+        }
+    }
+
+    fn from_terminal(terminal: &crate::shared_lexer::Token) -> Option<Self> {
+        match terminal {
+            crate::shared_lexer::Token::Num(_, _) => Some(Self::Num),
+            crate::shared_lexer::Token::Lt => Some(Self::Lt),
+            crate::shared_lexer::Token::Plus => Some(Self::Plus),
+            crate::shared_lexer::Token::Minus => Some(Self::Minus),
+            crate::shared_lexer::Token::Star => Some(Self::Star),
+            crate::shared_lexer::Token::Slash => Some(Self::Slash),
+            crate::shared_lexer::Token::Caret => Some(Self::Caret),
+            crate::shared_lexer::Token::LParen => Some(Self::LParen),
+            crate::shared_lexer::Token::RParen => Some(Self::RParen),
+            crate::shared_lexer::Token::UnaryMinus => Some(Self::UnaryMinus),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Num => "$Num",
+            Self::Lt => "$Lt",
+            Self::Plus => "$Plus",
+            Self::Minus => "$Minus",
+            Self::Star => "$Star",
+            Self::Slash => "$Slash",
+            Self::Caret => "$Caret",
+            Self::LParen => "$LParen",
+            Self::RParen => "$RParen",
+            Self::UnaryMinus => "$UnaryMinus",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: crate::shared_lexer::Token) -> Self {
+        match terminal {
+            crate::shared_lexer::Token::Num(value, _) => Self::Num(value),
+            crate::shared_lexer::Token::Lt => Self::Lt(()),
+            crate::shared_lexer::Token::Plus => Self::Plus(()),
+            crate::shared_lexer::Token::Minus => Self::Minus(()),
+            crate::shared_lexer::Token::Star => Self::Star(()),
+            crate::shared_lexer::Token::Slash => Self::Slash(()),
+            crate::shared_lexer::Token::Caret => Self::Caret(()),
+            crate::shared_lexer::Token::LParen => Self::LParen(()),
+            crate::shared_lexer::Token::RParen => Self::RParen(()),
+            crate::shared_lexer::Token::UnaryMinus => Self::UnaryMinus(()),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<crate::shared_lexer::Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 11]; 20] = [
+    [
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S1),
+        Action::Shift(State::S2),
+        Action::Shift(State::S3),
+        Action::Shift(State::S4),
+        Action::Shift(State::S5),
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Shift(State::S3),
+        Action::Shift(State::S4),
+        Action::Shift(State::S5),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+    ],
+    [
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Shift(State::S3),
+        Action::Shift(State::S4),
+        Action::Shift(State::S5),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+    ],
+    [
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Shift(State::S5),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+    ],
+    [
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Shift(State::S5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+    ],
+    [
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Shift(State::S5),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+    ],
+    [
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Shift(State::S5),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S0),
+        Action::Shift(State::S1),
+        Action::Shift(State::S2),
+        Action::Shift(State::S3),
+        Action::Shift(State::S4),
+        Action::Shift(State::S5),
+        Action::Err,
+        Action::Shift(State::S19),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S0),
+        Action::Shift(State::S1),
+        Action::Shift(State::S2),
+        Action::Shift(State::S3),
+        Action::Shift(State::S4),
+        Action::Shift(State::S5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+    [
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static DEFAULT_REDUCTIONS: [Option<RuleKind>; 20] = [
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(RuleKind::R0),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(RuleKind::R8),
+];
+
+/// If the state reduces by the same rule on every lookahead,
+/// the parser reduces without reading the next token.
+fn get_default_reduction(top_state: State) -> Option<RuleKind> {
+    DEFAULT_REDUCTIONS[top_state as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 11] = [
+    QuasiterminalKind::Num,
+    QuasiterminalKind::Lt,
+    QuasiterminalKind::Plus,
+    QuasiterminalKind::Minus,
+    QuasiterminalKind::Star,
+    QuasiterminalKind::Slash,
+    QuasiterminalKind::Caret,
+    QuasiterminalKind::LParen,
+    QuasiterminalKind::RParen,
+    QuasiterminalKind::UnaryMinus,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 1]; 20] = [
+    [
+        Some(State::S10),
+    ],
+    [
+        Some(State::S11),
+    ],
+    [
+        Some(State::S12),
+    ],
+    [
+        Some(State::S13),
+    ],
+    [
+        Some(State::S14),
+    ],
+    [
+        Some(State::S15),
+    ],
+    [
+        Some(State::S16),
+    ],
+    [
+        Some(State::S17),
+    ],
+    [
+        Some(State::S18),
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+    [
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for Expr {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Expr(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_num_0(self) -> Result<i32, Self> {
+        match self {
+            Self::Num(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_lt_1(self) -> Result<(), Self> {
+        match self {
+            Self::Lt(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_plus_2(self) -> Result<(), Self> {
+        match self {
+            Self::Plus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_minus_3(self) -> Result<(), Self> {
+        match self {
+            Self::Minus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_star_4(self) -> Result<(), Self> {
+        match self {
+            Self::Star(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_slash_5(self) -> Result<(), Self> {
+        match self {
+            Self::Slash(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_caret_6(self) -> Result<(), Self> {
+        match self {
+            Self::Caret(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_l_paren_7(self) -> Result<(), Self> {
+        match self {
+            Self::LParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_paren_8(self) -> Result<(), Self> {
+        match self {
+            Self::RParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_unary_minus_9(self) -> Result<(), Self> {
+        match self {
+            Self::UnaryMinus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
@@ -0,0 +1,516 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 9f2aaaea67bc556f6fb094057369b4fec71f09a91c37bbb7b8d7ced4924fe397
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[doc = " A token produced by the lexer."]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    #[doc = " The `let` keyword."]
+    LetKw(()),
+    #[doc = " An identifier."]
+    Ident(String),
+    #[doc = " The `=` sign."]
+    Eq(()),
+    #[doc = " The `+` sign."]
+    Plus(()),
+    #[doc = " The `;` terminator."]
+    Semicolon(()),
+}
+
+#[doc = " A variable binding, such as `let x = y + z;`."]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement {
+    #[doc = " The name of the variable being bound."]
+    pub name: String,
+    #[doc = " The value the variable is bound to."]
+    pub value: Box<Expr>,
+}
+
+#[doc = " An expression."]
+#[doc = ""]
+#[doc = " Addition is left-associative."]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    #[doc = " A variable reference."]
+    Var(
+        String,
+    ),
+    #[doc = " A sum, such as `x + y`."]
+    Add {
+        #[doc = " The left operand."]
+        left: Box<Expr>,
+        #[doc = " The right operand."]
+        right: String,
+    },
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<Statement, Option<Token>>
+where S: IntoIterator<Item = Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<Statement, ParseError>
+where S: IntoIterator<Item = Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S0];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(Statement::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    #[doc = " The `let` keyword."]
+    LetKw = 0,
+    #[doc = " An identifier."]
+    Ident = 1,
+    #[doc = " The `=` sign."]
+    Eq = 2,
+    #[doc = " The `+` sign."]
+    Plus = 3,
+    #[doc = " The `;` terminator."]
+    Semicolon = 4,
+    /// The end of input.
+    Eof = 5,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    Statement = 0,
+    Expr = 1,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+}
+
+enum Node {
+    Statement(Statement),
+    Expr(Expr),
+    LetKw(()),
+    Ident(String),
+    Eq(()),
+    Plus(()),
+    Semicolon(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let value_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let name_1 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::Statement(Statement {
+            name: name_1,
+            value: value_3,
+        }),
+        NonterminalKind::Statement,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Var(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let right_2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+    nodes.pop().unwrap();
+    let left_0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Add {
+            left: left_0,
+            right: right_2,
+        }),
+        NonterminalKind::Expr,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::LetKw(_) => Self::LetKw,
+            Token::Ident(_) => Self::Ident,
+            Token::Eq(_) => Self::Eq,
+            Token::Plus(_) => Self::Plus,
+            Token::Semicolon(_) => Self::Semicolon,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::LetKw => "$LetKw",
+            Self::Ident => "$Ident",
+            Self::Eq => "$Eq",
+            Self::Plus => "$Plus",
+            Self::Semicolon => "$Semicolon",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::LetKw(t) => Self::LetKw(t),
+            Token::Ident(t) => Self::Ident(t),
+            Token::Eq(t) => Self::Eq(t),
+            Token::Plus(t) => Self::Plus(t),
+            Token::Semicolon(t) => Self::Semicolon(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 6]; 10] = [
+    [
+        Action::Shift(State::S1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S3),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Shift(State::S5),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 6] = [
+    QuasiterminalKind::LetKw,
+    QuasiterminalKind::Ident,
+    QuasiterminalKind::Eq,
+    QuasiterminalKind::Plus,
+    QuasiterminalKind::Semicolon,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 2]; 10] = [
+    [
+        Some(State::S9),
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S4),
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for Statement {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Statement(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Expr {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Expr(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_let_kw_0(self) -> Result<(), Self> {
+        match self {
+            Self::LetKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_ident_1(self) -> Result<String, Self> {
+        match self {
+            Self::Ident(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_eq_2(self) -> Result<(), Self> {
+        match self {
+            Self::Eq(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_plus_3(self) -> Result<(), Self> {
+        match self {
+            Self::Plus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_semicolon_4(self) -> Result<(), Self> {
+        match self {
+            Self::Semicolon(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
@@ -0,0 +1,519 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 9d93a9b5e61d732eeae6fe8e5623bbecf302dbbf252ada1c422f6f666274d40d
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+    pub callee: String,
+    pub args: Vec<i32>,
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<Call, Option<crate::shared_lexer::Token>>
+where S: IntoIterator<Item = crate::shared_lexer::Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<Call, ParseError>
+where S: IntoIterator<Item = crate::shared_lexer::Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S0];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let Some(next_quasiterminal_kind) = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap()) else {
+            return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+        };
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(Call::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<crate::shared_lexer::Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(crate::shared_lexer::Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    Ident = 0,
+    Num = 1,
+    /// The literal `"("`.
+    LParen = 2,
+    /// The literal `")"`.
+    RParen = 3,
+    /// The literal `","`.
+    Comma = 4,
+    /// The end of input.
+    Eof = 5,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    Call = 0,
+    SepNumCommaPlus = 1,
+    SepNumCommaStar = 2,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+}
+
+enum Node {
+    Call(Call),
+    SepNumCommaPlus(Vec<i32>),
+    SepNumCommaStar(Vec<i32>),
+    Ident(String),
+    Num(i32),
+    LParen(()),
+    RParen(()),
+    Comma(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let args_2 = nodes.pop().unwrap().try_into_sep_num_comma_star().ok().unwrap();
+    nodes.pop().unwrap();
+    let callee_0 = nodes.pop().unwrap().try_into_ident_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::Call(Call {
+            callee: callee_0,
+            args: args_2,
+        }),
+        NonterminalKind::Call,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_num_1().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::SepNumCommaPlus(vec![t0]),
+        NonterminalKind::SepNumCommaPlus,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = nodes.pop().unwrap().try_into_num_1().ok().unwrap();
+    nodes.pop().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_sep_num_comma_plus().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    t0.push(t2);
+
+    (
+        Node::SepNumCommaPlus(t0),
+        NonterminalKind::SepNumCommaPlus,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_sep_num_comma_plus().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::SepNumCommaStar(t0),
+        NonterminalKind::SepNumCommaStar,
+    )
+}
+
+fn reduce_r4(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::SepNumCommaStar(vec![]),
+        NonterminalKind::SepNumCommaStar,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Option<Self> {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Some(Self::Eof),
+        }
+    }
+
+    fn from_terminal(terminal: &crate::shared_lexer::Token) -> Option<Self> {
+        match terminal {
+            crate::shared_lexer::Token::Ident(_) => Some(Self::Ident),
+            crate::shared_lexer::Token::Num(_, _) => Some(Self::Num),
+            crate::shared_lexer::Token::LParen => Some(Self::LParen),
+            crate::shared_lexer::Token::RParen => Some(Self::RParen),
+            crate::shared_lexer::Token::Comma => Some(Self::Comma),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Ident => "$Ident",
+            Self::Num => "$Num",
+            Self::LParen => "\"(\"",
+            Self::RParen => "\")\"",
+            Self::Comma => "\",\"",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: crate::shared_lexer::Token) -> Self {
+        match terminal {
+            crate::shared_lexer::Token::Ident(name) => Self::Ident(name),
+            crate::shared_lexer::Token::Num(value, _) => Self::Num(value),
+            crate::shared_lexer::Token::LParen => Self::LParen(()),
+            crate::shared_lexer::Token::RParen => Self::RParen(()),
+            crate::shared_lexer::Token::Comma => Self::Comma(()),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<crate::shared_lexer::Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 6]; 10] = [
+    [
+        Action::Shift(State::S1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S5),
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S4),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Shift(State::S7),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 6] = [
+    QuasiterminalKind::Ident,
+    QuasiterminalKind::Num,
+    QuasiterminalKind::LParen,
+    QuasiterminalKind::RParen,
+    QuasiterminalKind::Comma,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 3]; 10] = [
+    [
+        Some(State::S9),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S6),
+        Some(State::S3),
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for Call {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Call(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_ident_0(self) -> Result<String, Self> {
+        match self {
+            Self::Ident(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_num_1(self) -> Result<i32, Self> {
+        match self {
+            Self::Num(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_l_paren_2(self) -> Result<(), Self> {
+        match self {
+            Self::LParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_paren_3(self) -> Result<(), Self> {
+        match self {
+            Self::RParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_comma_4(self) -> Result<(), Self> {
+        match self {
+            Self::Comma(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_sep_num_comma_plus(self) -> Result<Vec<i32>, Self> {
+        match self {
+            Self::SepNumCommaPlus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_sep_num_comma_star(self) -> Result<Vec<i32>, Self> {
+        match self {
+            Self::SepNumCommaStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
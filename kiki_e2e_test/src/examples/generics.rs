@@ -0,0 +1,1325 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 f166f687be3c6ec34b518b3c0c995bcc2daccc4333350a17f0b8954feb4d91f2
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    ImportKw(()),
+    Ident(String),
+    Num(i32),
+    Comma(()),
+    Colon(()),
+    Semicolon(()),
+    LParen(()),
+    RParen(()),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct File {
+    pub imports: Vec<Import>,
+    pub entries: Vec<Entry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Import {
+    pub names: Box<CommaListIdent>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub pair: Box<PairIdentValue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Num(
+        i32,
+    ),
+    Tuple(
+        Box<TupleValue>,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommaListIdent {
+    One(
+        String,
+    ),
+    Cons(
+        Box<CommaListIdent>,
+        String,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairIdentValue {
+    pub key: String,
+    pub value: Box<Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommaListValue {
+    One(
+        Box<Value>,
+    ),
+    Cons(
+        Box<CommaListValue>,
+        Box<Value>,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TupleValue {
+    pub items: Box<CommaListValue>,
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<File, Option<Token>>
+where S: IntoIterator<Item = Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<File, ParseError>
+where S: IntoIterator<Item = Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S0];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(File::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    ImportKw = 0,
+    Ident = 1,
+    Num = 2,
+    Comma = 3,
+    Colon = 4,
+    Semicolon = 5,
+    LParen = 6,
+    RParen = 7,
+    /// The end of input.
+    Eof = 8,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    File = 0,
+    Import = 1,
+    Entry = 2,
+    Value = 3,
+    CommaListIdent = 4,
+    PairIdentValue = 5,
+    CommaListValue = 6,
+    TupleValue = 7,
+    ImportStar = 8,
+    EntryStar = 9,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+    S14 = 14,
+    S15 = 15,
+    S16 = 16,
+    S17 = 17,
+    S18 = 18,
+    S19 = 19,
+    S20 = 20,
+    S21 = 21,
+    S22 = 22,
+    S23 = 23,
+    S24 = 24,
+}
+
+enum Node {
+    File(File),
+    Import(Import),
+    Entry(Entry),
+    Value(Value),
+    CommaListIdent(CommaListIdent),
+    PairIdentValue(PairIdentValue),
+    CommaListValue(CommaListValue),
+    TupleValue(TupleValue),
+    ImportStar(Vec<Import>),
+    EntryStar(Vec<Entry>),
+    ImportKw(()),
+    Ident(String),
+    Num(i32),
+    Comma(()),
+    Colon(()),
+    Semicolon(()),
+    LParen(()),
+    RParen(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+    R5 = 5,
+    R6 = 6,
+    R7 = 7,
+    R8 = 8,
+    R9 = 9,
+    R10 = 10,
+    R11 = 11,
+    R12 = 12,
+    R13 = 13,
+    R14 = 14,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+        RuleKind::R9 => reduce_r9(states, nodes),
+        RuleKind::R10 => reduce_r10(states, nodes),
+        RuleKind::R11 => reduce_r11(states, nodes),
+        RuleKind::R12 => reduce_r12(states, nodes),
+        RuleKind::R13 => reduce_r13(states, nodes),
+        RuleKind::R14 => reduce_r14(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let entries_1 = nodes.pop().unwrap().try_into_entry_star().ok().unwrap();
+    let imports_0 = nodes.pop().unwrap().try_into_import_star().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::File(File {
+            imports: imports_0,
+            entries: entries_1,
+        }),
+        NonterminalKind::File,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let names_1 = Box::new(CommaListIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Import(Import {
+            names: names_1,
+        }),
+        NonterminalKind::Import,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let pair_0 = Box::new(PairIdentValue::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::Entry(Entry {
+            pair: pair_0,
+        }),
+        NonterminalKind::Entry,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_num_2().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Value(Value::Num(
+            t0,
+        )),
+        NonterminalKind::Value,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(TupleValue::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Value(Value::Tuple(
+            t0,
+        )),
+        NonterminalKind::Value,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::CommaListIdent(CommaListIdent::One(
+            t0,
+        )),
+        NonterminalKind::CommaListIdent,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+    nodes.pop().unwrap();
+    let t0 = Box::new(CommaListIdent::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::CommaListIdent(CommaListIdent::Cons(
+            t0,
+            t2,
+        )),
+        NonterminalKind::CommaListIdent,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let value_2 = Box::new(Value::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let key_0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::PairIdentValue(PairIdentValue {
+            key: key_0,
+            value: value_2,
+        }),
+        NonterminalKind::PairIdentValue,
+    )
+}
+
+fn reduce_r8(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Value::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::CommaListValue(CommaListValue::One(
+            t0,
+        )),
+        NonterminalKind::CommaListValue,
+    )
+}
+
+fn reduce_r9(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Value::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(CommaListValue::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::CommaListValue(CommaListValue::Cons(
+            t0,
+            t2,
+        )),
+        NonterminalKind::CommaListValue,
+    )
+}
+
+fn reduce_r10(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let items_1 = Box::new(CommaListValue::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::TupleValue(TupleValue {
+            items: items_1,
+        }),
+        NonterminalKind::TupleValue,
+    )
+}
+
+fn reduce_r11(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::ImportStar(vec![]),
+        NonterminalKind::ImportStar,
+    )
+}
+
+fn reduce_r12(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Import::try_from(nodes.pop().unwrap()).ok().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_import_star().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    t0.push(t1);
+
+    (
+        Node::ImportStar(t0),
+        NonterminalKind::ImportStar,
+    )
+}
+
+fn reduce_r13(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::EntryStar(vec![]),
+        NonterminalKind::EntryStar,
+    )
+}
+
+fn reduce_r14(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Entry::try_from(nodes.pop().unwrap()).ok().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_entry_star().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    t0.push(t1);
+
+    (
+        Node::EntryStar(t0),
+        NonterminalKind::EntryStar,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::ImportKw(_) => Self::ImportKw,
+            Token::Ident(_) => Self::Ident,
+            Token::Num(_) => Self::Num,
+            Token::Comma(_) => Self::Comma,
+            Token::Colon(_) => Self::Colon,
+            Token::Semicolon(_) => Self::Semicolon,
+            Token::LParen(_) => Self::LParen,
+            Token::RParen(_) => Self::RParen,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::ImportKw => "$ImportKw",
+            Self::Ident => "$Ident",
+            Self::Num => "$Num",
+            Self::Comma => "$Comma",
+            Self::Colon => "$Colon",
+            Self::Semicolon => "$Semicolon",
+            Self::LParen => "$LParen",
+            Self::RParen => "$RParen",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::ImportKw(t) => Self::ImportKw(t),
+            Token::Ident(t) => Self::Ident(t),
+            Token::Num(t) => Self::Num(t),
+            Token::Comma(t) => Self::Comma(t),
+            Token::Colon(t) => Self::Colon(t),
+            Token::Semicolon(t) => Self::Semicolon(t),
+            Token::LParen(t) => Self::LParen(t),
+            Token::RParen(t) => Self::RParen(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 9]; 25] = [
+    [
+        Action::Reduce(RuleKind::R11),
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+    ],
+    [
+        Action::Shift(State::S3),
+        Action::Reduce(RuleKind::R13),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R13),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S16),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S13),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Shift(State::S5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S8),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S8),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S8),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S21),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R14),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R14),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 9] = [
+    QuasiterminalKind::ImportKw,
+    QuasiterminalKind::Ident,
+    QuasiterminalKind::Num,
+    QuasiterminalKind::Comma,
+    QuasiterminalKind::Colon,
+    QuasiterminalKind::Semicolon,
+    QuasiterminalKind::LParen,
+    QuasiterminalKind::RParen,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 10]; 25] = [
+    [
+        Some(State::S24),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S1),
+        None,
+    ],
+    [
+        None,
+        Some(State::S22),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(State::S2),
+    ],
+    [
+        None,
+        None,
+        Some(State::S23),
+        None,
+        None,
+        Some(State::S6),
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        Some(State::S4),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        Some(State::S18),
+        None,
+        None,
+        Some(State::S19),
+        Some(State::S12),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        Some(State::S20),
+        None,
+        None,
+        None,
+        Some(State::S12),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        Some(State::S17),
+        None,
+        None,
+        None,
+        Some(State::S12),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for File {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::File(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Import {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Import(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Entry {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Entry(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Value {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Value(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for CommaListIdent {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::CommaListIdent(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for PairIdentValue {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::PairIdentValue(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for CommaListValue {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::CommaListValue(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for TupleValue {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::TupleValue(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_import_kw_0(self) -> Result<(), Self> {
+        match self {
+            Self::ImportKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_ident_1(self) -> Result<String, Self> {
+        match self {
+            Self::Ident(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_num_2(self) -> Result<i32, Self> {
+        match self {
+            Self::Num(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_comma_3(self) -> Result<(), Self> {
+        match self {
+            Self::Comma(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_colon_4(self) -> Result<(), Self> {
+        match self {
+            Self::Colon(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_semicolon_5(self) -> Result<(), Self> {
+        match self {
+            Self::Semicolon(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_l_paren_6(self) -> Result<(), Self> {
+        match self {
+            Self::LParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_paren_7(self) -> Result<(), Self> {
+        match self {
+            Self::RParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_import_star(self) -> Result<Vec<Import>, Self> {
+        match self {
+            Self::ImportStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_entry_star(self) -> Result<Vec<Entry>, Self> {
+        match self {
+            Self::EntryStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
@@ -12,6 +12,9 @@
 // Therefore, we disable certain lints for this file.
 #![allow(non_snake_case)]
 #![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
 
 pub enum Token {
     String(String),
@@ -101,7 +104,16 @@ pub enum Elements {
 
 /// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
 /// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
 pub fn parse<S>(src: S) -> Result<Json, Option<Token>>
+where S: IntoIterator<Item = Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<Json, ParseError>
 where S: IntoIterator<Item = Token> {
     let mut quasiterminals = src.into_iter()
         .map(Quasiterminal::Terminal)
@@ -109,6 +121,7 @@ where S: IntoIterator<Item = Token> {
         .peekable();
     let mut states = vec![State::S0];
     let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
     loop {
         let top_state = *states.last().unwrap();
         let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
@@ -116,6 +129,7 @@ where S: IntoIterator<Item = Token> {
             Action::Shift(new_state) => {
                 states.push(new_state);
                 nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
             }
 
             Action::Reduce(rule_kind) => {
@@ -123,7 +137,7 @@ where S: IntoIterator<Item = Token> {
                 nodes.push(new_node);
                 let temp_top_state = *states.last().unwrap();
                 let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
-                    return Err(quasiterminals.next().unwrap().try_into_terminal().ok());
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
                 };
                 states.push(new_state);
             }
@@ -133,19 +147,47 @@ where S: IntoIterator<Item = Token> {
             }
 
             Action::Err => {
-                return Err(quasiterminals.next().unwrap().try_into_terminal().ok());
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
             }
         }
     }
 }
 
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
 enum Quasiterminal {
     Terminal(Token),
     Eof,
 }
 
-#[derive(Clone, Copy, Debug)]
-enum QuasiterminalKind {
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
     String = 0,
     Num = 1,
     Bool = 2,
@@ -155,6 +197,7 @@ enum QuasiterminalKind {
     RSquare = 6,
     Colon = 7,
     Comma = 8,
+    /// The end of input.
     Eof = 9,
 }
 
@@ -533,6 +576,25 @@ impl QuasiterminalKind {
     }
 }
 
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::String => "$String",
+            Self::Num => "$Num",
+            Self::Bool => "$Bool",
+            Self::LCurly => "$LCurly",
+            Self::RCurly => "$RCurly",
+            Self::LSquare => "$LSquare",
+            Self::RSquare => "$RSquare",
+            Self::Colon => "$Colon",
+            Self::Comma => "$Comma",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
 impl Node {
     fn from_terminal(terminal: Token) -> Self {
         match terminal {
@@ -877,6 +939,19 @@ fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> A
     ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
 }
 
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 10] = [
+    QuasiterminalKind::String,
+    QuasiterminalKind::Num,
+    QuasiterminalKind::Bool,
+    QuasiterminalKind::LCurly,
+    QuasiterminalKind::RCurly,
+    QuasiterminalKind::LSquare,
+    QuasiterminalKind::RSquare,
+    QuasiterminalKind::Colon,
+    QuasiterminalKind::Comma,
+    QuasiterminalKind::Eof,
+];
+
 static GOTO_TABLE: [[Option<State>; 9]; 26] = [
     [
         Some(State::S25),
@@ -1173,7 +1248,7 @@ fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
 impl TryFrom<Node> for Json {
     type Error = Node;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
+    fn try_from(node: Node) -> Result<Self, Node> {
         match node {
             Node::Json(n) => Ok(n),
             _ => Err(node),
@@ -1184,7 +1259,7 @@ impl TryFrom<Node> for Json {
 impl TryFrom<Node> for Obj {
     type Error = Node;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
+    fn try_from(node: Node) -> Result<Self, Node> {
         match node {
             Node::Obj(n) => Ok(n),
             _ => Err(node),
@@ -1195,7 +1270,7 @@ impl TryFrom<Node> for Obj {
 impl TryFrom<Node> for OptEntries {
     type Error = Node;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
+    fn try_from(node: Node) -> Result<Self, Node> {
         match node {
             Node::OptEntries(n) => Ok(n),
             _ => Err(node),
@@ -1206,7 +1281,7 @@ impl TryFrom<Node> for OptEntries {
 impl TryFrom<Node> for Entries {
     type Error = Node;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
+    fn try_from(node: Node) -> Result<Self, Node> {
         match node {
             Node::Entries(n) => Ok(n),
             _ => Err(node),
@@ -1217,7 +1292,7 @@ impl TryFrom<Node> for Entries {
 impl TryFrom<Node> for Entry {
     type Error = Node;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
+    fn try_from(node: Node) -> Result<Self, Node> {
         match node {
             Node::Entry(n) => Ok(n),
             _ => Err(node),
@@ -1228,7 +1303,7 @@ impl TryFrom<Node> for Entry {
 impl TryFrom<Node> for Expr {
     type Error = Node;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
+    fn try_from(node: Node) -> Result<Self, Node> {
         match node {
             Node::Expr(n) => Ok(n),
             _ => Err(node),
@@ -1239,7 +1314,7 @@ impl TryFrom<Node> for Expr {
 impl TryFrom<Node> for Arr {
     type Error = Node;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
+    fn try_from(node: Node) -> Result<Self, Node> {
         match node {
             Node::Arr(n) => Ok(n),
             _ => Err(node),
@@ -1250,7 +1325,7 @@ impl TryFrom<Node> for Arr {
 impl TryFrom<Node> for OptElements {
     type Error = Node;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
+    fn try_from(node: Node) -> Result<Self, Node> {
         match node {
             Node::OptElements(n) => Ok(n),
             _ => Err(node),
@@ -1261,7 +1336,7 @@ impl TryFrom<Node> for OptElements {
 impl TryFrom<Node> for Elements {
     type Error = Node;
 
-    fn try_from(node: Node) -> Result<Self, Self::Error> {
+    fn try_from(node: Node) -> Result<Self, Node> {
         match node {
             Node::Elements(n) => Ok(n),
             _ => Err(node),
@@ -0,0 +1,1120 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 6405dbf053a1d140a01cbf24e3943f2a44188bb4716f1b161544d75eb998d04c
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    LetKw(()),
+    Ident(String),
+    Num(u8),
+    Str(String),
+    Eq(()),
+    Plus(()),
+    Semicolon(()),
+    LParen(()),
+    RParen(()),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct File {
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement {
+    pub name: String,
+    pub value: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Term(
+        Box<Term>,
+    ),
+    Add(
+        Box<Expr>,
+        Box<Term>,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Term {
+    Num(
+        u8,
+    ),
+    Var(
+        String,
+    ),
+    Str(
+        String,
+    ),
+    Paren(
+        Box<Expr>,
+    ),
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<File, Option<Token>>
+where S: IntoIterator<Item = Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<File, ParseError>
+where S: IntoIterator<Item = Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S0];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(File::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// `span` is the byte range of the text that could not be lexed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LexError {
+    pub span: std::ops::Range<usize>,
+}
+
+/// Splits `src` into tokens using the grammar's `lexer` rules.
+/// At each position, the lexer uses the rule that matches
+/// the longest prefix of the remaining input.
+/// If several rules match that prefix, the rule declared first wins.
+/// Text matched by a skip rule (i.e., `_ = ...`) is discarded.
+///
+/// If no rule matches, the lexer yields a `LexError`
+/// covering the next character, and resumes after it.
+/// If a token's text cannot be parsed into the terminal's type,
+/// the lexer yields a `LexError` covering the text, and resumes after it.
+pub fn lex(src: &str) -> impl Iterator<Item = Result<Token, LexError>> + '_ {
+    Lexer { src, offset: 0 }
+}
+
+struct Lexer<'a> {
+    src: &'a str,
+    offset: usize,
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<Token, LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.offset;
+            let rest = &self.src[start..];
+            let first_char = rest.chars().next()?;
+
+            let mut state = 0;
+            let mut longest_match = None;
+            for (i, c) in rest.char_indices() {
+                let Some(next_state) = get_lexer_transition(state, c) else {
+                    break;
+                };
+                state = next_state;
+                if let Some(rule_index) = LEXER_ACCEPTED_RULES[state] {
+                    longest_match = Some((rule_index, start + i + c.len_utf8()));
+                }
+            }
+
+            let Some((rule_index, end)) = longest_match else {
+                self.offset = start + first_char.len_utf8();
+                return Some(Err(LexError { span: start..self.offset }));
+            };
+            self.offset = end;
+
+            let token = match rule_index {
+                0 => continue,
+                1 => continue,
+                2 => Token::LetKw(()),
+                3 => match self.src[start..end].parse() {
+                    Ok(value) => Token::Ident(value),
+                    Err(_) => return Some(Err(LexError { span: start..end })),
+                },
+                4 => match self.src[start..end].parse() {
+                    Ok(value) => Token::Num(value),
+                    Err(_) => return Some(Err(LexError { span: start..end })),
+                },
+                5 => match self.src[start..end].parse() {
+                    Ok(value) => Token::Str(value),
+                    Err(_) => return Some(Err(LexError { span: start..end })),
+                },
+                6 => Token::Eq(()),
+                7 => Token::Plus(()),
+                8 => Token::Semicolon(()),
+                9 => Token::LParen(()),
+                10 => Token::RParen(()),
+                _ => unreachable!(),
+            };
+            return Some(Ok(token));
+        }
+    }
+}
+
+fn get_lexer_transition(state: usize, c: char) -> Option<usize> {
+    match (state, c) {
+        (0, '\t'..='\r') => Some(1),
+        (0, ' ') => Some(1),
+        (0, '"') => Some(2),
+        (0, '(') => Some(3),
+        (0, ')') => Some(4),
+        (0, '+') => Some(5),
+        (0, '/') => Some(6),
+        (0, '0'..='9') => Some(7),
+        (0, ';') => Some(8),
+        (0, '=') => Some(9),
+        (0, 'A'..='Z') => Some(10),
+        (0, '_') => Some(10),
+        (0, 'a'..='k') => Some(10),
+        (0, 'l') => Some(11),
+        (0, 'm'..='z') => Some(10),
+        (1, '\t'..='\r') => Some(12),
+        (1, ' ') => Some(12),
+        (2, '\0'..='!') => Some(13),
+        (2, '"') => Some(14),
+        (2, '#'..='[') => Some(13),
+        (2, '\\') => Some(15),
+        (2, ']'..='\u{10ffff}') => Some(13),
+        (6, '/') => Some(16),
+        (7, '0'..='9') => Some(17),
+        (10, '0'..='9') => Some(18),
+        (10, 'A'..='Z') => Some(18),
+        (10, '_') => Some(18),
+        (10, 'a'..='z') => Some(18),
+        (11, '0'..='9') => Some(18),
+        (11, 'A'..='Z') => Some(18),
+        (11, '_') => Some(18),
+        (11, 'a'..='d') => Some(18),
+        (11, 'e') => Some(19),
+        (11, 'f'..='z') => Some(18),
+        (12, '\t'..='\r') => Some(12),
+        (12, ' ') => Some(12),
+        (13, '\0'..='!') => Some(13),
+        (13, '"') => Some(14),
+        (13, '#'..='[') => Some(13),
+        (13, '\\') => Some(15),
+        (13, ']'..='\u{10ffff}') => Some(13),
+        (15, '\0'..='\t') => Some(20),
+        (15, '\u{b}'..='\u{10ffff}') => Some(20),
+        (16, '\0'..='\t') => Some(21),
+        (16, '\u{b}'..='\u{10ffff}') => Some(21),
+        (17, '0'..='9') => Some(17),
+        (18, '0'..='9') => Some(18),
+        (18, 'A'..='Z') => Some(18),
+        (18, '_') => Some(18),
+        (18, 'a'..='z') => Some(18),
+        (19, '0'..='9') => Some(18),
+        (19, 'A'..='Z') => Some(18),
+        (19, '_') => Some(18),
+        (19, 'a'..='s') => Some(18),
+        (19, 't') => Some(22),
+        (19, 'u'..='z') => Some(18),
+        (20, '\0'..='!') => Some(13),
+        (20, '"') => Some(14),
+        (20, '#'..='[') => Some(13),
+        (20, '\\') => Some(15),
+        (20, ']'..='\u{10ffff}') => Some(13),
+        (21, '\0'..='\t') => Some(21),
+        (21, '\u{b}'..='\u{10ffff}') => Some(21),
+        (22, '0'..='9') => Some(18),
+        (22, 'A'..='Z') => Some(18),
+        (22, '_') => Some(18),
+        (22, 'a'..='z') => Some(18),
+        _ => None,
+    }
+}
+
+static LEXER_ACCEPTED_RULES: [Option<usize>; 23] = [
+    None,
+    Some(0),
+    None,
+    Some(9),
+    Some(10),
+    Some(7),
+    None,
+    Some(4),
+    Some(8),
+    Some(6),
+    Some(3),
+    Some(3),
+    Some(0),
+    None,
+    Some(5),
+    None,
+    Some(1),
+    Some(4),
+    Some(3),
+    Some(3),
+    None,
+    Some(1),
+    Some(2),
+];
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    LetKw = 0,
+    Ident = 1,
+    Num = 2,
+    Str = 3,
+    Eq = 4,
+    Plus = 5,
+    Semicolon = 6,
+    LParen = 7,
+    RParen = 8,
+    /// The end of input.
+    Eof = 9,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    File = 0,
+    Statement = 1,
+    Expr = 2,
+    Term = 3,
+    StatementStar = 4,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+    S14 = 14,
+    S15 = 15,
+    S16 = 16,
+    S17 = 17,
+}
+
+enum Node {
+    File(File),
+    Statement(Statement),
+    Expr(Expr),
+    Term(Term),
+    StatementStar(Vec<Statement>),
+    LetKw(()),
+    Ident(String),
+    Num(u8),
+    Str(String),
+    Eq(()),
+    Plus(()),
+    Semicolon(()),
+    LParen(()),
+    RParen(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+    R5 = 5,
+    R6 = 6,
+    R7 = 7,
+    R8 = 8,
+    R9 = 9,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+        RuleKind::R9 => reduce_r9(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let statements_0 = nodes.pop().unwrap().try_into_statement_star().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::File(File {
+            statements: statements_0,
+        }),
+        NonterminalKind::File,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let value_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let name_1 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::Statement(Statement {
+            name: name_1,
+            value: value_3,
+        }),
+        NonterminalKind::Statement,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Box::new(Term::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Term(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Term::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Add(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_num_2().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Term(Term::Num(
+            t0,
+        )),
+        NonterminalKind::Term,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_1().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Term(Term::Var(
+            t0,
+        )),
+        NonterminalKind::Term,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_str_3().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Term(Term::Str(
+            t0,
+        )),
+        NonterminalKind::Term,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Term(Term::Paren(
+            t1,
+        )),
+        NonterminalKind::Term,
+    )
+}
+
+fn reduce_r8(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::StatementStar(vec![]),
+        NonterminalKind::StatementStar,
+    )
+}
+
+fn reduce_r9(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Statement::try_from(nodes.pop().unwrap()).ok().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_statement_star().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    t0.push(t1);
+
+    (
+        Node::StatementStar(t0),
+        NonterminalKind::StatementStar,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::LetKw(_) => Self::LetKw,
+            Token::Ident(_) => Self::Ident,
+            Token::Num(_) => Self::Num,
+            Token::Str(_) => Self::Str,
+            Token::Eq(_) => Self::Eq,
+            Token::Plus(_) => Self::Plus,
+            Token::Semicolon(_) => Self::Semicolon,
+            Token::LParen(_) => Self::LParen,
+            Token::RParen(_) => Self::RParen,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::LetKw => "$LetKw",
+            Self::Ident => "$Ident",
+            Self::Num => "$Num",
+            Self::Str => "$Str",
+            Self::Eq => "$Eq",
+            Self::Plus => "$Plus",
+            Self::Semicolon => "$Semicolon",
+            Self::LParen => "$LParen",
+            Self::RParen => "$RParen",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::LetKw(t) => Self::LetKw(t),
+            Token::Ident(t) => Self::Ident(t),
+            Token::Num(t) => Self::Num(t),
+            Token::Str(t) => Self::Str(t),
+            Token::Eq(t) => Self::Eq(t),
+            Token::Plus(t) => Self::Plus(t),
+            Token::Semicolon(t) => Self::Semicolon(t),
+            Token::LParen(t) => Self::LParen(t),
+            Token::RParen(t) => Self::RParen(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 10]; 18] = [
+    [
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+    ],
+    [
+        Action::Shift(State::S2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S3),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S4),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S13),
+        Action::Shift(State::S12),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S10),
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S13),
+        Action::Shift(State::S12),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S10),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S13),
+        Action::Shift(State::S12),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 10] = [
+    QuasiterminalKind::LetKw,
+    QuasiterminalKind::Ident,
+    QuasiterminalKind::Num,
+    QuasiterminalKind::Str,
+    QuasiterminalKind::Eq,
+    QuasiterminalKind::Plus,
+    QuasiterminalKind::Semicolon,
+    QuasiterminalKind::LParen,
+    QuasiterminalKind::RParen,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 5]; 18] = [
+    [
+        Some(State::S17),
+        None,
+        None,
+        None,
+        Some(State::S1),
+    ],
+    [
+        None,
+        Some(State::S16),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S5),
+        Some(State::S8),
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S9),
+        Some(State::S8),
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        Some(State::S11),
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for File {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::File(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Statement {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Statement(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Expr {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Expr(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Term {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Term(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_let_kw_0(self) -> Result<(), Self> {
+        match self {
+            Self::LetKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_ident_1(self) -> Result<String, Self> {
+        match self {
+            Self::Ident(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_num_2(self) -> Result<u8, Self> {
+        match self {
+            Self::Num(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_str_3(self) -> Result<String, Self> {
+        match self {
+            Self::Str(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_eq_4(self) -> Result<(), Self> {
+        match self {
+            Self::Eq(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_plus_5(self) -> Result<(), Self> {
+        match self {
+            Self::Plus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_semicolon_6(self) -> Result<(), Self> {
+        match self {
+            Self::Semicolon(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_l_paren_7(self) -> Result<(), Self> {
+        match self {
+            Self::LParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_paren_8(self) -> Result<(), Self> {
+        match self {
+            Self::RParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_statement_star(self) -> Result<Vec<Statement>, Self> {
+        match self {
+            Self::StatementStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
@@ -0,0 +1,521 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 fd001eb9c036cc40a6eba05f734cecf97d3a70472bab00bdffaff6302a399a5e
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+#![allow(clippy::ptr_arg)]
+#![allow(clippy::clone_on_copy)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    Ident(String),
+    LParen(()),
+    RParen(()),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+    pub callee: String,
+    pub args: Vec<String>,
+    pub span: std::ops::Range<usize>,
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<Call, Option<(Token, std::ops::Range<usize>)>>
+where S: IntoIterator<Item = (Token, std::ops::Range<usize>)> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<Call, ParseError>
+where S: IntoIterator<Item = (Token, std::ops::Range<usize>)> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S0];
+    let mut nodes: Vec<Node> = vec![];
+    let mut spans: Vec<std::ops::Range<usize>> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                let (terminal, span) = quasiterminals.next().unwrap().try_into_terminal().unwrap();
+                nodes.push(Node::from_terminal(terminal));
+                spans.push(span);
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let next_span = if spans.is_empty() {
+                    quasiterminals.peek().unwrap().span()
+                } else {
+                    None
+                };
+                let (new_node, new_node_kind, new_span) = pop_and_reduce(&mut states, &mut nodes, &mut spans, next_span, rule_kind);
+                nodes.push(new_node);
+                spans.push(new_span);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(Call::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// `span` is the byte range of the text that could not be lexed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LexError {
+    pub span: std::ops::Range<usize>,
+}
+
+/// Splits `src` into tokens using the grammar's `lexer` rules.
+/// At each position, the lexer uses the rule that matches
+/// the longest prefix of the remaining input.
+/// If several rules match that prefix, the rule declared first wins.
+/// Text matched by a skip rule (i.e., `_ = ...`) is discarded.
+///
+/// If no rule matches, the lexer yields a `LexError`
+/// covering the next character, and resumes after it.
+/// If a token's text cannot be parsed into the terminal's type,
+/// the lexer yields a `LexError` covering the text, and resumes after it.
+pub fn lex(src: &str) -> impl Iterator<Item = Result<(Token, std::ops::Range<usize>), LexError>> + '_ {
+    Lexer { src, offset: 0 }
+}
+
+struct Lexer<'a> {
+    src: &'a str,
+    offset: usize,
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<(Token, std::ops::Range<usize>), LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.offset;
+            let rest = &self.src[start..];
+            let first_char = rest.chars().next()?;
+
+            let mut state = 0;
+            let mut longest_match = None;
+            for (i, c) in rest.char_indices() {
+                let Some(next_state) = get_lexer_transition(state, c) else {
+                    break;
+                };
+                state = next_state;
+                if let Some(rule_index) = LEXER_ACCEPTED_RULES[state] {
+                    longest_match = Some((rule_index, start + i + c.len_utf8()));
+                }
+            }
+
+            let Some((rule_index, end)) = longest_match else {
+                self.offset = start + first_char.len_utf8();
+                return Some(Err(LexError { span: start..self.offset }));
+            };
+            self.offset = end;
+
+            let token = match rule_index {
+                0 => match self.src[start..end].parse() {
+                    Ok(value) => Token::Ident(value),
+                    Err(_) => return Some(Err(LexError { span: start..end })),
+                },
+                1 => Token::LParen(()),
+                2 => Token::RParen(()),
+                3 => continue,
+                _ => unreachable!(),
+            };
+            return Some(Ok((token, start..end)));
+        }
+    }
+}
+
+fn get_lexer_transition(state: usize, c: char) -> Option<usize> {
+    match (state, c) {
+        (0, ' ') => Some(1),
+        (0, '(') => Some(2),
+        (0, ')') => Some(3),
+        (0, 'a'..='z') => Some(4),
+        (4, 'a'..='z') => Some(5),
+        (5, 'a'..='z') => Some(5),
+        _ => None,
+    }
+}
+
+static LEXER_ACCEPTED_RULES: [Option<usize>; 6] = [
+    None,
+    Some(3),
+    Some(1),
+    Some(2),
+    Some(0),
+    Some(0),
+];
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<(Token, std::ops::Range<usize>)>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal((Token, std::ops::Range<usize>)),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    Ident = 0,
+    LParen = 1,
+    RParen = 2,
+    /// The end of input.
+    Eof = 3,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    Call = 0,
+    IdentStar = 1,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+}
+
+enum Node {
+    Call(Call),
+    IdentStar(Vec<String>),
+    Ident(String),
+    LParen(()),
+    RParen(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, next_span: Option<&std::ops::Range<usize>>, rule_kind: RuleKind) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes, spans, next_span),
+        RuleKind::R1 => reduce_r1(states, nodes, spans, next_span),
+        RuleKind::R2 => reduce_r2(states, nodes, spans, next_span),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, _next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    nodes.pop().unwrap();
+    let args_2 = nodes.pop().unwrap().try_into_ident_star().ok().unwrap();
+    nodes.pop().unwrap();
+    let callee_0 = nodes.pop().unwrap().try_into_ident_0().ok().unwrap();
+
+    states.truncate(states.len() - 4);
+    let span_end = spans.pop().unwrap().end;
+    spans.truncate(spans.len() - 2);
+    let span_start = spans.pop().unwrap().start;
+    let span = span_start..span_end;
+
+    (
+        Node::Call(Call {
+            callee: callee_0,
+            args: args_2,
+            span: span.clone(),
+        }),
+        NonterminalKind::Call,
+        span,
+    )
+}
+
+fn reduce_r1(_states: &mut Vec<State>, _nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    let span = get_empty_span(spans, next_span);
+
+    (
+        Node::IdentStar(vec![]),
+        NonterminalKind::IdentStar,
+        span,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, _next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    let t1 = nodes.pop().unwrap().try_into_ident_0().ok().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_ident_star().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+    let span_end = spans.pop().unwrap().end;
+    let span_start = spans.pop().unwrap().start;
+    let span = span_start..span_end;
+
+    t0.push(t1);
+
+    (
+        Node::IdentStar(t0),
+        NonterminalKind::IdentStar,
+        span,
+    )
+}
+
+fn get_empty_span(spans: &[std::ops::Range<usize>], next_span: Option<&std::ops::Range<usize>>) -> std::ops::Range<usize> {
+    let position = spans
+        .last()
+        .map(|span| span.end.clone())
+        .or_else(|| next_span.map(|span| span.start.clone()))
+        .unwrap_or_default();
+    position.clone()..position
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal((terminal, _)) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::Ident(_) => Self::Ident,
+            Token::LParen(_) => Self::LParen,
+            Token::RParen(_) => Self::RParen,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Ident => "$Ident",
+            Self::LParen => "$LParen",
+            Self::RParen => "$RParen",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::Ident(t) => Self::Ident(t),
+            Token::LParen(t) => Self::LParen(t),
+            Token::RParen(t) => Self::RParen(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<(Token, std::ops::Range<usize>), ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+
+    fn span(&self) -> Option<&std::ops::Range<usize>> {
+        match self {
+            Self::Terminal((_, span)) => Some(span),
+            Self::Eof => None,
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 4]; 7] = [
+    [
+        Action::Shift(State::S1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S2),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S5),
+        Action::Err,
+        Action::Shift(State::S4),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 4] = [
+    QuasiterminalKind::Ident,
+    QuasiterminalKind::LParen,
+    QuasiterminalKind::RParen,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 2]; 7] = [
+    [
+        Some(State::S6),
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S3),
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for Call {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Call(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_ident_0(self) -> Result<String, Self> {
+        match self {
+            Self::Ident(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_l_paren_1(self) -> Result<(), Self> {
+        match self {
+            Self::LParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_paren_2(self) -> Result<(), Self> {
+        match self {
+            Self::RParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_ident_star(self) -> Result<Vec<String>, Self> {
+        match self {
+            Self::IdentStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
@@ -0,0 +1,1723 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 414cf4883814f444eb4491f8bbd99abe1b41efa76945d1468ef4694c1de63646
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    Ident(String),
+    Num(i32),
+    UnaryMinus(()),
+    /// The literal `"+"`.
+    Plus(()),
+    /// The literal `"-"`.
+    Minus(()),
+    /// The literal `"*"`.
+    Star(()),
+    /// The literal `"let"`.
+    LetKw(()),
+    /// The literal `"="`.
+    Eq(()),
+    /// The literal `";"`.
+    Semicolon(()),
+    /// The literal `"print"`.
+    PrintKw(()),
+    /// The literal `"("`.
+    LParen(()),
+    /// The literal `","`.
+    Comma(()),
+    /// The literal `")"`.
+    RParen(()),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct File {
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Statement {
+    Let {
+        name: String,
+        value: Box<Expr>,
+    },
+    Print(
+        Vec<Expr>,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Num(
+        i32,
+    ),
+    Var(
+        String,
+    ),
+    Add(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Sub(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Mul(
+        Box<Expr>,
+        Box<Expr>,
+    ),
+    Neg(
+        Box<Expr>,
+    ),
+    Paren(
+        Box<Expr>,
+    ),
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<File, Option<Token>>
+where S: IntoIterator<Item = Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<File, ParseError>
+where S: IntoIterator<Item = Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S0];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(File::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// `span` is the byte range of the text that could not be lexed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LexError {
+    pub span: std::ops::Range<usize>,
+}
+
+/// Splits `src` into tokens using the grammar's `lexer` rules.
+/// At each position, the lexer uses the rule that matches
+/// the longest prefix of the remaining input.
+/// If several rules match that prefix, the rule declared first wins.
+/// Text matched by a skip rule (i.e., `_ = ...`) is discarded.
+///
+/// If no rule matches, the lexer yields a `LexError`
+/// covering the next character, and resumes after it.
+/// If a token's text cannot be parsed into the terminal's type,
+/// the lexer yields a `LexError` covering the text, and resumes after it.
+pub fn lex(src: &str) -> impl Iterator<Item = Result<Token, LexError>> + '_ {
+    Lexer { src, offset: 0 }
+}
+
+struct Lexer<'a> {
+    src: &'a str,
+    offset: usize,
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<Token, LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.offset;
+            let rest = &self.src[start..];
+            let first_char = rest.chars().next()?;
+
+            let mut state = 0;
+            let mut longest_match = None;
+            for (i, c) in rest.char_indices() {
+                let Some(next_state) = get_lexer_transition(state, c) else {
+                    break;
+                };
+                state = next_state;
+                if let Some(rule_index) = LEXER_ACCEPTED_RULES[state] {
+                    longest_match = Some((rule_index, start + i + c.len_utf8()));
+                }
+            }
+
+            let Some((rule_index, end)) = longest_match else {
+                self.offset = start + first_char.len_utf8();
+                return Some(Err(LexError { span: start..self.offset }));
+            };
+            self.offset = end;
+
+            let token = match rule_index {
+                0 => Token::Plus(()),
+                1 => Token::Minus(()),
+                2 => Token::Star(()),
+                3 => Token::LetKw(()),
+                4 => Token::Eq(()),
+                5 => Token::Semicolon(()),
+                6 => Token::PrintKw(()),
+                7 => Token::LParen(()),
+                8 => Token::Comma(()),
+                9 => Token::RParen(()),
+                10 => continue,
+                11 => match self.src[start..end].parse() {
+                    Ok(value) => Token::Ident(value),
+                    Err(_) => return Some(Err(LexError { span: start..end })),
+                },
+                12 => match self.src[start..end].parse() {
+                    Ok(value) => Token::Num(value),
+                    Err(_) => return Some(Err(LexError { span: start..end })),
+                },
+                _ => unreachable!(),
+            };
+            return Some(Ok(token));
+        }
+    }
+}
+
+fn get_lexer_transition(state: usize, c: char) -> Option<usize> {
+    match (state, c) {
+        (0, '\t'..='\r') => Some(1),
+        (0, ' ') => Some(1),
+        (0, '(') => Some(2),
+        (0, ')') => Some(3),
+        (0, '*') => Some(4),
+        (0, '+') => Some(5),
+        (0, ',') => Some(6),
+        (0, '-') => Some(7),
+        (0, '0'..='9') => Some(8),
+        (0, ';') => Some(9),
+        (0, '=') => Some(10),
+        (0, 'A'..='Z') => Some(11),
+        (0, '_') => Some(11),
+        (0, 'a'..='k') => Some(11),
+        (0, 'l') => Some(12),
+        (0, 'm'..='o') => Some(11),
+        (0, 'p') => Some(13),
+        (0, 'q'..='z') => Some(11),
+        (1, '\t'..='\r') => Some(14),
+        (1, ' ') => Some(14),
+        (8, '0'..='9') => Some(15),
+        (11, '0'..='9') => Some(16),
+        (11, 'A'..='Z') => Some(16),
+        (11, '_') => Some(16),
+        (11, 'a'..='z') => Some(16),
+        (12, '0'..='9') => Some(16),
+        (12, 'A'..='Z') => Some(16),
+        (12, '_') => Some(16),
+        (12, 'a'..='d') => Some(16),
+        (12, 'e') => Some(17),
+        (12, 'f'..='z') => Some(16),
+        (13, '0'..='9') => Some(16),
+        (13, 'A'..='Z') => Some(16),
+        (13, '_') => Some(16),
+        (13, 'a'..='q') => Some(16),
+        (13, 'r') => Some(18),
+        (13, 's'..='z') => Some(16),
+        (14, '\t'..='\r') => Some(14),
+        (14, ' ') => Some(14),
+        (15, '0'..='9') => Some(15),
+        (16, '0'..='9') => Some(16),
+        (16, 'A'..='Z') => Some(16),
+        (16, '_') => Some(16),
+        (16, 'a'..='z') => Some(16),
+        (17, '0'..='9') => Some(16),
+        (17, 'A'..='Z') => Some(16),
+        (17, '_') => Some(16),
+        (17, 'a'..='s') => Some(16),
+        (17, 't') => Some(19),
+        (17, 'u'..='z') => Some(16),
+        (18, '0'..='9') => Some(16),
+        (18, 'A'..='Z') => Some(16),
+        (18, '_') => Some(16),
+        (18, 'a'..='h') => Some(16),
+        (18, 'i') => Some(20),
+        (18, 'j'..='z') => Some(16),
+        (19, '0'..='9') => Some(16),
+        (19, 'A'..='Z') => Some(16),
+        (19, '_') => Some(16),
+        (19, 'a'..='z') => Some(16),
+        (20, '0'..='9') => Some(16),
+        (20, 'A'..='Z') => Some(16),
+        (20, '_') => Some(16),
+        (20, 'a'..='m') => Some(16),
+        (20, 'n') => Some(21),
+        (20, 'o'..='z') => Some(16),
+        (21, '0'..='9') => Some(16),
+        (21, 'A'..='Z') => Some(16),
+        (21, '_') => Some(16),
+        (21, 'a'..='s') => Some(16),
+        (21, 't') => Some(22),
+        (21, 'u'..='z') => Some(16),
+        (22, '0'..='9') => Some(16),
+        (22, 'A'..='Z') => Some(16),
+        (22, '_') => Some(16),
+        (22, 'a'..='z') => Some(16),
+        _ => None,
+    }
+}
+
+static LEXER_ACCEPTED_RULES: [Option<usize>; 23] = [
+    None,
+    Some(10),
+    Some(7),
+    Some(9),
+    Some(2),
+    Some(0),
+    Some(8),
+    Some(1),
+    Some(12),
+    Some(5),
+    Some(4),
+    Some(11),
+    Some(11),
+    Some(11),
+    Some(10),
+    Some(12),
+    Some(11),
+    Some(11),
+    Some(11),
+    Some(3),
+    Some(11),
+    Some(11),
+    Some(6),
+];
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    Ident = 0,
+    Num = 1,
+    UnaryMinus = 2,
+    /// The literal `"+"`.
+    Plus = 3,
+    /// The literal `"-"`.
+    Minus = 4,
+    /// The literal `"*"`.
+    Star = 5,
+    /// The literal `"let"`.
+    LetKw = 6,
+    /// The literal `"="`.
+    Eq = 7,
+    /// The literal `";"`.
+    Semicolon = 8,
+    /// The literal `"print"`.
+    PrintKw = 9,
+    /// The literal `"("`.
+    LParen = 10,
+    /// The literal `","`.
+    Comma = 11,
+    /// The literal `")"`.
+    RParen = 12,
+    /// The end of input.
+    Eof = 13,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    File = 0,
+    Statement = 1,
+    Expr = 2,
+    StatementStar = 3,
+    SepExprCommaPlus = 4,
+    SepExprCommaStar = 5,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+    S14 = 14,
+    S15 = 15,
+    S16 = 16,
+    S17 = 17,
+    S18 = 18,
+    S19 = 19,
+    S20 = 20,
+    S21 = 21,
+    S22 = 22,
+    S23 = 23,
+    S24 = 24,
+    S25 = 25,
+    S26 = 26,
+    S27 = 27,
+    S28 = 28,
+    S29 = 29,
+    S30 = 30,
+}
+
+enum Node {
+    File(File),
+    Statement(Statement),
+    Expr(Expr),
+    StatementStar(Vec<Statement>),
+    SepExprCommaPlus(Vec<Expr>),
+    SepExprCommaStar(Vec<Expr>),
+    Ident(String),
+    Num(i32),
+    UnaryMinus(()),
+    Plus(()),
+    Minus(()),
+    Star(()),
+    LetKw(()),
+    Eq(()),
+    Semicolon(()),
+    PrintKw(()),
+    LParen(()),
+    Comma(()),
+    RParen(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+    R5 = 5,
+    R6 = 6,
+    R7 = 7,
+    R8 = 8,
+    R9 = 9,
+    R10 = 10,
+    R11 = 11,
+    R12 = 12,
+    R13 = 13,
+    R14 = 14,
+    R15 = 15,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+        RuleKind::R9 => reduce_r9(states, nodes),
+        RuleKind::R10 => reduce_r10(states, nodes),
+        RuleKind::R11 => reduce_r11(states, nodes),
+        RuleKind::R12 => reduce_r12(states, nodes),
+        RuleKind::R13 => reduce_r13(states, nodes),
+        RuleKind::R14 => reduce_r14(states, nodes),
+        RuleKind::R15 => reduce_r15(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let statements_0 = nodes.pop().unwrap().try_into_statement_star().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::File(File {
+            statements: statements_0,
+        }),
+        NonterminalKind::File,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let value_3 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let name_1 = nodes.pop().unwrap().try_into_ident_0().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::Statement(Statement::Let {
+            name: name_1,
+            value: value_3,
+        }),
+        NonterminalKind::Statement,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    nodes.pop().unwrap();
+    let t2 = nodes.pop().unwrap().try_into_sep_expr_comma_star().ok().unwrap();
+    nodes.pop().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 5);
+
+    (
+        Node::Statement(Statement::Print(
+            t2,
+        )),
+        NonterminalKind::Statement,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_num_1().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Num(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_ident_0().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::Expr(Expr::Var(
+            t0,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Add(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Sub(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+    let t0 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Mul(
+            t0,
+            t2,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r8(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    (
+        Node::Expr(Expr::Neg(
+            t1,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r9(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = Box::new(Expr::try_from(nodes.pop().unwrap()).ok().unwrap());
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Expr(Expr::Paren(
+            t1,
+        )),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r10(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::StatementStar(vec![]),
+        NonterminalKind::StatementStar,
+    )
+}
+
+fn reduce_r11(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Statement::try_from(nodes.pop().unwrap()).ok().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_statement_star().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    t0.push(t1);
+
+    (
+        Node::StatementStar(t0),
+        NonterminalKind::StatementStar,
+    )
+}
+
+fn reduce_r12(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = Expr::try_from(nodes.pop().unwrap()).ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::SepExprCommaPlus(vec![t0]),
+        NonterminalKind::SepExprCommaPlus,
+    )
+}
+
+fn reduce_r13(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = Expr::try_from(nodes.pop().unwrap()).ok().unwrap();
+    nodes.pop().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_sep_expr_comma_plus().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    t0.push(t2);
+
+    (
+        Node::SepExprCommaPlus(t0),
+        NonterminalKind::SepExprCommaPlus,
+    )
+}
+
+fn reduce_r14(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_sep_expr_comma_plus().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::SepExprCommaStar(t0),
+        NonterminalKind::SepExprCommaStar,
+    )
+}
+
+fn reduce_r15(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::SepExprCommaStar(vec![]),
+        NonterminalKind::SepExprCommaStar,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::Ident(_) => Self::Ident,
+            Token::Num(_) => Self::Num,
+            Token::UnaryMinus(_) => Self::UnaryMinus,
+            Token::Plus(_) => Self::Plus,
+            Token::Minus(_) => Self::Minus,
+            Token::Star(_) => Self::Star,
+            Token::LetKw(_) => Self::LetKw,
+            Token::Eq(_) => Self::Eq,
+            Token::Semicolon(_) => Self::Semicolon,
+            Token::PrintKw(_) => Self::PrintKw,
+            Token::LParen(_) => Self::LParen,
+            Token::Comma(_) => Self::Comma,
+            Token::RParen(_) => Self::RParen,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Ident => "$Ident",
+            Self::Num => "$Num",
+            Self::UnaryMinus => "$UnaryMinus",
+            Self::Plus => "\"+\"",
+            Self::Minus => "\"-\"",
+            Self::Star => "\"*\"",
+            Self::LetKw => "\"let\"",
+            Self::Eq => "\"=\"",
+            Self::Semicolon => "\";\"",
+            Self::PrintKw => "\"print\"",
+            Self::LParen => "\"(\"",
+            Self::Comma => "\",\"",
+            Self::RParen => "\")\"",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::Ident(t) => Self::Ident(t),
+            Token::Num(t) => Self::Num(t),
+            Token::UnaryMinus(t) => Self::UnaryMinus(t),
+            Token::Plus(t) => Self::Plus(t),
+            Token::Minus(t) => Self::Minus(t),
+            Token::Star(t) => Self::Star(t),
+            Token::LetKw(t) => Self::LetKw(t),
+            Token::Eq(t) => Self::Eq(t),
+            Token::Semicolon(t) => Self::Semicolon(t),
+            Token::PrintKw(t) => Self::PrintKw(t),
+            Token::LParen(t) => Self::LParen(t),
+            Token::Comma(t) => Self::Comma(t),
+            Token::RParen(t) => Self::RParen(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 14]; 31] = [
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S2),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Shift(State::S3),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S4),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S19),
+        Action::Shift(State::S17),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S12),
+        Action::Shift(State::S13),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S19),
+        Action::Shift(State::S17),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Reduce(RuleKind::R15),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S10),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+    ],
+    [
+        Action::Shift(State::S19),
+        Action::Shift(State::S17),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S19),
+        Action::Shift(State::S17),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S19),
+        Action::Shift(State::S17),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S19),
+        Action::Shift(State::S17),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S19),
+        Action::Shift(State::S17),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+    ],
+    [
+        Action::Shift(State::S19),
+        Action::Shift(State::S17),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S15),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S12),
+        Action::Shift(State::S13),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Reduce(RuleKind::R12),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S12),
+        Action::Shift(State::S13),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R13),
+        Action::Reduce(RuleKind::R13),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S12),
+        Action::Shift(State::S13),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S27),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S16),
+        Action::Reduce(RuleKind::R14),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 14] = [
+    QuasiterminalKind::Ident,
+    QuasiterminalKind::Num,
+    QuasiterminalKind::UnaryMinus,
+    QuasiterminalKind::Plus,
+    QuasiterminalKind::Minus,
+    QuasiterminalKind::Star,
+    QuasiterminalKind::LetKw,
+    QuasiterminalKind::Eq,
+    QuasiterminalKind::Semicolon,
+    QuasiterminalKind::PrintKw,
+    QuasiterminalKind::LParen,
+    QuasiterminalKind::Comma,
+    QuasiterminalKind::RParen,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 6]; 31] = [
+    [
+        Some(State::S30),
+        None,
+        None,
+        Some(State::S1),
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S28),
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S5),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S24),
+        None,
+        Some(State::S29),
+        Some(State::S9),
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S20),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S21),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S22),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S23),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S25),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S26),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for File {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::File(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Statement {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Statement(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Expr {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Expr(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_ident_0(self) -> Result<String, Self> {
+        match self {
+            Self::Ident(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_num_1(self) -> Result<i32, Self> {
+        match self {
+            Self::Num(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_unary_minus_2(self) -> Result<(), Self> {
+        match self {
+            Self::UnaryMinus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_plus_3(self) -> Result<(), Self> {
+        match self {
+            Self::Plus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_minus_4(self) -> Result<(), Self> {
+        match self {
+            Self::Minus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_star_5(self) -> Result<(), Self> {
+        match self {
+            Self::Star(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_let_kw_6(self) -> Result<(), Self> {
+        match self {
+            Self::LetKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_eq_7(self) -> Result<(), Self> {
+        match self {
+            Self::Eq(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_semicolon_8(self) -> Result<(), Self> {
+        match self {
+            Self::Semicolon(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_print_kw_9(self) -> Result<(), Self> {
+        match self {
+            Self::PrintKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_l_paren_10(self) -> Result<(), Self> {
+        match self {
+            Self::LParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_comma_11(self) -> Result<(), Self> {
+        match self {
+            Self::Comma(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_paren_12(self) -> Result<(), Self> {
+        match self {
+            Self::RParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_statement_star(self) -> Result<Vec<Statement>, Self> {
+        match self {
+            Self::StatementStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_sep_expr_comma_plus(self) -> Result<Vec<Expr>, Self> {
+        match self {
+            Self::SepExprCommaPlus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_sep_expr_comma_star(self) -> Result<Vec<Expr>, Self> {
+        match self {
+            Self::SepExprCommaStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
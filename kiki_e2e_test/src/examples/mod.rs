@@ -1,7 +1,32 @@
+pub mod arithmetic;
 pub mod balanced_parens;
 pub mod balanced_parens_esoteric;
 pub mod balanced_parens_with_comments;
 pub mod balanced_parens_with_outer_attributes;
+pub mod canonical_lr1;
+pub mod compressed_tables;
+pub mod default_reductions;
+/// Every item in `doc_comments.kiki` is documented,
+/// so the generated API must have no undocumented public items.
+#[deny(missing_docs)]
+pub mod doc_comments;
+pub mod error_recovery;
+pub mod error_recovery_with_spans;
+pub mod extern_literal_terminals;
+pub mod extern_terminal_enum_call;
+pub mod extern_terminal_enum_sum;
+pub mod generics;
 pub mod json;
 pub mod json_with_outer_attributes;
+pub mod lazy_default_reductions;
+pub mod lexer;
+pub mod lexer_with_spans;
+pub mod literal_terminals;
+pub mod minimal_lr1;
+pub mod multiple_starts;
 pub mod nonempty_unitlike_fieldset;
+pub mod optional;
+pub mod repetition;
+pub mod semantic_actions;
+pub mod separated_list;
+pub mod spans;
@@ -0,0 +1,692 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 2bdcbb2560a5c924112c663e61b01eafb59f1316d2fc3ac51a15255f769a8d9e
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    PrintKw(()),
+    Num(i32),
+    Semicolon(()),
+    LCurly(()),
+    RCurly(()),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct File {
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Statement {
+    Print(
+        Vec<i32>,
+    ),
+    Block {
+        statements: Vec<Statement>,
+    },
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<File, Option<Token>>
+where S: IntoIterator<Item = Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<File, ParseError>
+where S: IntoIterator<Item = Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S0];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(File::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    PrintKw = 0,
+    Num = 1,
+    Semicolon = 2,
+    LCurly = 3,
+    RCurly = 4,
+    /// The end of input.
+    Eof = 5,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    File = 0,
+    Statement = 1,
+    StatementStar = 2,
+    NumPlus = 3,
+    SemicolonStar = 4,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+}
+
+enum Node {
+    File(File),
+    Statement(Statement),
+    StatementStar(Vec<Statement>),
+    NumPlus(Vec<i32>),
+    SemicolonStar(Vec<()>),
+    PrintKw(()),
+    Num(i32),
+    Semicolon(()),
+    LCurly(()),
+    RCurly(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+    R5 = 5,
+    R6 = 6,
+    R7 = 7,
+    R8 = 8,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let statements_0 = nodes.pop().unwrap().try_into_statement_star().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::File(File {
+            statements: statements_0,
+        }),
+        NonterminalKind::File,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let t1 = nodes.pop().unwrap().try_into_num_plus().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Statement(Statement::Print(
+            t1,
+        )),
+        NonterminalKind::Statement,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    nodes.pop().unwrap();
+    let statements_1 = nodes.pop().unwrap().try_into_statement_star().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 4);
+
+    (
+        Node::Statement(Statement::Block {
+            statements: statements_1,
+        }),
+        NonterminalKind::Statement,
+    )
+}
+
+fn reduce_r3(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::StatementStar(vec![]),
+        NonterminalKind::StatementStar,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = Statement::try_from(nodes.pop().unwrap()).ok().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_statement_star().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    t0.push(t1);
+
+    (
+        Node::StatementStar(t0),
+        NonterminalKind::StatementStar,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_num_1().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::NumPlus(vec![t0]),
+        NonterminalKind::NumPlus,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = nodes.pop().unwrap().try_into_num_1().ok().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_num_plus().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    t0.push(t1);
+
+    (
+        Node::NumPlus(t0),
+        NonterminalKind::NumPlus,
+    )
+}
+
+fn reduce_r7(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::SemicolonStar(vec![]),
+        NonterminalKind::SemicolonStar,
+    )
+}
+
+fn reduce_r8(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = nodes.pop().unwrap().try_into_semicolon_2().ok().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_semicolon_star().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    t0.push(t1);
+
+    (
+        Node::SemicolonStar(t0),
+        NonterminalKind::SemicolonStar,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::PrintKw(_) => Self::PrintKw,
+            Token::Num(_) => Self::Num,
+            Token::Semicolon(_) => Self::Semicolon,
+            Token::LCurly(_) => Self::LCurly,
+            Token::RCurly(_) => Self::RCurly,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::PrintKw => "$PrintKw",
+            Self::Num => "$Num",
+            Self::Semicolon => "$Semicolon",
+            Self::LCurly => "$LCurly",
+            Self::RCurly => "$RCurly",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::PrintKw(t) => Self::PrintKw(t),
+            Token::Num(t) => Self::Num(t),
+            Token::Semicolon(t) => Self::Semicolon(t),
+            Token::LCurly(t) => Self::LCurly(t),
+            Token::RCurly(t) => Self::RCurly(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 6]; 14] = [
+    [
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+    ],
+    [
+        Action::Shift(State::S3),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Shift(State::S3),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Shift(State::S7),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S11),
+        Action::Shift(State::S5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+    ],
+    [
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Shift(State::S12),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+    ],
+    [
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 6] = [
+    QuasiterminalKind::PrintKw,
+    QuasiterminalKind::Num,
+    QuasiterminalKind::Semicolon,
+    QuasiterminalKind::LCurly,
+    QuasiterminalKind::RCurly,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 5]; 14] = [
+    [
+        Some(State::S13),
+        None,
+        Some(State::S1),
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S9),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S9),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        Some(State::S4),
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S2),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        Some(State::S8),
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for File {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::File(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Statement {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Statement(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_print_kw_0(self) -> Result<(), Self> {
+        match self {
+            Self::PrintKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_num_1(self) -> Result<i32, Self> {
+        match self {
+            Self::Num(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_semicolon_2(self) -> Result<(), Self> {
+        match self {
+            Self::Semicolon(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_l_curly_3(self) -> Result<(), Self> {
+        match self {
+            Self::LCurly(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_curly_4(self) -> Result<(), Self> {
+        match self {
+            Self::RCurly(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_statement_star(self) -> Result<Vec<Statement>, Self> {
+        match self {
+            Self::StatementStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_num_plus(self) -> Result<Vec<i32>, Self> {
+        match self {
+            Self::NumPlus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_semicolon_star(self) -> Result<Vec<()>, Self> {
+        match self {
+            Self::SemicolonStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
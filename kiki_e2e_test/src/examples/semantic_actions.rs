@@ -0,0 +1,1220 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 874238816ab06e6e5491c54f194d6d24951535dcf07bec64f61f4bd8f88c4924
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    Ident(String),
+    Num(String),
+    Eq(()),
+    Comma(()),
+    Plus(()),
+    Minus(()),
+    Star(()),
+    LParen(()),
+    RParen(()),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Assignment {
+    pub name: String,
+    pub values: Vec<i64>,
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<Assignment, Option<Token>>
+where S: IntoIterator<Item = Token> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<Assignment, ParseError>
+where S: IntoIterator<Item = Token> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S0];
+    let mut nodes: Vec<Node> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                nodes.push(Node::from_terminal(quasiterminals.next().unwrap().try_into_terminal().unwrap()));
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let (new_node, new_node_kind) = pop_and_reduce(&mut states, &mut nodes, rule_kind);
+                nodes.push(new_node);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(Assignment::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<Token>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal(Token),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    Ident = 0,
+    Num = 1,
+    Eq = 2,
+    Comma = 3,
+    Plus = 4,
+    Minus = 5,
+    Star = 6,
+    LParen = 7,
+    RParen = 8,
+    /// The end of input.
+    Eof = 9,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    Assignment = 0,
+    Expr = 1,
+    Term = 2,
+    Factor = 3,
+    Parenthesized = 4,
+    Sign = 5,
+    SepExprCommaPlus = 6,
+    SepExprCommaStar = 7,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+    S14 = 14,
+    S15 = 15,
+    S16 = 16,
+    S17 = 17,
+    S18 = 18,
+    S19 = 19,
+    S20 = 20,
+    S21 = 21,
+    S22 = 22,
+    S23 = 23,
+}
+
+enum Node {
+    Assignment(Assignment),
+    Expr(i64),
+    Term(i64),
+    Factor(i64),
+    Parenthesized(i64),
+    Sign(i64),
+    SepExprCommaPlus(Vec<i64>),
+    SepExprCommaStar(Vec<i64>),
+    Ident(String),
+    Num(String),
+    Eq(()),
+    Comma(()),
+    Plus(()),
+    Minus(()),
+    Star(()),
+    LParen(()),
+    RParen(()),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+    R5 = 5,
+    R6 = 6,
+    R7 = 7,
+    R8 = 8,
+    R9 = 9,
+    R10 = 10,
+    R11 = 11,
+    R12 = 12,
+    R13 = 13,
+    R14 = 14,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, rule_kind: RuleKind) -> (Node, NonterminalKind) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes),
+        RuleKind::R1 => reduce_r1(states, nodes),
+        RuleKind::R2 => reduce_r2(states, nodes),
+        RuleKind::R3 => reduce_r3(states, nodes),
+        RuleKind::R4 => reduce_r4(states, nodes),
+        RuleKind::R5 => reduce_r5(states, nodes),
+        RuleKind::R6 => reduce_r6(states, nodes),
+        RuleKind::R7 => reduce_r7(states, nodes),
+        RuleKind::R8 => reduce_r8(states, nodes),
+        RuleKind::R9 => reduce_r9(states, nodes),
+        RuleKind::R10 => reduce_r10(states, nodes),
+        RuleKind::R11 => reduce_r11(states, nodes),
+        RuleKind::R12 => reduce_r12(states, nodes),
+        RuleKind::R13 => reduce_r13(states, nodes),
+        RuleKind::R14 => reduce_r14(states, nodes),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let values_2 = nodes.pop().unwrap().try_into_sep_expr_comma_star().ok().unwrap();
+    nodes.pop().unwrap();
+    let name_0 = nodes.pop().unwrap().try_into_ident_0().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    (
+        Node::Assignment(Assignment {
+            name: name_0,
+            values: values_2,
+        }),
+        NonterminalKind::Assignment,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let right_2 = nodes.pop().unwrap().try_into_term().ok().unwrap();
+    nodes.pop().unwrap();
+    let left_0 = nodes.pop().unwrap().try_into_expr().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    let value: i64 = {
+        let left = left_0;
+        let right = right_2;
+        left + right
+    };
+
+    (
+        Node::Expr(value),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let right_2 = nodes.pop().unwrap().try_into_term().ok().unwrap();
+    nodes.pop().unwrap();
+    let left_0 = nodes.pop().unwrap().try_into_expr().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    let value: i64 = {
+        let left = left_0;
+        let right = right_2;
+        left - right
+    };
+
+    (
+        Node::Expr(value),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_term().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    let value: i64 = {
+        let _0 = t0;
+        _0
+    };
+
+    (
+        Node::Expr(value),
+        NonterminalKind::Expr,
+    )
+}
+
+fn reduce_r4(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = nodes.pop().unwrap().try_into_factor().ok().unwrap();
+    nodes.pop().unwrap();
+    let t0 = nodes.pop().unwrap().try_into_term().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    let value: i64 = {
+        let _0 = t0;
+        let _1 = t2;
+        _0 * _1
+    };
+
+    (
+        Node::Term(value),
+        NonterminalKind::Term,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_factor().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    let value: i64 = {
+        let _0 = t0;
+        _0
+    };
+
+    (
+        Node::Term(value),
+        NonterminalKind::Term,
+    )
+}
+
+fn reduce_r6(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t1 = nodes.pop().unwrap().try_into_num_1().ok().unwrap();
+    let t0 = nodes.pop().unwrap().try_into_sign().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+
+    let value: i64 = {
+        let _0 = t0;
+        let _1 = t1;
+        // Braces in comments (}), strings ("}"), and chars ('}') are ignored.
+        let magnitude: i64 = _1.parse().unwrap();
+        _0 * magnitude
+    };
+
+    (
+        Node::Factor(value),
+        NonterminalKind::Factor,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_parenthesized().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    let value: i64 = {
+        let _0 = t0;
+        _0
+    };
+
+    (
+        Node::Factor(value),
+        NonterminalKind::Factor,
+    )
+}
+
+fn reduce_r8(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+    let inner_1 = nodes.pop().unwrap().try_into_expr().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    let value: i64 = {
+        let inner = inner_1;
+        inner
+    };
+
+    (
+        Node::Parenthesized(value),
+        NonterminalKind::Parenthesized,
+    )
+}
+
+fn reduce_r9(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let value: i64 = {
+        1
+    };
+
+    (
+        Node::Sign(value),
+        NonterminalKind::Sign,
+    )
+}
+
+fn reduce_r10(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    let value: i64 = {
+        -1
+    };
+
+    (
+        Node::Sign(value),
+        NonterminalKind::Sign,
+    )
+}
+
+fn reduce_r11(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_expr().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::SepExprCommaPlus(vec![t0]),
+        NonterminalKind::SepExprCommaPlus,
+    )
+}
+
+fn reduce_r12(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t2 = nodes.pop().unwrap().try_into_expr().ok().unwrap();
+    nodes.pop().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_sep_expr_comma_plus().ok().unwrap();
+
+    states.truncate(states.len() - 3);
+
+    t0.push(t2);
+
+    (
+        Node::SepExprCommaPlus(t0),
+        NonterminalKind::SepExprCommaPlus,
+    )
+}
+
+fn reduce_r13(states: &mut Vec<State>, nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    let t0 = nodes.pop().unwrap().try_into_sep_expr_comma_plus().ok().unwrap();
+
+    states.truncate(states.len() - 1);
+
+    (
+        Node::SepExprCommaStar(t0),
+        NonterminalKind::SepExprCommaStar,
+    )
+}
+
+fn reduce_r14(_states: &mut Vec<State>, _nodes: &mut Vec<Node>) -> (Node, NonterminalKind) {
+    (
+        Node::SepExprCommaStar(vec![]),
+        NonterminalKind::SepExprCommaStar,
+    )
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal(terminal) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::Ident(_) => Self::Ident,
+            Token::Num(_) => Self::Num,
+            Token::Eq(_) => Self::Eq,
+            Token::Comma(_) => Self::Comma,
+            Token::Plus(_) => Self::Plus,
+            Token::Minus(_) => Self::Minus,
+            Token::Star(_) => Self::Star,
+            Token::LParen(_) => Self::LParen,
+            Token::RParen(_) => Self::RParen,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Ident => "$Ident",
+            Self::Num => "$Num",
+            Self::Eq => "$Eq",
+            Self::Comma => "$Comma",
+            Self::Plus => "$Plus",
+            Self::Minus => "$Minus",
+            Self::Star => "$Star",
+            Self::LParen => "$LParen",
+            Self::RParen => "$RParen",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::Ident(t) => Self::Ident(t),
+            Token::Num(t) => Self::Num(t),
+            Token::Eq(t) => Self::Eq(t),
+            Token::Comma(t) => Self::Comma(t),
+            Token::Plus(t) => Self::Plus(t),
+            Token::Minus(t) => Self::Minus(t),
+            Token::Star(t) => Self::Star(t),
+            Token::LParen(t) => Self::LParen(t),
+            Token::RParen(t) => Self::RParen(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<Token, ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 10]; 24] = [
+    [
+        Action::Shift(State::S1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S2),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S21),
+        Action::Err,
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Reduce(RuleKind::R14),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S21),
+        Action::Err,
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+        Action::Shift(State::S7),
+        Action::Shift(State::S11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R11),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+        Action::Shift(State::S7),
+        Action::Shift(State::S11),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R12),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S21),
+        Action::Err,
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S21),
+        Action::Err,
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S7),
+        Action::Shift(State::S11),
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S20),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S21),
+        Action::Err,
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Shift(State::S14),
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R9),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S21),
+        Action::Err,
+        Action::Shift(State::S9),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Reduce(RuleKind::R4),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Reduce(RuleKind::R5),
+    ],
+    [
+        Action::Err,
+        Action::Shift(State::S18),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+        Action::Err,
+        Action::Reduce(RuleKind::R8),
+        Action::Reduce(RuleKind::R8),
+    ],
+    [
+        Action::Err,
+        Action::Reduce(RuleKind::R10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S4),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R13),
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 10] = [
+    QuasiterminalKind::Ident,
+    QuasiterminalKind::Num,
+    QuasiterminalKind::Eq,
+    QuasiterminalKind::Comma,
+    QuasiterminalKind::Plus,
+    QuasiterminalKind::Minus,
+    QuasiterminalKind::Star,
+    QuasiterminalKind::LParen,
+    QuasiterminalKind::RParen,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 8]; 24] = [
+    [
+        Some(State::S23),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S5),
+        Some(State::S13),
+        Some(State::S16),
+        Some(State::S19),
+        Some(State::S17),
+        Some(State::S22),
+        Some(State::S3),
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S6),
+        Some(State::S13),
+        Some(State::S16),
+        Some(State::S19),
+        Some(State::S17),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S8),
+        Some(State::S16),
+        Some(State::S19),
+        Some(State::S17),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        Some(State::S10),
+        Some(State::S13),
+        Some(State::S16),
+        Some(State::S19),
+        Some(State::S17),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S12),
+        Some(State::S16),
+        Some(State::S19),
+        Some(State::S17),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        Some(State::S15),
+        Some(State::S19),
+        Some(State::S17),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for Assignment {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Assignment(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_ident_0(self) -> Result<String, Self> {
+        match self {
+            Self::Ident(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_num_1(self) -> Result<String, Self> {
+        match self {
+            Self::Num(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_eq_2(self) -> Result<(), Self> {
+        match self {
+            Self::Eq(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_comma_3(self) -> Result<(), Self> {
+        match self {
+            Self::Comma(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_plus_4(self) -> Result<(), Self> {
+        match self {
+            Self::Plus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_minus_5(self) -> Result<(), Self> {
+        match self {
+            Self::Minus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_star_6(self) -> Result<(), Self> {
+        match self {
+            Self::Star(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_l_paren_7(self) -> Result<(), Self> {
+        match self {
+            Self::LParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_paren_8(self) -> Result<(), Self> {
+        match self {
+            Self::RParen(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_expr(self) -> Result<i64, Self> {
+        match self {
+            Self::Expr(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_term(self) -> Result<i64, Self> {
+        match self {
+            Self::Term(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_factor(self) -> Result<i64, Self> {
+        match self {
+            Self::Factor(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_parenthesized(self) -> Result<i64, Self> {
+        match self {
+            Self::Parenthesized(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_sign(self) -> Result<i64, Self> {
+        match self {
+            Self::Sign(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_sep_expr_comma_plus(self) -> Result<Vec<i64>, Self> {
+        match self {
+            Self::SepExprCommaPlus(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_sep_expr_comma_star(self) -> Result<Vec<i64>, Self> {
+        match self {
+            Self::SepExprCommaStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
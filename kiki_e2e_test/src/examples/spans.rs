@@ -0,0 +1,763 @@
+// This code was generated by Kiki.
+// Kiki is an open-source minimalist parser generator for Rust.
+// You can read more at https://crates.io/crates/kiki
+//
+// This code was generated from a grammar with the following hash:
+// @sha256 92f6a3fde8a4e801cf183695b76116ce6a693107a7c6bd7a987dfe5aedd74e33
+
+// Since this code is automatically generated,
+// some parts may be unidiomatic.
+// The linter often complains about these parts.
+// However, these warnings are not useful.
+// Therefore, we disable certain lints for this file.
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+#![allow(clippy::let_unit_value)]
+#![allow(clippy::just_underscores_and_digits)]
+#![allow(clippy::let_and_return)]
+#![allow(clippy::ptr_arg)]
+#![allow(clippy::clone_on_copy)]
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    LCurly(()),
+    RCurly(()),
+    Semicolon(()),
+    Colon(()),
+    LetKw(()),
+    Ident(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Block {
+    pub stmts: Vec<Stmt>,
+    pub span: std::ops::Range<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Stmt {
+    Empty {
+        span: std::ops::Range<usize>,
+    },
+    Let {
+        name: String,
+        type_: Box<OptTypeAnnotation>,
+        span: std::ops::Range<usize>,
+    },
+    Nested(
+        Box<Block>,
+        std::ops::Range<usize>,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OptTypeAnnotation {
+    None {
+        span: std::ops::Range<usize>,
+    },
+    Some(
+        String,
+        std::ops::Range<usize>,
+    ),
+}
+
+/// If the parser encounters an unexpected token `t`, it will return `Err(Some(t))`.
+/// If the parser encounters an unexpected end of input, it will return `Err(None)`.
+/// For a more detailed error, use `parse_verbose`.
+pub fn parse<S>(src: S) -> Result<Block, Option<(Token, std::ops::Range<usize>)>>
+where S: IntoIterator<Item = (Token, std::ops::Range<usize>)> {
+    parse_verbose(src).map_err(|err| err.unexpected)
+}
+
+/// If the parser encounters an unexpected token or an unexpected end of input,
+/// it will return a `ParseError` describing where the error occurred
+/// and which tokens were expected.
+pub fn parse_verbose<S>(src: S) -> Result<Block, ParseError>
+where S: IntoIterator<Item = (Token, std::ops::Range<usize>)> {
+    let mut quasiterminals = src.into_iter()
+        .map(Quasiterminal::Terminal)
+        .chain(std::iter::once(Quasiterminal::Eof))
+        .peekable();
+    let mut states = vec![State::S3];
+    let mut nodes: Vec<Node> = vec![];
+    let mut spans: Vec<std::ops::Range<usize>> = vec![];
+    let mut next_token_index = 0;
+    loop {
+        let top_state = *states.last().unwrap();
+        let next_quasiterminal_kind = QuasiterminalKind::from_quasiterminal(quasiterminals.peek().unwrap());
+        match get_action(top_state, next_quasiterminal_kind) {
+            Action::Shift(new_state) => {
+                states.push(new_state);
+                let (terminal, span) = quasiterminals.next().unwrap().try_into_terminal().unwrap();
+                nodes.push(Node::from_terminal(terminal));
+                spans.push(span);
+                next_token_index += 1;
+            }
+
+            Action::Reduce(rule_kind) => {
+                let next_span = if spans.is_empty() {
+                    quasiterminals.peek().unwrap().span()
+                } else {
+                    None
+                };
+                let (new_node, new_node_kind, new_span) = pop_and_reduce(&mut states, &mut nodes, &mut spans, next_span, rule_kind);
+                nodes.push(new_node);
+                spans.push(new_span);
+                let temp_top_state = *states.last().unwrap();
+                let Some(new_state) = get_goto(temp_top_state, new_node_kind) else {
+                    return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+                };
+                states.push(new_state);
+            }
+
+            Action::Accept => {
+                return Ok(Block::try_from(nodes.pop().unwrap()).ok().unwrap());
+            }
+
+            Action::Err => {
+                return Err(ParseError::new(quasiterminals.next().unwrap(), next_token_index, top_state));
+            }
+        }
+    }
+}
+
+/// An error that the parser encountered.
+pub struct ParseError {
+    /// The unexpected token,
+    /// or `None` if the parser encountered an unexpected end of input.
+    pub unexpected: Option<(Token, std::ops::Range<usize>)>,
+    /// The zero-based index of `unexpected` in the input.
+    /// If the input ended unexpectedly, this is the number of tokens.
+    pub token_index: usize,
+    /// The kinds of tokens that the parser would have accepted
+    /// in place of `unexpected`.
+    pub expected: Vec<QuasiterminalKind>,
+}
+
+impl ParseError {
+    fn new(unexpected: Quasiterminal, token_index: usize, state: State) -> Self {
+        let expected = QUASITERMINAL_KINDS
+            .into_iter()
+            .filter(|&kind| !matches!(get_action(state, kind), Action::Err))
+            .collect();
+        Self {
+            unexpected: unexpected.try_into_terminal().ok(),
+            token_index,
+            expected,
+        }
+    }
+}
+
+enum Quasiterminal {
+    Terminal((Token, std::ops::Range<usize>)),
+    Eof,
+}
+
+/// The kind of a token, or the end of input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuasiterminalKind {
+    LCurly = 0,
+    RCurly = 1,
+    Semicolon = 2,
+    Colon = 3,
+    LetKw = 4,
+    Ident = 5,
+    /// The end of input.
+    Eof = 6,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NonterminalKind {
+    Block = 0,
+    Stmt = 1,
+    OptTypeAnnotation = 2,
+    StmtStar = 3,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+}
+
+enum Node {
+    Block(Block),
+    Stmt(Stmt),
+    OptTypeAnnotation(OptTypeAnnotation),
+    StmtStar(Vec<Stmt>),
+    LCurly(()),
+    RCurly(()),
+    Semicolon(()),
+    Colon(()),
+    LetKw(()),
+    Ident(String),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    Shift(State),
+    Reduce(RuleKind),
+    Accept,
+    Err,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleKind {
+    R0 = 0,
+    R1 = 1,
+    R2 = 2,
+    R3 = 3,
+    R4 = 4,
+    R5 = 5,
+    R6 = 6,
+    R7 = 7,
+}
+
+fn pop_and_reduce(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, next_span: Option<&std::ops::Range<usize>>, rule_kind: RuleKind) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    match rule_kind {
+        RuleKind::R0 => reduce_r0(states, nodes, spans, next_span),
+        RuleKind::R1 => reduce_r1(states, nodes, spans, next_span),
+        RuleKind::R2 => reduce_r2(states, nodes, spans, next_span),
+        RuleKind::R3 => reduce_r3(states, nodes, spans, next_span),
+        RuleKind::R4 => reduce_r4(states, nodes, spans, next_span),
+        RuleKind::R5 => reduce_r5(states, nodes, spans, next_span),
+        RuleKind::R6 => reduce_r6(states, nodes, spans, next_span),
+        RuleKind::R7 => reduce_r7(states, nodes, spans, next_span),
+    }
+}
+
+fn reduce_r0(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, _next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    nodes.pop().unwrap();
+    let stmts_1 = nodes.pop().unwrap().try_into_stmt_star().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 3);
+    let span_end = spans.pop().unwrap().end;
+    spans.truncate(spans.len() - 1);
+    let span_start = spans.pop().unwrap().start;
+    let span = span_start..span_end;
+
+    (
+        Node::Block(Block {
+            stmts: stmts_1,
+            span: span.clone(),
+        }),
+        NonterminalKind::Block,
+        span,
+    )
+}
+
+fn reduce_r1(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, _next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 1);
+    let span = spans.pop().unwrap();
+
+    (
+        Node::Stmt(Stmt::Empty { span: span.clone() }),
+        NonterminalKind::Stmt,
+        span,
+    )
+}
+
+fn reduce_r2(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, _next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    nodes.pop().unwrap();
+    let type__2 = Box::new(OptTypeAnnotation::try_from(nodes.pop().unwrap()).ok().unwrap());
+    let name_1 = nodes.pop().unwrap().try_into_ident_5().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 4);
+    let span_end = spans.pop().unwrap().end;
+    spans.truncate(spans.len() - 2);
+    let span_start = spans.pop().unwrap().start;
+    let span = span_start..span_end;
+
+    (
+        Node::Stmt(Stmt::Let {
+            name: name_1,
+            type_: type__2,
+            span: span.clone(),
+        }),
+        NonterminalKind::Stmt,
+        span,
+    )
+}
+
+fn reduce_r3(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, _next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    let t0 = Box::new(Block::try_from(nodes.pop().unwrap()).ok().unwrap());
+
+    states.truncate(states.len() - 1);
+    let span = spans.pop().unwrap();
+
+    (
+        Node::Stmt(Stmt::Nested(
+            t0,
+            span.clone(),
+        )),
+        NonterminalKind::Stmt,
+        span,
+    )
+}
+
+fn reduce_r4(_states: &mut Vec<State>, _nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    let span = get_empty_span(spans, next_span);
+
+    (
+        Node::OptTypeAnnotation(OptTypeAnnotation::None { span: span.clone() }),
+        NonterminalKind::OptTypeAnnotation,
+        span,
+    )
+}
+
+fn reduce_r5(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, _next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    let t1 = nodes.pop().unwrap().try_into_ident_5().ok().unwrap();
+    nodes.pop().unwrap();
+
+    states.truncate(states.len() - 2);
+    let span_end = spans.pop().unwrap().end;
+    let span_start = spans.pop().unwrap().start;
+    let span = span_start..span_end;
+
+    (
+        Node::OptTypeAnnotation(OptTypeAnnotation::Some(
+            t1,
+            span.clone(),
+        )),
+        NonterminalKind::OptTypeAnnotation,
+        span,
+    )
+}
+
+fn reduce_r6(_states: &mut Vec<State>, _nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    let span = get_empty_span(spans, next_span);
+
+    (
+        Node::StmtStar(vec![]),
+        NonterminalKind::StmtStar,
+        span,
+    )
+}
+
+fn reduce_r7(states: &mut Vec<State>, nodes: &mut Vec<Node>, spans: &mut Vec<std::ops::Range<usize>>, _next_span: Option<&std::ops::Range<usize>>) -> (Node, NonterminalKind, std::ops::Range<usize>) {
+    let t1 = Stmt::try_from(nodes.pop().unwrap()).ok().unwrap();
+    let mut t0 = nodes.pop().unwrap().try_into_stmt_star().ok().unwrap();
+
+    states.truncate(states.len() - 2);
+    let span_end = spans.pop().unwrap().end;
+    let span_start = spans.pop().unwrap().start;
+    let span = span_start..span_end;
+
+    t0.push(t1);
+
+    (
+        Node::StmtStar(t0),
+        NonterminalKind::StmtStar,
+        span,
+    )
+}
+
+fn get_empty_span(spans: &[std::ops::Range<usize>], next_span: Option<&std::ops::Range<usize>>) -> std::ops::Range<usize> {
+    let position = spans
+        .last()
+        .map(|span| span.end.clone())
+        .or_else(|| next_span.map(|span| span.start.clone()))
+        .unwrap_or_default();
+    position.clone()..position
+}
+
+impl QuasiterminalKind {
+    fn from_quasiterminal(quasiterminal: &Quasiterminal) -> Self {
+        match quasiterminal {
+            Quasiterminal::Terminal((terminal, _)) => Self::from_terminal(terminal),
+            Quasiterminal::Eof => Self::Eof,
+        }
+    }
+
+    fn from_terminal(terminal: &Token) -> Self {
+        match terminal {
+            Token::LCurly(_) => Self::LCurly,
+            Token::RCurly(_) => Self::RCurly,
+            Token::Semicolon(_) => Self::Semicolon,
+            Token::Colon(_) => Self::Colon,
+            Token::LetKw(_) => Self::LetKw,
+            Token::Ident(_) => Self::Ident,
+        }
+    }
+}
+
+/// Literal terminals are displayed as the literal (e.g., `"("`).
+/// Other terminals are displayed by name (e.g., `$Ident`).
+impl std::fmt::Display for QuasiterminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::LCurly => "$LCurly",
+            Self::RCurly => "$RCurly",
+            Self::Semicolon => "$Semicolon",
+            Self::Colon => "$Colon",
+            Self::LetKw => "$LetKw",
+            Self::Ident => "$Ident",
+            Self::Eof => "end of input",
+        })
+    }
+}
+
+impl Node {
+    fn from_terminal(terminal: Token) -> Self {
+        match terminal {
+            Token::LCurly(t) => Self::LCurly(t),
+            Token::RCurly(t) => Self::RCurly(t),
+            Token::Semicolon(t) => Self::Semicolon(t),
+            Token::Colon(t) => Self::Colon(t),
+            Token::LetKw(t) => Self::LetKw(t),
+            Token::Ident(t) => Self::Ident(t),
+        }
+    }
+}
+
+impl Quasiterminal {
+    fn try_into_terminal(self) -> Result<(Token, std::ops::Range<usize>), ()> {
+        match self {
+            Self::Terminal(terminal) => Ok(terminal),
+            Self::Eof => Err(()),
+        }
+    }
+
+    fn span(&self) -> Option<&std::ops::Range<usize>> {
+        match self {
+            Self::Terminal((_, span)) => Some(span),
+            Self::Eof => None,
+        }
+    }
+}
+
+static ACTION_TABLE: [[Action; 7]; 14] = [
+    [
+        Action::Shift(State::S1),
+        Action::Shift(State::S2),
+        Action::Shift(State::S4),
+        Action::Err,
+        Action::Shift(State::S5),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Reduce(RuleKind::R6),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+        Action::Err,
+        Action::Reduce(RuleKind::R0),
+    ],
+    [
+        Action::Shift(State::S1),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Reduce(RuleKind::R1),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S6),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R4),
+        Action::Shift(State::S10),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S8),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Reduce(RuleKind::R2),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Reduce(RuleKind::R3),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Shift(State::S11),
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Reduce(RuleKind::R5),
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Reduce(RuleKind::R7),
+        Action::Err,
+        Action::Err,
+    ],
+    [
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Err,
+        Action::Accept,
+    ],
+];
+
+fn get_action(top_state: State, next_quasiterminal_kind: QuasiterminalKind) -> Action {
+    ACTION_TABLE[top_state as usize][next_quasiterminal_kind as usize]
+}
+
+static QUASITERMINAL_KINDS: [QuasiterminalKind; 7] = [
+    QuasiterminalKind::LCurly,
+    QuasiterminalKind::RCurly,
+    QuasiterminalKind::Semicolon,
+    QuasiterminalKind::Colon,
+    QuasiterminalKind::LetKw,
+    QuasiterminalKind::Ident,
+    QuasiterminalKind::Eof,
+];
+
+static GOTO_TABLE: [[Option<State>; 4]; 14] = [
+    [
+        Some(State::S9),
+        Some(State::S12),
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        Some(State::S0),
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        Some(State::S13),
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        Some(State::S7),
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+    [
+        None,
+        None,
+        None,
+        None,
+    ],
+];
+
+fn get_goto(top_state: State, new_node_kind: NonterminalKind) -> Option<State> {
+    GOTO_TABLE[top_state as usize][new_node_kind as usize]
+}
+
+impl TryFrom<Node> for Block {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Block(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for Stmt {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::Stmt(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl TryFrom<Node> for OptTypeAnnotation {
+    type Error = Node;
+
+    fn try_from(node: Node) -> Result<Self, Node> {
+        match node {
+            Node::OptTypeAnnotation(n) => Ok(n),
+            _ => Err(node),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_l_curly_0(self) -> Result<(), Self> {
+        match self {
+            Self::LCurly(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_r_curly_1(self) -> Result<(), Self> {
+        match self {
+            Self::RCurly(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_semicolon_2(self) -> Result<(), Self> {
+        match self {
+            Self::Semicolon(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_colon_3(self) -> Result<(), Self> {
+        match self {
+            Self::Colon(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_let_kw_4(self) -> Result<(), Self> {
+        match self {
+            Self::LetKw(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+
+    fn try_into_ident_5(self) -> Result<String, Self> {
+        match self {
+            Self::Ident(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
+
+impl Node {
+    fn try_into_stmt_star(self) -> Result<Vec<Stmt>, Self> {
+        match self {
+            Self::StmtStar(t) => Ok(t),
+            _ => Err(self),
+        }
+    }
+}
@@ -1,5 +1,10 @@
+// `examples` is public so that `missing_docs`
+// can check the examples that deny it.
 #[cfg(test)]
-mod examples;
+pub mod examples;
 
 #[cfg(test)]
 mod tests;
+
+#[cfg(test)]
+mod shared_lexer;
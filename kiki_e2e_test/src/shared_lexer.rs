@@ -0,0 +1,68 @@
+//! A hand-written lexer shared by the grammars
+//! with extern terminal enums.
+
+use std::cell::Cell;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    Ident(String),
+    /// The second field is the byte index of the number in the source.
+    Num(i32, usize),
+    Lt,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    Comma,
+    LParen,
+    RParen,
+    /// `lex` never emits this token.
+    /// It only exists so that grammars can declare a terminal
+    /// for a `prec` override to borrow the precedence of.
+    #[allow(dead_code)]
+    UnaryMinus,
+}
+
+pub fn lex(src: &str) -> Vec<Token> {
+    src.split_whitespace()
+        .map(|word| {
+            let position = word.as_ptr() as usize - src.as_ptr() as usize;
+            match word {
+                "<" => Token::Lt,
+                "+" => Token::Plus,
+                "-" => Token::Minus,
+                "*" => Token::Star,
+                "/" => Token::Slash,
+                "^" => Token::Caret,
+                "," => Token::Comma,
+                "(" => Token::LParen,
+                ")" => Token::RParen,
+                _ => match word.parse() {
+                    Ok(n) => Token::Num(n, position),
+                    Err(_) => Token::Ident(word.to_owned()),
+                },
+            }
+        })
+        .collect()
+}
+
+thread_local! {
+    static TOKENS_READ: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Like `lex`, but counts the tokens as the parser reads them.
+/// Use `tokens_read` to get the count.
+pub fn lex_counted(src: &str) -> impl Iterator<Item = Token> {
+    TOKENS_READ.with(|count| count.set(0));
+    lex(src)
+        .into_iter()
+        .inspect(|_| TOKENS_READ.with(|count| count.set(count.get() + 1)))
+}
+
+/// Returns the number of tokens read so far
+/// from the iterator that `lex_counted` most recently returned
+/// on this thread.
+pub fn tokens_read() -> usize {
+    TOKENS_READ.with(Cell::get)
+}
@@ -0,0 +1,116 @@
+use crate::examples::arithmetic::{parse, Expr};
+use crate::shared_lexer::{lex, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn single_num() {
+    let actual = parse([Token::Num(1, 0)]).unwrap();
+    let expected = num(1);
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn addition_is_left_associative() {
+    let actual = parse(lex("1 + 2 + 3")).unwrap();
+    let expected = add(add(num(1), num(2)), num(3));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn subtraction_is_left_associative() {
+    let actual = parse(lex("1 - 2 - 3")).unwrap();
+    let expected = sub(sub(num(1), num(2)), num(3));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn multiplication_binds_tighter_than_addition() {
+    let actual = parse(lex("1 + 2 * 3")).unwrap();
+    let expected = add(num(1), mul(num(2), num(3)));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn multiplication_binds_tighter_than_addition_on_left() {
+    let actual = parse(lex("1 * 2 + 3")).unwrap();
+    let expected = add(mul(num(1), num(2)), num(3));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn multiplication_and_division_are_equal_and_left_associative() {
+    let actual = parse(lex("1 / 2 * 3")).unwrap();
+    let expected = mul(div(num(1), num(2)), num(3));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn exponentiation_is_right_associative() {
+    let actual = parse(lex("1 ^ 2 ^ 3")).unwrap();
+    let expected = pow(num(1), pow(num(2), num(3)));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn unary_minus_uses_prec_override() {
+    let actual = parse(lex("- 1 * 2")).unwrap();
+    let expected = mul(neg(num(1)), num(2));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn exponentiation_binds_tighter_than_unary_minus() {
+    let actual = parse(lex("- 1 ^ 2")).unwrap();
+    let expected = neg(pow(num(1), num(2)));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn parentheses_override_precedence() {
+    let actual = parse(lex("( 1 + 2 ) * 3")).unwrap();
+    let expected = mul(Expr::Paren(Box::new(add(num(1), num(2)))), num(3));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn less_than_binds_loosest() {
+    let actual = parse(lex("1 + 2 < 3")).unwrap();
+    let expected = Expr::Lt(Box::new(add(num(1), num(2))), Box::new(num(3)));
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn less_than_is_nonassociative() {
+    let actual = parse(lex("1 < 2 < 3")).unwrap_err();
+    let expected = Some(Token::Lt);
+    assert_eq!(expected, actual)
+}
+
+fn num(n: i32) -> Expr {
+    Expr::Num(n)
+}
+
+fn add(a: Expr, b: Expr) -> Expr {
+    Expr::Add(Box::new(a), Box::new(b))
+}
+
+fn sub(a: Expr, b: Expr) -> Expr {
+    Expr::Sub(Box::new(a), Box::new(b))
+}
+
+fn mul(a: Expr, b: Expr) -> Expr {
+    Expr::Mul(Box::new(a), Box::new(b))
+}
+
+fn div(a: Expr, b: Expr) -> Expr {
+    Expr::Div(Box::new(a), Box::new(b))
+}
+
+fn pow(a: Expr, b: Expr) -> Expr {
+    Expr::Pow(Box::new(a), Box::new(b))
+}
+
+fn neg(a: Expr) -> Expr {
+    Expr::Neg(Box::new(a))
+}
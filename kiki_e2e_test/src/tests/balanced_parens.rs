@@ -1,4 +1,4 @@
-use crate::examples::balanced_parens::{parse, Expr, Token};
+use crate::examples::balanced_parens::{parse, parse_verbose, Expr, QuasiterminalKind, Token};
 
 use pretty_assertions::assert_eq;
 
@@ -46,6 +46,41 @@ fn unexpected_rparen() {
     assert_eq!(expected, actual)
 }
 
+#[test]
+fn verbose_unexpected_eof() {
+    let err = parse_verbose([lparen()]).unwrap_err();
+    assert_eq!(None, err.unexpected);
+    assert_eq!(1, err.token_index);
+    assert_eq!(
+        vec![QuasiterminalKind::LParen, QuasiterminalKind::RParen],
+        err.expected
+    );
+}
+
+#[test]
+fn verbose_unexpected_lparen() {
+    let err = parse_verbose([lparen(), lparen(), rparen(), lparen()]).unwrap_err();
+    assert_eq!(Some(lparen()), err.unexpected);
+    assert_eq!(3, err.token_index);
+    // The parser detects the error before reducing the inner `()`,
+    // so it still expects any token that may follow an `Expr`.
+    assert_eq!(
+        vec![QuasiterminalKind::RParen, QuasiterminalKind::Eof],
+        err.expected
+    );
+}
+
+#[test]
+fn verbose_unexpected_rparen_expects_eof() {
+    let err = parse_verbose([rparen(), lparen()]).unwrap_err();
+    assert_eq!(Some(rparen()), err.unexpected);
+    assert_eq!(0, err.token_index);
+    assert_eq!(
+        vec![QuasiterminalKind::LParen, QuasiterminalKind::Eof],
+        err.expected
+    );
+}
+
 fn lparen() -> Token {
     Token::LParen(())
 }
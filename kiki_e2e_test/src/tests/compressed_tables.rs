@@ -0,0 +1,68 @@
+//! `compressed_tables.rs` is generated from `arithmetic.kiki`,
+//! but with `TableEncoding::Compressed`.
+//! So, the two parsers should behave identically.
+
+use crate::examples::{arithmetic as dense, compressed_tables as compressed};
+use crate::shared_lexer::lex;
+use crate::tests::test_utils::for_each_arithmetic_input;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn parses_expression() {
+    let actual = compressed::parse(lex("- 1 + 2 * ( 3 ^ 4 )")).unwrap();
+    let expected = compressed::Expr::Add(
+        Box::new(compressed::Expr::Neg(Box::new(compressed::Expr::Num(1)))),
+        Box::new(compressed::Expr::Mul(
+            Box::new(compressed::Expr::Num(2)),
+            Box::new(compressed::Expr::Paren(Box::new(compressed::Expr::Pow(
+                Box::new(compressed::Expr::Num(3)),
+                Box::new(compressed::Expr::Num(4)),
+            )))),
+        )),
+    );
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn reports_expected_tokens() {
+    let err = compressed::parse_verbose(lex("1 +")).unwrap_err();
+    assert_eq!(None, err.unexpected);
+    assert_eq!(2, err.token_index);
+    assert_eq!(
+        vec![
+            compressed::QuasiterminalKind::Num,
+            compressed::QuasiterminalKind::Minus,
+            compressed::QuasiterminalKind::LParen,
+        ],
+        err.expected
+    );
+}
+
+/// Compares the two parsers on every input
+/// of up to 4 words.
+#[test]
+fn behaves_like_dense_parser() {
+    for_each_arithmetic_input(4, |src| {
+        assert_eq!(
+            parse_to_debug_string!(dense, src),
+            parse_to_debug_string!(compressed, src),
+            "results differ for {src:?}"
+        );
+    });
+}
+
+/// Parses `src` with `$parser` and returns the `Debug` representation
+/// of the result, so it can be compared to the other parser's.
+macro_rules! parse_to_debug_string {
+    ($parser:ident, $src:expr) => {
+        match $parser::parse_verbose(lex($src)) {
+            Ok(expr) => format!("Ok({expr:?})"),
+            Err(err) => format!(
+                "Err({:?}, {}, {:?})",
+                err.unexpected, err.token_index, err.expected
+            ),
+        }
+    };
+}
+use parse_to_debug_string;
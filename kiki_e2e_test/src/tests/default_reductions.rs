@@ -0,0 +1,72 @@
+//! `default_reductions.rs` is generated from `arithmetic.kiki`,
+//! but with `DefaultReductions::SingleReductionStates`.
+//! So, the two parsers should accept the same inputs,
+//! and reject the others at the same token.
+
+use crate::examples::{arithmetic, default_reductions as defaulted};
+use crate::shared_lexer::{lex, Token};
+use crate::tests::test_utils::for_each_arithmetic_input;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn parses_expression() {
+    let actual = defaulted::parse(lex("- 1 + 2 * 3")).unwrap();
+    let expected = defaulted::Expr::Add(
+        Box::new(defaulted::Expr::Neg(Box::new(defaulted::Expr::Num(1)))),
+        Box::new(defaulted::Expr::Mul(
+            Box::new(defaulted::Expr::Num(2)),
+            Box::new(defaulted::Expr::Num(3)),
+        )),
+    );
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn less_than_is_still_nonassociative() {
+    let err = defaulted::parse_verbose(lex("1 < 2 < 3")).unwrap_err();
+    assert_eq!(Some(Token::Lt), err.unexpected);
+    assert_eq!(3, err.token_index);
+}
+
+/// Compares the two parsers on every input
+/// of up to 4 words.
+#[test]
+fn detects_errors_at_same_token_as_parser_without_default_reductions() {
+    for_each_arithmetic_input(4, |src| {
+        let expected = arithmetic::parse_verbose(lex(src));
+        let actual = defaulted::parse_verbose(lex(src));
+        match (expected, actual) {
+            (Ok(expected), Ok(actual)) => {
+                assert_eq!(format!("{expected:?}"), format!("{actual:?}"), "{src:?}")
+            }
+            (Err(expected), Err(actual)) => {
+                assert_eq!(
+                    format!("{:?}", expected.unexpected),
+                    format!("{:?}", actual.unexpected),
+                    "{src:?}"
+                );
+                assert_eq!(expected.token_index, actual.token_index, "{src:?}");
+
+                // The error may be detected after a few extra reductions,
+                // in a state that expects fewer tokens.
+                let expected_kinds: Vec<String> = expected
+                    .expected
+                    .iter()
+                    .map(|kind| format!("{kind:?}"))
+                    .collect();
+                for kind in &actual.expected {
+                    assert!(
+                        expected_kinds.contains(&format!("{kind:?}")),
+                        "{src:?} unexpectedly expects {kind:?}"
+                    );
+                }
+            }
+            (expected, actual) => panic!(
+                "{src:?}: expected ok: {}, actual ok: {}",
+                expected.is_ok(),
+                actual.is_ok()
+            ),
+        }
+    });
+}
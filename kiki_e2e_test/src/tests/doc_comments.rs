@@ -0,0 +1,42 @@
+use crate::examples::doc_comments::{parse, Expr, Statement, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn statement() {
+    let actual = parse(lex("let x = y + z ;")).unwrap();
+    let expected = Statement {
+        name: "x".to_owned(),
+        value: Box::new(add(var("y"), "z")),
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn missing_semicolon() {
+    let actual = parse(lex("let x = y"));
+    assert_eq!(Err(None), actual)
+}
+
+fn lex(src: &str) -> Vec<Token> {
+    src.split_whitespace()
+        .map(|word| match word {
+            "let" => Token::LetKw(()),
+            "=" => Token::Eq(()),
+            "+" => Token::Plus(()),
+            ";" => Token::Semicolon(()),
+            _ => Token::Ident(word.to_owned()),
+        })
+        .collect()
+}
+
+fn var(name: &str) -> Expr {
+    Expr::Var(name.to_owned())
+}
+
+fn add(left: Expr, right: &str) -> Expr {
+    Expr::Add {
+        left: Box::new(left),
+        right: right.to_owned(),
+    }
+}
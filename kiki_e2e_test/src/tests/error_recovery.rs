@@ -0,0 +1,95 @@
+use crate::examples::error_recovery::{
+    parse, parse_recovering, File, QuasiterminalKind, Statement, Token,
+};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn valid_input_has_no_errors() {
+    let (actual, errors) = parse_recovering(lex("let x = y ; let a = b ;"));
+    let expected = File {
+        statements: vec![let_("x", "y"), let_("a", "b")],
+    };
+    assert_eq!(Some(expected), actual);
+    assert_eq!(0, errors.len());
+}
+
+#[test]
+fn recovers_from_error_in_middle_statement() {
+    let (actual, errors) = parse_recovering(lex("let x = y ; let = z ; let a = b ;"));
+    let expected = File {
+        statements: vec![let_("x", "y"), Statement::Error, let_("a", "b")],
+    };
+    assert_eq!(Some(expected), actual);
+    assert_eq!(1, errors.len());
+    assert_eq!(Some(Token::Eq(())), errors[0].unexpected);
+    assert_eq!(6, errors[0].token_index);
+    assert_eq!(vec![QuasiterminalKind::Ident], errors[0].expected);
+}
+
+#[test]
+fn unexpected_token_can_follow_error() {
+    let (actual, errors) = parse_recovering(lex("let x ; let a = b ;"));
+    let expected = File {
+        statements: vec![Statement::Error, let_("a", "b")],
+    };
+    assert_eq!(Some(expected), actual);
+    assert_eq!(1, errors.len());
+    assert_eq!(Some(Token::Semicolon(())), errors[0].unexpected);
+    assert_eq!(2, errors[0].token_index);
+}
+
+#[test]
+fn collects_multiple_errors() {
+    let (actual, errors) = parse_recovering(lex("let x ; let y = z ; let = ;"));
+    let expected = File {
+        statements: vec![Statement::Error, let_("y", "z"), Statement::Error],
+    };
+    assert_eq!(Some(expected), actual);
+    let token_indices: Vec<usize> = errors.iter().map(|err| err.token_index).collect();
+    assert_eq!(vec![2, 9], token_indices);
+}
+
+#[test]
+fn errors_within_three_tokens_of_recovery_are_not_recorded() {
+    let (actual, errors) = parse_recovering(lex("let ; let ; let a = b ;"));
+    let expected = File {
+        statements: vec![Statement::Error, Statement::Error, let_("a", "b")],
+    };
+    assert_eq!(Some(expected), actual);
+    assert_eq!(1, errors.len());
+    assert_eq!(1, errors[0].token_index);
+}
+
+#[test]
+fn unrecoverable_eof() {
+    let (actual, errors) = parse_recovering(lex("let x = y"));
+    assert_eq!(None, actual);
+    assert_eq!(1, errors.len());
+    assert_eq!(None, errors[0].unexpected);
+    assert_eq!(4, errors[0].token_index);
+}
+
+#[test]
+fn non_recovering_parse_stops_at_first_error() {
+    let actual = parse(lex("let x = y ; let = z ;"));
+    assert_eq!(Err(Some(Token::Eq(()))), actual);
+}
+
+fn lex(src: &str) -> Vec<Token> {
+    src.split_whitespace()
+        .map(|word| match word {
+            "let" => Token::LetKw(()),
+            "=" => Token::Eq(()),
+            ";" => Token::Semicolon(()),
+            _ => Token::Ident(word.to_owned()),
+        })
+        .collect()
+}
+
+fn let_(name: &str, value: &str) -> Statement {
+    Statement::Let {
+        name: name.to_owned(),
+        value: value.to_owned(),
+    }
+}
@@ -0,0 +1,45 @@
+use crate::examples::error_recovery_with_spans::{
+    parse_block_recovering, parse_stmt_recovering, Block, Stmt, Token,
+};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn error_spans_replaced_nodes_and_skipped_tokens() {
+    let (actual, errors) = parse_block_recovering(lex("{ 1 ; 2 3 ; 4 ; }"));
+    let expected = Block {
+        stmts: vec![
+            Stmt::Expr(1, 2..5),
+            Stmt::Error { span: 6..11 },
+            Stmt::Expr(4, 12..15),
+        ],
+        span: 0..17,
+    };
+    assert_eq!(Some(expected), actual);
+    assert_eq!(1, errors.len());
+    assert_eq!(Some((Token::Num(3), 8..9)), errors[0].unexpected);
+}
+
+#[test]
+fn error_that_replaces_no_nodes_is_empty() {
+    let (actual, errors) = parse_stmt_recovering(lex("} ;"));
+    let expected = Stmt::Error { span: 0..3 };
+    assert_eq!(Some(expected), actual);
+    assert_eq!(1, errors.len());
+    assert_eq!(0, errors[0].token_index);
+}
+
+fn lex(src: &str) -> Vec<(Token, std::ops::Range<usize>)> {
+    src.split_whitespace()
+        .map(|word| {
+            let start = word.as_ptr() as usize - src.as_ptr() as usize;
+            let token = match word {
+                "{" => Token::LCurly(()),
+                "}" => Token::RCurly(()),
+                ";" => Token::Semicolon(()),
+                _ => Token::Num(word.parse().unwrap()),
+            };
+            (token, start..start + word.len())
+        })
+        .collect()
+}
@@ -0,0 +1,27 @@
+use crate::examples::extern_literal_terminals::{parse, parse_verbose, Call, QuasiterminalKind};
+use crate::shared_lexer::{lex, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn declared_literals_match_extern_variants() {
+    let actual = parse(lex("f ( 1 , 2 )")).unwrap();
+    let expected = Call {
+        callee: "f".to_owned(),
+        args: vec![1, 2],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn unexpected_literal_token() {
+    let actual = parse(lex("f ( 1 , )"));
+    assert_eq!(Err(Some(Token::RParen)), actual)
+}
+
+#[test]
+fn expected_literals_are_displayed_as_literals() {
+    let err = parse_verbose(lex("f 1")).unwrap_err();
+    assert_eq!(vec![QuasiterminalKind::LParen], err.expected);
+    assert_eq!("\"(\"", err.expected[0].to_string());
+}
@@ -0,0 +1,30 @@
+use crate::examples::extern_terminal_enum_call::{parse, Arg, Call};
+use crate::shared_lexer::{lex, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn no_args() {
+    let actual = parse(lex("f ( )")).unwrap();
+    let expected = Call {
+        callee: "f".to_owned(),
+        args: vec![],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn mixed_args() {
+    let actual = parse(lex("f ( 1 , x , 2 )")).unwrap();
+    let expected = Call {
+        callee: "f".to_owned(),
+        args: vec![Arg::Num(1), Arg::Ident("x".to_owned()), Arg::Num(2)],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn token_unmapped_by_this_grammar_is_unexpected() {
+    let actual = parse(lex("f ( 1 + 2 )"));
+    assert_eq!(Err(Some(Token::Plus)), actual)
+}
@@ -0,0 +1,36 @@
+use crate::examples::extern_terminal_enum_sum::{parse, Sum};
+use crate::shared_lexer::{lex, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn one() {
+    let actual = parse(lex("1")).unwrap();
+    let expected = Sum::One(1);
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn binding_ignores_other_fields() {
+    let actual = parse(lex("1 + 20 + 300")).unwrap();
+    let expected = Sum::Add(Box::new(Sum::Add(Box::new(Sum::One(1)), 20)), 300);
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn unexpected_mapped_token() {
+    let actual = parse(lex("1 + + 2"));
+    assert_eq!(Err(Some(Token::Plus)), actual)
+}
+
+#[test]
+fn unmapped_token_is_unexpected() {
+    let actual = parse(lex("1 + ( 2 )"));
+    assert_eq!(Err(Some(Token::LParen)), actual)
+}
+
+#[test]
+fn unexpected_eof() {
+    let actual = parse(lex("1 +"));
+    assert_eq!(Err(None), actual)
+}
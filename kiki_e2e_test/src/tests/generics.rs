@@ -0,0 +1,95 @@
+use crate::examples::generics::{
+    parse, CommaListIdent, CommaListValue, Entry, File, Import, PairIdentValue, Token, TupleValue,
+    Value,
+};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn empty() {
+    let actual = parse(lex("")).unwrap();
+    let expected = File {
+        imports: vec![],
+        entries: vec![],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn instantiation_with_terminal_arg() {
+    let actual = parse(lex("import a , b ;")).unwrap();
+    let expected = File {
+        imports: vec![Import {
+            names: Box::new(CommaListIdent::Cons(
+                Box::new(CommaListIdent::One("a".to_owned())),
+                "b".to_owned(),
+            )),
+        }],
+        entries: vec![],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn instantiation_with_multiple_args() {
+    let actual = parse(lex("x : 1 ;")).unwrap();
+    let expected = File {
+        imports: vec![],
+        entries: vec![entry("x", Value::Num(1))],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn nested_instantiation_with_nonterminal_arg() {
+    let actual = parse(lex("x : ( 1 , ( 2 ) ) ;")).unwrap();
+    let expected = File {
+        imports: vec![],
+        entries: vec![entry(
+            "x",
+            tuple(CommaListValue::Cons(
+                Box::new(CommaListValue::One(Box::new(Value::Num(1)))),
+                Box::new(tuple(CommaListValue::One(Box::new(Value::Num(2))))),
+            )),
+        )],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn instantiations_with_different_args_are_distinct() {
+    let actual = parse(lex("import a , 1 ;"));
+    assert_eq!(Err(Some(Token::Num(1))), actual)
+}
+
+fn entry(key: &str, value: Value) -> Entry {
+    Entry {
+        pair: Box::new(PairIdentValue {
+            key: key.to_owned(),
+            value: Box::new(value),
+        }),
+    }
+}
+
+fn tuple(items: CommaListValue) -> Value {
+    Value::Tuple(Box::new(TupleValue {
+        items: Box::new(items),
+    }))
+}
+
+fn lex(src: &str) -> Vec<Token> {
+    src.split_whitespace()
+        .map(|word| match word {
+            "import" => Token::ImportKw(()),
+            "," => Token::Comma(()),
+            ":" => Token::Colon(()),
+            ";" => Token::Semicolon(()),
+            "(" => Token::LParen(()),
+            ")" => Token::RParen(()),
+            _ => match word.parse() {
+                Ok(n) => Token::Num(n),
+                Err(_) => Token::Ident(word.to_owned()),
+            },
+        })
+        .collect()
+}
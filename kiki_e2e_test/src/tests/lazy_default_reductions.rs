@@ -0,0 +1,17 @@
+//! `lazy_default_reductions.rs` is generated with
+//! `DefaultReductions::SingleReductionStates`.
+//! Each `Item` records how many tokens the parser had read
+//! when it reduced the item,
+//! so this test checks that the parser applies default reductions
+//! without reading the next token.
+
+use crate::examples::lazy_default_reductions::parse;
+use crate::shared_lexer::lex_counted;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn reduces_without_reading_next_token() {
+    let actual = parse(lex_counted("1 , 2 , 3 ,")).unwrap();
+    assert_eq!(vec![2, 4, 6], actual)
+}
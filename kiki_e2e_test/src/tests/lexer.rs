@@ -0,0 +1,105 @@
+use crate::examples::lexer::{lex, parse, Expr, File, LexError, Statement, Term, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn tokens() {
+    let actual: Vec<_> = lex("let x = (y + 42);").collect();
+    let expected = vec![
+        Ok(Token::LetKw(())),
+        Ok(Token::Ident("x".to_owned())),
+        Ok(Token::Eq(())),
+        Ok(Token::LParen(())),
+        Ok(Token::Ident("y".to_owned())),
+        Ok(Token::Plus(())),
+        Ok(Token::Num(42)),
+        Ok(Token::RParen(())),
+        Ok(Token::Semicolon(())),
+    ];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn longest_match_beats_declaration_order() {
+    let actual: Vec<_> = lex("letter let").collect();
+    let expected = vec![Ok(Token::Ident("letter".to_owned())), Ok(Token::LetKw(()))];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn skip_rules() {
+    let actual: Vec<_> = lex("  // let x\n\t; // ;").collect();
+    assert_eq!(vec![Ok(Token::Semicolon(()))], actual)
+}
+
+#[test]
+fn string_with_escaped_quote() {
+    let actual: Vec<_> = lex(r#""a\"b" "c""#).collect();
+    let expected = vec![
+        Ok(Token::Str(r#""a\"b""#.to_owned())),
+        Ok(Token::Str(r#""c""#.to_owned())),
+    ];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn unmatched_character_is_skipped() {
+    let actual: Vec<_> = lex("x @é y").collect();
+    let expected = vec![
+        Ok(Token::Ident("x".to_owned())),
+        Err(LexError { span: 2..3 }),
+        Err(LexError { span: 3..5 }),
+        Ok(Token::Ident("y".to_owned())),
+    ];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn unterminated_string_falls_back_to_shorter_matches() {
+    let actual: Vec<_> = lex(r#""ab"#).collect();
+    let expected = vec![
+        Err(LexError { span: 0..1 }),
+        Ok(Token::Ident("ab".to_owned())),
+    ];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn unparsable_token_text() {
+    let actual: Vec<_> = lex("255 256 1").collect();
+    let expected = vec![
+        Ok(Token::Num(255)),
+        Err(LexError { span: 4..7 }),
+        Ok(Token::Num(1)),
+    ];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(None, lex("").next());
+    assert_eq!(None, lex(" \n ").next());
+}
+
+#[test]
+fn lex_then_parse() {
+    let src = "let a = 1 + b;\nlet c = \"s\";";
+    let tokens = lex(src).collect::<Result<Vec<_>, _>>().unwrap();
+    let actual = parse(tokens).unwrap();
+    let expected = File {
+        statements: vec![
+            Statement {
+                name: "a".to_owned(),
+                value: Box::new(Expr::Add(
+                    Box::new(Expr::Term(Box::new(Term::Num(1)))),
+                    Box::new(Term::Var("b".to_owned())),
+                )),
+            },
+            Statement {
+                name: "c".to_owned(),
+                value: Box::new(Expr::Term(Box::new(Term::Str("\"s\"".to_owned())))),
+            },
+        ],
+    };
+    assert_eq!(expected, actual)
+}
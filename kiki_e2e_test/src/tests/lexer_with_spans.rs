@@ -0,0 +1,39 @@
+use crate::examples::lexer_with_spans::{lex, parse, Call, LexError, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn tokens_have_byte_spans() {
+    let actual: Vec<_> = lex("f (ab)").collect();
+    let expected = vec![
+        Ok((Token::Ident("f".to_owned()), 0..1)),
+        Ok((Token::LParen(()), 2..3)),
+        Ok((Token::Ident("ab".to_owned()), 3..5)),
+        Ok((Token::RParen(()), 5..6)),
+    ];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn error_span() {
+    let actual: Vec<_> = lex("f(X)").collect();
+    let expected = vec![
+        Ok((Token::Ident("f".to_owned()), 0..1)),
+        Ok((Token::LParen(()), 1..2)),
+        Err(LexError { span: 2..3 }),
+        Ok((Token::RParen(()), 3..4)),
+    ];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn lex_then_parse() {
+    let tokens = lex("f(x y)").collect::<Result<Vec<_>, _>>().unwrap();
+    let actual = parse(tokens).unwrap();
+    let expected = Call {
+        callee: "f".to_owned(),
+        args: vec!["x".to_owned(), "y".to_owned()],
+        span: 0..6,
+    };
+    assert_eq!(expected, actual)
+}
@@ -0,0 +1,98 @@
+use crate::examples::literal_terminals::{
+    lex, parse, parse_verbose, Expr, File, QuasiterminalKind, Statement, Token,
+};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn literals_are_lexed_with_generated_names() {
+    let actual: Vec<_> = lex("let x = (1);").collect();
+    let expected = vec![
+        Ok(Token::LetKw(())),
+        Ok(Token::Ident("x".to_owned())),
+        Ok(Token::Eq(())),
+        Ok(Token::LParen(())),
+        Ok(Token::Num(1)),
+        Ok(Token::RParen(())),
+        Ok(Token::Semicolon(())),
+    ];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn literal_keywords_beat_user_patterns_of_the_same_length() {
+    let actual: Vec<_> = lex("let letter print printer").collect();
+    let expected = vec![
+        Ok(Token::LetKw(())),
+        Ok(Token::Ident("letter".to_owned())),
+        Ok(Token::PrintKw(())),
+        Ok(Token::Ident("printer".to_owned())),
+    ];
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn statements() {
+    let actual = parse_src("let x = 1 + 2 * 3; print(x, -x);");
+    let expected = File {
+        statements: vec![
+            Statement::Let {
+                name: "x".to_owned(),
+                value: Box::new(add(num(1), mul(num(2), num(3)))),
+            },
+            Statement::Print(vec![var("x"), Expr::Neg(Box::new(var("x")))]),
+        ],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn literal_precedence() {
+    let actual = parse_src("print(1 - 2 - 3 * 4);");
+    let expected = File {
+        statements: vec![Statement::Print(vec![sub(
+            sub(num(1), num(2)),
+            mul(num(3), num(4)),
+        )])],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn literals_in_parse_errors_are_displayed_as_literals() {
+    let tokens: Vec<Token> = lex("let x 1;").map(Result::unwrap).collect();
+    let err = parse_verbose(tokens).unwrap_err();
+    assert_eq!(vec![QuasiterminalKind::Eq], err.expected);
+    assert_eq!("\"=\"", err.expected[0].to_string());
+}
+
+#[test]
+fn named_terminals_and_eof_are_displayed_by_name() {
+    assert_eq!("$Ident", QuasiterminalKind::Ident.to_string());
+    assert_eq!("end of input", QuasiterminalKind::Eof.to_string());
+}
+
+fn parse_src(src: &str) -> File {
+    let tokens: Vec<Token> = lex(src).map(Result::unwrap).collect();
+    parse(tokens).unwrap()
+}
+
+fn num(n: i32) -> Expr {
+    Expr::Num(n)
+}
+
+fn var(name: &str) -> Expr {
+    Expr::Var(name.to_owned())
+}
+
+fn add(left: Expr, right: Expr) -> Expr {
+    Expr::Add(Box::new(left), Box::new(right))
+}
+
+fn sub(left: Expr, right: Expr) -> Expr {
+    Expr::Sub(Box::new(left), Box::new(right))
+}
+
+fn mul(left: Expr, right: Expr) -> Expr {
+    Expr::Mul(Box::new(left), Box::new(right))
+}
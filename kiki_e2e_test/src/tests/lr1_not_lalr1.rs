@@ -0,0 +1,70 @@
+//! `canonical_lr1.rs` and `minimal_lr1.rs` are both generated
+//! from `canonical_lr1.kiki`, with different parser algorithms.
+//! Each of the two modules below runs the same tests
+//! against one of the parsers.
+
+macro_rules! lr1_not_lalr1_tests {
+    ($example:ident) => {
+        mod $example {
+            use crate::examples::$example::{parse, Foo, Token, X, Y};
+
+            use pretty_assertions::assert_eq;
+
+            #[test]
+            fn axd() {
+                let actual = parse(lex("a c d")).unwrap();
+                let expected = Foo::Axd(Box::new(X));
+                assert_eq!(expected, actual)
+            }
+
+            #[test]
+            fn aye() {
+                let actual = parse(lex("a c e")).unwrap();
+                let expected = Foo::Aye(Box::new(Y));
+                assert_eq!(expected, actual)
+            }
+
+            #[test]
+            fn byd() {
+                let actual = parse(lex("b c d")).unwrap();
+                let expected = Foo::Byd(Box::new(Y));
+                assert_eq!(expected, actual)
+            }
+
+            #[test]
+            fn bxe() {
+                let actual = parse(lex("b c e")).unwrap();
+                let expected = Foo::Bxe(Box::new(X));
+                assert_eq!(expected, actual)
+            }
+
+            #[test]
+            fn unexpected_c() {
+                let actual = parse(lex("a c c"));
+                assert_eq!(Err(Some(Token::C(()))), actual)
+            }
+
+            #[test]
+            fn unexpected_eof() {
+                let actual = parse(lex("b c"));
+                assert_eq!(Err(None), actual)
+            }
+
+            fn lex(src: &str) -> Vec<Token> {
+                src.split_whitespace()
+                    .map(|word| match word {
+                        "a" => Token::A(()),
+                        "b" => Token::B(()),
+                        "c" => Token::C(()),
+                        "d" => Token::D(()),
+                        "e" => Token::E(()),
+                        _ => panic!("unexpected word {word:?}"),
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+lr1_not_lalr1_tests!(canonical_lr1);
+lr1_not_lalr1_tests!(minimal_lr1);
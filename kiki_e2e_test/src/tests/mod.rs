@@ -1,7 +1,34 @@
+// Some of the `PartialEq` impls in these tests are written as
+// exhaustive matches for symmetry with their sibling impls.
+#![allow(clippy::match_like_matches_macro)]
+#![allow(clippy::match_single_binding)]
+
+mod arithmetic;
 mod balanced_parens;
 mod balanced_parens_esoteric;
 mod balanced_parens_with_comments;
 mod balanced_parens_with_outer_attributes;
+mod compressed_tables;
+mod default_reductions;
+mod doc_comments;
+mod error_recovery;
+mod error_recovery_with_spans;
+mod extern_literal_terminals;
+mod extern_terminal_enum_call;
+mod extern_terminal_enum_sum;
+mod generics;
 mod json;
 mod json_with_outer_attributes;
+mod lazy_default_reductions;
+mod lexer;
+mod lexer_with_spans;
+mod literal_terminals;
+mod lr1_not_lalr1;
+mod multiple_starts;
 mod nonempty_unitlike_fieldset;
+mod optional;
+mod repetition;
+mod semantic_actions;
+mod separated_list;
+mod spans;
+mod test_utils;
@@ -0,0 +1,68 @@
+use crate::examples::multiple_starts::{
+    parse_expr, parse_statement, parse_statement_verbose, Expr, QuasiterminalKind, Statement, Token,
+};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn statement() {
+    let actual = parse_statement(lex("let x = y + z ;")).unwrap();
+    let expected = Statement {
+        name: "x".to_owned(),
+        value: Box::new(add(var("y"), "z")),
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn expr() {
+    let actual = parse_expr(lex("a + b + c")).unwrap();
+    let expected = add(add(var("a"), "b"), "c");
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn expr_parser_rejects_statement() {
+    let actual = parse_expr(lex("let x = y ;"));
+    assert_eq!(Err(Some(Token::LetKw(()))), actual)
+}
+
+#[test]
+fn statement_parser_rejects_expr() {
+    let actual = parse_statement(lex("y + z"));
+    assert_eq!(Err(Some(Token::Ident("y".to_owned()))), actual)
+}
+
+#[test]
+fn expr_parser_rejects_trailing_semicolon() {
+    let actual = parse_expr(lex("y ;"));
+    assert_eq!(Err(Some(Token::Semicolon(()))), actual)
+}
+
+#[test]
+fn verbose_statement_parser_reports_expected_eq() {
+    let err = parse_statement_verbose(lex("let x y ;")).unwrap_err();
+    assert_eq!(Some(Token::Ident("y".to_owned())), err.unexpected);
+    assert_eq!(2, err.token_index);
+    assert_eq!(vec![QuasiterminalKind::Eq], err.expected);
+}
+
+fn lex(src: &str) -> Vec<Token> {
+    src.split_whitespace()
+        .map(|word| match word {
+            "let" => Token::LetKw(()),
+            "=" => Token::Eq(()),
+            "+" => Token::Plus(()),
+            ";" => Token::Semicolon(()),
+            _ => Token::Ident(word.to_owned()),
+        })
+        .collect()
+}
+
+fn var(name: &str) -> Expr {
+    Expr::Var(name.to_owned())
+}
+
+fn add(left: Expr, right: &str) -> Expr {
+    Expr::Add(Box::new(left), right.to_owned())
+}
@@ -0,0 +1,77 @@
+use crate::examples::optional::{parse, Function, Param, ReturnType, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn no_param_no_return_type() {
+    let actual = parse(lex("fn foo ( )")).unwrap();
+    let expected = Function {
+        name: "foo".to_owned(),
+        param: None,
+        return_type: None,
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn param() {
+    let actual = parse(lex("fn foo ( x : i32 )")).unwrap();
+    let expected = Function {
+        name: "foo".to_owned(),
+        param: Some(Box::new(Param {
+            name: "x".to_owned(),
+            type_: "i32".to_owned(),
+        })),
+        return_type: None,
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn return_type() {
+    let actual = parse(lex("fn foo ( ) -> bool")).unwrap();
+    let expected = Function {
+        name: "foo".to_owned(),
+        param: None,
+        return_type: Some(Box::new(ReturnType {
+            type_: "bool".to_owned(),
+        })),
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn param_return_type_and_skipped_semicolon() {
+    let actual = parse(lex("fn foo ( x : i32 ) -> bool ;")).unwrap();
+    let expected = Function {
+        name: "foo".to_owned(),
+        param: Some(Box::new(Param {
+            name: "x".to_owned(),
+            type_: "i32".to_owned(),
+        })),
+        return_type: Some(Box::new(ReturnType {
+            type_: "bool".to_owned(),
+        })),
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn optional_symbol_appears_at_most_once() {
+    let actual = parse(lex("fn foo ( ) ; ;"));
+    assert_eq!(Err(Some(Token::Semicolon(()))), actual)
+}
+
+fn lex(src: &str) -> Vec<Token> {
+    src.split_whitespace()
+        .map(|word| match word {
+            "fn" => Token::FnKw(()),
+            "(" => Token::LParen(()),
+            ")" => Token::RParen(()),
+            ":" => Token::Colon(()),
+            "->" => Token::Arrow(()),
+            ";" => Token::Semicolon(()),
+            _ => Token::Ident(word.to_owned()),
+        })
+        .collect()
+}
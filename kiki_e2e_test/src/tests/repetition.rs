@@ -0,0 +1,84 @@
+use crate::examples::repetition::{parse, File, Statement, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn empty_file() {
+    let actual = parse([]).unwrap();
+    let expected = File { statements: vec![] };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn print_one_num() {
+    let actual = parse(lex("print 1 ;")).unwrap();
+    let expected = File {
+        statements: vec![print(vec![1])],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn print_many_nums() {
+    let actual = parse(lex("print 1 2 3 ;")).unwrap();
+    let expected = File {
+        statements: vec![print(vec![1, 2, 3])],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn print_requires_at_least_one_num() {
+    let actual = parse(lex("print ;"));
+    assert_eq!(Err(Some(Token::Semicolon(()))), actual)
+}
+
+#[test]
+fn statements_are_in_source_order() {
+    let actual = parse(lex("print 1 ; print 2 ; print 3 ;")).unwrap();
+    let expected = File {
+        statements: vec![print(vec![1]), print(vec![2]), print(vec![3])],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn nested_blocks() {
+    let actual = parse(lex("{ print 1 ; { } print 2 ; } print 3 ;")).unwrap();
+    let expected = File {
+        statements: vec![
+            block(vec![print(vec![1]), block(vec![]), print(vec![2])]),
+            print(vec![3]),
+        ],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn skipped_repetition_accepts_any_number_of_semicolons() {
+    let actual = parse(lex("{ } { } ; { } ; ; ;")).unwrap();
+    let expected = File {
+        statements: vec![block(vec![]), block(vec![]), block(vec![])],
+    };
+    assert_eq!(expected, actual)
+}
+
+fn print(nums: Vec<i32>) -> Statement {
+    Statement::Print(nums)
+}
+
+fn block(statements: Vec<Statement>) -> Statement {
+    Statement::Block { statements }
+}
+
+fn lex(src: &str) -> Vec<Token> {
+    src.split_whitespace()
+        .map(|word| match word {
+            "print" => Token::PrintKw(()),
+            ";" => Token::Semicolon(()),
+            "{" => Token::LCurly(()),
+            "}" => Token::RCurly(()),
+            _ => Token::Num(word.parse().unwrap()),
+        })
+        .collect()
+}
@@ -0,0 +1,67 @@
+use crate::examples::semantic_actions::{parse, Assignment, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn single_number() {
+    let actual = parse(lex("x = 42")).unwrap();
+    let expected = assignment("x", vec![42]);
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn negative_number() {
+    let actual = parse(lex("x = - 42")).unwrap();
+    let expected = assignment("x", vec![-42]);
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn precedence() {
+    let actual = parse(lex("x = 1 + 2 * 3 - 4")).unwrap();
+    let expected = assignment("x", vec![3]);
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn left_associativity() {
+    let actual = parse(lex("x = 10 - 3 - 2")).unwrap();
+    let expected = assignment("x", vec![5]);
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn parentheses() {
+    let actual = parse(lex("x = ( 1 + 2 ) * - 3 , 10 - ( 3 - 2 )")).unwrap();
+    let expected = assignment("x", vec![-9, 9]);
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn unbalanced_parentheses() {
+    let actual = parse(lex("x = ( 1 + 2"));
+    assert_eq!(Err(None), actual)
+}
+
+fn lex(src: &str) -> Vec<Token> {
+    src.split_whitespace()
+        .map(|word| match word {
+            "=" => Token::Eq(()),
+            "," => Token::Comma(()),
+            "+" => Token::Plus(()),
+            "-" => Token::Minus(()),
+            "*" => Token::Star(()),
+            "(" => Token::LParen(()),
+            ")" => Token::RParen(()),
+            _ if word.chars().all(|c| c.is_ascii_digit()) => Token::Num(word.to_owned()),
+            _ => Token::Ident(word.to_owned()),
+        })
+        .collect()
+}
+
+fn assignment(name: &str, values: Vec<i64>) -> Assignment {
+    Assignment {
+        name: name.to_owned(),
+        values,
+    }
+}
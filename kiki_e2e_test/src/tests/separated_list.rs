@@ -0,0 +1,139 @@
+use crate::examples::separated_list::{parse, Call, Expr, File, Import, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn nonempty_list_with_one_element() {
+    let actual = parse(lex("import a ;")).unwrap();
+    let expected = File {
+        imports: vec![import(&["a"])],
+        calls: vec![],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn nonempty_list_with_many_elements() {
+    let actual = parse(lex("import a , b , c ;")).unwrap();
+    let expected = File {
+        imports: vec![import(&["a", "b", "c"])],
+        calls: vec![],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn nonempty_list_rejects_empty_list() {
+    let actual = parse(lex("import ;"));
+    assert_eq!(Err(Some(Token::Semicolon(()))), actual)
+}
+
+#[test]
+fn nontrailing_list_rejects_trailing_separator() {
+    let actual = parse(lex("import a , ;"));
+    assert_eq!(Err(Some(Token::Semicolon(()))), actual)
+}
+
+#[test]
+fn possibly_empty_list_accepts_empty_list() {
+    let actual = parse(lex("f ( ) ;")).unwrap();
+    let expected = File {
+        imports: vec![],
+        calls: vec![call("f", vec![])],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn possibly_empty_list_with_many_elements() {
+    let actual = parse(lex("f ( 1 , 2 , 3 ) ;")).unwrap();
+    let expected = File {
+        imports: vec![],
+        calls: vec![call("f", vec![num(1), num(2), num(3)])],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn trailing_list_accepts_trailing_separator() {
+    let actual = parse(lex("f ( [ 1 , 2 , ] ) ;")).unwrap();
+    let expected = File {
+        imports: vec![],
+        calls: vec![call("f", vec![Expr::Array(vec![num(1), num(2)])])],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn trailing_list_accepts_missing_trailing_separator() {
+    let actual = parse(lex("f ( [ 1 , 2 ] ) ;")).unwrap();
+    let expected = File {
+        imports: vec![],
+        calls: vec![call("f", vec![Expr::Array(vec![num(1), num(2)])])],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn possibly_empty_trailing_list_accepts_empty_list() {
+    let actual = parse(lex("f ( [ ] ) ;")).unwrap();
+    let expected = File {
+        imports: vec![],
+        calls: vec![call("f", vec![Expr::Array(vec![])])],
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn possibly_empty_trailing_list_rejects_lone_separator() {
+    let actual = parse(lex("f ( [ , ] ) ;"));
+    assert_eq!(Err(Some(Token::Comma(()))), actual)
+}
+
+#[test]
+fn nonempty_trailing_list() {
+    let actual = parse(lex("f ( ( 1 , ) , ( 2 , 3 ) ) ;")).unwrap();
+    let expected = File {
+        imports: vec![],
+        calls: vec![call(
+            "f",
+            vec![Expr::Tuple(vec![num(1)]), Expr::Tuple(vec![num(2), num(3)])],
+        )],
+    };
+    assert_eq!(expected, actual)
+}
+
+fn import(names: &[&str]) -> Import {
+    Import {
+        names: names.iter().map(|name| name.to_string()).collect(),
+    }
+}
+
+fn call(callee: &str, args: Vec<Expr>) -> Call {
+    Call {
+        callee: callee.to_owned(),
+        args,
+    }
+}
+
+fn num(n: i32) -> Expr {
+    Expr::Num(n)
+}
+
+fn lex(src: &str) -> Vec<Token> {
+    src.split_whitespace()
+        .map(|word| match word {
+            "import" => Token::ImportKw(()),
+            "," => Token::Comma(()),
+            ";" => Token::Semicolon(()),
+            "(" => Token::LParen(()),
+            ")" => Token::RParen(()),
+            "[" => Token::LBracket(()),
+            "]" => Token::RBracket(()),
+            _ => match word.parse() {
+                Ok(n) => Token::Num(n),
+                Err(_) => Token::Ident(word.to_owned()),
+            },
+        })
+        .collect()
+}
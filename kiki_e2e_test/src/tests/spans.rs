@@ -0,0 +1,89 @@
+use crate::examples::spans::{parse, Block, OptTypeAnnotation, Stmt, Token};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn empty_block() {
+    let actual = parse(lex("{ }")).unwrap();
+    let expected = Block {
+        stmts: vec![],
+        span: 0..3,
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn empty_rule_is_positioned_at_end_of_previous_node() {
+    let actual = parse(lex("{ let x ; }")).unwrap();
+    let expected = Block {
+        stmts: vec![Stmt::Let {
+            name: "x".to_owned(),
+            type_: Box::new(OptTypeAnnotation::None { span: 7..7 }),
+            span: 2..9,
+        }],
+        span: 0..11,
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn span_covers_skipped_children() {
+    let actual = parse(lex("{ let x : T ; }")).unwrap();
+    let expected = Block {
+        stmts: vec![Stmt::Let {
+            name: "x".to_owned(),
+            type_: Box::new(OptTypeAnnotation::Some("T".to_owned(), 8..11)),
+            span: 2..13,
+        }],
+        span: 0..15,
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn nested() {
+    let actual = parse(lex("{ ; { } }")).unwrap();
+    let expected = Block {
+        stmts: vec![
+            Stmt::Empty { span: 2..3 },
+            Stmt::Nested(
+                Box::new(Block {
+                    stmts: vec![],
+                    span: 4..7,
+                }),
+                4..7,
+            ),
+        ],
+        span: 0..9,
+    };
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn unexpected_token_has_span() {
+    let actual = parse(lex("{ let }"));
+    assert_eq!(Err(Some((Token::RCurly(()), 6..7))), actual)
+}
+
+#[test]
+fn unexpected_eof() {
+    let actual = parse(lex("{ let x ;"));
+    assert_eq!(Err(None), actual)
+}
+
+fn lex(src: &str) -> Vec<(Token, std::ops::Range<usize>)> {
+    src.split_whitespace()
+        .map(|word| {
+            let start = word.as_ptr() as usize - src.as_ptr() as usize;
+            let token = match word {
+                "{" => Token::LCurly(()),
+                "}" => Token::RCurly(()),
+                ";" => Token::Semicolon(()),
+                ":" => Token::Colon(()),
+                "let" => Token::LetKw(()),
+                _ => Token::Ident(word.to_owned()),
+            };
+            (token, start..start + word.len())
+        })
+        .collect()
+}
@@ -0,0 +1,16 @@
+const ARITHMETIC_WORDS: [&str; 9] = ["1", "<", "+", "-", "*", "/", "^", "(", ")"];
+
+/// Calls `f` on every input of up to `max_len` words,
+/// where each word is one of the terminals of `arithmetic.kiki`.
+pub fn for_each_arithmetic_input(max_len: u32, mut f: impl FnMut(&str)) {
+    for len in 0..=max_len {
+        for n in 0..ARITHMETIC_WORDS.len().pow(len) {
+            let words: Vec<&str> = (0..len)
+                .map(|i| {
+                    ARITHMETIC_WORDS[n / ARITHMETIC_WORDS.len().pow(i) % ARITHMETIC_WORDS.len()]
+                })
+                .collect();
+            f(&words.join(" "));
+        }
+    }
+}